@@ -1,5 +1,10 @@
 use crate::cache::EntryCache;
-use crate::monday::{MondayClient, MondayUser};
+use crate::config::Config;
+use crate::monday::{Board, CreateItemInput, MondayClient, MondayUser};
+use crate::notify;
+use crate::prompts::PromptConfig;
+use crate::target::sum_hours_by_date;
+use crate::utils::ProgressTracker;
 use crate::{
     calculate_working_dates, get_year_group_id, map_activity_type_to_value, normalize_date,
     validate_date,
@@ -8,7 +13,7 @@ use anyhow::{anyhow, Result};
 use chrono::prelude::*;
 use serde_json::json;
 use std::io;
-use tokio::time;
+use std::path::{Path, PathBuf};
 
 #[allow(clippy::too_many_arguments)]
 pub async fn handle_add_command(
@@ -19,27 +24,37 @@ pub async fn handle_add_command(
     activity_type: Option<String>,
     customer: Option<String>,
     work_item: Option<String>,
-    hours: Option<f64>,
+    hours: Option<HoursArg>,
     days: Option<f64>,
+    hours_per_day: Option<f64>,
     comment: Option<String>,
+    customer_file: Option<PathBuf>,
+    from_file: Option<PathBuf>,
+    include_weekends: bool,
+    config: &Config,
     refresh_cache: bool,
+    notify: bool,
+    dry_run: bool,
+    force: bool,
     yes: bool,
     verbose: bool,
 ) -> Result<()> {
     // Handle cache refresh if requested
     let mut cache = EntryCache::load().unwrap_or_else(|_| EntryCache::new());
 
-    if refresh_cache || cache.is_stale(24) {
+    if refresh_cache || cache.needs_refresh(config.cache_ttl_hours) {
         if verbose || refresh_cache {
             println!("🔄 Refreshing cache from last 4 weeks...");
         }
 
         // Query last 4 weeks (28 days)
-        let today = Local::now().naive_local().date();
+        let today = config.today();
         let start_date = today - chrono::Duration::days(28);
 
         // Get the group ID for the current year
-        let board = client.get_board_with_groups("6500270039", verbose).await?;
+        let board = client
+            .get_board_with_groups(&config.board_id, verbose)
+            .await?;
         let group_id = get_year_group_id(&board, current_year);
 
         if verbose {
@@ -53,7 +68,7 @@ pub async fn handle_add_command(
         // We'll filter by date on the client side to avoid API limits
         let all_items = client
             .query_items_with_filters(
-                "6500270039",
+                &config.board_id,
                 &group_id,
                 user.id,
                 &[], // Empty date filter - get all items for the user
@@ -99,6 +114,34 @@ pub async fn handle_add_command(
         }
     }
 
+    let hours_total = hours.as_ref().map(HoursArg::total);
+    let hours_given = hours.is_some();
+
+    if let Some(path) = customer_file {
+        if matches!(hours, Some(HoursArg::PerItem(_))) {
+            return Err(anyhow!(
+                "Hours per work item (e.g. 4:4) need several comma-separated --wi work items"
+            ));
+        }
+        return add_from_customer_file(
+            client,
+            user,
+            current_year,
+            &mut cache,
+            config,
+            &path,
+            date,
+            activity_type,
+            round_hours(hours_total, config.round_hours_to),
+            comment,
+            notify,
+            dry_run,
+            yes,
+            verbose,
+        )
+        .await;
+    }
+
     let (
         final_date,
         final_activity_type,
@@ -112,32 +155,49 @@ pub async fn handle_add_command(
         && activity_type.is_none()
         && customer.is_none()
         && work_item.is_none()
-        && hours.is_none()
+        && hours_total.is_none()
         && days.is_none()
+        && hours_per_day.is_none()
         && comment.is_none()
+        && from_file.is_none()
     {
-        let (d, at, c, wi, h, d_val, cmt) = prompt_for_claim_details(&cache, user.id)?;
+        let (d, at, c, wi, h, d_val, cmt) = prompt_for_claim_details(
+            &mut cache,
+            user.id,
+            &config.prompts,
+            config.default_hours_per_day(),
+            config.today(),
+        )?;
         (d, at, c, wi, h, d_val, cmt, true)
     } else {
         if let Some(ref d) = date {
-            validate_date(d)?;
+            validate_date(d, config.today())?;
         }
+        // Entries without --hours get --hours-per-day (with --days) or the
+        // configured day length on every day
+        let hours_total = match (hours_total, days) {
+            (Some(hours), _) => Some(hours),
+            (None, Some(_)) => hours_per_day.or(Some(config.default_hours_per_day())),
+            (None, None) => Some(config.default_hours_per_day()),
+        };
         (
             date.unwrap_or_default(),
             activity_type,
-            customer,
+            customer.or_else(|| config.default_customer()),
             work_item,
-            hours,
+            hours_total,
             days,
             comment,
             false,
         )
     };
 
+    let final_hours = round_hours(final_hours, config.round_hours_to);
+
     let final_date = if final_date.is_empty() {
-        Local::now().format("%Y-%m-%d").to_string()
+        config.today().format("%Y-%m-%d").to_string()
     } else {
-        final_date
+        normalize_date(&final_date, config.today())
     };
 
     let activity_type_str = final_activity_type.unwrap_or_else(|| "billable".to_string());
@@ -171,9 +231,195 @@ pub async fn handle_add_command(
         final_work_item
     };
 
+    // A comma-separated work item creates one entry per work item and date
+    let work_items: Vec<Option<String>> = match final_work_item.as_deref().map(split_work_items) {
+        Some(items) if !items.is_empty() => items.into_iter().map(Some).collect(),
+        _ => vec![final_work_item.clone()],
+    };
+    let split_hours = match hours {
+        Some(HoursArg::PerItem(per_item)) => Some(HoursArg::PerItem(per_item)),
+        _ => final_hours.map(HoursArg::Total),
+    };
+    let mut item_hours = hours_per_work_item(split_hours.as_ref(), work_items.len())?;
+    if work_items.len() > 1 {
+        item_hours = item_hours
+            .into_iter()
+            .map(|h| round_hours(h, config.round_hours_to))
+            .collect();
+    }
+
+    let allowed_check: Vec<&str> = work_items.iter().flatten().map(String::as_str).collect();
+    if !confirm_allowed_work_items(config, &allowed_check, yes)? {
+        println!("Operation cancelled.");
+        return Ok(());
+    }
+
+    // --from-file: one entry per `date,hours` line, all sharing the activity
+    // type, customer, work item and comment; invalid lines are skipped
+    if let Some(path) = from_file {
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        let (rows, invalid) = parse_hours_file(&content, config.round_hours_to, config.today());
+        for error in &invalid {
+            println!("⚠️  Skipping {} {}", path.display(), error);
+        }
+        if rows.is_empty() {
+            return Err(anyhow!("{} has no valid date,hours lines", path.display()));
+        }
+        for (date, _) in &rows {
+            config.ensure_week_unlocked(*date)?;
+        }
+
+        println!("\n=== Adding Claims from {} ===", path.display());
+        println!(
+            "Activity Type: {} (value: {})",
+            config.activity_label(activity_type_value),
+            activity_type_value
+        );
+        println!(
+            "Customer: {}",
+            final_customer.as_deref().unwrap_or("Not specified")
+        );
+        println!(
+            "Work Item: {}",
+            final_work_item.as_deref().unwrap_or("Not specified")
+        );
+        println!(
+            "Comment: {}",
+            final_comment.as_deref().unwrap_or("Not specified")
+        );
+        println!("\n📅 Dates that will be created ({}):", rows.len());
+        for (i, (date, hours)) in rows.iter().enumerate() {
+            println!(
+                "  {}. {} ({}): {}h",
+                i + 1,
+                date.format("%Y-%m-%d"),
+                date.format("%A"),
+                hours
+            );
+        }
+
+        let board = client
+            .get_board_with_groups(&config.board_id, verbose)
+            .await?;
+        if !force
+            && !check_daily_hours_limit(client, config, user, &board, &rows, dry_run, verbose)
+                .await?
+        {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+
+        let item_count = rows.len() * work_items.len();
+        if dry_run {
+            for (date, hours) in &rows {
+                let group_id = get_year_group_id(&board, &date.year().to_string());
+                let item_hours =
+                    hours_per_work_item(Some(&HoursArg::Total(*hours)), work_items.len())?;
+                for (work_item, hours) in work_items.iter().zip(item_hours) {
+                    show_graphql_mutations(
+                        &[*date],
+                        &activity_type_value,
+                        &final_customer,
+                        work_item,
+                        &final_comment,
+                        hours,
+                        user.id,
+                        &user.name,
+                        &config.board_id,
+                        &group_id,
+                    );
+                }
+            }
+            println!(
+                "\nDry run: nothing was created. Run without --dry-run to create the {} item(s).",
+                item_count
+            );
+            return Ok(());
+        }
+        if !yes {
+            println!("\n🚀 Ready to create {} item(s) on Monday.com", item_count);
+            println!("Do you want to proceed? (y/N)");
+            let mut confirmation = String::new();
+            io::stdin().read_line(&mut confirmation)?;
+            if confirmation.trim().to_lowercase() != "y" {
+                println!("Operation cancelled.");
+                return Ok(());
+            }
+        }
+
+        let mut created = 0;
+        let mut failures = Vec::new();
+        for (date, hours) in &rows {
+            let group_id = get_year_group_id(&board, &date.year().to_string());
+            let item_hours = hours_per_work_item(Some(&HoursArg::Total(*hours)), work_items.len())?;
+            for (work_item, hours) in work_items.iter().zip(item_hours) {
+                match create_items_on_monday(
+                    client,
+                    &[*date],
+                    activity_type_value,
+                    &final_customer,
+                    work_item,
+                    &final_comment,
+                    hours,
+                    user.id,
+                    &user.name,
+                    &config.board_id,
+                    &group_id,
+                    verbose,
+                )
+                .await
+                {
+                    Ok(count) => created += count,
+                    Err(e) => failures.push(format!("{}: {}", date.format("%Y-%m-%d"), e)),
+                }
+            }
+        }
+        for failure in &failures {
+            eprintln!("❌ {}", failure);
+        }
+        let result = if failures.is_empty() {
+            Ok(created)
+        } else {
+            Err(anyhow!(
+                "{} of {} item(s) failed to create",
+                failures.len(),
+                item_count
+            ))
+        };
+        if notify {
+            notify_add_result(&result);
+        }
+        result?;
+
+        if let (Some(customer), Some((last_date, _))) = (&final_customer, rows.last()) {
+            for work_item in work_items.iter().flatten() {
+                if !customer.is_empty() && !work_item.is_empty() {
+                    cache.add_entry(user.id, customer.clone(), work_item.clone(), *last_date);
+                }
+            }
+            if let Err(e) = cache.save() {
+                if verbose {
+                    println!("⚠️  Warning: Failed to save cache: {}", e);
+                }
+            }
+        }
+        return Ok(());
+    }
+
     let start_date = chrono::NaiveDate::parse_from_str(&final_date, "%Y-%m-%d")?;
     let target_days = days_value as i64;
-    let actual_dates = calculate_working_dates(start_date, target_days);
+    let actual_dates = calculate_working_dates(start_date, target_days, !include_weekends);
+    for date in &actual_dates {
+        config.ensure_week_unlocked(*date)?;
+    }
+
+    if let (false, false, Some(per_day)) = (hours_given, is_interactive, final_hours) {
+        println!(
+            "{}",
+            hours_per_day_summary(per_day, days_value, actual_dates.len())
+        );
+    }
 
     println!("\n=== Adding Claim for User ===");
     println!(
@@ -185,7 +431,8 @@ pub async fn handle_add_command(
     println!("Date: {}", final_date);
     println!(
         "Activity Type: {} (value: {})",
-        activity_type_str, activity_type_value
+        config.activity_label(activity_type_value),
+        activity_type_value
     );
     println!(
         "Customer: {}",
@@ -208,104 +455,828 @@ pub async fn handle_add_command(
     println!("Days requested: {}", days_value);
     println!("Actual working days: {}", actual_dates.len());
 
-    println!("\n📅 Dates that will be created (weekends skipped):");
+    println!(
+        "\n📅 Dates that will be created ({}):",
+        if include_weekends {
+            "weekends included"
+        } else {
+            "weekends skipped"
+        }
+    );
     for (i, date) in actual_dates.iter().enumerate() {
         let weekday = date.format("%A");
         println!("  {}. {} ({})", i + 1, date.format("%Y-%m-%d"), weekday);
     }
 
     let board = client
-        .query_board_verbose("6500270039", current_year, user.id, 1, verbose)
+        .query_board_verbose(&config.board_id, current_year, user.id, 1, verbose)
         .await?;
     let group_id = get_year_group_id(&board, current_year);
 
-    if verbose {
+    if verbose || dry_run {
+        if verbose {
+            println!(
+                "\n🔍 Verbose mode: Found group '{}' with ID: {}",
+                current_year, group_id
+            );
+        }
+        for (work_item, hours) in work_items.iter().zip(&item_hours) {
+            show_graphql_mutations(
+                &actual_dates,
+                &activity_type_value,
+                &final_customer,
+                work_item,
+                &final_comment,
+                *hours,
+                user.id,
+                &user.name,
+                &config.board_id,
+                &group_id,
+            );
+        }
+    } else {
+        println!("\nFound group '{}' with ID: {}", current_year, group_id);
+    }
+
+    let weekend_note = if include_weekends {
+        " (weekend entries included)"
+    } else {
+        ""
+    };
+
+    if let (true, Some(total)) = (work_items.len() > 1, final_hours) {
+        let names: Vec<&str> = work_items.iter().flatten().map(String::as_str).collect();
+        println!("\n{}", split_summary(total, &names, &item_hours));
+    }
+
+    let added_per_day: f64 = item_hours.iter().flatten().sum();
+    if !force && added_per_day > 0.0 {
+        let added: Vec<(NaiveDate, f64)> =
+            actual_dates.iter().map(|d| (*d, added_per_day)).collect();
+        if !check_daily_hours_limit(client, config, user, &board, &added, dry_run, verbose).await? {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    }
+
+    let item_count = actual_dates.len() * work_items.len();
+    if dry_run {
         println!(
-            "\n🔍 Verbose mode: Found group '{}' with ID: {}",
-            current_year, group_id
+            "\nDry run: nothing was created. Run without --dry-run to create the {} item(s){}.",
+            item_count, weekend_note
         );
-        show_graphql_mutations(
+        return Ok(());
+    }
+    if !yes {
+        println!(
+            "\n🚀 Ready to create {} item(s) on Monday.com{}",
+            item_count, weekend_note
+        );
+        println!("Do you want to proceed? (y/N)");
+
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+
+        if confirmation.trim().to_lowercase() != "y" {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    } else {
+        println!(
+            "\n🚀 Creating {} item(s) on Monday.com{} (skipping confirmation)",
+            item_count, weekend_note
+        );
+    }
+
+    let mut created = 0;
+    let mut result = Ok(0);
+    for (work_item, hours) in work_items.iter().zip(&item_hours) {
+        result = create_items_on_monday(
+            client,
             &actual_dates,
-            &activity_type_value,
+            activity_type_value,
             &final_customer,
-            &final_work_item,
+            work_item, // Now using the auto-set work item
             &final_comment,
-            final_hours,
+            *hours,
             user.id,
             &user.name,
+            &config.board_id,
             &group_id,
+            verbose,
+        )
+        .await;
+        match &result {
+            Ok(count) => created += count,
+            Err(_) => break,
+        }
+    }
+    let result = result.map(|_| created);
+    if notify {
+        notify_add_result(&result);
+    }
+    result?;
+
+    print_today_total(client, config, user, &board, verbose).await;
+
+    // Save the used client-workitem pairs to cache after successful add
+    if let Some(ref customer) = final_customer {
+        for work_item in work_items.iter().flatten() {
+            if !customer.is_empty() && !work_item.is_empty() {
+                cache.add_entry(user.id, customer.clone(), work_item.clone(), start_date);
+                if let Err(e) = cache.save() {
+                    if verbose {
+                        println!("⚠️  Warning: Failed to save cache: {}", e);
+                    }
+                }
+            }
+        }
+    }
+
+    if is_interactive {
+        show_equivalent_command(
+            &final_date,
+            &activity_type_str,
+            &final_customer,
+            &final_work_item,
+            &final_comment,
+            final_hours,
+            days_value,
+            yes,
+            verbose,
         );
+    }
+
+    Ok(())
+}
+
+/// Parses the `date,hours` lines of an `add --from-file` file, skipping blank
+/// lines, `#` comments and a header row. Returns the valid rows and a message
+/// for every invalid line.
+fn parse_hours_file(
+    content: &str,
+    round_hours_to: Option<f64>,
+    today: NaiveDate,
+) -> (Vec<(NaiveDate, f64)>, Vec<String>) {
+    let mut rows = Vec::new();
+    let mut invalid = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let parsed = line
+            .split_once(',')
+            .ok_or_else(|| anyhow!("expected date,hours"))
+            .and_then(|(date, hours)| {
+                let date = date.trim();
+                validate_date(date, today)?;
+                let date = NaiveDate::parse_from_str(&normalize_date(date, today), "%Y-%m-%d")?;
+                let hours: f64 = hours
+                    .trim()
+                    .parse()
+                    .map_err(|_| anyhow!("invalid hours: {}", hours.trim()))?;
+                if !(hours > 0.0 && hours <= 24.0) {
+                    return Err(anyhow!("hours must be between 0 and 24, got {}", hours));
+                }
+                Ok((
+                    date,
+                    round_hours(Some(hours), round_hours_to).unwrap_or(hours),
+                ))
+            });
+        match parsed {
+            Ok(row) => rows.push(row),
+            Err(_) if index == 0 && line.to_lowercase().starts_with("date") => {}
+            Err(e) => invalid.push(format!("line {}: {}", index + 1, e)),
+        }
+    }
+    (rows, invalid)
+}
+
+/// Resolves `--customer-prefix` against `customer_prefixes` in the config,
+/// asking the user to pick when several customers match
+pub fn resolve_customer_prefix_arg(config: &Config, prefix: &str) -> Result<String> {
+    if let Some(customer) = config.resolve_customer_prefix(prefix) {
+        println!("Customer prefix '{}' resolved to {}", prefix, customer);
+        return Ok(customer.to_string());
+    }
+
+    let matches = config.customers_matching_prefix(prefix);
+    if matches.is_empty() {
+        return Err(anyhow!(
+            "No customer in customer_prefixes starts with '{}'. Add the full name to customer_prefixes in the config file.",
+            prefix
+        ));
+    }
+
+    println!("\nSeveral customers start with '{}':", prefix);
+    for (i, customer) in matches.iter().enumerate() {
+        println!("  {}. {}", i + 1, customer);
+    }
+    println!("Select a customer (1-{}):", matches.len());
+
+    let mut selection = String::new();
+    io::stdin().read_line(&mut selection)?;
+    selection
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_sub(1))
+        .and_then(|i| matches.get(i))
+        .map(|customer| customer.to_string())
+        .ok_or_else(|| anyhow!("Invalid selection: {}", selection.trim()))
+}
+
+/// Value of `--hours`: a total, or one value per work item (`4:4`)
+#[derive(Debug, Clone, PartialEq)]
+pub enum HoursArg {
+    Total(f64),
+    PerItem(Vec<f64>),
+}
+
+impl HoursArg {
+    /// Hours across all work items
+    fn total(&self) -> f64 {
+        match self {
+            HoursArg::Total(hours) => *hours,
+            HoursArg::PerItem(hours) => hours.iter().sum(),
+        }
+    }
+}
+
+/// Parses `--hours`: a number, or colon-separated hours per work item
+pub fn parse_hours_arg(value: &str) -> std::result::Result<HoursArg, String> {
+    let hours = value
+        .split(':')
+        .map(|part| part.trim().parse::<f64>())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|_| format!("Invalid hours: {} (use e.g. 8 or 4:4)", value))?;
+
+    match hours.as_slice() {
+        [total] => Ok(HoursArg::Total(*total)),
+        _ => Ok(HoursArg::PerItem(hours)),
+    }
+}
+
+/// Splits a comma-separated work item value, dropping empty parts
+fn split_work_items(work_item: &str) -> Vec<String> {
+    work_item
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Hours for each of `count` work items: per-item hours as given, otherwise
+/// the total split equally
+fn hours_per_work_item(hours: Option<&HoursArg>, count: usize) -> Result<Vec<Option<f64>>> {
+    match hours {
+        None => Ok(vec![None; count]),
+        Some(HoursArg::Total(total)) => Ok(vec![Some(total / count as f64); count]),
+        Some(HoursArg::PerItem(per_item)) if per_item.len() == count => {
+            Ok(per_item.iter().copied().map(Some).collect())
+        }
+        Some(HoursArg::PerItem(per_item)) => Err(anyhow!(
+            "--hours gives {} values but {} work item(s) were given",
+            per_item.len(),
+            count
+        )),
+    }
+}
+
+/// Describes hours derived from `--days`, e.g. "Using 8.0 hours/day × 3 days =
+/// 24.0 total hours, split across 3 entries (8.0h each)"
+fn hours_per_day_summary(hours_per_day: f64, days: f64, entries: usize) -> String {
+    format!(
+        "Using {:.1} hours/day × {} day{} = {:.1} total hours, split across {} entr{} ({:.1}h each)",
+        hours_per_day,
+        days,
+        if days == 1.0 { "" } else { "s" },
+        hours_per_day * days,
+        entries,
+        if entries == 1 { "y" } else { "ies" },
+        hours_per_day
+    )
+}
+
+/// Describes how `total` hours are split, e.g. "Splitting 8h across 2 work
+/// items: 4h each for PROJ-123 and PROJ-124."
+fn split_summary(total: f64, work_items: &[&str], hours: &[Option<f64>]) -> String {
+    let join = |parts: Vec<String>| match parts.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+        _ => parts.join(""),
+    };
+    let hours_label = |h: &Option<f64>| h.map(|h| format!("{}h", h)).unwrap_or_default();
+
+    let detail = if hours.windows(2).all(|pair| pair[0] == pair[1]) {
+        format!(
+            "{} each for {}",
+            hours.first().map(hours_label).unwrap_or_default(),
+            join(work_items.iter().map(|w| w.to_string()).collect())
+        )
     } else {
-        println!("\nFound group '{}' with ID: {}", current_year, group_id);
+        join(
+            work_items
+                .iter()
+                .zip(hours)
+                .map(|(w, h)| format!("{} for {}", hours_label(h), w))
+                .collect(),
+        )
+    };
+
+    format!(
+        "Splitting {}h across {} work items: {}.",
+        total,
+        work_items.len(),
+        detail
+    )
+}
+
+/// Applies the configured hours rounding, telling the user when the value changed
+fn round_hours(hours: Option<f64>, round_hours_to: Option<f64>) -> Option<f64> {
+    match (hours, round_hours_to) {
+        (Some(h), Some(nearest)) => {
+            let rounded = crate::utils::round_to_nearest(h, nearest);
+            if rounded != h {
+                println!(
+                    "Hours rounded from {} to {} ({} rounding enabled)",
+                    h,
+                    rounded,
+                    crate::utils::rounding_label(nearest)
+                );
+            }
+            Some(rounded)
+        }
+        (hours, _) => hours,
+    }
+}
+
+/// Parses a `customer:work_item` line from a customer file
+fn parse_customer_work_item_line(line: &str) -> Result<(String, String)> {
+    let (customer, work_item) = line
+        .split_once(':')
+        .ok_or_else(|| anyhow!("Expected 'customer:work_item', got '{}'", line.trim()))?;
+
+    let customer = customer.trim();
+    let work_item = work_item.trim();
+    if customer.is_empty() || work_item.is_empty() {
+        return Err(anyhow!(
+            "Both customer and work item are required, got '{}'",
+            line.trim()
+        ));
+    }
+
+    Ok((customer.to_string(), work_item.to_string()))
+}
+
+/// Reads all `customer:work_item` pairs from a file, skipping blank lines and `#` comments
+fn read_customer_file(path: &Path) -> Result<Vec<(String, String)>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read customer file {}: {}", path.display(), e))?;
+
+    let mut pairs = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let pair = parse_customer_work_item_line(trimmed)
+            .map_err(|e| anyhow!("{} line {}: {}", path.display(), index + 1, e))?;
+        pairs.push(pair);
+    }
+
+    if pairs.is_empty() {
+        return Err(anyhow!(
+            "Customer file {} contains no entries",
+            path.display()
+        ));
+    }
+
+    Ok(pairs)
+}
+
+/// Warns about work items missing from `allowed_work_items` and asks whether
+/// to continue. With `yes` the warning is still printed but not confirmed.
+pub(crate) fn confirm_allowed_work_items(
+    config: &Config,
+    work_items: &[&str],
+    yes: bool,
+) -> Result<bool> {
+    let mut unknown: Vec<&str> = work_items
+        .iter()
+        .copied()
+        .filter(|item| !config.is_work_item_allowed(item))
+        .collect();
+    if unknown.is_empty() {
+        return Ok(true);
+    }
+    unknown.dedup();
+
+    println!(
+        "\n⚠️  Work item {} not in the allowed list (see `claim config list-work-items`)",
+        if unknown.len() == 1 {
+            format!("{} is", unknown[0])
+        } else {
+            format!("{} are", unknown.join(", "))
+        }
+    );
+    if yes {
+        return Ok(true);
+    }
+
+    println!("Use it anyway? (y/N)");
+    let mut confirmation = String::new();
+    io::stdin().read_line(&mut confirmation)?;
+    Ok(confirmation.trim().to_lowercase() == "y")
+}
+
+/// Compares the hours `added` on each date with `daily_hours_limit` and asks
+/// whether to continue when a day would go over it
+#[allow(clippy::too_many_arguments)]
+async fn check_daily_hours_limit(
+    client: &MondayClient,
+    config: &Config,
+    user: &MondayUser,
+    board: &Board,
+    added: &[(NaiveDate, f64)],
+    dry_run: bool,
+    verbose: bool,
+) -> Result<bool> {
+    let dates: Vec<NaiveDate> = added.iter().map(|(date, _)| *date).collect();
+    let existing = existing_hours_by_date(client, config, user, board, &dates, verbose).await?;
+    let over_limit = days_over_limit(&existing, added, config.daily_hours_limit);
+    confirm_daily_hours_limit(&over_limit, config.daily_hours_limit, dry_run)
+}
+
+/// Hours already logged on each of `dates`
+async fn existing_hours_by_date(
+    client: &MondayClient,
+    config: &Config,
+    user: &MondayUser,
+    board: &Board,
+    dates: &[NaiveDate],
+    verbose: bool,
+) -> Result<std::collections::HashMap<NaiveDate, f64>> {
+    let mut hours = std::collections::HashMap::new();
+    let mut years: Vec<i32> = dates.iter().map(|d| d.year()).collect();
+    years.sort_unstable();
+    years.dedup();
+    for year in years {
+        let group_id = get_year_group_id(board, &year.to_string());
+        let date_strings: Vec<String> = dates
+            .iter()
+            .filter(|d| d.year() == year)
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .collect();
+        let items = client
+            .query_items_with_filters(
+                &config.board_id,
+                &group_id,
+                user.id,
+                &date_strings,
+                500,
+                verbose,
+            )
+            .await?;
+        hours.extend(sum_hours_by_date(&items));
+    }
+    Ok(hours)
+}
+
+/// Days whose total would go over `limit` once the `added` hours are
+/// logged, with the hours already logged and the hours added there
+fn days_over_limit(
+    existing: &std::collections::HashMap<NaiveDate, f64>,
+    added: &[(NaiveDate, f64)],
+    limit: f64,
+) -> Vec<(NaiveDate, f64, f64)> {
+    let mut added_by_date: std::collections::BTreeMap<NaiveDate, f64> = Default::default();
+    for (date, hours) in added {
+        *added_by_date.entry(*date).or_default() += hours;
+    }
+    added_by_date
+        .into_iter()
+        .map(|(date, added)| (date, existing.get(&date).copied().unwrap_or(0.0), added))
+        .filter(|(_, logged, added)| logged + added > limit)
+        .collect()
+}
+
+/// Warns about days going over `daily_hours_limit` and asks whether to
+/// continue, even with `--yes`; `--force` skips the check
+fn confirm_daily_hours_limit(
+    over_limit: &[(NaiveDate, f64, f64)],
+    limit: f64,
+    dry_run: bool,
+) -> Result<bool> {
+    if over_limit.is_empty() {
+        return Ok(true);
+    }
+
+    println!(
+        "\n⚠️  Over the daily limit of {}h (daily_hours_limit):",
+        limit
+    );
+    for (date, logged, added) in over_limit {
+        println!(
+            "  {} ({}): {}h logged, {}h after adding {}h",
+            date.format("%Y-%m-%d"),
+            date.format("%A"),
+            logged,
+            logged + added,
+            added
+        );
+    }
+    if dry_run {
+        return Ok(true);
+    }
+
+    println!("Add the hours anyway? (y/N) (use --force to skip this check)");
+    let mut confirmation = String::new();
+    io::stdin().read_line(&mut confirmation)?;
+    Ok(confirmation.trim().to_lowercase() == "y")
+}
+
+/// Creates one entry per `customer:work_item` line of `path` on a single date,
+/// all sharing the same activity type, hours and comment
+#[allow(clippy::too_many_arguments)]
+async fn add_from_customer_file(
+    client: &MondayClient,
+    user: &MondayUser,
+    current_year: &str,
+    cache: &mut EntryCache,
+    config: &Config,
+    path: &Path,
+    date: Option<String>,
+    activity_type: Option<String>,
+    hours: Option<f64>,
+    comment: Option<String>,
+    notify: bool,
+    dry_run: bool,
+    yes: bool,
+    verbose: bool,
+) -> Result<()> {
+    let pairs = read_customer_file(path)?;
+
+    let date = match date {
+        Some(d) => {
+            validate_date(&d, config.today())?;
+            normalize_date(&d, config.today())
+        }
+        None => config.today().format("%Y-%m-%d").to_string(),
+    };
+    let entry_date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")?;
+    config.ensure_week_unlocked(entry_date)?;
+
+    let activity_type_str = activity_type.unwrap_or_else(|| "billable".to_string());
+    let activity_type_value = map_activity_type_to_value(&activity_type_str);
+    let hours_label = hours
+        .map(|h| h.to_string())
+        .unwrap_or_else(|| "-".to_string());
+
+    println!("\n=== Adding Claims from {} ===", path.display());
+    println!(
+        "Date: {}  Activity Type: {}  Comment: {}",
+        date,
+        activity_type_str,
+        comment.as_deref().unwrap_or("Not specified")
+    );
+    println!(
+        "\n{:<4} {:<30} {:<20} {:>6}",
+        "#", "Customer", "Work Item", "Hours"
+    );
+    println!("{}", "-".repeat(63));
+    for (i, (customer, work_item)) in pairs.iter().enumerate() {
+        println!(
+            "{:<4} {:<30} {:<20} {:>6}",
+            i + 1,
+            crate::utils::truncate_string(customer, 30),
+            crate::utils::truncate_string(work_item, 20),
+            hours_label
+        );
+    }
+    if let Some(h) = hours {
+        println!("{}", "-".repeat(63));
+        println!("{:<56} {:>6}", "Total", h * pairs.len() as f64);
+    }
+
+    let work_items: Vec<&str> = pairs.iter().map(|(_, w)| w.as_str()).collect();
+    if !confirm_allowed_work_items(config, &work_items, yes)? {
+        println!("Operation cancelled.");
+        return Ok(());
+    }
+
+    let board = client
+        .query_board_verbose(&config.board_id, current_year, user.id, 1, verbose)
+        .await?;
+    let group_id = get_year_group_id(&board, current_year);
+
+    if dry_run {
+        for (customer, work_item) in &pairs {
+            show_graphql_mutations(
+                &[entry_date],
+                &activity_type_value,
+                &Some(customer.clone()),
+                &Some(work_item.clone()),
+                &comment,
+                hours,
+                user.id,
+                &user.name,
+                &config.board_id,
+                &group_id,
+            );
+        }
+        println!(
+            "Dry run: nothing was created. Run without --dry-run to create the {} item(s).",
+            pairs.len()
+        );
+        return Ok(());
+    }
+
+    if !yes {
+        println!("\n🚀 Ready to create {} item(s) on Monday.com", pairs.len());
+        println!("Do you want to proceed? (y/N)");
+
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+
+        if confirmation.trim().to_lowercase() != "y" {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    }
+
+    for (customer, work_item) in &pairs {
+        let result = create_items_on_monday(
+            client,
+            &[entry_date],
+            activity_type_value,
+            &Some(customer.clone()),
+            &Some(work_item.clone()),
+            &comment,
+            hours,
+            user.id,
+            &user.name,
+            &config.board_id,
+            &group_id,
+            verbose,
+        )
+        .await;
+        if notify && result.is_err() {
+            notify_add_result(&result);
+        }
+        result?;
+        cache.add_entry(user.id, customer.clone(), work_item.clone(), entry_date);
+    }
+
+    if notify {
+        notify_add_result(&Ok(pairs.len()));
+    }
+
+    print_today_total(client, config, user, &board, verbose).await;
+
+    if let Err(e) = cache.save() {
+        if verbose {
+            println!("⚠️  Warning: Failed to save cache: {}", e);
+        }
     }
 
-    if !yes {
-        println!(
-            "\n🚀 Ready to create {} item(s) on Monday.com",
-            actual_dates.len()
-        );
-        println!("Do you want to proceed? (y/N)");
+    Ok(())
+}
 
-        let mut confirmation = String::new();
-        io::stdin().read_line(&mut confirmation)?;
+/// Prints today's logged hours against the daily target. Failures only
+/// produce a warning since the entries have already been created.
+async fn print_today_total(
+    client: &MondayClient,
+    config: &Config,
+    user: &MondayUser,
+    board: &Board,
+    verbose: bool,
+) {
+    let today = config.today();
+    let group_id = get_year_group_id(board, &today.year().to_string());
+    let dates = [today.format("%Y-%m-%d").to_string()];
 
-        if confirmation.trim().to_lowercase() != "y" {
-            println!("Operation cancelled.");
-            return Ok(());
+    match client
+        .query_items_with_filters(&config.board_id, &group_id, user.id, &dates, 100, verbose)
+        .await
+    {
+        Ok(items) => {
+            let total = sum_hours_by_date(&items).values().sum();
+            println!(
+                "\n{}",
+                today_total_line(total, config.weekly_target_hours() / 5.0)
+            );
         }
-    } else {
-        println!(
-            "\n🚀 Creating {} item(s) on Monday.com (skipping confirmation)",
-            actual_dates.len()
-        );
+        Err(e) => println!("⚠️  Warning: Failed to fetch today's total: {}", e),
     }
+}
 
-    create_items_on_monday(
-        client,
-        &actual_dates,
-        activity_type_value,
-        &final_customer,
-        &final_work_item, // Now using the auto-set work item
-        &final_comment,
-        final_hours,
-        user.id,
-        &user.name,
-        &group_id,
-        verbose,
-    )
-    .await?;
+fn today_total_line(total: f64, target: f64) -> String {
+    if total >= target {
+        format!(
+            "📊 Today's total: {:.1}h (target: {:.1}h ✅)",
+            total, target
+        )
+    } else {
+        format!(
+            "📊 Today's total: {:.1}h (target: {:.1}h — {:.1}h remaining)",
+            total,
+            target,
+            target - total
+        )
+    }
+}
 
-    // Save the used client-workitem pair to cache after successful add
-    if let (Some(ref customer), Some(ref work_item)) = (&final_customer, &final_work_item) {
-        if !customer.is_empty() && !work_item.is_empty() {
-            cache.add_entry(user.id, customer.clone(), work_item.clone(), start_date);
-            if let Err(e) = cache.save() {
-                if verbose {
-                    println!("⚠️  Warning: Failed to save cache: {}", e);
-                }
-            }
+/// Handles `claim add --append-to ID --hours N`: adds hours to an existing
+/// entry instead of creating a new one
+pub async fn append_hours_to_entry(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    entry_id: &str,
+    hours: Option<HoursArg>,
+    verbose: bool,
+) -> Result<()> {
+    let added = match hours {
+        Some(HoursArg::Total(hours)) => round_hours(Some(hours), config.round_hours_to)
+            .ok_or_else(|| anyhow!("--append-to needs --hours"))?,
+        Some(HoursArg::PerItem(_)) => {
+            return Err(anyhow!("--append-to takes a single --hours value"))
         }
+        None => return Err(anyhow!("--append-to needs --hours")),
+    };
+
+    let item = client
+        .get_item_by_id(entry_id, verbose)
+        .await?
+        .ok_or_else(|| anyhow!("No entry found with ID {}", entry_id))?;
+    if !crate::monday::is_user_item(&item, user.id) {
+        return Err(anyhow!("Entry {} belongs to another user", entry_id));
     }
+    let date =
+        extract_date_from_item(&item).ok_or_else(|| anyhow!("Entry {} has no date", entry_id))?;
+    config.ensure_week_unlocked(date)?;
 
-    if is_interactive {
-        show_equivalent_command(
-            &final_date,
-            &activity_type_str,
-            &final_customer,
-            &final_work_item,
-            &final_comment,
-            final_hours,
-            days_value,
-            yes,
+    let old_hours = crate::query::extract_column_value(&item, "numbers__1")
+        .trim_matches('"')
+        .parse::<f64>()
+        .unwrap_or(0.0);
+
+    // The other entries of the day count towards max_daily_hours too
+    let board = client
+        .get_board_with_groups(&config.board_id, verbose)
+        .await?;
+    let group_id = get_year_group_id(&board, &date.year().to_string());
+    let day_items = client
+        .query_items_with_filters(
+            &config.board_id,
+            &group_id,
+            user.id,
+            &[date.format("%Y-%m-%d").to_string()],
+            100,
             verbose,
-        );
-    }
+        )
+        .await?;
+    let day_total = sum_hours_by_date(&day_items)
+        .get(&date)
+        .copied()
+        .unwrap_or(old_hours);
+    let new_hours = appended_hours(old_hours, added, day_total, config.max_daily_hours)?;
+
+    let column_values = json!({ "numbers__1": new_hours.to_string() });
+    client
+        .update_item_verbose(&config.board_id, entry_id, &column_values, verbose)
+        .await?;
 
+    println!(
+        "✅ Updated entry ID {}: {:.1}h → {:.1}h ({:+.1}h)",
+        entry_id, old_hours, new_hours, added
+    );
     Ok(())
 }
 
+/// Hours of an entry after adding `added` to its `old_hours`, failing when
+/// the day's total (`day_total` includes `old_hours`) would go over
+/// `max_daily_hours`
+fn appended_hours(old_hours: f64, added: f64, day_total: f64, max_daily_hours: f64) -> Result<f64> {
+    if added <= 0.0 {
+        return Err(anyhow!("--hours must be positive, got {}", added));
+    }
+    if day_total + added > max_daily_hours {
+        return Err(anyhow!(
+            "Adding {}h would bring the day to {}h, over the maximum of {}h (max_daily_hours)",
+            added,
+            day_total + added,
+            max_daily_hours
+        ));
+    }
+    Ok(old_hours + added)
+}
+
+/// Creates one item per date, returning how many were created
 #[allow(clippy::too_many_arguments)]
-async fn create_items_on_monday(
+pub(crate) async fn create_items_on_monday(
     client: &MondayClient,
     actual_dates: &[NaiveDate],
     activity_type_value: u8,
@@ -315,77 +1286,72 @@ async fn create_items_on_monday(
     hours: Option<f64>,
     user_id: i64,
     user_name: &str,
+    board_id: &str,
     group_id: &str,
     verbose: bool,
-) -> Result<()> {
-    let board_id = "6500270039";
-    let mut successful_creations = 0;
+) -> Result<usize> {
+    let mut progress = ProgressTracker::new(actual_dates.len());
 
     println!("\n🔄 Creating items on Monday.com...");
 
-    for (i, date) in actual_dates.iter().enumerate() {
-        let date_str = date.format("%Y-%m-%d").to_string();
-
-        let mut column_values = json!({});
-
-        // Set person column
-        column_values["person"] = json!({
-            "personsAndTeams": [
-                {
-                    "id": user_id,
-                    "kind": "person"
-                }
-            ]
-        });
-
-        // Set date column
-        column_values["date4"] = json!({
-            "date": date_str
-        });
-
-        // Set activity type column
-        column_values["status"] = json!({
-            "index": activity_type_value
-        });
-
-        // Set customer name if provided
-        if let Some(c) = customer {
-            if !c.is_empty() {
-                column_values["text__1"] = json!(c);
-            }
-        }
-
-        // Set work item if provided
-        if let Some(wi) = work_item {
-            if !wi.is_empty() {
-                column_values["text8__1"] = json!(wi);
-            }
-        }
-
-        // FIXED: Set comment using the correct column ID "text2__1"
-        if let Some(cmt) = comment {
-            if !cmt.is_empty() {
-                column_values["text2__1"] = json!(cmt);
-                if verbose {
-                    println!("   Setting comment in column 'text2__1': '{}'", cmt);
+    if actual_dates.len() > 1 {
+        // Several dates: send the mutations in batches to save round trips
+        let items: Vec<CreateItemInput> = actual_dates
+            .iter()
+            .map(|date| CreateItemInput {
+                board_id: board_id.to_string(),
+                group_id: group_id.to_string(),
+                item_name: user_name.to_string(),
+                column_values: build_item_column_values(
+                    *date,
+                    activity_type_value,
+                    customer,
+                    work_item,
+                    comment,
+                    hours,
+                    user_id,
+                    verbose,
+                ),
+            })
+            .collect();
+
+        let results = client.batch_create_items(&items, verbose).await;
+        for (date, result) in actual_dates.iter().zip(results) {
+            let date_str = date.format("%Y-%m-%d");
+            match result {
+                Ok(item_id) => progress.tick_success(&format!(
+                    "Successfully created item for {} with ID: {}",
+                    date_str, item_id
+                )),
+                Err(e) => {
+                    progress.tick_failure(&format!("Failed to create item for {}: {}", date_str, e))
                 }
             }
         }
-
-        // Set hours if provided
-        if let Some(h) = hours {
-            column_values["numbers__1"] = json!(h.to_string());
-        }
-
-        if verbose {
-            println!(
-                "\n📋 GraphQL Mutation for {} ({} of {}):",
-                date_str,
-                i + 1,
-                actual_dates.len()
+    } else {
+        // A single date goes through the plain create_item mutation
+        for (i, date) in actual_dates.iter().enumerate() {
+            let date_str = date.format("%Y-%m-%d").to_string();
+            let column_values = build_item_column_values(
+                *date,
+                activity_type_value,
+                customer,
+                work_item,
+                comment,
+                hours,
+                user_id,
+                verbose,
             );
-            let mutation = format!(
-                r#"mutation {{
+
+            if verbose {
+                println!(
+                    "\n📋 GraphQL Mutation for {} ({} of {}):",
+                    date_str,
+                    i + 1,
+                    actual_dates.len()
+                );
+                let mutation = format!(
+                    r#"mutation {{
     create_item(
         board_id: "{}",
         group_id: "{}",
@@ -395,52 +1361,126 @@ async fn create_items_on_monday(
         id
     }}
 }}"#,
-                board_id,
-                group_id,
-                user_name,
-                column_values.to_string().replace('"', "\\\"")
-            );
-            println!("{}", mutation);
+                    board_id,
+                    group_id,
+                    user_name,
+                    column_values.to_string().replace('"', "\\\"")
+                );
+                println!("{}", mutation);
+            }
+
+            match client
+                .create_item_verbose(board_id, group_id, user_name, &column_values, verbose)
+                .await
+            {
+                Ok(item_id) => {
+                    progress.tick_success(&format!(
+                        "Successfully created item for {} with ID: {}",
+                        date_str, item_id
+                    ));
+                }
+                Err(e) => {
+                    progress
+                        .tick_failure(&format!("Failed to create item for {}: {}", date_str, e));
+                }
+            }
         }
+    }
 
-        println!(
-            "Creating item for {} ({} of {})...",
-            date_str,
-            i + 1,
-            actual_dates.len()
-        );
+    println!("\n🎉 {}", progress.summary());
 
-        match client
-            .create_item_verbose(board_id, group_id, user_name, &column_values, verbose)
-            .await
-        {
-            Ok(item_id) => {
-                println!("✅ Successfully created item with ID: {}", item_id);
-                successful_creations += 1;
-            }
-            Err(e) => {
-                println!("❌ Failed to create item for {}: {}", date_str, e);
-                // Continue with other items even if one fails
+    if !progress.all_succeeded() {
+        return Err(anyhow!(
+            "{} of {} items failed to create. Check the errors above.",
+            progress.failed,
+            progress.total
+        ));
+    }
+
+    Ok(progress.completed)
+}
+
+/// Sends the `--notify` completion notification for an add operation
+fn notify_add_result(result: &Result<usize>) {
+    match result {
+        Ok(count) => notify::notify_completion(
+            true,
+            &format!(
+                "Added {} {}",
+                count,
+                if *count == 1 { "entry" } else { "entries" }
+            ),
+        ),
+        Err(e) => notify::notify_completion(false, &format!("Add failed — {}", e)),
+    }
+}
+
+/// Builds the column values of a new item for `date`
+#[allow(clippy::too_many_arguments)]
+fn build_item_column_values(
+    date: NaiveDate,
+    activity_type_value: u8,
+    customer: &Option<String>,
+    work_item: &Option<String>,
+    comment: &Option<String>,
+    hours: Option<f64>,
+    user_id: i64,
+    verbose: bool,
+) -> serde_json::Value {
+    let date_str = date.format("%Y-%m-%d").to_string();
+
+    let mut column_values = json!({});
+
+    // Set person column
+    column_values["person"] = json!({
+        "personsAndTeams": [
+            {
+                "id": user_id,
+                "kind": "person"
             }
+        ]
+    });
+
+    // Set date column
+    column_values["date4"] = json!({
+        "date": date_str
+    });
+
+    // Set activity type column
+    column_values["status"] = json!({
+        "index": activity_type_value
+    });
+
+    // Set customer name if provided
+    if let Some(c) = customer {
+        if !c.is_empty() {
+            column_values["text__1"] = json!(c);
         }
+    }
 
-        // Add a small delay to avoid rate limiting
-        time::sleep(time::Duration::from_millis(200)).await;
+    // Set work item if provided
+    if let Some(wi) = work_item {
+        if !wi.is_empty() {
+            column_values["text8__1"] = json!(wi);
+        }
     }
 
-    println!(
-        "\n🎉 Successfully created {} out of {} items",
-        successful_creations,
-        actual_dates.len()
-    );
+    // FIXED: Set comment using the correct column ID "text2__1"
+    if let Some(cmt) = comment {
+        if !cmt.is_empty() {
+            column_values["text2__1"] = json!(cmt);
+            if verbose {
+                println!("   Setting comment in column 'text2__1': '{}'", cmt);
+            }
+        }
+    }
 
-    if successful_creations < actual_dates.len() {
-        return Err(anyhow!(
-            "Some items failed to create. Check the errors above."
-        ));
+    // Set hours if provided
+    if let Some(h) = hours {
+        column_values["numbers__1"] = json!(h.to_string());
     }
 
-    Ok(())
+    column_values
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -453,12 +1493,11 @@ fn show_graphql_mutations(
     hours: Option<f64>,
     user_id: i64,
     user_name: &str,
+    board_id: &str,
     group_id: &str,
 ) {
     println!("\n📋 GraphQL Mutations that would be executed:");
 
-    let board_id = "6500270039";
-
     for (i, date) in actual_dates.iter().enumerate() {
         let date_str = date.format("%Y-%m-%d").to_string();
 
@@ -604,8 +1643,11 @@ fn show_equivalent_command(
 
 #[allow(clippy::type_complexity)]
 fn prompt_for_claim_details(
-    cache: &EntryCache,
+    cache: &mut EntryCache,
     user_id: i64,
+    prompts: &PromptConfig,
+    default_hours: f64,
+    today: NaiveDate,
 ) -> Result<(
     String,
     Option<String>,
@@ -629,7 +1671,7 @@ fn prompt_for_claim_details(
                 i + 1,
                 entry.customer,
                 entry.work_item,
-                entry.last_used
+                entry.last_used.format("%Y-%m-%d")
             );
         }
         println!("\nYou can select an entry by number, or enter details manually.");
@@ -648,11 +1690,15 @@ fn prompt_for_claim_details(
                         "\n✅ Selected: {} | {}",
                         selected.customer, selected.work_item
                     );
+                    cache.mark_used(user_id, &selected.customer, &selected.work_item);
 
                     // Continue with the rest of the prompts using the selected entry
                     return prompt_with_preselected_entry(
                         Some(selected.customer.clone()),
                         Some(selected.work_item.clone()),
+                        prompts,
+                        default_hours,
+                        today,
                     );
                 }
             }
@@ -663,7 +1709,7 @@ fn prompt_for_claim_details(
 
     // Date (optional, defaults to today)
     let mut date = String::new();
-    print!("Date (YYYY-MM-DD, YYYY.MM.DD, or YYYY/MM/DD, optional - default: today): ");
+    print!("{}", prompts.date_prompt);
     io::stdout().flush()?;
     io::stdin().read_line(&mut date)?;
     date = date.trim().to_string();
@@ -671,12 +1717,12 @@ fn prompt_for_claim_details(
     // If date is provided, validate it
     if !date.is_empty() {
         // Basic date validation with flexible separators
-        if validate_date_flexible(&date).is_ok() {
+        if validate_date_flexible(&date, today).is_ok() {
             // Normalize the date to YYYY-MM-DD format
-            date = normalize_date(&date);
+            date = normalize_date(&date, today);
         } else {
             println!(
-                "Invalid date format. Please use YYYY-MM-DD, YYYY.MM.DD, or YYYY/MM/DD format."
+                "Invalid date format. Please use YYYY-MM-DD, YYYY.MM.DD, YYYY/MM/DD, or a relative date such as yesterday."
             );
             return Err(anyhow!("Invalid date format"));
         }
@@ -697,7 +1743,7 @@ fn prompt_for_claim_details(
     println!("10 - intellectual_capital");
     println!("11 - business_development");
     println!("12 - overhead");
-    print!("\nActivity type (enter number or name, optional - default: billable): ");
+    print!("\n{}", prompts.activity_type_prompt);
     io::stdout().flush()?;
     let mut activity_type = String::new();
     io::stdin().read_line(&mut activity_type)?;
@@ -757,7 +1803,7 @@ fn prompt_for_claim_details(
     };
 
     // Customer name (optional)
-    print!("Customer name (optional): ");
+    print!("{}", prompts.customer_prompt);
     io::stdout().flush()?;
     let mut customer = String::new();
     io::stdin().read_line(&mut customer)?;
@@ -769,7 +1815,7 @@ fn prompt_for_claim_details(
     };
 
     // Work item (optional)
-    print!("Work item (optional): ");
+    print!("{}", prompts.work_item_prompt);
     io::stdout().flush()?;
     let mut work_item = String::new();
     io::stdin().read_line(&mut work_item)?;
@@ -781,7 +1827,7 @@ fn prompt_for_claim_details(
     };
 
     // Comment (optional)
-    print!("Comment (optional): ");
+    print!("{}", prompts.comment_prompt);
     io::stdout().flush()?;
     let mut comment = String::new();
     io::stdin().read_line(&mut comment)?;
@@ -792,26 +1838,29 @@ fn prompt_for_claim_details(
         Some(comment)
     };
 
-    // Hours (optional)
-    print!("Number of hours (optional): ");
+    // Hours (defaults to `default_hours_per_day`)
+    print!("{}[{}] ", prompts.hours_prompt, default_hours);
     io::stdout().flush()?;
     let mut hours = String::new();
     io::stdin().read_line(&mut hours)?;
     let hours = hours.trim();
     let hours = if hours.is_empty() {
-        None
+        Some(default_hours)
     } else {
         match hours.parse::<f64>() {
             Ok(h) => Some(h),
             Err(_) => {
-                println!("Invalid number format for hours. Skipping.");
-                None
+                println!(
+                    "Invalid number format for hours. Using {} hours.",
+                    default_hours
+                );
+                Some(default_hours)
             }
         }
     };
 
     // Days (optional, defaults to 1)
-    print!("Number of working days (optional, default: 1, skips weekends): ");
+    print!("{}", prompts.days_prompt);
     io::stdout().flush()?;
     let mut days = String::new();
     io::stdin().read_line(&mut days)?;
@@ -858,12 +1907,12 @@ fn normalize_activity_type_input(input: &str) -> String {
     }
 }
 
-fn validate_date_flexible(date_str: &str) -> Result<()> {
-    validate_date(date_str)
+fn validate_date_flexible(date_str: &str, today: NaiveDate) -> Result<()> {
+    validate_date(date_str, today)
 }
 
 // Helper function to extract customer from item
-fn extract_customer_from_item(item: &crate::monday::Item) -> String {
+pub(crate) fn extract_customer_from_item(item: &crate::monday::Item) -> String {
     for col in &item.column_values {
         if let Some(id) = &col.id {
             if id == "text__1" {
@@ -879,7 +1928,7 @@ fn extract_customer_from_item(item: &crate::monday::Item) -> String {
 }
 
 // Helper function to extract work item from item
-fn extract_work_item_from_item(item: &crate::monday::Item) -> String {
+pub(crate) fn extract_work_item_from_item(item: &crate::monday::Item) -> String {
     for col in &item.column_values {
         if let Some(id) = &col.id {
             if id == "text8__1" {
@@ -895,7 +1944,7 @@ fn extract_work_item_from_item(item: &crate::monday::Item) -> String {
 }
 
 // Helper function to extract date from item
-fn extract_date_from_item(item: &crate::monday::Item) -> Option<NaiveDate> {
+pub(crate) fn extract_date_from_item(item: &crate::monday::Item) -> Option<NaiveDate> {
     for col in &item.column_values {
         if let Some(id) = &col.id {
             if id == "date4" {
@@ -922,6 +1971,9 @@ fn extract_date_from_item(item: &crate::monday::Item) -> Option<NaiveDate> {
 fn prompt_with_preselected_entry(
     customer: Option<String>,
     work_item: Option<String>,
+    prompts: &PromptConfig,
+    default_hours: f64,
+    today: NaiveDate,
 ) -> Result<(
     String,
     Option<String>,
@@ -935,17 +1987,17 @@ fn prompt_with_preselected_entry(
 
     // Date (optional, defaults to today)
     let mut date = String::new();
-    print!("Date (YYYY-MM-DD, YYYY.MM.DD, or YYYY/MM/DD, optional - default: today): ");
+    print!("{}", prompts.date_prompt);
     io::stdout().flush()?;
     io::stdin().read_line(&mut date)?;
     date = date.trim().to_string();
 
     if !date.is_empty() {
-        if validate_date_flexible(&date).is_ok() {
-            date = normalize_date(&date);
+        if validate_date_flexible(&date, today).is_ok() {
+            date = normalize_date(&date, today);
         } else {
             println!(
-                "Invalid date format. Please use YYYY-MM-DD, YYYY.MM.DD, or YYYY/MM/DD format."
+                "Invalid date format. Please use YYYY-MM-DD, YYYY.MM.DD, YYYY/MM/DD, or a relative date such as yesterday."
             );
             return Err(anyhow!("Invalid date format"));
         }
@@ -966,7 +2018,7 @@ fn prompt_with_preselected_entry(
     println!("10 - intellectual_capital");
     println!("11 - business_development");
     println!("12 - overhead");
-    print!("\nActivity type (enter number or name, optional - default: billable): ");
+    print!("\n{}", prompts.activity_type_prompt);
     io::stdout().flush()?;
     let mut activity_type = String::new();
     io::stdin().read_line(&mut activity_type)?;
@@ -1021,7 +2073,7 @@ fn prompt_with_preselected_entry(
     };
 
     // Comment (optional)
-    print!("Comment (optional): ");
+    print!("{}", prompts.comment_prompt);
     io::stdout().flush()?;
     let mut comment = String::new();
     io::stdin().read_line(&mut comment)?;
@@ -1032,26 +2084,29 @@ fn prompt_with_preselected_entry(
         Some(comment)
     };
 
-    // Hours (optional)
-    print!("Number of hours (optional): ");
+    // Hours (defaults to `default_hours_per_day`)
+    print!("{}[{}] ", prompts.hours_prompt, default_hours);
     io::stdout().flush()?;
     let mut hours = String::new();
     io::stdin().read_line(&mut hours)?;
     let hours = hours.trim();
     let hours = if hours.is_empty() {
-        None
+        Some(default_hours)
     } else {
         match hours.parse::<f64>() {
             Ok(h) => Some(h),
             Err(_) => {
-                println!("Invalid number format for hours. Skipping.");
-                None
+                println!(
+                    "Invalid number format for hours. Using {} hours.",
+                    default_hours
+                );
+                Some(default_hours)
             }
         }
     };
 
     // Days (optional, defaults to 1)
-    print!("Number of working days (optional, default: 1, skips weekends): ");
+    print!("{}", prompts.days_prompt);
     io::stdout().flush()?;
     let mut days = String::new();
     io::stdin().read_line(&mut days)?;
@@ -1082,13 +2137,156 @@ fn prompt_with_preselected_entry(
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn test_appended_hours() {
+        assert_eq!(appended_hours(4.0, 4.0, 4.0, 24.0).unwrap(), 8.0);
+        // Other entries of the day count towards the maximum
+        assert_eq!(appended_hours(2.0, 2.0, 8.0, 10.0).unwrap(), 4.0);
+        assert!(appended_hours(2.0, 3.0, 8.0, 10.0).is_err());
+        assert!(appended_hours(4.0, 0.0, 4.0, 24.0).is_err());
+    }
+
+    #[test]
+    fn test_parse_hours_file() {
+        let day = |d: u32| NaiveDate::from_ymd_opt(2025, 9, d).unwrap();
+        let (rows, invalid) = parse_hours_file(
+            "date,hours\n\
+             2025-09-15,4\n\
+             # Wednesday\n\
+             \n\
+             2025.09.17, 6.5\n\
+             2025-09-18\n\
+             2025-09-19,lots\n\
+             2025-09-32,4\n\
+             2025-09-20,30\n",
+            None,
+            day(22),
+        );
+        assert_eq!(rows, [(day(15), 4.0), (day(17), 6.5)]);
+        assert_eq!(invalid.len(), 4);
+        assert!(invalid[0].starts_with("line 6:"), "{}", invalid[0]);
+
+        let (rows, _) = parse_hours_file("2025-09-15,7.4", Some(0.5), day(22));
+        assert_eq!(rows, [(day(15), 7.5)]);
+    }
+
+    #[test]
+    fn test_days_over_limit() {
+        let day = |d: u32| NaiveDate::from_ymd_opt(2025, 9, d).unwrap();
+        let existing = std::collections::HashMap::from([(day(15), 8.0), (day(16), 6.0)]);
+        let added = |hours: f64| [(day(15), hours), (day(16), hours), (day(17), hours)];
+
+        assert_eq!(
+            days_over_limit(&existing, &added(3.0), 10.0),
+            [(day(15), 8.0, 3.0)]
+        );
+        // Reaching the limit exactly is allowed
+        assert!(days_over_limit(&existing, &added(2.0), 10.0).is_empty());
+        assert_eq!(days_over_limit(&existing, &added(9.0), 8.0).len(), 3);
+        // Several entries on the same day add up
+        assert_eq!(
+            days_over_limit(&existing, &[(day(17), 6.0), (day(17), 5.0)], 10.0),
+            [(day(17), 0.0, 11.0)]
+        );
+    }
     use crate::monday::{Board, Group};
 
+    #[test]
+    fn test_parse_hours_arg() {
+        assert_eq!(parse_hours_arg("8").unwrap(), HoursArg::Total(8.0));
+        assert_eq!(
+            parse_hours_arg("5:3").unwrap(),
+            HoursArg::PerItem(vec![5.0, 3.0])
+        );
+        assert_eq!(parse_hours_arg("5:3").unwrap().total(), 8.0);
+        assert!(parse_hours_arg("4:x").is_err());
+    }
+
+    #[test]
+    fn test_split_work_items_and_hours() {
+        assert_eq!(
+            split_work_items("PROJ-123, PROJ-124,"),
+            vec!["PROJ-123", "PROJ-124"]
+        );
+
+        let equal = hours_per_work_item(Some(&HoursArg::Total(8.0)), 2).unwrap();
+        assert_eq!(equal, vec![Some(4.0), Some(4.0)]);
+        assert_eq!(
+            split_summary(8.0, &["PROJ-123", "PROJ-124"], &equal),
+            "Splitting 8h across 2 work items: 4h each for PROJ-123 and PROJ-124."
+        );
+
+        let ratio = hours_per_work_item(Some(&HoursArg::PerItem(vec![5.0, 3.0])), 2).unwrap();
+        assert_eq!(
+            split_summary(8.0, &["A", "B"], &ratio),
+            "Splitting 8h across 2 work items: 5h for A and 3h for B."
+        );
+
+        assert!(hours_per_work_item(Some(&HoursArg::PerItem(vec![5.0, 3.0])), 3).is_err());
+        assert_eq!(hours_per_work_item(None, 2).unwrap(), vec![None, None]);
+    }
+
+    #[test]
+    fn test_hours_per_day_summary() {
+        assert_eq!(
+            hours_per_day_summary(8.0, 3.0, 3),
+            "Using 8.0 hours/day × 3 days = 24.0 total hours, split across 3 entries (8.0h each)"
+        );
+        assert_eq!(
+            hours_per_day_summary(7.5, 1.0, 1),
+            "Using 7.5 hours/day × 1 day = 7.5 total hours, split across 1 entry (7.5h each)"
+        );
+    }
+
+    #[test]
+    fn test_today_total_line() {
+        assert_eq!(
+            today_total_line(8.0, 8.0),
+            "📊 Today's total: 8.0h (target: 8.0h ✅)"
+        );
+        assert_eq!(
+            today_total_line(4.0, 8.0),
+            "📊 Today's total: 4.0h (target: 8.0h — 4.0h remaining)"
+        );
+    }
+
+    #[test]
+    fn test_parse_customer_work_item_line() {
+        assert_eq!(
+            parse_customer_work_item_line("ACME : WI.1001").unwrap(),
+            ("ACME".to_string(), "WI.1001".to_string())
+        );
+        // Only the first ':' separates customer from work item
+        assert_eq!(
+            parse_customer_work_item_line("ACME:PROJ:42").unwrap(),
+            ("ACME".to_string(), "PROJ:42".to_string())
+        );
+        assert!(parse_customer_work_item_line("ACME WI.1001").is_err());
+        assert!(parse_customer_work_item_line("ACME:").is_err());
+    }
+
+    #[test]
+    fn test_read_customer_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("customers.txt");
+        std::fs::write(&path, "# today\nACME:WI.1\n\nGLOBEX:WI.2\n").unwrap();
+
+        let pairs = read_customer_file(&path).unwrap();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[1], ("GLOBEX".to_string(), "WI.2".to_string()));
+
+        std::fs::write(&path, "ACME:WI.1\nbroken line\n").unwrap();
+        let err = read_customer_file(&path).unwrap_err().to_string();
+        assert!(err.contains("line 2"), "{}", err);
+    }
+
     #[test]
     fn test_get_year_group_id() {
         let board = Board {
             id: None,
             name: None,
+            columns: Vec::new(),
             groups: Some(vec![
                 Group {
                     id: "group_2024".to_string(),
@@ -1113,6 +2311,7 @@ mod tests {
         let board = Board {
             id: None,
             name: None,
+            columns: Vec::new(),
             groups: None,
         };
 
@@ -1162,10 +2361,26 @@ mod tests {
 
     #[test]
     fn test_validate_date_flexible() {
-        assert!(validate_date_flexible("2025-09-15").is_ok());
-        assert!(validate_date_flexible("2025.09.15").is_ok());
-        assert!(validate_date_flexible("2025/09/15").is_ok());
-        assert!(validate_date_flexible("invalid-date").is_err());
+        assert!(validate_date_flexible(
+            "2025-09-15",
+            NaiveDate::from_ymd_opt(2025, 9, 17).unwrap()
+        )
+        .is_ok());
+        assert!(validate_date_flexible(
+            "2025.09.15",
+            NaiveDate::from_ymd_opt(2025, 9, 17).unwrap()
+        )
+        .is_ok());
+        assert!(validate_date_flexible(
+            "2025/09/15",
+            NaiveDate::from_ymd_opt(2025, 9, 17).unwrap()
+        )
+        .is_ok());
+        assert!(validate_date_flexible(
+            "invalid-date",
+            NaiveDate::from_ymd_opt(2025, 9, 17).unwrap()
+        )
+        .is_err());
     }
 
     #[test]
@@ -1174,13 +2389,15 @@ mod tests {
         // We can't easily test the actual IO, but we can test that the functions are properly defined
 
         // Test that the function signatures are correct
-        let result = validate_date_flexible("2025-09-15");
+        let result =
+            validate_date_flexible("2025-09-15", NaiveDate::from_ymd_opt(2025, 9, 17).unwrap());
         assert!(result.is_ok());
 
         // Test that get_year_group_id returns a string
         let board = Board {
             id: None,
             name: None,
+            columns: Vec::new(),
             groups: None,
         };
         let group_id = get_year_group_id(&board, "2025");
@@ -1220,7 +2437,7 @@ mod tests {
     fn test_date_calculation_integration() {
         // Test that the date calculation works correctly
         let start_date = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap(); // Monday
-        let dates = calculate_working_dates(start_date, 5);
+        let dates = calculate_working_dates(start_date, 5, true);
 
         // Should get 5 weekdays
         assert_eq!(dates.len(), 5);
@@ -0,0 +1,89 @@
+//! `claim boards`: lists the boards the API key can access
+
+use crate::config::Config;
+use crate::monday::{Board, MondayClient};
+use anyhow::Result;
+
+/// Handles `claim boards`
+pub async fn handle_boards_command(
+    client: &MondayClient,
+    config: &Config,
+    verbose: bool,
+) -> Result<()> {
+    let boards = client.list_user_boards(verbose).await?;
+    if boards.is_empty() {
+        println!("The API key cannot access any boards");
+        return Ok(());
+    }
+
+    println!("\n=== BOARDS ({}) ===", boards.len());
+    for (i, line) in board_lines(&boards, &config.board_id, "current")
+        .iter()
+        .enumerate()
+    {
+        println!("  {:>2}. {}", i + 1, line);
+    }
+    println!("\nSet the board with `claim config set board_id ID` or `claim init`.");
+    Ok(())
+}
+
+/// One line per board: name, ID and number of groups, with `label` after
+/// the board whose ID is `current`
+pub(crate) fn board_lines(boards: &[Board], current: &str, label: &str) -> Vec<String> {
+    boards
+        .iter()
+        .map(|board| {
+            let id = board.id.as_deref().unwrap_or_default();
+            let groups = board.groups.as_ref().map_or(0, Vec::len);
+            format!(
+                "{} ({}) — {} group{}{}",
+                board.name.as_deref().unwrap_or("Unnamed board"),
+                id,
+                groups,
+                if groups == 1 { "" } else { "s" },
+                if id == current {
+                    format!(" ({})", label)
+                } else {
+                    String::new()
+                }
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monday::Group;
+
+    fn board(id: &str, name: &str, groups: usize) -> Board {
+        Board {
+            id: Some(id.to_string()),
+            name: Some(name.to_string()),
+            columns: Vec::new(),
+            groups: Some(
+                (0..groups)
+                    .map(|i| Group {
+                        id: format!("group_{}", i),
+                        title: format!("{}", 2024 + i),
+                        items_page: None,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_board_lines() {
+        let boards = [board("6500270039", "Claims", 2), board("42", "Roadmap", 1)];
+
+        assert_eq!(
+            board_lines(&boards, "6500270039", "current"),
+            [
+                "Claims (6500270039) — 2 groups (current)",
+                "Roadmap (42) — 1 group"
+            ]
+        );
+        assert!(!board_lines(&boards, "1", "current")[0].contains("current"));
+    }
+}
@@ -0,0 +1,275 @@
+//! `claim bulk-add`: creates entries from the rows of a CSV file
+
+use crate::add::{confirm_allowed_work_items, create_items_on_monday};
+use crate::config::Config;
+use crate::formatters::parse_csv_record;
+use crate::init::parse_activity_type;
+use crate::monday::{MondayClient, MondayUser};
+use crate::truncate_string;
+use crate::utils::{
+    self, get_year_group_id, map_activity_type_to_value, normalize_date, validate_date,
+};
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate};
+use std::io;
+use std::path::Path;
+
+/// Columns of a bulk-add file, in order; the comment may be left out
+const COLUMNS: [&str; 6] = [
+    "date",
+    "activity_type",
+    "customer",
+    "work_item",
+    "hours",
+    "comment",
+];
+
+/// One entry to create
+#[derive(Debug, Clone, PartialEq)]
+struct BulkRow {
+    line: usize,
+    date: NaiveDate,
+    activity_type: String,
+    customer: String,
+    work_item: String,
+    hours: f64,
+    comment: Option<String>,
+}
+
+/// Handles `claim bulk-add --file PATH`
+pub async fn handle_bulk_add_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    path: &Path,
+    yes: bool,
+    verbose: bool,
+) -> Result<()> {
+    let rows = read_bulk_file(path, config)?;
+    for row in &rows {
+        config.ensure_week_unlocked(row.date)?;
+    }
+
+    display_preview(&rows, config);
+
+    let work_items: Vec<&str> = rows
+        .iter()
+        .map(|row| row.work_item.as_str())
+        .filter(|work_item| !work_item.is_empty())
+        .collect();
+    if !confirm_allowed_work_items(config, &work_items, yes)? {
+        println!("Operation cancelled.");
+        return Ok(());
+    }
+    if !yes {
+        println!("\n🚀 Ready to create {} item(s) on Monday.com", rows.len());
+        println!("Do you want to proceed? (y/N)");
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+        if confirmation.trim().to_lowercase() != "y" {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    }
+
+    let board_id = config.board_id.as_str();
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let mut failures = Vec::new();
+    for row in &rows {
+        let group_id = get_year_group_id(&board, &row.date.year().to_string());
+        let result = create_items_on_monday(
+            client,
+            &[row.date],
+            map_activity_type_to_value(&row.activity_type),
+            &non_empty(&row.customer),
+            &non_empty(&row.work_item),
+            &row.comment,
+            Some(row.hours),
+            user.id,
+            &user.name,
+            board_id,
+            &group_id,
+            verbose,
+        )
+        .await;
+        if let Err(e) = result {
+            failures.push(format!("line {} ({}): {}", row.line, row.date, e));
+        }
+    }
+
+    println!(
+        "\n=== BULK ADD: {} succeeded, {} failed ===",
+        rows.len() - failures.len(),
+        failures.len()
+    );
+    for failure in &failures {
+        eprintln!("❌ {}", failure);
+    }
+    if !failures.is_empty() {
+        return Err(anyhow!(
+            "{} of {} rows failed to create. Check the errors above.",
+            failures.len(),
+            rows.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Parses every row of `path`, skipping blank lines, `#` comments and an
+/// optional header row
+fn read_bulk_file(path: &Path, config: &Config) -> Result<Vec<BulkRow>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read CSV file {}: {}", path.display(), e))?;
+
+    let mut rows = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let fields = parse_csv_record(trimmed);
+        if rows.is_empty() && fields[0].trim().eq_ignore_ascii_case(COLUMNS[0]) {
+            continue;
+        }
+        let row = parse_row(&fields, index + 1, config)
+            .map_err(|e| anyhow!("{} line {}: {}", path.display(), index + 1, e))?;
+        rows.push(row);
+    }
+
+    if rows.is_empty() {
+        return Err(anyhow!("CSV file {} contains no entries", path.display()));
+    }
+    Ok(rows)
+}
+
+fn parse_row(fields: &[String], line: usize, config: &Config) -> Result<BulkRow> {
+    if !(COLUMNS.len() - 1..=COLUMNS.len()).contains(&fields.len()) {
+        return Err(anyhow!(
+            "expected the columns {} (comment optional), found {} field(s)",
+            COLUMNS.join(","),
+            fields.len()
+        ));
+    }
+    let field = |index: usize| fields.get(index).map_or("", |f| f.trim());
+
+    validate_date(field(0), config.today())?;
+    let date = NaiveDate::parse_from_str(&normalize_date(field(0), config.today()), "%Y-%m-%d")?;
+    let activity_type = parse_activity_type(field(1))?;
+    let hours: f64 = field(4)
+        .parse()
+        .map_err(|_| anyhow!("Invalid hours: {}", field(4)))?;
+    if !(hours > 0.0 && hours <= 24.0) {
+        return Err(anyhow!("Hours must be between 0 and 24, got {}", hours));
+    }
+
+    Ok(BulkRow {
+        line,
+        date,
+        activity_type,
+        customer: field(2).to_string(),
+        work_item: field(3).to_string(),
+        hours: match config.round_hours_to {
+            Some(nearest) => utils::round_to_nearest(hours, nearest),
+            None => hours,
+        },
+        comment: non_empty(field(5)),
+    })
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn display_preview(rows: &[BulkRow], config: &Config) {
+    println!("\n=== BULK ADD: {} item(s) to create ===", rows.len());
+    println!(
+        "{:<5} {:<12} {:<15} {:<20} {:<15} {:>6}  Comment",
+        "Line", "Date", "Activity Type", "Customer", "Work Item", "Hours"
+    );
+    println!("{}", "-".repeat(90));
+    for row in rows {
+        println!(
+            "{:<5} {:<12} {:<15} {:<20} {:<15} {:>5}h  {}",
+            row.line,
+            row.date.format("%Y-%m-%d"),
+            truncate_string(
+                &config.activity_label(map_activity_type_to_value(&row.activity_type)),
+                15
+            ),
+            truncate_string(&row.customer, 20),
+            truncate_string(&row.work_item, 15),
+            row.hours,
+            row.comment.as_deref().unwrap_or("")
+        );
+    }
+    let total: f64 = rows.iter().map(|row| row.hours).sum();
+    println!("{}", "-".repeat(90));
+    println!("Total: {}h", total);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(line: &str) -> Vec<String> {
+        parse_csv_record(line)
+    }
+
+    #[test]
+    fn test_parse_row() {
+        let config = Config::default();
+        let row = parse_row(
+            &fields("2025.09.15,billable,ACME,PROJ-1,7.5,\"design, review\""),
+            2,
+            &config,
+        )
+        .unwrap();
+        assert_eq!(row.date, NaiveDate::from_ymd_opt(2025, 9, 15).unwrap());
+        assert_eq!(row.activity_type, "billable");
+        assert_eq!(row.hours, 7.5);
+        assert_eq!(row.comment.as_deref(), Some("design, review"));
+
+        let row = parse_row(&fields("2025-09-16,2,Internal,,4"), 3, &config).unwrap();
+        assert_eq!(row.activity_type, "holding");
+        assert_eq!(row.comment, None);
+        assert_eq!(non_empty(&row.work_item), None);
+    }
+
+    #[test]
+    fn test_parse_row_errors() {
+        let config = Config::default();
+        for line in [
+            "2025-13-45,billable,ACME,PROJ-1,8",
+            "2025-09-15,party,ACME,PROJ-1,8",
+            "2025-09-15,billable,ACME,PROJ-1,lots",
+            "2025-09-15,billable,ACME,PROJ-1,25",
+            "2025-09-15,billable,ACME",
+        ] {
+            assert!(parse_row(&fields(line), 1, &config).is_err(), "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_read_bulk_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("entries.csv");
+        std::fs::write(
+            &path,
+            "date,activity_type,customer,work_item,hours,comment\n\
+             # week 38\n\
+             2025-09-15,billable,ACME,PROJ-1,8,\n\
+             \n\
+             2025-09-16,billable,Globex,PROJ-2,4,call\n",
+        )
+        .unwrap();
+
+        let rows = read_bulk_file(&path, &Config::default()).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].line, 3);
+        assert_eq!(rows[1].customer, "Globex");
+
+        std::fs::write(&path, "2025-09-15,billable,ACME,PROJ-1,x\n").unwrap();
+        let error = read_bulk_file(&path, &Config::default()).unwrap_err();
+        assert!(error.to_string().contains("line 1"), "{}", error);
+    }
+}
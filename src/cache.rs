@@ -1,17 +1,120 @@
 use anyhow::{anyhow, Result};
-use chrono::{DateTime, Local, NaiveDate};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use directories::ProjectDirs;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
+/// Default maximum number of cached entries kept per user
+pub const DEFAULT_MAX_CACHE_ENTRIES: usize = 50;
+
+fn default_max_entries() -> usize {
+    DEFAULT_MAX_CACHE_ENTRIES
+}
+
+/// Score of how well `query` matches `text`, ignoring case, or None when the
+/// characters of `query` do not all appear in `text` in order. Every matched
+/// character scores, with bonuses for runs of adjacent characters, for
+/// characters starting a word and for `text` containing `query` as a whole.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<u32> {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+
+    let mut score = 0;
+    let mut matched = 0;
+    let mut last_match: Option<usize> = None;
+    for (i, c) in text.iter().enumerate() {
+        if matched == query.len() {
+            break;
+        }
+        if *c != query[matched] {
+            continue;
+        }
+        score += 1;
+        if last_match.is_some_and(|last| last + 1 == i) {
+            score += 2;
+        }
+        if i == 0 || !text[i - 1].is_alphanumeric() {
+            score += 3;
+        }
+        last_match = Some(i);
+        matched += 1;
+    }
+    if matched < query.len() {
+        return None;
+    }
+    if !query.is_empty() && text.windows(query.len()).any(|w| w == query.as_slice()) {
+        score += 2 * query.len() as u32;
+    }
+    Some(score)
+}
+
+/// Best `fuzzy_score` of `query` against the customer and work item of `entry`
+fn entry_score(query: &str, entry: &CachedEntry) -> Option<u32> {
+    fuzzy_score(query, &entry.customer).max(fuzzy_score(query, &entry.work_item))
+}
+
+/// Start of `date` in UTC, the `last_used` time of a pair logged on that day
+pub fn day_start(date: NaiveDate) -> DateTime<Utc> {
+    date.and_time(chrono::NaiveTime::MIN).and_utc()
+}
+
+/// Reads `last_used` as an RFC 3339 time, or as a `YYYY-MM-DD` date from
+/// cache files written before it held the time
+fn deserialize_last_used<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<DateTime<Utc>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    DateTime::parse_from_rfc3339(&value)
+        .map(|time| time.with_timezone(&Utc))
+        .or_else(|_| NaiveDate::parse_from_str(&value, "%Y-%m-%d").map(day_start))
+        .map_err(serde::de::Error::custom)
+}
+
+/// Order of the entries returned by `get_unique_entries_sorted`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheSort {
+    /// Most recently used first
+    #[default]
+    LastUsed,
+    /// Most used first, then most recent first
+    UseCount,
+    /// Alphabetically by customer, then work item (ignoring case)
+    CustomerAlpha,
+}
+
+impl CacheSort {
+    /// The next sort order, for cycling through them in the interactive UI
+    pub fn next(self) -> Self {
+        match self {
+            CacheSort::UseCount => CacheSort::LastUsed,
+            CacheSort::LastUsed => CacheSort::CustomerAlpha,
+            CacheSort::CustomerAlpha => CacheSort::UseCount,
+        }
+    }
+
+    /// Short description shown in the interactive UI
+    pub fn label(self) -> &'static str {
+        match self {
+            CacheSort::LastUsed => "most recent",
+            CacheSort::UseCount => "most used",
+            CacheSort::CustomerAlpha => "A-Z",
+        }
+    }
+}
+
 /// Represents a cached client and work item pair
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct CachedEntry {
     pub customer: String,
     pub work_item: String,
-    pub last_used: String, // ISO 8601 date string
+    /// When the pair was last logged or picked from the cache, whichever is
+    /// later; a logged day counts from its start in UTC
+    #[serde(deserialize_with = "deserialize_last_used")]
+    pub last_used: DateTime<Utc>,
+    #[serde(default)]
+    pub use_count: u32, // Number of entries logged with this pair
 }
 
 /// Cache structure for storing recent entries per user
@@ -19,6 +122,13 @@ pub struct CachedEntry {
 pub struct EntryCache {
     pub entries: HashMap<i64, Vec<CachedEntry>>, // user_id -> entries
     pub last_updated: String,                    // ISO 8601 timestamp
+    /// When the entries were last read from Monday.com (the Unix epoch when
+    /// never), compared with `cache_ttl_hours` in the config
+    #[serde(default)]
+    pub last_refreshed: DateTime<Utc>,
+    /// Maximum entries kept per user (from `cache_max_entries` in the config)
+    #[serde(skip, default = "default_max_entries")]
+    pub max_entries: usize,
 }
 
 impl EntryCache {
@@ -27,6 +137,8 @@ impl EntryCache {
         EntryCache {
             entries: HashMap::new(),
             last_updated: Local::now().to_rfc3339(),
+            last_refreshed: DateTime::default(),
+            max_entries: DEFAULT_MAX_CACHE_ENTRIES,
         }
     }
 
@@ -41,15 +153,19 @@ impl EntryCache {
         let cache_path =
             Self::get_cache_path().ok_or_else(|| anyhow!("Could not determine cache directory"))?;
 
-        if !cache_path.exists() {
-            return Ok(Self::new());
-        }
+        let mut cache = if cache_path.exists() {
+            let cache_data = fs::read_to_string(&cache_path)
+                .map_err(|e| anyhow!("Failed to read cache file: {}", e))?;
 
-        let cache_data = fs::read_to_string(&cache_path)
-            .map_err(|e| anyhow!("Failed to read cache file: {}", e))?;
+            serde_json::from_str::<EntryCache>(&cache_data)
+                .map_err(|e| anyhow!("Failed to parse cache: {}", e))?
+        } else {
+            Self::new()
+        };
 
-        let cache: EntryCache = serde_json::from_str(&cache_data)
-            .map_err(|e| anyhow!("Failed to parse cache: {}", e))?;
+        cache.max_entries = crate::config::Config::load()
+            .map(|config| config.cache_max_entries())
+            .unwrap_or(DEFAULT_MAX_CACHE_ENTRIES);
 
         Ok(cache)
     }
@@ -74,25 +190,31 @@ impl EntryCache {
     }
 
     /// Add or update entries from query results for a specific user
-    /// Deduplicates entries and keeps the most recent date
+    /// Deduplicates entries and keeps the most recent date. The use count of
+    /// a pair found in `items` is the number of items for it; pairs missing
+    /// from `items` keep their previous count.
     pub fn update_from_items(&mut self, user_id: i64, items: &[(String, String, NaiveDate)]) {
-        // Use a HashMap to deduplicate and keep the most recent date
-        let mut entry_map: HashMap<(String, String), NaiveDate> = HashMap::new();
+        // Use a HashMap to deduplicate and keep the most recent use and use count
+        let mut entry_map: HashMap<(String, String), (DateTime<Utc>, u32)> = HashMap::new();
 
         // Add existing entries for this user to the map
         if let Some(user_entries) = self.entries.get(&user_id) {
             for entry in user_entries {
-                if let Ok(date) = NaiveDate::parse_from_str(&entry.last_used, "%Y-%m-%d") {
-                    let key = (entry.customer.clone(), entry.work_item.clone());
-                    entry_map
-                        .entry(key)
-                        .and_modify(|existing_date| {
-                            if date > *existing_date {
-                                *existing_date = date;
-                            }
-                        })
-                        .or_insert(date);
-                }
+                let key = (entry.customer.clone(), entry.work_item.clone());
+                entry_map
+                    .entry(key)
+                    .and_modify(|(last_used, _)| *last_used = (*last_used).max(entry.last_used))
+                    .or_insert((entry.last_used, entry.use_count));
+            }
+        }
+
+        // Count how often each pair appears in the new items
+        let mut counts: HashMap<(String, String), u32> = HashMap::new();
+        for (customer, work_item, _) in items {
+            if !customer.is_empty() && !work_item.is_empty() {
+                *counts
+                    .entry((customer.clone(), work_item.clone()))
+                    .or_insert(0) += 1;
             }
         }
 
@@ -100,47 +222,109 @@ impl EntryCache {
         for (customer, work_item, date) in items {
             if !customer.is_empty() && !work_item.is_empty() {
                 let key = (customer.clone(), work_item.clone());
+                let used = day_start(*date);
                 entry_map
                     .entry(key)
-                    .and_modify(|existing_date| {
-                        if *date > *existing_date {
-                            *existing_date = *date;
-                        }
-                    })
-                    .or_insert(*date);
+                    .and_modify(|(last_used, _)| *last_used = (*last_used).max(used))
+                    .or_insert((used, 0));
+            }
+        }
+        for (key, count) in counts {
+            if let Some((_, use_count)) = entry_map.get_mut(&key) {
+                *use_count = count;
             }
         }
 
         // Convert map back to vector and sort by date (most recent first)
         let mut entries: Vec<CachedEntry> = entry_map
             .into_iter()
-            .map(|((customer, work_item), date)| CachedEntry {
-                customer,
-                work_item,
-                last_used: date.format("%Y-%m-%d").to_string(),
-            })
+            .map(
+                |((customer, work_item), (last_used, use_count))| CachedEntry {
+                    customer,
+                    work_item,
+                    last_used,
+                    use_count,
+                },
+            )
             .collect();
 
-        entries.sort_by(|a, b| b.last_used.cmp(&a.last_used));
+        entries.sort_by_key(|e| std::cmp::Reverse(e.last_used));
 
         self.entries.insert(user_id, entries);
+        self.evict_lru(self.max_entries);
         self.last_updated = Local::now().to_rfc3339();
+        self.last_refreshed = Utc::now();
+    }
+
+    /// Merges the items logged since the last refresh (see `refresh_since`).
+    /// Unlike a full refresh, use counts only grow: the delta holds a few
+    /// days, not every item of the pair.
+    pub fn update_from_delta(&mut self, user_id: i64, items: &[(String, String, NaiveDate)]) {
+        let previous: HashMap<(String, String), u32> = self
+            .entries
+            .get(&user_id)
+            .into_iter()
+            .flatten()
+            .map(|e| ((e.customer.clone(), e.work_item.clone()), e.use_count))
+            .collect();
+        self.update_from_items(user_id, items);
+        if let Some(user_entries) = self.entries.get_mut(&user_id) {
+            for entry in user_entries {
+                let key = (entry.customer.clone(), entry.work_item.clone());
+                if let Some(count) = previous.get(&key) {
+                    entry.use_count = entry.use_count.max(*count);
+                }
+            }
+        }
+    }
+
+    /// Keeps at most `max` entries per user, evicting the least recently used
+    pub fn evict_lru(&mut self, max: usize) {
+        for user_entries in self.entries.values_mut() {
+            if user_entries.len() > max {
+                user_entries.sort_by_key(|e| std::cmp::Reverse(e.last_used));
+                user_entries.truncate(max);
+            }
+        }
     }
 
     /// Get entries sorted by most recent first for a specific user
     pub fn get_sorted_entries(&self, user_id: i64) -> Vec<CachedEntry> {
         if let Some(user_entries) = self.entries.get(&user_id) {
             let mut entries = user_entries.clone();
-            entries.sort_by(|a, b| b.last_used.cmp(&a.last_used));
+            entries.sort_by_key(|e| std::cmp::Reverse(e.last_used));
             entries
         } else {
             Vec::new()
         }
     }
 
-    /// Get unique entries (deduplicated by customer + work_item) for a specific user
+    /// Get unique entries (deduplicated by customer + work_item) for a specific user,
+    /// most recently used first.
     /// Filters out test entries (TEST.DELETE.ME.*)
     pub fn get_unique_entries(&self, user_id: i64) -> Vec<CachedEntry> {
+        self.get_unique_entries_sorted(user_id, CacheSort::LastUsed)
+    }
+
+    /// Get one page of the unique entries matching `query` in the given
+    /// order; `page` starts at 0
+    pub fn get_unique_entries_page(
+        &self,
+        user_id: i64,
+        sort: CacheSort,
+        query: &str,
+        page: usize,
+        per_page: usize,
+    ) -> Vec<CachedEntry> {
+        self.get_unique_entries_matching(user_id, sort, query)
+            .into_iter()
+            .skip(page * per_page)
+            .take(per_page)
+            .collect()
+    }
+
+    /// Get unique entries for a specific user in the given order
+    pub fn get_unique_entries_sorted(&self, user_id: i64, sort: CacheSort) -> Vec<CachedEntry> {
         let mut seen = std::collections::HashSet::new();
         let mut unique = Vec::new();
 
@@ -157,9 +341,92 @@ impl EntryCache {
             }
         }
 
+        // Stable sorts keep the most recent first among equal entries
+        match sort {
+            CacheSort::LastUsed => {}
+            CacheSort::UseCount => unique.sort_by_key(|e| std::cmp::Reverse(e.use_count)),
+            CacheSort::CustomerAlpha => {
+                unique.sort_by_key(|e| (e.customer.to_lowercase(), e.work_item.to_lowercase()))
+            }
+        }
         unique
     }
 
+    /// Unique entries of a user whose customer or work item fuzzy-matches
+    /// `query`, best match first and in the given order among equal matches;
+    /// all entries when `query` is blank
+    pub fn get_unique_entries_matching(
+        &self,
+        user_id: i64,
+        sort: CacheSort,
+        query: &str,
+    ) -> Vec<CachedEntry> {
+        let entries = self.get_unique_entries_sorted(user_id, sort);
+        if query.trim().is_empty() {
+            return entries;
+        }
+        let mut scored: Vec<(u32, CachedEntry)> = entries
+            .into_iter()
+            .filter_map(|entry| Some((entry_score(query.trim(), &entry)?, entry)))
+            .collect();
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// Entries of every user whose customer or work item fuzzy-matches
+    /// `query`, one per customer/work item pair, best match first and then
+    /// most used first. "acm" ranks "ACME Corp" before "Acumen".
+    pub fn search(&self, query: &str) -> Vec<&CachedEntry> {
+        let query = query.trim();
+        let mut seen = std::collections::HashSet::new();
+        let mut matches: Vec<(u32, &CachedEntry)> = self
+            .entries
+            .values()
+            .flatten()
+            .filter(|entry| seen.insert((&entry.customer, &entry.work_item)))
+            .filter_map(|entry| Some((entry_score(query, entry)?, entry)))
+            .collect();
+        matches.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .cmp(a_score)
+                .then_with(|| b.use_count.cmp(&a.use_count))
+                .then_with(|| a.customer.cmp(&b.customer))
+                .then_with(|| a.work_item.cmp(&b.work_item))
+        });
+        matches.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// Entries of every user whose customer starts with `prefix` (ignoring
+    /// case), one per customer/work item pair, most used first
+    pub fn search_by_prefix(&self, prefix: &str) -> Vec<&CachedEntry> {
+        self.search_field(prefix, |entry| &entry.customer)
+    }
+
+    /// Entries of every user whose work item starts with `prefix` (ignoring
+    /// case), one per customer/work item pair, most used first
+    pub fn search_by_work_item_prefix(&self, prefix: &str) -> Vec<&CachedEntry> {
+        self.search_field(prefix, |entry| &entry.work_item)
+    }
+
+    fn search_field(&self, prefix: &str, field: fn(&CachedEntry) -> &String) -> Vec<&CachedEntry> {
+        let prefix = prefix.to_lowercase();
+        let mut seen = std::collections::HashSet::new();
+        let mut matches: Vec<&CachedEntry> = self
+            .entries
+            .values()
+            .flatten()
+            .filter(|entry| field(entry).to_lowercase().starts_with(&prefix))
+            .filter(|entry| seen.insert((&entry.customer, &entry.work_item)))
+            .collect();
+        matches.sort_by(|a, b| {
+            b.use_count
+                .cmp(&a.use_count)
+                .then_with(|| a.customer.cmp(&b.customer))
+                .then_with(|| a.work_item.cmp(&b.work_item))
+        });
+        matches
+    }
+
     /// Add a single entry for a user (used after successful add operation)
     pub fn add_entry(
         &mut self,
@@ -174,32 +441,62 @@ impl EntryCache {
 
         let user_entries = self.entries.entry(user_id).or_default();
 
-        // Check if this entry already exists
-        let date_str = date.format("%Y-%m-%d").to_string();
+        let used = day_start(date);
         if let Some(existing) = user_entries
             .iter_mut()
             .find(|e| e.customer == customer && e.work_item == work_item)
         {
-            // Update the date if newer
-            if date_str > existing.last_used {
-                existing.last_used = date_str;
-            }
+            existing.last_used = existing.last_used.max(used);
+            existing.use_count += 1;
         } else {
             // Add new entry
             user_entries.push(CachedEntry {
                 customer,
                 work_item,
-                last_used: date_str,
+                last_used: used,
+                use_count: 1,
             });
         }
 
         // Sort by most recent first
-        user_entries.sort_by(|a, b| b.last_used.cmp(&a.last_used));
+        user_entries.sort_by_key(|e| std::cmp::Reverse(e.last_used));
+
+        self.evict_lru(self.max_entries);
 
         self.last_updated = Local::now().to_rfc3339();
     }
 
+    /// Marks a pair picked from the cache as used now, so it stays clear of
+    /// eviction and moves to the top of the most recent order
+    pub fn mark_used(&mut self, user_id: i64, customer: &str, work_item: &str) {
+        if let Some(entry) = self.entries.get_mut(&user_id).and_then(|entries| {
+            entries
+                .iter_mut()
+                .find(|e| e.customer == customer && e.work_item == work_item)
+        }) {
+            entry.last_used = entry.last_used.max(Utc::now());
+            self.last_updated = Local::now().to_rfc3339();
+        }
+    }
+
+    /// Returns true when the entries were last read from Monday.com more than
+    /// `ttl_hours` ago; a TTL of 0 turns the automatic refresh off
+    pub fn needs_refresh(&self, ttl_hours: u64) -> bool {
+        ttl_hours > 0
+            && Utc::now().signed_duration_since(self.last_refreshed)
+                > chrono::Duration::hours(ttl_hours as i64)
+    }
+
+    /// First day to fetch for an incremental refresh: the day of the last
+    /// refresh. None when a full refresh is due, on first run or once the
+    /// cache is older than `ttl_hours`.
+    pub fn refresh_since(&self, ttl_hours: u64) -> Option<NaiveDate> {
+        (self.last_refreshed != DateTime::<Utc>::default() && !self.needs_refresh(ttl_hours))
+            .then(|| self.last_refreshed.date_naive())
+    }
+
     /// Check if cache is stale (older than specified hours)
+    #[allow(dead_code)]
     pub fn is_stale(&self, hours: i64) -> bool {
         if let Ok(last_updated) = DateTime::parse_from_rfc3339(&self.last_updated) {
             let now = Local::now();
@@ -223,6 +520,14 @@ impl EntryCache {
         self.entries.remove(&user_id);
         self.last_updated = Local::now().to_rfc3339();
     }
+
+    /// Remove a customer/work item pair from a user's cached entries
+    pub fn remove_entry(&mut self, user_id: i64, customer: &str, work_item: &str) {
+        if let Some(user_entries) = self.entries.get_mut(&user_id) {
+            user_entries.retain(|e| e.customer != customer || e.work_item != work_item);
+        }
+        self.last_updated = Local::now().to_rfc3339();
+    }
 }
 
 impl Default for EntryCache {
@@ -237,6 +542,10 @@ mod tests {
 
     const TEST_USER_ID: i64 = 12345;
 
+    fn used_on(date: &str) -> DateTime<Utc> {
+        day_start(NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap())
+    }
+
     #[test]
     fn test_cache_new() {
         let cache = EntryCache::new();
@@ -265,7 +574,7 @@ mod tests {
             .iter()
             .find(|e| e.customer == "Customer A")
             .unwrap();
-        assert_eq!(entry_a.last_used, "2025-01-20");
+        assert_eq!(entry_a.last_used, day_start(date2));
     }
 
     #[test]
@@ -294,17 +603,20 @@ mod tests {
             CachedEntry {
                 customer: "Customer A".to_string(),
                 work_item: "WI-001".to_string(),
-                last_used: "2025-01-20".to_string(),
+                last_used: used_on("2025-01-20"),
+                use_count: 0,
             },
             CachedEntry {
                 customer: "Customer A".to_string(),
                 work_item: "WI-001".to_string(),
-                last_used: "2025-01-15".to_string(),
+                last_used: used_on("2025-01-15"),
+                use_count: 0,
             },
             CachedEntry {
                 customer: "Customer B".to_string(),
                 work_item: "WI-002".to_string(),
-                last_used: "2025-01-18".to_string(),
+                last_used: used_on("2025-01-18"),
+                use_count: 0,
             },
         ];
         cache.entries.insert(TEST_USER_ID, user_entries);
@@ -313,6 +625,46 @@ mod tests {
         assert_eq!(unique.len(), 2);
     }
 
+    #[test]
+    fn test_get_unique_entries_sorted_and_paged() {
+        let entry = |customer: &str, last_used: &str, use_count: u32| CachedEntry {
+            customer: customer.to_string(),
+            work_item: "WI-001".to_string(),
+            last_used: used_on(last_used),
+            use_count,
+        };
+        let mut cache = EntryCache::new();
+        cache.entries.insert(
+            TEST_USER_ID,
+            vec![
+                entry("beta", "2025-01-20", 1),
+                entry("Alpha", "2025-01-10", 5),
+                entry("Gamma", "2025-01-15", 2),
+            ],
+        );
+        let customers = |sort: CacheSort| -> Vec<String> {
+            cache
+                .get_unique_entries_sorted(TEST_USER_ID, sort)
+                .into_iter()
+                .map(|e| e.customer)
+                .collect()
+        };
+
+        assert_eq!(customers(CacheSort::LastUsed), ["beta", "Gamma", "Alpha"]);
+        assert_eq!(customers(CacheSort::UseCount), ["Alpha", "Gamma", "beta"]);
+        assert_eq!(
+            customers(CacheSort::CustomerAlpha),
+            ["Alpha", "beta", "Gamma"]
+        );
+
+        let page = cache.get_unique_entries_page(TEST_USER_ID, CacheSort::LastUsed, "", 1, 2);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].customer, "Alpha");
+        assert!(cache
+            .get_unique_entries_page(TEST_USER_ID, CacheSort::LastUsed, "", 2, 2)
+            .is_empty());
+    }
+
     #[test]
     fn test_is_stale() {
         let mut cache = EntryCache::new();
@@ -331,6 +683,59 @@ mod tests {
         assert!(!cache.is_stale(72));
     }
 
+    #[test]
+    fn test_needs_refresh() {
+        let mut cache = EntryCache::new();
+
+        // A cache that was never refreshed needs a refresh, unless disabled
+        assert!(cache.needs_refresh(24));
+        assert!(!cache.needs_refresh(0));
+
+        cache.last_refreshed = Utc::now() - chrono::Duration::hours(30);
+        assert!(cache.needs_refresh(24));
+        assert!(!cache.needs_refresh(48));
+
+        cache.update_from_items(1, &[]);
+        assert!(!cache.needs_refresh(1));
+    }
+
+    #[test]
+    fn test_refresh_since() {
+        let mut cache = EntryCache::new();
+        // First run: full refresh, even with the automatic refresh off
+        assert_eq!(cache.refresh_since(24), None);
+        assert_eq!(cache.refresh_since(0), None);
+
+        cache.last_refreshed = Utc::now() - chrono::Duration::hours(2);
+        assert_eq!(
+            cache.refresh_since(24),
+            Some(cache.last_refreshed.date_naive())
+        );
+        assert_eq!(cache.refresh_since(1), None);
+        assert!(cache.refresh_since(0).is_some());
+    }
+
+    #[test]
+    fn test_update_from_delta_keeps_use_counts() {
+        let mut cache = EntryCache::new();
+        let date = |day: u32| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let item =
+            |customer: &str, day: u32| (customer.to_string(), "WI-001".to_string(), date(day));
+
+        cache.update_from_items(
+            TEST_USER_ID,
+            &[item("A", 10), item("A", 11), item("A", 12), item("B", 12)],
+        );
+        cache.update_from_delta(TEST_USER_ID, &[item("A", 15), item("C", 15)]);
+
+        let entries = cache.get_unique_entries(TEST_USER_ID);
+        let find = |customer: &str| entries.iter().find(|e| e.customer == customer).unwrap();
+        assert_eq!(find("A").use_count, 3);
+        assert_eq!(find("A").last_used, day_start(date(15)));
+        assert_eq!(find("B").use_count, 1);
+        assert_eq!(find("C").use_count, 1);
+    }
+
     #[test]
     fn test_clear() {
         let mut cache = EntryCache::new();
@@ -405,7 +810,101 @@ mod tests {
 
         let user_entries = cache.entries.get(&TEST_USER_ID).unwrap();
         assert_eq!(user_entries.len(), 1);
-        assert_eq!(user_entries[0].last_used, "2025-01-20");
+        assert_eq!(user_entries[0].last_used, day_start(date2));
+    }
+
+    #[test]
+    fn test_evict_lru() {
+        let mut cache = EntryCache::new();
+        cache.max_entries = 2;
+
+        for (i, day) in [10, 12, 11].iter().enumerate() {
+            cache.add_entry(
+                TEST_USER_ID,
+                format!("Customer {}", i),
+                format!("WI-{}", i),
+                NaiveDate::from_ymd_opt(2025, 1, *day).unwrap(),
+            );
+        }
+
+        // The oldest entry (Customer 0, Jan 10) is evicted
+        let entries = cache.get_sorted_entries(TEST_USER_ID);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].customer, "Customer 1");
+        assert_eq!(entries[1].customer, "Customer 2");
+    }
+
+    #[test]
+    fn test_unique_entries_sorted_by_use_count() {
+        let mut cache = EntryCache::new();
+        let date1 = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        let date2 = NaiveDate::from_ymd_opt(2025, 1, 20).unwrap();
+
+        let items = vec![
+            ("Customer A".to_string(), "WI-001".to_string(), date1),
+            ("Customer A".to_string(), "WI-001".to_string(), date1),
+            ("Customer B".to_string(), "WI-002".to_string(), date2),
+            ("Customer C".to_string(), "WI-003".to_string(), date1),
+        ];
+        cache.update_from_items(TEST_USER_ID, &items);
+
+        let unique = cache.get_unique_entries_sorted(TEST_USER_ID, CacheSort::UseCount);
+        assert_eq!(unique[0].customer, "Customer A");
+        assert_eq!(unique[0].use_count, 2);
+        // Equal use counts fall back to most recent first
+        assert_eq!(unique[1].customer, "Customer B");
+        assert_eq!(unique[2].customer, "Customer C");
+
+        // Refreshing with the same items does not inflate the counts
+        cache.update_from_items(TEST_USER_ID, &items);
+        assert_eq!(
+            cache.get_unique_entries_sorted(TEST_USER_ID, CacheSort::UseCount)[0].use_count,
+            2
+        );
+    }
+
+    #[test]
+    fn test_update_from_items_evicts_least_recently_used() {
+        let mut cache = EntryCache::new();
+        cache.max_entries = 3;
+        let date = |day: u32| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let item =
+            |customer: &str, day: u32| (customer.to_string(), "WI-001".to_string(), date(day));
+
+        cache.update_from_items(TEST_USER_ID, &[item("A", 10), item("B", 11), item("C", 12)]);
+        // Picking A from the cache makes it the most recently used pair
+        cache.mark_used(TEST_USER_ID, "A", "WI-001");
+        cache.update_from_items(TEST_USER_ID, &[item("D", 13), item("E", 14)]);
+
+        let customers: Vec<String> = cache
+            .get_unique_entries(TEST_USER_ID)
+            .into_iter()
+            .map(|e| e.customer)
+            .collect();
+        // B and C, the least recently used, are evicted in that order
+        assert_eq!(customers, ["A", "E", "D"]);
+
+        cache.max_entries = 1;
+        cache.update_from_items(TEST_USER_ID, &[]);
+        assert_eq!(cache.get_unique_entries(TEST_USER_ID)[0].customer, "A");
+    }
+
+    #[test]
+    fn test_last_used_reads_dates_and_times() {
+        let entry: CachedEntry = serde_json::from_str(
+            r#"{"customer": "A", "work_item": "WI-001", "last_used": "2025-01-20"}"#,
+        )
+        .unwrap();
+        assert_eq!(entry.last_used, used_on("2025-01-20"));
+
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(json.contains("2025-01-20T00:00:00Z"), "{}", json);
+        assert_eq!(serde_json::from_str::<CachedEntry>(&json).unwrap(), entry);
+
+        assert!(serde_json::from_str::<CachedEntry>(
+            r#"{"customer": "A", "work_item": "WI-001", "last_used": "yesterday"}"#
+        )
+        .is_err());
     }
 
     #[test]
@@ -421,6 +920,79 @@ mod tests {
         assert_eq!(cache.get_unique_entries(200).len(), 1);
         assert_eq!(cache.get_unique_entries(300).len(), 0);
     }
+
+    #[test]
+    fn test_search_by_prefix() {
+        let mut cache = EntryCache::new();
+        let date = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        cache.add_entry(100, "ACME Corp".to_string(), "PROJ-1".to_string(), date);
+        cache.add_entry(100, "Acme Labs".to_string(), "LAB-1".to_string(), date);
+        cache.add_entry(100, "Acme Labs".to_string(), "LAB-1".to_string(), date);
+        cache.add_entry(200, "ACME Corp".to_string(), "PROJ-1".to_string(), date);
+        cache.add_entry(200, "Globex".to_string(), "PROJ-2".to_string(), date);
+
+        let customers: Vec<&str> = cache
+            .search_by_prefix("acme")
+            .iter()
+            .map(|e| e.customer.as_str())
+            .collect();
+        assert_eq!(customers, ["Acme Labs", "ACME Corp"]);
+        assert_eq!(cache.search_by_prefix("").len(), 3);
+        assert!(cache.search_by_prefix("Initech").is_empty());
+
+        let work_items: Vec<&str> = cache
+            .search_by_work_item_prefix("proj")
+            .iter()
+            .map(|e| e.work_item.as_str())
+            .collect();
+        assert_eq!(work_items, ["PROJ-1", "PROJ-2"]);
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("acm", "ACME Corp") > fuzzy_score("acm", "Acumen"));
+        assert!(fuzzy_score("corp", "ACME Corp") > fuzzy_score("corp", "Cooperative"));
+        assert_eq!(fuzzy_score("xyz", "ACME Corp"), None);
+        assert_eq!(fuzzy_score("macme", "ACME"), None);
+        assert_eq!(fuzzy_score("", "ACME"), Some(0));
+    }
+
+    #[test]
+    fn test_search() {
+        let mut cache = EntryCache::new();
+        let date = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        cache.add_entry(100, "Acumen".to_string(), "AC-1".to_string(), date);
+        cache.add_entry(100, "Acumen".to_string(), "AC-1".to_string(), date);
+        cache.add_entry(100, "ACME Corp".to_string(), "PROJ-1".to_string(), date);
+        cache.add_entry(200, "ACME Corp".to_string(), "PROJ-1".to_string(), date);
+        cache.add_entry(200, "Globex".to_string(), "GLX-7".to_string(), date);
+
+        let customers = |query: &str| -> Vec<String> {
+            cache
+                .search(query)
+                .iter()
+                .map(|e| e.customer.clone())
+                .collect()
+        };
+        assert_eq!(customers("acm"), ["ACME Corp", "Acumen"]);
+        assert_eq!(customers("glx"), ["Globex"]);
+        assert!(customers("initech").is_empty());
+        // A blank query lists every pair, most used first
+        assert_eq!(customers(""), ["Acumen", "ACME Corp", "Globex"]);
+
+        let matching: Vec<String> = cache
+            .get_unique_entries_matching(100, CacheSort::UseCount, "acm")
+            .into_iter()
+            .map(|e| e.customer)
+            .collect();
+        assert_eq!(matching, ["ACME Corp", "Acumen"]);
+        assert_eq!(
+            cache
+                .get_unique_entries_matching(100, CacheSort::UseCount, " ")
+                .len(),
+            2
+        );
+    }
 }
 
 // Made with Bob
@@ -0,0 +1,98 @@
+//! `claim check-api`: verifies that the Monday.com API still offers the
+//! mutations and board columns claim relies on
+
+use crate::config::Config;
+use crate::init::map_columns;
+use crate::monday::{MondayClient, SchemaOverview};
+use anyhow::{anyhow, Result};
+
+/// Mutations claim sends to Monday.com
+const USED_MUTATIONS: [&str; 4] = [
+    "create_item",
+    "change_multiple_column_values",
+    "delete_item",
+    "move_item_to_group",
+];
+
+/// Handles `claim check-api [--board-id ID]`
+pub async fn handle_check_api_command(
+    client: &MondayClient,
+    config: &Config,
+    board_id: Option<String>,
+    verbose: bool,
+) -> Result<()> {
+    println!("🔎 Checking the Monday.com API schema...\n");
+
+    let schema = client.get_schema_overview(verbose).await?;
+    println!("GraphQL schema: {} types", schema.types.len());
+    let mutations = mutation_checks(&schema);
+    for (name, found) in &mutations {
+        println!("  {} mutation {}", found_label(*found), name);
+    }
+
+    let board_id = board_id.unwrap_or_else(|| config.board_id.clone());
+    let board = client.get_board_with_groups(&board_id, verbose).await?;
+    println!(
+        "\nColumns of board '{}' ({}):",
+        board.name.as_deref().unwrap_or("?"),
+        board_id
+    );
+    let columns = map_columns(&board.columns);
+    for (purpose, id, column) in &columns {
+        match column {
+            Some(column) => println!(
+                "  {} {:<24} {} ({})",
+                found_label(true),
+                purpose,
+                id,
+                column.column_type
+            ),
+            None => println!("  {} {:<24} {}", found_label(false), purpose, id),
+        }
+    }
+
+    let missing = mutations.iter().filter(|(_, found)| !found).count()
+        + columns.iter().filter(|(_, _, c)| c.is_none()).count();
+    if missing > 0 {
+        return Err(anyhow!(
+            "{} mutation(s) or column(s) were not found; adding and querying entries may fail",
+            missing
+        ));
+    }
+    println!("\n✅ All mutations and columns used by claim were found");
+    Ok(())
+}
+
+/// Whether each mutation claim uses exists in the schema
+fn mutation_checks(schema: &SchemaOverview) -> Vec<(&'static str, bool)> {
+    USED_MUTATIONS
+        .iter()
+        .map(|name| (*name, schema.mutations.iter().any(|m| m == name)))
+        .collect()
+}
+
+fn found_label(found: bool) -> &'static str {
+    if found {
+        "✅ FOUND    "
+    } else {
+        "❌ NOT FOUND"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutation_checks() {
+        let schema = SchemaOverview {
+            types: vec!["Item".to_string()],
+            mutations: vec!["create_item".to_string(), "delete_item".to_string()],
+        };
+        let checks = mutation_checks(&schema);
+
+        assert_eq!(checks.len(), USED_MUTATIONS.len());
+        assert!(checks.contains(&("create_item", true)));
+        assert!(checks.contains(&("move_item_to_group", false)));
+    }
+}
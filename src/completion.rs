@@ -0,0 +1,287 @@
+//! `claim completion`: shell completion scripts generated with clap_complete
+
+use crate::cache::{fuzzy_score, EntryCache};
+use crate::utils::{self, RELATIVE_DATES};
+use anyhow::Result;
+use clap::builder::PossibleValuesParser;
+use clap::{Command, ValueEnum};
+use clap_complete::Shell;
+use std::collections::BTreeSet;
+
+/// Values read from the local cache while completing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CachedValues {
+    Customers,
+    WorkItems,
+}
+
+impl CachedValues {
+    fn arg_name(self) -> &'static str {
+        match self {
+            CachedValues::Customers => "customers",
+            CachedValues::WorkItems => "work-items",
+        }
+    }
+
+    /// Placeholder possible value replaced by a call to
+    /// `claim completion --cached` in the generated script
+    fn placeholder(self) -> String {
+        format!("__claim_cached_{}", self.arg_name().replace('-', "_"))
+    }
+}
+
+/// What `--complete-customer`, `--complete-wi` and `--complete-pair` list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefixLookup {
+    /// Customers fuzzy-matching the prefix, best match first
+    Customer,
+    /// Work items starting with the prefix
+    WorkItem,
+    /// `customer<TAB>work_item` pairs whose customer starts with the prefix
+    Pair,
+}
+
+/// Prints the cached values matching `prefix`, one per line
+pub fn print_prefix_matches(lookup: PrefixLookup, prefix: &str) -> Result<()> {
+    let cache = EntryCache::load().unwrap_or_else(|_| EntryCache::new());
+    for line in prefix_matches(&cache, lookup, prefix) {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Cached values matching `prefix` (ignoring case): customers by fuzzy
+/// match, best first; work items and pairs by prefix, most used first
+fn prefix_matches(cache: &EntryCache, lookup: PrefixLookup, prefix: &str) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    match lookup {
+        PrefixLookup::Customer => cache
+            .search(prefix)
+            .into_iter()
+            .filter(|entry| fuzzy_score(prefix.trim(), &entry.customer).is_some())
+            .map(|entry| entry.customer.clone())
+            .filter(|customer| seen.insert(customer.clone()))
+            .collect(),
+        PrefixLookup::WorkItem => cache
+            .search_by_work_item_prefix(prefix)
+            .into_iter()
+            .map(|entry| entry.work_item.clone())
+            .filter(|work_item| seen.insert(work_item.clone()))
+            .collect(),
+        PrefixLookup::Pair => cache
+            .search_by_prefix(prefix)
+            .into_iter()
+            .map(|entry| format!("{}\t{}", entry.customer, entry.work_item))
+            .collect(),
+    }
+}
+
+/// Prints the completion script for `shell`
+pub fn print_completion(command: Command, shell: Shell) -> Result<()> {
+    let bin_name = command.get_name().to_string();
+    let mut command = with_completion_values(command, shell);
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut command, &bin_name, &mut script);
+    print!(
+        "{}",
+        with_cached_lookups(&String::from_utf8(script)?, shell, &bin_name)
+    );
+    Ok(())
+}
+
+/// Prints the cached customers or work items, one per line
+pub fn print_cached_values(values: CachedValues) -> Result<()> {
+    let cache = EntryCache::load().unwrap_or_else(|_| EntryCache::new());
+    for value in cached_values(&cache, values) {
+        println!("{}", value);
+    }
+    Ok(())
+}
+
+/// Customers or work items of every user in the cache, sorted and unique
+fn cached_values(cache: &EntryCache, values: CachedValues) -> BTreeSet<String> {
+    cache
+        .entries
+        .values()
+        .flatten()
+        .map(|entry| match values {
+            CachedValues::Customers => entry.customer.clone(),
+            CachedValues::WorkItems => entry.work_item.clone(),
+        })
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+/// Adds the values to complete to the arguments of `command` and all its
+/// subcommands: activity types for `--type`, cache placeholders for
+/// `--customer` and `--wi`, and relative dates for zsh and fish
+fn with_completion_values(mut command: Command, shell: Shell) -> Command {
+    let activity_types: Vec<String> = (0..=utils::MAX_ACTIVITY_VALUE)
+        .map(utils::map_activity_value_to_name)
+        .collect();
+    let dynamic = matches!(shell, Shell::Bash | Shell::Zsh | Shell::Fish);
+    let relative_dates = matches!(shell, Shell::Zsh | Shell::Fish);
+
+    let args: Vec<(String, Option<String>)> = command
+        .get_arguments()
+        .map(|arg| (arg.get_id().to_string(), arg.get_long().map(str::to_string)))
+        .collect();
+    for (id, long) in args {
+        let values: Vec<String> = match (id.as_str(), long.as_deref()) {
+            ("activity_type", _) => activity_types.clone(),
+            (_, Some("customer")) if dynamic => vec![CachedValues::Customers.placeholder()],
+            (_, Some("wi")) if dynamic => vec![CachedValues::WorkItems.placeholder()],
+            (_, Some("date" | "from" | "to")) if relative_dates => {
+                RELATIVE_DATES.iter().map(|d| d.to_string()).collect()
+            }
+            _ => continue,
+        };
+        command = command.mut_arg(id, |arg| {
+            arg.value_parser(PossibleValuesParser::new(values))
+        });
+    }
+
+    let subcommands: Vec<String> = command
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    for name in subcommands {
+        command = command.mut_subcommand(name, |sub| with_completion_values(sub, shell));
+    }
+    command
+}
+
+/// Replaces the cache placeholders with a call to `claim completion --cached`
+fn with_cached_lookups(script: &str, shell: Shell, bin_name: &str) -> String {
+    let mut script = script.to_string();
+    for values in [CachedValues::Customers, CachedValues::WorkItems] {
+        let placeholder = values.placeholder();
+        let lookup = format!(
+            "{} completion --cached {} 2>/dev/null",
+            bin_name,
+            values.arg_name()
+        );
+        script = match shell {
+            Shell::Bash => script.replace(
+                &format!("\"{}\"", placeholder),
+                &format!("\"$({})\"", lookup),
+            ),
+            Shell::Zsh => script.replace(
+                &format!("({})", placeholder),
+                &format!("{{compadd -- ${{(f)\"$({})\"}}}}", lookup),
+            ),
+            Shell::Fish => script.replace(
+                &format!("\"{}\\t''\"", placeholder),
+                &format!("\"({})\"", lookup),
+            ),
+            _ => script,
+        };
+    }
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::CachedEntry;
+    use chrono::Utc;
+    use clap::{Arg, ArgAction};
+
+    fn command() -> Command {
+        Command::new("claim").subcommand(
+            Command::new("add")
+                .arg(Arg::new("date").short('D').long("date"))
+                .arg(Arg::new("activity_type").short('t').long("type"))
+                .arg(Arg::new("customer").short('c').long("customer"))
+                .arg(Arg::new("work_item").short('w').long("wi"))
+                .arg(Arg::new("yes").short('y').action(ArgAction::SetTrue)),
+        )
+    }
+
+    fn script(shell: Shell) -> String {
+        let mut command = with_completion_values(command(), shell);
+        let mut script = Vec::new();
+        clap_complete::generate(shell, &mut command, "claim", &mut script);
+        with_cached_lookups(&String::from_utf8(script).unwrap(), shell, "claim")
+    }
+
+    #[test]
+    fn test_completion_scripts() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let script = script(shell);
+            assert!(script.contains("presales"), "{:?}", shell);
+            assert!(!script.contains("__claim_cached"), "{:?}", shell);
+        }
+        assert!(!script(Shell::PowerShell).contains("__claim_cached"));
+
+        let zsh = script(Shell::Zsh);
+        assert!(zsh.contains("last-week-monday"));
+        assert!(zsh.contains(
+            r#"{compadd -- ${(f)"$(claim completion --cached customers 2>/dev/null)"}}"#
+        ));
+        let fish = script(Shell::Fish);
+        assert!(fish.contains("yesterday"));
+        assert!(fish.contains(r#""(claim completion --cached work-items 2>/dev/null)""#));
+        let bash = script(Shell::Bash);
+        assert!(!bash.contains("yesterday"));
+        assert!(bash.contains(r#""$(claim completion --cached customers 2>/dev/null)""#));
+    }
+
+    #[test]
+    fn test_cached_values() {
+        let entry = |customer: &str, work_item: &str| CachedEntry {
+            customer: customer.to_string(),
+            work_item: work_item.to_string(),
+            last_used: Utc::now(),
+            use_count: 1,
+        };
+        let mut cache = EntryCache::new();
+        cache
+            .entries
+            .insert(1, vec![entry("Globex", "GLX-1"), entry("ACME", "PROJ-1")]);
+        cache.entries.insert(2, vec![entry("ACME", "PROJ-2")]);
+
+        let customers: Vec<String> = cached_values(&cache, CachedValues::Customers)
+            .into_iter()
+            .collect();
+        assert_eq!(customers, ["ACME", "Globex"]);
+        assert_eq!(cached_values(&cache, CachedValues::WorkItems).len(), 3);
+    }
+
+    #[test]
+    fn test_prefix_matches() {
+        let entry = |customer: &str, work_item: &str, use_count: u32| CachedEntry {
+            customer: customer.to_string(),
+            work_item: work_item.to_string(),
+            last_used: Utc::now(),
+            use_count,
+        };
+        let mut cache = EntryCache::new();
+        cache.entries.insert(
+            1,
+            vec![
+                entry("ACME", "PROJ-1", 1),
+                entry("ACME", "PROJ-2", 3),
+                entry("Globex", "GLX-1", 2),
+            ],
+        );
+
+        assert_eq!(
+            prefix_matches(&cache, PrefixLookup::Customer, "a"),
+            ["ACME"]
+        );
+        assert_eq!(
+            prefix_matches(&cache, PrefixLookup::Customer, "glx"),
+            ["Globex"]
+        );
+        assert_eq!(
+            prefix_matches(&cache, PrefixLookup::WorkItem, "proj"),
+            ["PROJ-2", "PROJ-1"]
+        );
+        assert_eq!(
+            prefix_matches(&cache, PrefixLookup::Pair, "ac"),
+            ["ACME\tPROJ-2", "ACME\tPROJ-1"]
+        );
+        assert!(prefix_matches(&cache, PrefixLookup::Pair, "x").is_empty());
+    }
+}
@@ -1,16 +1,505 @@
+use crate::error::ConfigError;
+use crate::monday::{MondayUser, RetryPolicy};
+use crate::prompts::PromptConfig;
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, NaiveDate, Weekday};
 use directories::ProjectDirs;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::io;
+use std::path::PathBuf;
+use std::sync::OnceLock;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Default length of a working day in hours
+pub const DEFAULT_HOURS_PER_DAY: f64 = 8.0;
+
+/// Time tracking board used when the config file does not name one
+pub const DEFAULT_BOARD_ID: &str = "6500270039";
+
+/// How long the cached Monday.com user stays valid
+pub const CACHED_USER_TTL_MINUTES: i64 = 60;
+
+/// Profile used when `--profile` is not given
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Profile selected with `--profile`, read by `Config::load`
+static ACTIVE_PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Selects the profile `Config::load` applies; only the first call counts
+pub fn set_active_profile(name: &str) {
+    let _ = ACTIVE_PROFILE.set(name.to_string());
+}
+
+/// Name of the profile selected with `--profile`
+pub fn active_profile() -> &'static str {
+    ACTIVE_PROFILE
+        .get()
+        .map(String::as_str)
+        .unwrap_or(DEFAULT_PROFILE)
+}
+
+/// A separate Monday.com account: its own API key, board and cached user
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub api_key: String,
+
+    /// Empty until a board is chosen, and then left out of the file, so the
+    /// board is asked for on first use of the profile
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub board_id: String,
+
+    /// Customer used by `claim add` when none is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_customer: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(skip)]
+    pub cached_user: Option<(i64, String, String, DateTime<Local>)>,
+}
+
+impl Profile {
+    pub fn new(api_key: String, board_id: String, default_customer: Option<String>) -> Self {
+        Profile {
+            api_key,
+            board_id,
+            default_customer,
+            cached_user: None,
+        }
+    }
+}
+
+/// The top-level values replaced while a profile is applied, put back on save
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AppliedProfile {
+    name: String,
+    api_key: String,
+    board_id: String,
+    cached_user: Option<(i64, String, String, DateTime<Local>)>,
+}
+
+fn default_board_id() -> String {
+    DEFAULT_BOARD_ID.to_string()
+}
+
+/// The `board_id` stored for `profile` in the raw config file: the profile's
+/// own entry, or the top-level one when no such profile exists
+fn board_id_entry<'a>(raw: &'a serde_json::Value, profile: &str) -> Option<&'a serde_json::Value> {
+    raw.get("profiles")
+        .and_then(|profiles| profiles.get(profile))
+        .unwrap_or(raw)
+        .get("board_id")
+}
+
+fn default_first_day_of_week() -> Weekday {
+    Weekday::Mon
+}
+
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
+fn default_stale_data_warning_minutes() -> u64 {
+    30
+}
+
+fn default_stale_data_error_minutes() -> u64 {
+    120
+}
+
+fn default_cache_display_format() -> String {
+    "{customer} / {work_item}".to_string()
+}
+
+fn default_cache_ttl_hours() -> u64 {
+    24
+}
+
+fn default_max_daily_hours() -> f64 {
+    24.0
+}
+
+fn default_expected_daily_hours() -> [f64; 2] {
+    [7.0, 9.0]
+}
+
+fn default_daily_hours_limit() -> f64 {
+    10.0
+}
+
+fn default_api_max_retries() -> u32 {
+    RetryPolicy::default().max_retries
+}
+
+fn default_api_retry_backoff_ms() -> u64 {
+    RetryPolicy::default().initial_backoff.as_millis() as u64
+}
+
+fn default_color_scheme() -> String {
+    "default".to_string()
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 pub struct Config {
     pub api_key: String,
+
+    /// Monday.com board that entries are read from and written to
+    #[serde(default = "default_board_id")]
+    pub board_id: String,
+
+    /// Expected hours per working day (used by `claim target`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours_per_day: Option<f64>,
+
+    /// Expected hours per week (falls back to five times `hours_per_day`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weekly_target_hours: Option<f64>,
+
+    /// Hours logged by `claim add` without `-H` and pre-filled when logging
+    /// time (falls back to `hours_per_day`); formerly `default_hours`
+    #[serde(
+        default,
+        alias = "default_hours",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_hours_per_day: Option<f64>,
+
+    /// Default activity type used by quick-log (e.g. "billable")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_activity_type: Option<String>,
+
+    /// First day of the week used for week navigation (e.g. "Sun" for US users)
+    #[serde(default = "default_first_day_of_week")]
+    pub first_day_of_week: Weekday,
+
+    /// First day shown in weekly tables: Monday or Sunday (display only;
+    /// working days stay Monday to Friday)
+    #[serde(default = "default_first_day_of_week")]
+    pub week_starts_on: Weekday,
+
+    /// When set, logs are also written to this file with daily rotation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_file: Option<PathBuf>,
+
+    /// Log level used for the log file (trace, debug, info, warn, error)
+    #[serde(default = "default_log_level")]
+    pub log_level: String,
+
+    /// When set, entered hours are rounded to the nearest multiple (e.g. 0.25)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub round_hours_to: Option<f64>,
+
+    /// Maximum number of recent customer/work item pairs kept in the cache
+    /// per user; the least recently used are evicted first
+    #[serde(
+        default,
+        alias = "max_cache_entries",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cache_max_entries: Option<usize>,
+
+    /// Hours after which the customer/work item cache is refreshed from
+    /// Monday.com automatically; 0 turns the automatic refresh off
+    #[serde(default = "default_cache_ttl_hours")]
+    pub cache_ttl_hours: u64,
+
+    /// Finalized weeks (identified by their Monday) that can no longer be edited
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locked_weeks: Vec<NaiveDate>,
+
+    /// Show Saturday and Sunday columns in the interactive week view
+    #[serde(default)]
+    pub show_weekends: bool,
+
+    /// Display names for activity type indices, for boards whose status
+    /// options are named differently (e.g. `{"1": "Client Work"}`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_type_labels: Option<HashMap<u8, String>>,
+
+    /// Enable vim-style navigation in the interactive UI (h/l weeks, j/k entries)
+    #[serde(default)]
+    pub vi_mode: bool,
+
+    /// Current Monday.com user (id, name, email, expiry), cached to skip the
+    /// user lookup on every command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached_user: Option<(i64, String, String, DateTime<Local>)>,
+
+    /// Time zone of the Monday.com workspace (e.g. "Europe/Rome"), refreshed
+    /// together with the cached user; "today" is computed in this zone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<String>")]
+    pub workspace_timezone: Option<chrono_tz::Tz>,
+
+    /// Minutes after which the TUI refresh indicator turns yellow
+    #[serde(default = "default_stale_data_warning_minutes")]
+    pub stale_data_warning_minutes: u64,
+
+    /// Minutes after which the TUI refresh indicator turns red
+    #[serde(default = "default_stale_data_error_minutes")]
+    pub stale_data_error_minutes: u64,
+
+    /// Work items that may be logged without a warning; any work item is
+    /// accepted when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_work_items: Option<Vec<String>>,
+
+    /// One-line label for cached entries in the TUI; `{customer}` and
+    /// `{work_item}` are replaced with the entry's values
+    #[serde(default = "default_cache_display_format")]
+    pub cache_display_format: String,
+
+    /// Full customer names that `claim add --customer-prefix` completes
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub customer_prefixes: Vec<String>,
+
+    /// When set, each interactive UI session is appended to this JSONL file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_log: Option<PathBuf>,
+
+    /// Name shown in the TUI header instead of the default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app_title: Option<String>,
+
+    /// Slack incoming webhook `claim summary --slack` posts to; saved by
+    /// `--slack-webhook` and `claim config set-slack-webhook`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack_webhook: Option<String>,
+
+    /// Built-in TUI color scheme: default, solarized, nord or high-contrast
+    #[serde(default = "default_color_scheme")]
+    pub color_scheme: String,
+
+    /// Most hours that may be logged on one day (checked by `add --append-to`)
+    #[serde(default = "default_max_daily_hours")]
+    pub max_daily_hours: f64,
+
+    /// Lowest and highest total hours `claim validate` accepts on a working day
+    #[serde(default = "default_expected_daily_hours")]
+    pub expected_daily_hours: [f64; 2],
+
+    /// Hours per day above which `claim add` asks for confirmation
+    #[serde(default = "default_daily_hours_limit")]
+    pub daily_hours_limit: f64,
+
+    /// Retries of an API request after HTTP 429 (rate limit) or 5xx responses
+    #[serde(default = "default_api_max_retries")]
+    pub api_max_retries: u32,
+
+    /// First wait in milliseconds before retrying after a 5xx response,
+    /// doubled on every further retry
+    #[serde(default = "default_api_retry_backoff_ms")]
+    pub api_retry_backoff_ms: u64,
+
+    /// Text of the `claim add` stdin prompts (`claim config set-prompt`)
+    #[serde(default, skip_serializing_if = "PromptConfig::is_default")]
+    pub prompts: PromptConfig,
+
+    /// Named Monday.com accounts selected with `--profile`; without a
+    /// `default` profile the top-level `api_key` and `board_id` are used
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub profiles: HashMap<String, Profile>,
+
+    /// JSON Schema reference for editors (see `claim json-schema`); kept so
+    /// saving the config does not drop it
+    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
+    #[serde(skip)]
+    pub(crate) applied_profile: Option<AppliedProfile>,
+
+    /// Set when the config file has no `board_id` for the active profile;
+    /// `board_id` then holds the default board until one is chosen
+    #[serde(skip)]
+    pub(crate) board_id_missing: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            api_key: String::new(),
+            board_id: default_board_id(),
+            hours_per_day: None,
+            weekly_target_hours: None,
+            default_hours_per_day: None,
+            default_activity_type: None,
+            first_day_of_week: default_first_day_of_week(),
+            week_starts_on: default_first_day_of_week(),
+            log_file: None,
+            log_level: default_log_level(),
+            round_hours_to: None,
+            cache_max_entries: None,
+            cache_ttl_hours: default_cache_ttl_hours(),
+            locked_weeks: Vec::new(),
+            show_weekends: false,
+            activity_type_labels: None,
+            vi_mode: false,
+            cached_user: None,
+            workspace_timezone: None,
+            stale_data_warning_minutes: default_stale_data_warning_minutes(),
+            stale_data_error_minutes: default_stale_data_error_minutes(),
+            allowed_work_items: None,
+            cache_display_format: default_cache_display_format(),
+            customer_prefixes: Vec::new(),
+            session_log: None,
+            app_title: None,
+            slack_webhook: None,
+            color_scheme: default_color_scheme(),
+            max_daily_hours: default_max_daily_hours(),
+            expected_daily_hours: default_expected_daily_hours(),
+            daily_hours_limit: default_daily_hours_limit(),
+            api_max_retries: default_api_max_retries(),
+            api_retry_backoff_ms: default_api_retry_backoff_ms(),
+            prompts: PromptConfig::default(),
+            profiles: HashMap::new(),
+            schema: None,
+            applied_profile: None,
+            board_id_missing: false,
+        }
+    }
 }
 
 impl Config {
     pub fn new(api_key: String) -> Self {
-        Config { api_key }
+        Config {
+            api_key,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the configured hours per day, or `DEFAULT_HOURS_PER_DAY`
+    pub fn hours_per_day(&self) -> f64 {
+        self.hours_per_day.unwrap_or(DEFAULT_HOURS_PER_DAY)
+    }
+
+    /// Returns the expected hours per (five-day) week
+    pub fn weekly_target_hours(&self) -> f64 {
+        self.weekly_target_hours
+            .unwrap_or_else(|| self.hours_per_day() * 5.0)
+    }
+
+    /// Returns the hours of a new entry when none are given
+    pub fn default_hours_per_day(&self) -> f64 {
+        self.default_hours_per_day
+            .unwrap_or_else(|| self.hours_per_day())
+    }
+
+    /// Returns how API requests are retried after rate limits and server errors
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_retries: self.api_max_retries,
+            initial_backoff: std::time::Duration::from_millis(self.api_retry_backoff_ms),
+        }
+    }
+
+    /// Returns the activity type to use when none is given
+    pub fn default_activity_type(&self) -> String {
+        self.default_activity_type
+            .clone()
+            .unwrap_or_else(|| "billable".to_string())
+    }
+
+    /// Returns the display name for an activity type index, preferring the
+    /// configured label over the built-in name
+    pub fn activity_label(&self, value: u8) -> String {
+        self.activity_type_labels
+            .as_ref()
+            .and_then(|labels| labels.get(&value))
+            .cloned()
+            .unwrap_or_else(|| crate::utils::map_activity_value_to_name(value))
+    }
+
+    /// Returns true when `work_item` is in `allowed_work_items` (ignoring
+    /// case), or when no allowed list is configured
+    pub fn is_work_item_allowed(&self, work_item: &str) -> bool {
+        match &self.allowed_work_items {
+            Some(allowed) => allowed
+                .iter()
+                .any(|item| item.trim().eq_ignore_ascii_case(work_item.trim())),
+            None => true,
+        }
+    }
+
+    /// Formats a cached customer/work item pair with `cache_display_format`
+    pub fn cache_entry_label(&self, customer: &str, work_item: &str) -> String {
+        self.cache_display_format
+            .replace("{customer}", customer)
+            .replace("{work_item}", work_item)
+    }
+
+    /// Customers in `customer_prefixes` starting with `prefix` (ignoring case)
+    pub fn customers_matching_prefix(&self, prefix: &str) -> Vec<&str> {
+        let prefix = prefix.trim().to_lowercase();
+        self.customer_prefixes
+            .iter()
+            .filter(|customer| customer.to_lowercase().starts_with(&prefix))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// The customer starting with `prefix`, when exactly one matches
+    pub fn resolve_customer_prefix(&self, prefix: &str) -> Option<&str> {
+        match self.customers_matching_prefix(prefix).as_slice() {
+            [customer] => Some(customer),
+            _ => None,
+        }
+    }
+
+    /// Returns the cached user if it has not expired at `now`
+    pub fn cached_user(&self, now: DateTime<Local>) -> Option<MondayUser> {
+        match &self.cached_user {
+            Some((id, name, email, expiry)) if *expiry > now => Some(MondayUser {
+                id: *id,
+                name: name.clone(),
+                email: email.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Caches `user` for `CACHED_USER_TTL_MINUTES` from `now`
+    pub fn set_cached_user(&mut self, user: &MondayUser, now: DateTime<Local>) {
+        let expiry = now + chrono::Duration::minutes(CACHED_USER_TTL_MINUTES);
+        self.cached_user = Some((user.id, user.name.clone(), user.email.clone(), expiry));
+    }
+
+    /// Title of the TUI header: `app_title`, else the name of a renamed
+    /// binary, else "Claim Manager"
+    pub fn display_name(&self) -> String {
+        self.app_title
+            .clone()
+            .or_else(|| crate::utils::binary_name().filter(|name| name != "claim"))
+            .unwrap_or_else(|| "Claim Manager".to_string())
+    }
+
+    /// Today's date in the workspace time zone (local time until it is known)
+    pub fn today(&self) -> NaiveDate {
+        crate::utils::today_in_workspace_tz(self.workspace_timezone)
+    }
+
+    /// Returns the maximum number of cached entries per user
+    pub fn cache_max_entries(&self) -> usize {
+        self.cache_max_entries
+            .unwrap_or(crate::cache::DEFAULT_MAX_CACHE_ENTRIES)
+    }
+
+    /// Returns true when the week containing `date` has been locked
+    pub fn is_week_locked(&self, date: NaiveDate) -> bool {
+        self.locked_weeks
+            .contains(&crate::utils::get_week_start(date, Weekday::Mon))
+    }
+
+    /// Fails when the week containing `date` has been locked
+    pub fn ensure_week_unlocked(&self, date: NaiveDate) -> Result<()> {
+        if self.is_week_locked(date) {
+            let monday = crate::utils::get_week_start(date, Weekday::Mon);
+            return Err(anyhow!(
+                "The week of {} is locked. Run `claim unlock --week {}` to change it.",
+                monday,
+                monday
+            ));
+        }
+        Ok(())
     }
 
     pub fn get_config_path() -> Option<std::path::PathBuf> {
@@ -18,9 +507,13 @@ impl Config {
             .map(|proj_dirs| proj_dirs.config_dir().join("config.json"))
     }
 
+    /// Location of the config file
+    pub fn path() -> Result<PathBuf> {
+        Self::get_config_path().ok_or_else(|| anyhow!("Could not determine config directory"))
+    }
+
     pub fn load() -> Result<Self> {
-        let config_path = Self::get_config_path()
-            .ok_or_else(|| anyhow!("Could not determine config directory"))?;
+        let config_path = Self::path()?;
 
         if !config_path.exists() {
             return Err(anyhow!("Config file does not exist"));
@@ -29,23 +522,95 @@ impl Config {
         let config_data = std::fs::read_to_string(&config_path)
             .map_err(|e| anyhow!("Failed to read config file: {}", e))?;
 
-        let config: Config = serde_json::from_str(&config_data)
+        let raw: serde_json::Value = serde_json::from_str(&config_data)
+            .map_err(|e| anyhow!("Failed to parse config: {}", e))?;
+        let mut config: Config = serde_json::from_value(raw.clone())
             .map_err(|e| anyhow!("Failed to parse config: {}", e))?;
+        config.apply_profile(active_profile())?;
+        config.board_id_missing = board_id_entry(&raw, config.profile_name()).is_none();
 
         Ok(config)
     }
 
+    /// Replaces the API key, board and cached user with those of profile
+    /// `name`. The `default` profile falls back to the top-level values.
+    pub fn apply_profile(&mut self, name: &str) -> Result<()> {
+        let Some(profile) = self.profiles.get(name).cloned() else {
+            if name == DEFAULT_PROFILE {
+                return Ok(());
+            }
+            return Err(ConfigError::ProfileNotFound(name.to_string()).into());
+        };
+
+        self.applied_profile = Some(AppliedProfile {
+            name: name.to_string(),
+            api_key: std::mem::replace(&mut self.api_key, profile.api_key),
+            board_id: std::mem::replace(&mut self.board_id, profile.board_id),
+            cached_user: std::mem::replace(&mut self.cached_user, profile.cached_user),
+        });
+        Ok(())
+    }
+
+    /// Name of the profile whose API key and board are in use
+    pub fn profile_name(&self) -> &str {
+        self.applied_profile
+            .as_ref()
+            .map_or(DEFAULT_PROFILE, |applied| applied.name.as_str())
+    }
+
+    /// Customer of the active profile used when none is given
+    pub fn default_customer(&self) -> Option<String> {
+        self.profiles
+            .get(self.profile_name())?
+            .default_customer
+            .clone()
+    }
+
+    /// The config as stored on disk: the values of an applied profile go
+    /// back into the profile and the top-level values are restored
+    pub(crate) fn to_stored(&self) -> Config {
+        let mut stored = self.clone();
+        if let Some(applied) = stored.applied_profile.take() {
+            if let Some(profile) = stored.profiles.get_mut(&applied.name) {
+                profile.api_key = std::mem::replace(&mut stored.api_key, applied.api_key);
+                profile.board_id = std::mem::replace(&mut stored.board_id, applied.board_id);
+                profile.cached_user =
+                    std::mem::replace(&mut stored.cached_user, applied.cached_user);
+            }
+        }
+        stored
+    }
+
+    /// The stored config as JSON; a `board_id` that was never chosen stays
+    /// out of the file so the next run still asks for it
+    fn to_stored_json(&self) -> Result<String> {
+        let mut value = serde_json::to_value(self.to_stored())
+            .map_err(|e| anyhow!("Failed to serialize config: {}", e))?;
+        if self.board_id_missing {
+            let pointer = match &self.applied_profile {
+                Some(applied) => format!(
+                    "/profiles/{}",
+                    applied.name.replace('~', "~0").replace('/', "~1")
+                ),
+                None => String::new(),
+            };
+            if let Some(map) = value.pointer_mut(&pointer).and_then(|v| v.as_object_mut()) {
+                map.remove("board_id");
+            }
+        }
+        serde_json::to_string_pretty(&value)
+            .map_err(|e| anyhow!("Failed to serialize config: {}", e))
+    }
+
     pub fn save(&self) -> Result<()> {
-        let config_path = Self::get_config_path()
-            .ok_or_else(|| anyhow!("Could not determine config directory"))?;
+        let config_path = Self::path()?;
 
         if let Some(parent) = config_path.parent() {
             std::fs::create_dir_all(parent)
                 .map_err(|e| anyhow!("Failed to create config directory: {}", e))?;
         }
 
-        let config_data = serde_json::to_string_pretty(self)
-            .map_err(|e| anyhow!("Failed to serialize config: {}", e))?;
+        let config_data = self.to_stored_json()?;
 
         std::fs::write(&config_path, config_data)
             .map_err(|e| anyhow!("Failed to write config file: {}", e))?;
@@ -75,8 +640,11 @@ impl Config {
         let config_data = std::fs::read_to_string(path)
             .map_err(|e| anyhow!("Failed to read config file: {}", e))?;
 
-        let config: Config = serde_json::from_str(&config_data)
+        let raw: serde_json::Value = serde_json::from_str(&config_data)
+            .map_err(|e| anyhow!("Failed to parse config: {}", e))?;
+        let mut config: Config = serde_json::from_value(raw.clone())
             .map_err(|e| anyhow!("Failed to parse config: {}", e))?;
+        config.board_id_missing = board_id_entry(&raw, config.profile_name()).is_none();
 
         Ok(config)
     }
@@ -88,8 +656,7 @@ impl Config {
                 .map_err(|e| anyhow!("Failed to create config directory: {}", e))?;
         }
 
-        let config_data = serde_json::to_string_pretty(self)
-            .map_err(|e| anyhow!("Failed to serialize config: {}", e))?;
+        let config_data = self.to_stored_json()?;
 
         std::fs::write(path, config_data)
             .map_err(|e| anyhow!("Failed to write config file: {}", e))?;
@@ -132,6 +699,263 @@ mod tests {
     fn test_config_new() {
         let config = Config::new("test-api-key".to_string());
         assert_eq!(config.api_key, "test-api-key");
+        assert_eq!(config.hours_per_day, None);
+        assert_eq!(config.hours_per_day(), DEFAULT_HOURS_PER_DAY);
+    }
+
+    #[test]
+    fn test_config_without_hours_per_day_is_backward_compatible() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert_eq!(config.api_key, "abc");
+        assert_eq!(config.hours_per_day(), 8.0);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","hours_per_day":7.5}"#).unwrap();
+        assert_eq!(config.hours_per_day(), 7.5);
+    }
+
+    #[test]
+    fn test_config_weekly_target_hours() {
+        let mut config = Config::new("abc".to_string());
+        assert_eq!(config.weekly_target_hours(), 40.0);
+
+        config.hours_per_day = Some(7.5);
+        assert_eq!(config.weekly_target_hours(), 37.5);
+
+        config.weekly_target_hours = Some(32.0);
+        assert_eq!(config.weekly_target_hours(), 32.0);
+    }
+
+    #[test]
+    fn test_config_cache_max_entries() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert_eq!(config.cache_max_entries(), 50);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","cache_max_entries":25}"#).unwrap();
+        assert_eq!(config.cache_max_entries(), 25);
+
+        // Files written before the setting was renamed keep their limit
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","max_cache_entries":80}"#).unwrap();
+        assert_eq!(config.cache_max_entries(), 80);
+        assert!(serde_json::to_string(&config)
+            .unwrap()
+            .contains(r#""cache_max_entries":80"#));
+    }
+
+    #[test]
+    fn test_config_locked_weeks() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert!(config.locked_weeks.is_empty());
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","locked_weeks":["2025-09-15"]}"#).unwrap();
+        let wednesday = NaiveDate::from_ymd_opt(2025, 9, 17).unwrap();
+        let next_monday = NaiveDate::from_ymd_opt(2025, 9, 22).unwrap();
+        assert!(config.is_week_locked(wednesday));
+        assert!(config.ensure_week_unlocked(wednesday).is_err());
+        assert!(!config.is_week_locked(next_monday));
+        assert!(config.ensure_week_unlocked(next_monday).is_ok());
+    }
+
+    #[test]
+    fn test_config_entry_defaults() {
+        let mut config = Config::new("abc".to_string());
+        assert_eq!(config.default_hours_per_day(), DEFAULT_HOURS_PER_DAY);
+        assert_eq!(config.default_activity_type(), "billable");
+
+        config.hours_per_day = Some(7.5);
+        assert_eq!(config.default_hours_per_day(), 7.5);
+
+        config.default_hours_per_day = Some(4.0);
+        config.default_activity_type = Some("presales".to_string());
+        assert_eq!(config.default_hours_per_day(), 4.0);
+        assert_eq!(config.default_activity_type(), "presales");
+
+        // Files written before the rename use `default_hours`
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","default_hours":6.0}"#).unwrap();
+        assert_eq!(config.default_hours_per_day, Some(6.0));
+        let saved = serde_json::to_value(&config).unwrap();
+        assert_eq!(saved["default_hours_per_day"], 6.0);
+    }
+
+    #[test]
+    fn test_config_first_day_of_week() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert_eq!(config.first_day_of_week, Weekday::Mon);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","first_day_of_week":"Sunday"}"#).unwrap();
+        assert_eq!(config.first_day_of_week, Weekday::Sun);
+    }
+
+    #[test]
+    fn test_config_week_starts_on() {
+        assert_eq!(Config::default().week_starts_on, Weekday::Mon);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","week_starts_on":"Sun"}"#).unwrap();
+        assert_eq!(config.week_starts_on, Weekday::Sun);
+        assert_eq!(config.first_day_of_week, Weekday::Mon);
+    }
+
+    #[test]
+    fn test_config_log_settings_default() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert_eq!(config.log_file, None);
+        assert_eq!(config.log_level, "info");
+
+        let config: Config = serde_json::from_str(
+            r#"{"api_key":"abc","log_file":"/tmp/claim.log","log_level":"debug"}"#,
+        )
+        .unwrap();
+        assert_eq!(config.log_file, Some(PathBuf::from("/tmp/claim.log")));
+        assert_eq!(config.log_level, "debug");
+    }
+
+    #[test]
+    fn test_config_round_hours_to() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert_eq!(config.round_hours_to, None);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","round_hours_to":0.25}"#).unwrap();
+        assert_eq!(config.round_hours_to, Some(0.25));
+    }
+
+    #[test]
+    fn test_config_show_weekends() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert!(!config.show_weekends);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","show_weekends":true}"#).unwrap();
+        assert!(config.show_weekends);
+    }
+
+    #[test]
+    fn test_config_cached_user() {
+        let now = Local::now();
+        let mut config = Config::new("abc".to_string());
+        assert!(config.cached_user(now).is_none());
+
+        let user = MondayUser {
+            id: 42,
+            name: "Jane Doe".to_string(),
+            email: "jane@example.com".to_string(),
+        };
+        config.set_cached_user(&user, now);
+
+        let cached = config.cached_user(now).unwrap();
+        assert_eq!(cached.id, 42);
+        assert_eq!(cached.email, "jane@example.com");
+
+        let later = now + chrono::Duration::minutes(CACHED_USER_TTL_MINUTES + 1);
+        assert!(config.cached_user(later).is_none());
+
+        // Survives a round trip through the config file format
+        let json = serde_json::to_string(&config).unwrap();
+        let loaded: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.cached_user(now).unwrap().name, "Jane Doe");
+    }
+
+    #[test]
+    fn test_config_vi_mode() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert!(!config.vi_mode);
+
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc","vi_mode":true}"#).unwrap();
+        assert!(config.vi_mode);
+    }
+
+    #[test]
+    fn test_config_workspace_timezone() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert_eq!(config.workspace_timezone, None);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","workspace_timezone":"Europe/Rome"}"#)
+                .unwrap();
+        assert_eq!(config.workspace_timezone, Some(chrono_tz::Europe::Rome));
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(r#""workspace_timezone":"Europe/Rome""#));
+    }
+
+    #[test]
+    fn test_config_stale_data_thresholds() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert_eq!(config.stale_data_warning_minutes, 30);
+        assert_eq!(config.stale_data_error_minutes, 120);
+
+        let config: Config = serde_json::from_str(
+            r#"{"api_key":"abc","stale_data_warning_minutes":5,"stale_data_error_minutes":15}"#,
+        )
+        .unwrap();
+        assert_eq!(config.stale_data_warning_minutes, 5);
+        assert_eq!(config.stale_data_error_minutes, 15);
+    }
+
+    #[test]
+    fn test_config_allowed_work_items() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert!(config.allowed_work_items.is_none());
+        assert!(config.is_work_item_allowed("anything"));
+
+        let config: Config = serde_json::from_str(
+            r#"{"api_key":"abc","allowed_work_items":["PROJ-123","M.00556"]}"#,
+        )
+        .unwrap();
+        assert!(config.is_work_item_allowed("proj-123"));
+        assert!(config.is_work_item_allowed(" M.00556 "));
+        assert!(!config.is_work_item_allowed("PROJ-12"));
+    }
+
+    #[test]
+    fn test_config_cache_display_format() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert_eq!(config.cache_display_format, "{customer} / {work_item}");
+        assert_eq!(config.cache_entry_label("ACME", "PROJ-1"), "ACME / PROJ-1");
+
+        let config: Config = serde_json::from_str(
+            r#"{"api_key":"abc","cache_display_format":"{work_item}@{customer}"}"#,
+        )
+        .unwrap();
+        assert_eq!(config.cache_entry_label("ACME", "PROJ-1"), "PROJ-1@ACME");
+    }
+
+    #[test]
+    fn test_config_customer_prefixes() {
+        let config: Config = serde_json::from_str(
+            r#"{"api_key":"abc","customer_prefixes":["ACME Corp","Acorn Ltd","Globex"]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.resolve_customer_prefix("glo"), Some("Globex"));
+        assert_eq!(config.resolve_customer_prefix("ACM"), Some("ACME Corp"));
+        assert_eq!(config.resolve_customer_prefix("ac"), None);
+        assert_eq!(
+            config.customers_matching_prefix("ac"),
+            vec!["ACME Corp", "Acorn Ltd"]
+        );
+        assert!(config.customers_matching_prefix("x").is_empty());
+
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert!(config.customer_prefixes.is_empty());
+    }
+
+    #[test]
+    fn test_config_activity_label() {
+        let config: Config = serde_json::from_str(r#"{"api_key":"abc"}"#).unwrap();
+        assert_eq!(config.activity_label(1), "billable");
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key":"abc","activity_type_labels":{"1":"Client Work"}}"#)
+                .unwrap();
+        assert_eq!(config.activity_label(1), "Client Work");
+        assert_eq!(config.activity_label(7), "presales");
     }
 
     #[test]
@@ -172,9 +996,7 @@ mod tests {
         let save_result = config.save();
         if save_result.is_ok() {
             // If save worked, try to load
-            let loaded_config = Config::load();
-            if loaded_config.is_ok() {
-                let loaded = loaded_config.unwrap();
+            if let Ok(loaded) = Config::load() {
                 assert_eq!(loaded.api_key, "test-api-key");
             }
             // If load fails, it's likely due to directories crate issues, not our code
@@ -219,6 +1041,7 @@ mod tests {
 
         let path = Config::get_config_path();
         assert!(path.is_some());
+        assert_eq!(Config::path().ok(), path);
 
         let path_str = path.unwrap().to_string_lossy().to_string();
         if cfg!(target_os = "windows") {
@@ -301,4 +1124,233 @@ mod tests {
         let loaded_config = Config::load_from_path(&config_path).unwrap();
         assert_eq!(loaded_config.api_key, special_key);
     }
+
+    #[test]
+    fn test_json_schema_reference_is_kept() {
+        let config: Config =
+            serde_json::from_str(r#"{"$schema": "./config-schema.json", "api_key": "test-key"}"#)
+                .unwrap();
+        assert_eq!(config.schema.as_deref(), Some("./config-schema.json"));
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["$schema"], "./config-schema.json");
+        assert!(serde_json::to_value(Config::default())
+            .unwrap()
+            .get("$schema")
+            .is_none());
+
+        let schema = serde_json::to_value(schemars::schema_for!(Config)).unwrap();
+        assert!(schema["properties"]["api_key"].is_object());
+        assert!(schema["properties"]["workspace_timezone"].is_object());
+        assert_eq!(schema["required"], serde_json::json!(["api_key"]));
+    }
+
+    #[test]
+    fn test_session_log_setting() {
+        assert!(Config::default().session_log.is_none());
+
+        let config: Config = serde_json::from_str(
+            r#"{"api_key": "test-key", "session_log": "/tmp/claim-sessions.jsonl"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.session_log,
+            Some(PathBuf::from("/tmp/claim-sessions.jsonl"))
+        );
+    }
+
+    #[test]
+    fn test_app_title_setting() {
+        assert!(Config::default().app_title.is_none());
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key": "test-key", "app_title": "TimeLogger"}"#).unwrap();
+        assert_eq!(config.app_title.as_deref(), Some("TimeLogger"));
+        assert_eq!(config.display_name(), "TimeLogger");
+    }
+
+    #[test]
+    fn test_slack_webhook_setting() {
+        assert!(Config::default().slack_webhook.is_none());
+
+        let config: Config = serde_json::from_str(
+            r#"{"api_key": "test-key", "slack_webhook": "https://hooks.slack.com/services/T0/B0/X"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.slack_webhook.as_deref(),
+            Some("https://hooks.slack.com/services/T0/B0/X")
+        );
+    }
+
+    #[test]
+    fn test_color_scheme_setting() {
+        assert_eq!(Config::default().color_scheme, "default");
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key": "test-key", "color_scheme": "nord"}"#).unwrap();
+        assert_eq!(config.color_scheme, "nord");
+    }
+
+    #[test]
+    fn test_max_daily_hours_setting() {
+        assert_eq!(Config::default().max_daily_hours, 24.0);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key": "test-key", "max_daily_hours": 10}"#).unwrap();
+        assert_eq!(config.max_daily_hours, 10.0);
+    }
+
+    #[test]
+    fn test_expected_daily_hours_setting() {
+        assert_eq!(Config::default().expected_daily_hours, [7.0, 9.0]);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key": "test-key", "expected_daily_hours": [7.5, 8]}"#)
+                .unwrap();
+        assert_eq!(config.expected_daily_hours, [7.5, 8.0]);
+    }
+
+    #[test]
+    fn test_cache_ttl_hours_setting() {
+        assert_eq!(Config::default().cache_ttl_hours, 24);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key": "test-key", "cache_ttl_hours": 0}"#).unwrap();
+        assert_eq!(config.cache_ttl_hours, 0);
+    }
+
+    #[test]
+    fn test_daily_hours_limit_setting() {
+        assert_eq!(Config::default().daily_hours_limit, 10.0);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key": "test-key", "daily_hours_limit": 8}"#).unwrap();
+        assert_eq!(config.daily_hours_limit, 8.0);
+    }
+
+    #[test]
+    fn test_board_id_setting() {
+        assert_eq!(Config::default().board_id, DEFAULT_BOARD_ID);
+
+        let config: Config =
+            serde_json::from_str(r#"{"api_key": "test-key", "board_id": "1234567890"}"#).unwrap();
+        assert_eq!(config.board_id, "1234567890");
+    }
+
+    #[test]
+    fn test_missing_board_id_detected() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let config_path = temp_dir.path().join("config.json");
+
+        std::fs::write(&config_path, r#"{"api_key": "test-key"}"#).unwrap();
+        let mut config = Config::load_from_path(&config_path).unwrap();
+        assert!(config.board_id_missing);
+        assert_eq!(config.board_id, DEFAULT_BOARD_ID);
+
+        // Saving before a board is chosen keeps it missing
+        config.save_to_path(&config_path).unwrap();
+        assert!(
+            Config::load_from_path(&config_path)
+                .unwrap()
+                .board_id_missing
+        );
+
+        config.board_id = "1234567890".to_string();
+        config.board_id_missing = false;
+        config.save_to_path(&config_path).unwrap();
+        let loaded = Config::load_from_path(&config_path).unwrap();
+        assert!(!loaded.board_id_missing);
+        assert_eq!(loaded.board_id, "1234567890");
+
+        let raw: serde_json::Value = serde_json::from_str(
+            r#"{"api_key": "k", "board_id": "1", "profiles": {"work": {"api_key": "w"}}}"#,
+        )
+        .unwrap();
+        assert!(board_id_entry(&raw, DEFAULT_PROFILE).is_some());
+        assert!(board_id_entry(&raw, "work").is_none());
+
+        // A profile added without a board stores none
+        let profile = Profile::new("w".to_string(), String::new(), None);
+        assert!(serde_json::to_value(&profile)
+            .unwrap()
+            .get("board_id")
+            .is_none());
+    }
+
+    #[test]
+    fn test_retry_settings() {
+        assert_eq!(Config::default().retry_policy(), RetryPolicy::default());
+
+        let config: Config = serde_json::from_str(
+            r#"{"api_key": "test-key", "api_max_retries": 0, "api_retry_backoff_ms": 250}"#,
+        )
+        .unwrap();
+        assert_eq!(config.retry_policy().max_retries, 0);
+        assert_eq!(
+            config.retry_policy().initial_backoff,
+            std::time::Duration::from_millis(250)
+        );
+    }
+
+    #[test]
+    fn test_prompts_setting() {
+        assert!(Config::default().prompts.is_default());
+
+        let config: Config = serde_json::from_str(
+            r#"{"api_key": "test-key", "prompts": {"customer_prompt": "Client: "}}"#,
+        )
+        .unwrap();
+        assert_eq!(config.prompts.customer_prompt, "Client: ");
+        assert_eq!(
+            config.prompts.hours_prompt,
+            PromptConfig::default().hours_prompt
+        );
+        assert!(!serde_json::to_string(&Config::default())
+            .unwrap()
+            .contains("prompts"));
+    }
+
+    #[test]
+    fn test_profiles() {
+        let json = r#"{
+            "api_key": "top-key",
+            "board_id": "111",
+            "profiles": {
+                "acme": {"api_key": "acme-key", "board_id": "222", "default_customer": "ACME"}
+            }
+        }"#;
+
+        // Without a default profile the top-level values are used
+        let mut config: Config = serde_json::from_str(json).unwrap();
+        config.apply_profile(DEFAULT_PROFILE).unwrap();
+        assert_eq!(
+            (config.api_key.as_str(), config.board_id.as_str()),
+            ("top-key", "111")
+        );
+        assert_eq!(config.default_customer(), None);
+
+        let mut config: Config = serde_json::from_str(json).unwrap();
+        config.apply_profile("acme").unwrap();
+        assert_eq!(
+            (config.api_key.as_str(), config.board_id.as_str()),
+            ("acme-key", "222")
+        );
+        assert_eq!(config.profile_name(), "acme");
+        assert_eq!(config.default_customer().as_deref(), Some("ACME"));
+
+        // Saving puts the profile values back where they came from
+        config.board_id = "333".to_string();
+        let stored = config.to_stored();
+        assert_eq!(
+            (stored.api_key.as_str(), stored.board_id.as_str()),
+            ("top-key", "111")
+        );
+        assert_eq!(stored.profiles["acme"].board_id, "333");
+
+        let error = config.apply_profile("globex").unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("claim config profile add globex"));
+    }
 }
@@ -0,0 +1,682 @@
+//! Handlers for the `claim config` subcommands
+
+use crate::cache::EntryCache;
+use crate::config::{Config, Profile, DEFAULT_PROFILE};
+use crate::prompts::PromptConfig;
+use crate::summary;
+use crate::utils;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, Utc, Weekday};
+use serde_json::Value;
+use std::io;
+use std::path::PathBuf;
+
+/// Loads the existing config, failing with a helpful message when the tool
+/// has not been set up yet
+fn load_existing_config() -> Result<Config> {
+    Config::load().map_err(|e| {
+        anyhow!(
+            "{}. Run `claim` once to set up your API key before changing settings.",
+            e
+        )
+    })
+}
+
+/// Prints the JSON Schema of the config file, for editor completion and
+/// validation
+pub fn print_json_schema() -> Result<()> {
+    let schema = schemars::schema_for!(Config);
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+    Ok(())
+}
+
+/// Prints the config file location and its settings, with API keys masked
+pub fn show_config() -> Result<()> {
+    let config = load_existing_config()?;
+
+    println!("Config file: {}", Config::path()?.display());
+    if config.profile_name() != DEFAULT_PROFILE || !config.profiles.is_empty() {
+        println!("Profile: {}", config.profile_name());
+    }
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&masked_config(&config)?)?
+    );
+    let cache = EntryCache::load().unwrap_or_else(|_| EntryCache::new());
+    println!("{}", cache_status(&cache, config.cache_ttl_hours));
+    Ok(())
+}
+
+/// Cache TTL and time of the last refresh, e.g. "Cache: refreshed ... (TTL 24h)"
+fn cache_status(cache: &EntryCache, ttl_hours: u64) -> String {
+    let refreshed = if cache.last_refreshed == DateTime::<Utc>::default() {
+        "never refreshed".to_string()
+    } else {
+        format!(
+            "last refreshed {}",
+            cache
+                .last_refreshed
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+        )
+    };
+    let ttl = match ttl_hours {
+        0 => "automatic refresh off".to_string(),
+        hours => format!("TTL {}h", hours),
+    };
+    format!("Cache: {} ({})", refreshed, ttl)
+}
+
+/// The stored config as JSON with every API key masked
+fn masked_config(config: &Config) -> Result<Value> {
+    let mut value = serde_json::to_value(config.to_stored())?;
+    let mut mask = |object: &mut Value| {
+        for field in ["api_key", "slack_webhook"] {
+            if let Some(Value::String(key)) = object.get_mut(field) {
+                *key = utils::mask_api_key(key);
+            }
+        }
+    };
+    mask(&mut value);
+    if let Some(Value::Object(profiles)) = value.get_mut("profiles") {
+        profiles.values_mut().for_each(&mut mask);
+    }
+    Ok(value)
+}
+
+/// Sets one setting of the config file, e.g. `claim config set board_id 12345`
+pub fn set_config(key: &str, value: &str) -> Result<()> {
+    let config = load_existing_config()?;
+    let updated = set_config_value(&config, key, value)?;
+    updated.save()?;
+
+    let shown = if key == "api_key" {
+        utils::mask_api_key(value)
+    } else {
+        value.to_string()
+    };
+    println!("✅ {} set to {}", key, shown);
+    Ok(())
+}
+
+/// `config` with `key` set to `value`. The value is read as JSON (numbers,
+/// booleans, lists, `null` to unset) and otherwise as text; for settings
+/// that hold text it is always text.
+fn set_config_value(config: &Config, key: &str, value: &str) -> Result<Config> {
+    let schema = schemars::schema_for!(Config);
+    let known = schema
+        .schema
+        .object
+        .as_ref()
+        .is_some_and(|object| object.properties.contains_key(key));
+    if !known || key == "$schema" {
+        return Err(anyhow!(
+            "Unknown setting: {}. See `claim json-schema` for the available settings",
+            key
+        ));
+    }
+
+    let mut json = serde_json::to_value(config)?;
+    let text = Value::String(value.to_string());
+    let candidates = match (json.get(key), serde_json::from_str::<Value>(value)) {
+        (_, Ok(Value::Null)) => vec![Value::Null, text],
+        (Some(Value::String(_)), _) | (_, Err(_)) => vec![text],
+        (_, Ok(parsed)) => vec![parsed, text],
+    };
+
+    let mut last_error = None;
+    for candidate in candidates {
+        json[key] = candidate;
+        match serde_json::from_value::<Config>(json.clone()) {
+            Ok(mut updated) => {
+                // Keep the active profile so its values are saved into it
+                updated.applied_profile = config.applied_profile.clone();
+                // A board that was never chosen stays missing until it is set
+                updated.board_id_missing = config.board_id_missing && key != "board_id";
+                // The cached user and time zone belong to the previous account
+                if key == "api_key" {
+                    updated.cached_user = None;
+                    updated.workspace_timezone = None;
+                }
+                return Ok(updated);
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(anyhow!(
+        "Invalid value for {}: {} ({})",
+        key,
+        value,
+        last_error.map(|e| e.to_string()).unwrap_or_default()
+    ))
+}
+
+/// Deletes the config file so the setup wizard runs again on the next command
+pub fn reset_config(yes: bool) -> Result<()> {
+    let path = Config::path()?;
+    if !path.exists() {
+        println!("No config file at {}", path.display());
+        return Ok(());
+    }
+
+    if !yes {
+        println!(
+            "Delete {} with your API key and all settings? (y/N)",
+            path.display()
+        );
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+        if confirmation.trim().to_lowercase() != "y" {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    }
+
+    std::fs::remove_file(&path)
+        .map_err(|e| anyhow!("Failed to delete config file {}: {}", path.display(), e))?;
+    println!(
+        "✅ Deleted {}. The setup wizard runs on the next command.",
+        path.display()
+    );
+    Ok(())
+}
+
+/// Enables file logging to `path` (rotated daily), optionally changing the log level
+pub fn set_log_file(path: PathBuf, level: Option<String>) -> Result<()> {
+    let mut config = load_existing_config()?;
+
+    let path = if path.is_relative() {
+        std::env::current_dir()?.join(path)
+    } else {
+        path
+    };
+
+    if let Some(level) = level {
+        config.log_level = validate_log_level(&level)?;
+    }
+
+    config.log_file = Some(path.clone());
+    config.save()?;
+
+    tracing::info!(log_file = %path.display(), "File logging enabled");
+    println!(
+        "✅ Logging to {} (level: {}, rotated daily)",
+        path.display(),
+        config.log_level
+    );
+    Ok(())
+}
+
+/// Disables file logging
+pub fn disable_log() -> Result<()> {
+    let mut config = load_existing_config()?;
+
+    if config.log_file.take().is_none() {
+        println!("File logging is already disabled");
+        return Ok(());
+    }
+
+    config.save()?;
+    println!("✅ File logging disabled");
+    Ok(())
+}
+
+/// Sets (or disables, with "off") the increment entered hours are rounded to
+pub fn set_rounding(value: &str) -> Result<()> {
+    let mut config = load_existing_config()?;
+
+    config.round_hours_to = parse_rounding(value)?;
+    config.save()?;
+
+    match config.round_hours_to {
+        Some(nearest) => println!(
+            "✅ Hours will be rounded to the nearest {} ({} rounding)",
+            nearest,
+            crate::utils::rounding_label(nearest)
+        ),
+        None => println!("✅ Hours rounding disabled"),
+    }
+    Ok(())
+}
+
+/// Parses a rounding increment; "off" disables rounding
+fn parse_rounding(value: &str) -> Result<Option<f64>> {
+    match value.trim().to_lowercase().as_str() {
+        "off" | "none" => Ok(None),
+        "0.25" => Ok(Some(0.25)),
+        "0.5" => Ok(Some(0.5)),
+        "1" | "1.0" => Ok(Some(1.0)),
+        _ => Err(anyhow!(
+            "Invalid rounding: {}. Use one of: 0.25, 0.5, 1.0, off",
+            value
+        )),
+    }
+}
+
+/// Sets the maximum number of cached customer/work item pairs per user and
+/// trims the existing cache to match
+pub fn set_max_cache_entries(max: usize) -> Result<()> {
+    if max == 0 {
+        return Err(anyhow!("The cache must hold at least one entry"));
+    }
+
+    let mut config = load_existing_config()?;
+    config.cache_max_entries = Some(max);
+    config.save()?;
+
+    let mut cache = EntryCache::load().unwrap_or_else(|_| EntryCache::new());
+    cache.evict_lru(max);
+    cache.save()?;
+
+    println!("✅ Cache limited to {} entries per user", max);
+    Ok(())
+}
+
+/// Sets the display name for an activity type index; an empty label restores
+/// the built-in name
+pub fn set_activity_label(index: u8, label: &str) -> Result<()> {
+    if index > utils::MAX_ACTIVITY_VALUE {
+        return Err(anyhow!(
+            "Invalid activity type index: {}. Use a value between 0 and {}",
+            index,
+            utils::MAX_ACTIVITY_VALUE
+        ));
+    }
+    let builtin = utils::map_activity_value_to_name(index);
+
+    let mut config = load_existing_config()?;
+    let labels = config
+        .activity_type_labels
+        .get_or_insert_with(Default::default);
+    let label = label.trim();
+
+    if label.is_empty() {
+        labels.remove(&index);
+        println!("✅ Activity type {} is shown as '{}' again", index, builtin);
+    } else {
+        labels.insert(index, label.to_string());
+        println!(
+            "✅ Activity type {} ({}) is now shown as '{}'",
+            index, builtin, label
+        );
+    }
+
+    if labels.is_empty() {
+        config.activity_type_labels = None;
+    }
+    config.save()
+}
+
+/// Adds `item` to the allowed work items
+pub fn add_work_item(item: &str) -> Result<()> {
+    let item = item.trim();
+    if item.is_empty() {
+        return Err(anyhow!("The work item cannot be empty"));
+    }
+
+    let mut config = load_existing_config()?;
+    if config.allowed_work_items.is_some() && config.is_work_item_allowed(item) {
+        println!("Work item {} is already allowed", item);
+        return Ok(());
+    }
+
+    config
+        .allowed_work_items
+        .get_or_insert_with(Vec::new)
+        .push(item.to_string());
+    config.save()?;
+
+    println!("✅ Added {} to the allowed work items", item);
+    Ok(())
+}
+
+/// Prints the allowed work items
+pub fn list_work_items() -> Result<()> {
+    let config = load_existing_config()?;
+
+    match config.allowed_work_items.as_deref() {
+        Some(items) if !items.is_empty() => {
+            println!("Allowed work items ({}):", items.len());
+            for item in items {
+                println!("  {}", item);
+            }
+        }
+        _ => println!("No allowed work items configured; any work item is accepted"),
+    }
+    Ok(())
+}
+
+/// Sets the first day shown in weekly tables ("monday" or "sunday")
+pub fn set_week_start(day: &str) -> Result<()> {
+    let week_starts_on = utils::parse_week_start(day)?;
+    let mut config = load_existing_config()?;
+    config.week_starts_on = week_starts_on;
+    config.save()?;
+
+    println!(
+        "✅ Weeks are shown starting on {}",
+        if week_starts_on == Weekday::Sun {
+            "Sunday"
+        } else {
+            "Monday"
+        }
+    );
+    Ok(())
+}
+
+/// Saves the Slack incoming webhook `claim summary --slack` posts to
+pub fn set_slack_webhook(url: &str) -> Result<()> {
+    summary::validate_slack_webhook(url)?;
+    let mut config = load_existing_config()?;
+    config.slack_webhook = Some(url.to_string());
+    config.save()?;
+
+    println!("✅ Slack webhook saved; post summaries with `claim summary --slack`");
+    Ok(())
+}
+
+/// Sets the text of one `claim add` prompt; an empty text restores the
+/// built-in prompt
+pub fn set_prompt(field: &str, text: &str) -> Result<()> {
+    let mut config = load_existing_config()?;
+    config.prompts.set(field, text)?;
+    config.save()?;
+
+    if text.is_empty() {
+        println!("✅ Prompt '{}' restored to the built-in text", field);
+    } else {
+        println!("✅ Prompt '{}' set to \"{}\"", field, text);
+    }
+    Ok(())
+}
+
+/// Replaces all `claim add` prompts with a built-in language set
+pub fn set_prompt_language(language: &str) -> Result<()> {
+    let prompts = PromptConfig::for_language(language).ok_or_else(|| {
+        anyhow!(
+            "No built-in prompts for '{}'. Available languages: {}",
+            language,
+            PromptConfig::LANGUAGES.join(", ")
+        )
+    })?;
+    let mut config = load_existing_config()?;
+    config.prompts = prompts;
+    config.save()?;
+
+    println!(
+        "✅ Prompts of `claim add` set to the '{}' language set",
+        language
+    );
+    Ok(())
+}
+
+/// Locks the week containing `week` (a date or YYYY-WN) against changes
+pub fn lock_week(week: &str) -> Result<()> {
+    let mut config = load_existing_config()?;
+    let monday = utils::get_week_start(
+        utils::parse_date_or_week(week, config.today())?,
+        Weekday::Mon,
+    );
+
+    if config.locked_weeks.contains(&monday) {
+        println!("🔒 The week of {} is already locked", monday);
+        return Ok(());
+    }
+
+    config.locked_weeks.push(monday);
+    config.locked_weeks.sort();
+    config.save()?;
+
+    tracing::info!(week = %monday, "Locked week");
+    println!("🔒 Locked the week of {}", monday);
+    Ok(())
+}
+
+/// Unlocks the week containing `week` (a date or YYYY-WN)
+pub fn unlock_week(week: &str) -> Result<()> {
+    let mut config = load_existing_config()?;
+    let monday = utils::get_week_start(
+        utils::parse_date_or_week(week, config.today())?,
+        Weekday::Mon,
+    );
+
+    let before = config.locked_weeks.len();
+    config.locked_weeks.retain(|w| *w != monday);
+    if config.locked_weeks.len() == before {
+        println!("The week of {} is not locked", monday);
+        return Ok(());
+    }
+
+    config.save()?;
+
+    tracing::info!(week = %monday, "Unlocked week");
+    println!("🔓 Unlocked the week of {}", monday);
+    Ok(())
+}
+
+/// Adds a profile with its own API key and board, prompting for the key
+/// when it is not given. Without `board_id` the board is asked for the first
+/// time the profile is used.
+pub fn add_profile(
+    name: &str,
+    api_key: Option<String>,
+    board_id: Option<String>,
+    default_customer: Option<String>,
+) -> Result<()> {
+    let name = name.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(anyhow!(
+            "Invalid profile name: '{}'. Use a name without spaces",
+            name
+        ));
+    }
+    let mut config = load_existing_config()?;
+    if config.profiles.contains_key(name) {
+        return Err(anyhow!("Profile '{}' already exists", name));
+    }
+
+    let api_key = api_key.unwrap_or_else(Config::prompt_for_api_key);
+    if api_key.trim().is_empty() {
+        return Err(anyhow!("API key cannot be empty"));
+    }
+    let profile = Profile::new(
+        api_key.trim().to_string(),
+        board_id.unwrap_or_default(),
+        default_customer,
+    );
+    config.profiles.insert(name.to_string(), profile);
+    config.save()?;
+
+    println!(
+        "✅ Profile '{}' added. Use it with `claim --profile {} ...`",
+        name, name
+    );
+    Ok(())
+}
+
+/// Lists the profiles, marking the one in use
+pub fn list_profiles() -> Result<()> {
+    let config = load_existing_config()?;
+
+    println!(
+        "  {:<15} {:<15} {:<12} Default customer",
+        "Profile", "API key", "Board"
+    );
+    for (name, profile) in profile_rows(&config) {
+        println!(
+            "{} {:<15} {:<15} {:<12} {}",
+            if name == config.profile_name() {
+                "*"
+            } else {
+                " "
+            },
+            name,
+            utils::mask_api_key(&profile.api_key),
+            if profile.board_id.is_empty() {
+                "-"
+            } else {
+                profile.board_id.as_str()
+            },
+            profile.default_customer.as_deref().unwrap_or("-")
+        );
+    }
+    Ok(())
+}
+
+/// Every profile sorted by name, including the implicit `default` one made
+/// of the top-level API key and board
+fn profile_rows(config: &Config) -> Vec<(String, Profile)> {
+    let stored = config.to_stored();
+    let mut rows: Vec<(String, Profile)> = stored.profiles.clone().into_iter().collect();
+    if !stored.profiles.contains_key(DEFAULT_PROFILE) {
+        rows.push((
+            DEFAULT_PROFILE.to_string(),
+            Profile::new(stored.api_key.clone(), stored.board_id.clone(), None),
+        ));
+    }
+    rows.sort_by(|a, b| a.0.cmp(&b.0));
+    rows
+}
+
+/// Validates and normalizes a log level name
+fn validate_log_level(level: &str) -> Result<String> {
+    let normalized = level.trim().to_lowercase();
+    match normalized.as_str() {
+        "trace" | "debug" | "info" | "warn" | "error" => Ok(normalized),
+        _ => Err(anyhow!(
+            "Invalid log level: {}. Use one of: trace, debug, info, warn, error",
+            level
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DEFAULT_BOARD_ID;
+
+    #[test]
+    fn test_cache_status() {
+        let mut cache = EntryCache::new();
+        assert_eq!(cache_status(&cache, 24), "Cache: never refreshed (TTL 24h)");
+
+        cache.update_from_items(1, &[]);
+        let status = cache_status(&cache, 0);
+        assert!(status.starts_with("Cache: last refreshed "), "{}", status);
+        assert!(status.ends_with("(automatic refresh off)"), "{}", status);
+    }
+
+    #[test]
+    fn test_validate_log_level() {
+        assert_eq!(validate_log_level("debug").unwrap(), "debug");
+        assert_eq!(validate_log_level(" INFO ").unwrap(), "info");
+        assert!(validate_log_level("verbose").is_err());
+    }
+
+    #[test]
+    fn test_parse_rounding() {
+        assert_eq!(parse_rounding("0.25").unwrap(), Some(0.25));
+        assert_eq!(parse_rounding("1").unwrap(), Some(1.0));
+        assert_eq!(parse_rounding("OFF").unwrap(), None);
+        assert!(parse_rounding("0.3").is_err());
+    }
+
+    #[test]
+    fn test_set_config_value() {
+        let config = Config::new("secret-key".to_string());
+
+        let updated = set_config_value(&config, "board_id", "12345").unwrap();
+        assert_eq!(updated.board_id, "12345");
+
+        let mut unchosen = config.clone();
+        unchosen.board_id_missing = true;
+        assert!(
+            set_config_value(&unchosen, "hours_per_day", "7")
+                .unwrap()
+                .board_id_missing
+        );
+        assert!(
+            !set_config_value(&unchosen, "board_id", "1")
+                .unwrap()
+                .board_id_missing
+        );
+        let updated = set_config_value(&config, "hours_per_day", "7.5").unwrap();
+        assert_eq!(updated.hours_per_day, Some(7.5));
+        let updated = set_config_value(&config, "vi_mode", "true").unwrap();
+        assert!(updated.vi_mode);
+        let updated = set_config_value(&config, "app_title", "1234").unwrap();
+        assert_eq!(updated.app_title.as_deref(), Some("1234"));
+        let updated = set_config_value(&updated, "app_title", "null").unwrap();
+        assert_eq!(updated.app_title, None);
+
+        assert!(set_config_value(&config, "hours_per_day", "many").is_err());
+        assert!(set_config_value(&config, "no_such_setting", "1").is_err());
+    }
+
+    #[test]
+    fn test_set_api_key_clears_cached_user() {
+        let mut config = Config::new("old-key".to_string());
+        config.cached_user = Some((
+            42,
+            "Old User".to_string(),
+            "old@example.com".to_string(),
+            Local::now(),
+        ));
+        config.workspace_timezone = Some(chrono_tz::Europe::Rome);
+
+        let updated = set_config_value(&config, "hours_per_day", "7").unwrap();
+        assert!(updated.cached_user.is_some());
+        assert!(updated.workspace_timezone.is_some());
+
+        let updated = set_config_value(&config, "api_key", "new-key").unwrap();
+        assert_eq!(updated.api_key, "new-key");
+        assert!(updated.cached_user.is_none());
+        assert!(updated.workspace_timezone.is_none());
+    }
+
+    #[test]
+    fn test_set_config_value_keeps_profile() {
+        let mut config: Config = serde_json::from_str(
+            r#"{"api_key": "top-key", "profiles": {"acme": {"api_key": "acme-key"}}}"#,
+        )
+        .unwrap();
+        config.apply_profile("acme").unwrap();
+
+        let stored = set_config_value(&config, "board_id", "222")
+            .unwrap()
+            .to_stored();
+        assert_eq!(stored.board_id, DEFAULT_BOARD_ID);
+        assert_eq!(stored.profiles["acme"].board_id, "222");
+    }
+
+    #[test]
+    fn test_masked_config() {
+        let config: Config = serde_json::from_str(
+            r#"{"api_key": "top-secret", "profiles": {"acme": {"api_key": "acme-secret"}}}"#,
+        )
+        .unwrap();
+
+        let value = masked_config(&config).unwrap();
+        assert_eq!(value["api_key"], "top-******");
+        assert_eq!(value["profiles"]["acme"]["api_key"], "acme*******");
+        assert_eq!(value["board_id"], DEFAULT_BOARD_ID);
+    }
+
+    #[test]
+    fn test_profile_rows() {
+        let mut config: Config = serde_json::from_str(
+            r#"{
+                "api_key": "top-key",
+                "profiles": {"acme": {"api_key": "acme-key", "board_id": "222"}}
+            }"#,
+        )
+        .unwrap();
+        config.apply_profile("acme").unwrap();
+
+        let rows = profile_rows(&config);
+        let names: Vec<&str> = rows.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["acme", "default"]);
+        assert_eq!(rows[1].1.api_key, "top-key");
+        assert_eq!(rows[1].1.board_id, DEFAULT_BOARD_ID);
+    }
+}
@@ -0,0 +1,206 @@
+//! `claim copy`: duplicates the entries of one day to other days
+
+use crate::add::extract_date_from_item;
+use crate::config::Config;
+use crate::monday::{is_user_item, Item, MondayClient, MondayUser};
+use crate::query::{extract_column_value, matches_filter};
+use crate::utils::{self, calculate_working_dates, get_year_group_id};
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate};
+use serde_json::json;
+use std::io;
+
+const DATE_COLUMN_ID: &str = "date4";
+
+/// Handles `claim copy --from DATE --to DATE [--days N] [-c CUSTOMER] [-w WI]`
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_copy_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    from: &str,
+    to: &str,
+    days: usize,
+    customer: Option<String>,
+    work_item: Option<String>,
+    yes: bool,
+    verbose: bool,
+) -> Result<()> {
+    let board_id = config.board_id.as_str();
+    let source_date = utils::parse_date_or_week(from, config.today())?;
+    let target_dates = calculate_working_dates(
+        utils::parse_date_or_week(to, config.today())?,
+        days as i64,
+        true,
+    );
+    if target_dates.is_empty() {
+        return Err(anyhow!("--days must be at least 1"));
+    }
+    if target_dates.contains(&source_date) {
+        return Err(anyhow!(
+            "The target days include the source date {}; entries would be duplicated on it",
+            source_date
+        ));
+    }
+    for date in &target_dates {
+        config.ensure_week_unlocked(*date)?;
+    }
+
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let source_group = get_year_group_id(&board, &source_date.year().to_string());
+    let source_date_string = source_date.format("%Y-%m-%d").to_string();
+    let entries: Vec<Item> = client
+        .query_items_with_filters(
+            board_id,
+            &source_group,
+            user.id,
+            &[source_date_string],
+            500,
+            verbose,
+        )
+        .await?
+        .into_iter()
+        .filter(|item| is_user_item(item, user.id))
+        .filter(|item| extract_date_from_item(item) == Some(source_date))
+        .filter(|item| {
+            matches_filter(item, "text__1", &customer)
+                && matches_filter(item, "text8__1", &work_item)
+        })
+        .collect();
+
+    if entries.is_empty() {
+        println!("No entries found on {} to copy", source_date);
+        return Ok(());
+    }
+
+    let total = entries.len() * target_dates.len();
+    println!(
+        "\n=== Copy {} entr{} from {} ===",
+        entries.len(),
+        if entries.len() == 1 { "y" } else { "ies" },
+        source_date
+    );
+    for item in &entries {
+        println!(
+            "  {:<20} {:<15} {:>6}h  {}",
+            extract_column_value(item, "text__1"),
+            extract_column_value(item, "text8__1"),
+            extract_column_value(item, "numbers__1"),
+            extract_column_value(item, "text2__1")
+        );
+    }
+    println!(
+        "to {}",
+        target_dates
+            .iter()
+            .map(|d| d.format("%a %Y-%m-%d").to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    if !yes {
+        println!("\n🚀 Ready to create {} item(s) on Monday.com", total);
+        println!("Do you want to proceed? (y/N)");
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+        if confirmation.trim().to_lowercase() != "y" {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    }
+
+    let mut created = 0;
+    for date in &target_dates {
+        let group_id = get_year_group_id(&board, &date.year().to_string());
+        for item in &entries {
+            let column_values = copied_column_values(item, *date);
+            match client
+                .create_item_verbose(board_id, &group_id, &user.name, &column_values, verbose)
+                .await
+            {
+                Ok(item_id) => {
+                    created += 1;
+                    println!("✅ Created item for {} with ID: {}", date, item_id);
+                }
+                Err(e) => eprintln!("❌ Failed to create item for {}: {}", date, e),
+            }
+        }
+    }
+
+    println!("\n🎉 Copied {} of {} item(s)", created, total);
+    if created < total {
+        return Err(anyhow!(
+            "{} of {} items failed to copy. Check the errors above.",
+            total - created,
+            total
+        ));
+    }
+    Ok(())
+}
+
+/// Column values of `item` for a copy dated `date`: every set column is
+/// reused as-is except the date
+fn copied_column_values(item: &Item, date: NaiveDate) -> serde_json::Value {
+    let mut values: serde_json::Map<String, serde_json::Value> = item
+        .column_values
+        .iter()
+        .filter_map(|column| {
+            let id = column.id.as_deref()?;
+            if id == DATE_COLUMN_ID {
+                return None;
+            }
+            let value = serde_json::from_str::<serde_json::Value>(column.value.as_deref()?).ok()?;
+            (!value.is_null()).then(|| (id.to_string(), value))
+        })
+        .collect();
+    values.insert(
+        DATE_COLUMN_ID.to_string(),
+        json!({ "date": date.format("%Y-%m-%d").to_string() }),
+    );
+    serde_json::Value::Object(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monday::ColumnValue;
+
+    fn column(id: &str, value: Option<&str>) -> ColumnValue {
+        ColumnValue {
+            id: Some(id.to_string()),
+            value: value.map(str::to_string),
+            text: None,
+        }
+    }
+
+    #[test]
+    fn test_copied_column_values() {
+        let item = Item {
+            column_values: vec![
+                column("date4", Some(r#"{"date": "2025-09-15"}"#)),
+                column("status", Some(r#"{"index": 1}"#)),
+                column(
+                    "person",
+                    Some(r#"{"personsAndTeams":[{"id":12345,"kind":"person"}]}"#),
+                ),
+                column("text__1", Some("\"ACME\"")),
+                column("numbers__1", Some("\"8\"")),
+                column("text2__1", Some("null")),
+                column("text8__1", None),
+            ],
+            ..Default::default()
+        };
+
+        let values = copied_column_values(&item, NaiveDate::from_ymd_opt(2025, 9, 22).unwrap());
+        assert_eq!(
+            values,
+            json!({
+                "date4": {"date": "2025-09-22"},
+                "status": {"index": 1},
+                "person": {"personsAndTeams": [{"id": 12345, "kind": "person"}]},
+                "text__1": "ACME",
+                "numbers__1": "8",
+            })
+        );
+    }
+}
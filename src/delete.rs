@@ -1,7 +1,18 @@
-use crate::monday::{MondayClient, MondayUser};
+use crate::config::Config;
+use crate::formatters::PsqlTableFormatter;
+use crate::monday::{
+    delete_item_mutation, is_user_item, Board, Item, MondayClient, MondayUser, MAX_ITEMS_PAGE_LIMIT,
+};
+use crate::query::{extract_item_date, extract_status_label};
+use crate::utils::{calculate_working_dates, count_days_in_range, get_year_group_id};
 use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate};
+use std::collections::HashSet;
 use std::io;
 
+/// Pause between deletions of a date range, to stay clear of rate limits
+const DELETE_THROTTLE: std::time::Duration = std::time::Duration::from_millis(200);
+
 #[allow(clippy::too_many_arguments)]
 pub async fn handle_delete_command(
     client: &MondayClient,
@@ -11,6 +22,8 @@ pub async fn handle_delete_command(
     date: Option<String>,
     customer: Option<String>,
     work_item: Option<String>,
+    config: &Config,
+    dry_run: bool,
     yes: bool,
     verbose: bool,
 ) -> Result<()> {
@@ -29,7 +42,7 @@ pub async fn handle_delete_command(
 
     // If delete_id is provided, use the existing logic
     if let Some(id) = delete_id {
-        return delete_by_id(client, user, &id, yes, verbose).await;
+        return delete_by_id(client, user, &id, config, dry_run, yes, verbose).await;
     }
 
     // Otherwise, search for items matching date + customer + work_item
@@ -40,6 +53,8 @@ pub async fn handle_delete_command(
         date.as_ref().unwrap(),
         customer.as_ref().unwrap(),
         work_item.as_ref().unwrap(),
+        config,
+        dry_run,
         yes,
         verbose,
     )
@@ -50,6 +65,8 @@ async fn delete_by_id(
     client: &MondayClient,
     user: &MondayUser,
     delete_id: &str,
+    config: &Config,
+    dry_run: bool,
     yes: bool,
     verbose: bool,
 ) -> Result<()> {
@@ -64,6 +81,12 @@ async fn delete_by_id(
 
     match client.get_item_by_id(delete_id, verbose).await {
         Ok(Some(item)) => {
+            let item_date = crate::query::extract_item_date(&item)
+                .and_then(|d| chrono::NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok());
+            if let Some(date) = item_date {
+                config.ensure_week_unlocked(date)?;
+            }
+
             println!("\n📋 Item Details:");
             println!("  Name: {}", item.name.as_deref().unwrap_or("Unnamed"));
             println!("  ID: {}", item.id.as_deref().unwrap_or("Unknown"));
@@ -97,7 +120,7 @@ async fn delete_by_id(
                                     if let Some(status_index) = parsed_value.get("index") {
                                         if let Some(index_num) = status_index.as_u64() {
                                             let status_name =
-                                                crate::map_activity_value_to_name(index_num as u8);
+                                                config.activity_label(index_num as u8);
                                             println!("    {}: {}", column_title, status_name);
                                             continue;
                                         }
@@ -124,6 +147,13 @@ async fn delete_by_id(
         }
     }
 
+    if dry_run {
+        println!("\n📋 GraphQL Mutation that would be executed:");
+        println!("{}", delete_item_mutation(delete_id));
+        println!("Dry run: nothing was deleted. Run without --dry-run to delete the item.");
+        return Ok(());
+    }
+
     // Ask for confirmation unless -y flag is used
     if !yes {
         println!("\n🗑️  Are you sure you want to delete this item?");
@@ -161,6 +191,8 @@ async fn delete_by_criteria(
     date: &str,
     customer: &str,
     work_item: &str,
+    config: &Config,
+    dry_run: bool,
     yes: bool,
     verbose: bool,
 ) -> Result<()> {
@@ -172,8 +204,12 @@ async fn delete_by_criteria(
     println!("  Work Item: {}", work_item);
 
     // Validate and normalize the date
-    crate::validate_date(date)?;
-    let normalized_date = crate::normalize_date(date);
+    crate::validate_date(date, config.today())?;
+    let normalized_date = crate::normalize_date(date, config.today());
+    config.ensure_week_unlocked(chrono::NaiveDate::parse_from_str(
+        &normalized_date,
+        "%Y-%m-%d",
+    )?)?;
 
     if verbose {
         println!("🔍 Querying items for date: {}", normalized_date);
@@ -181,7 +217,7 @@ async fn delete_by_criteria(
 
     // Query items for the specified date
     let board = client
-        .query_board_verbose("6500270039", current_year, user.id, 1000, verbose)
+        .query_board_verbose(&config.board_id, current_year, user.id, 1000, verbose)
         .await?;
 
     let group_id = crate::get_year_group_id(&board, current_year);
@@ -192,7 +228,7 @@ async fn delete_by_criteria(
 
     // Get all items for the user
     let items = client
-        .query_all_items_in_group("6500270039", &group_id, 1000, verbose)
+        .query_all_items_in_group(&config.board_id, &group_id, 1000, verbose)
         .await?;
 
     if verbose {
@@ -268,6 +304,18 @@ async fn delete_by_criteria(
         println!("   Work Item: {}", work_item);
     }
 
+    if dry_run {
+        println!("\n📋 GraphQL Mutations that would be executed:");
+        for item_id in matching_items.iter().filter_map(|item| item.id.as_deref()) {
+            println!("{}", delete_item_mutation(item_id));
+        }
+        println!(
+            "Dry run: nothing was deleted. Run without --dry-run to delete the {} item(s).",
+            matching_items.len()
+        );
+        return Ok(());
+    }
+
     // Ask for confirmation unless -y flag is used
     if !yes {
         println!(
@@ -317,6 +365,203 @@ async fn delete_by_criteria(
     Ok(())
 }
 
+/// Handles `claim delete --date-range FROM TO [-c CUSTOMER] [-w WI]`:
+/// deletes the user's entries on the working days from `start` to `end`,
+/// after one confirmation for all of them. Fails when any deletion fails.
+#[allow(clippy::too_many_arguments)]
+pub async fn delete_date_range(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    start: NaiveDate,
+    end: NaiveDate,
+    customer: Option<String>,
+    work_item: Option<String>,
+    dry_run: bool,
+    yes: bool,
+    verbose: bool,
+) -> Result<()> {
+    let dates = calculate_working_dates(start, count_days_in_range(start, end, true) as i64, true);
+    for date in &dates {
+        config.ensure_week_unlocked(*date)?;
+    }
+
+    println!("\n=== Delete Claim Items from {} to {} ===", start, end);
+    println!("User: {} ({})", user.name, user.email);
+
+    let board_id = config.board_id.as_str();
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let mut items = Vec::new();
+    for (group_id, date_strings) in range_queries(&board, &dates) {
+        items.extend(
+            client
+                .query_items_with_filters(
+                    board_id,
+                    &group_id,
+                    user.id,
+                    &date_strings,
+                    MAX_ITEMS_PAGE_LIMIT,
+                    verbose,
+                )
+                .await?,
+        );
+    }
+
+    let matching_items = items_in_range(
+        items,
+        user.id,
+        &dates,
+        customer.as_deref(),
+        work_item.as_deref(),
+    );
+    if matching_items.is_empty() {
+        println!("❌ No items found matching the specified criteria.");
+        return Ok(());
+    }
+
+    println!("\n📋 Found {} matching item(s):", matching_items.len());
+    print!("{}", range_table(&matching_items, config));
+
+    if dry_run {
+        println!("\n📋 GraphQL Mutations that would be executed:");
+        for item_id in matching_items.iter().filter_map(|item| item.id.as_deref()) {
+            println!("{}", delete_item_mutation(item_id));
+        }
+        println!(
+            "Dry run: nothing was deleted. Run without --dry-run to delete the {} item(s).",
+            matching_items.len()
+        );
+        return Ok(());
+    }
+
+    if !yes {
+        println!(
+            "\n🗑️  Are you sure you want to delete {} item(s)?",
+            matching_items.len()
+        );
+        println!("This action cannot be undone! (y/N)");
+
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+
+        if confirmation.trim().to_lowercase() != "y" {
+            println!("Deletion cancelled.");
+            return Ok(());
+        }
+    }
+
+    println!("\n🔄 Deleting {} item(s)...", matching_items.len());
+    let mut deleted_count = 0;
+    let mut failed_count = 0;
+    for (i, item_id) in matching_items
+        .iter()
+        .filter_map(|item| item.id.as_deref())
+        .enumerate()
+    {
+        if i > 0 {
+            tokio::time::sleep(DELETE_THROTTLE).await;
+        }
+        match client.delete_item(item_id, verbose).await {
+            Ok(_) => {
+                println!("✅ Deleted item ID: {}", item_id);
+                deleted_count += 1;
+            }
+            Err(e) => {
+                println!("❌ Failed to delete item ID {}: {}", item_id, e);
+                failed_count += 1;
+            }
+        }
+    }
+
+    println!(
+        "\n🎉 Deletion complete: {} deleted, {} failed",
+        deleted_count, failed_count
+    );
+
+    if failed_count > 0 {
+        return Err(anyhow!(
+            "{} of {} items failed to delete",
+            failed_count,
+            matching_items.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Year group and dates of each query `delete_date_range` sends, one per
+/// year the dates span
+fn range_queries(board: &Board, dates: &[NaiveDate]) -> Vec<(String, Vec<String>)> {
+    let (Some(first), Some(last)) = (dates.first(), dates.last()) else {
+        return Vec::new();
+    };
+    (first.year()..=last.year())
+        .map(|year| {
+            let date_strings: Vec<String> = dates
+                .iter()
+                .filter(|d| d.year() == year)
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .collect();
+            (get_year_group_id(board, &year.to_string()), date_strings)
+        })
+        .filter(|(_, date_strings)| !date_strings.is_empty())
+        .collect()
+}
+
+/// Items of `user_id` dated on one of `dates`, narrowed to `customer` and
+/// `work_item` (ignoring case) when given, sorted by date
+fn items_in_range(
+    items: Vec<Item>,
+    user_id: i64,
+    dates: &[NaiveDate],
+    customer: Option<&str>,
+    work_item: Option<&str>,
+) -> Vec<Item> {
+    let dates: HashSet<String> = dates
+        .iter()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect();
+    let matches = |value: &str, filter: Option<&str>| {
+        filter.is_none_or(|filter| value.eq_ignore_ascii_case(filter))
+    };
+
+    let mut seen = HashSet::new();
+    let mut matching: Vec<Item> = items
+        .into_iter()
+        .filter(|item| is_user_item(item, user_id))
+        .filter(|item| extract_item_date(item).is_some_and(|date| dates.contains(&date)))
+        .filter(|item| matches(&extract_column_value(item, "text__1"), customer))
+        .filter(|item| matches(&extract_column_value(item, "text8__1"), work_item))
+        .filter(|item| seen.insert(item.id.clone()))
+        .collect();
+    matching.sort_by_key(|item| (extract_item_date(item), item.id.clone()));
+    matching
+}
+
+/// The items to delete as one table
+fn range_table(items: &[Item], config: &Config) -> String {
+    let mut table = PsqlTableFormatter::new(&[
+        "id",
+        "date",
+        "activity_type",
+        "customer",
+        "work_item",
+        "hours",
+    ])
+    .align_right(5);
+    for item in items {
+        table.add_row(vec![
+            item.id.clone().unwrap_or_default(),
+            extract_item_date(item).unwrap_or_default(),
+            extract_status_label(item, config),
+            extract_column_value(item, "text__1"),
+            extract_column_value(item, "text8__1"),
+            extract_column_value(item, "numbers__1"),
+        ]);
+    }
+    table.render()
+}
+
 // Helper function to extract specific column value (same logic as in query.rs)
 fn extract_column_value(item: &crate::monday::Item, column_id: &str) -> String {
     for col in &item.column_values {
@@ -347,7 +592,7 @@ fn extract_column_value(item: &crate::monday::Item, column_id: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::monday::{ColumnValue, Item};
+    use crate::monday::{validate_query_params, ColumnValue, Group, Item};
 
     // Helper function to create a test user
     fn create_test_user() -> MondayUser {
@@ -363,6 +608,7 @@ mod tests {
         Item {
             id: Some(id.to_string()),
             name: Some("Test Item".to_string()),
+            updated_at: None,
             column_values: vec![
                 ColumnValue {
                     id: Some("date4".to_string()),
@@ -476,9 +722,20 @@ mod tests {
         let client = MondayClient::new("test_key".to_string());
         let user = create_test_user();
 
-        let result =
-            handle_delete_command(&client, &user, "2025", None, None, None, None, false, false)
-                .await;
+        let result = handle_delete_command(
+            &client,
+            &user,
+            "2025",
+            None,
+            None,
+            None,
+            None,
+            &Config::default(),
+            false,
+            false,
+            false,
+        )
+        .await;
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -501,6 +758,8 @@ mod tests {
             Some("2025-01-15".to_string()),
             None,
             None,
+            &Config::default(),
+            false,
             false,
             false,
         )
@@ -526,6 +785,8 @@ mod tests {
             Some("2025-01-15".to_string()),
             Some("ACME".to_string()),
             Some("PROJ-001".to_string()),
+            &Config::default(),
+            false,
             false,
             false,
         )
@@ -536,11 +797,39 @@ mod tests {
         assert!(err_msg.contains("Cannot specify both"));
     }
 
+    #[tokio::test]
+    async fn test_handle_delete_command_locked_week() {
+        let client = MondayClient::new("test_key".to_string());
+        let user = create_test_user();
+        let config = Config {
+            locked_weeks: vec![chrono::NaiveDate::from_ymd_opt(2025, 1, 13).unwrap()],
+            ..Default::default()
+        };
+
+        let result = handle_delete_command(
+            &client,
+            &user,
+            "2025",
+            None,
+            Some("2025-01-15".to_string()),
+            Some("ACME".to_string()),
+            Some("PROJ-001".to_string()),
+            &config,
+            false,
+            true,
+            false,
+        )
+        .await;
+
+        assert!(result.unwrap_err().to_string().contains("is locked"));
+    }
+
     #[test]
     fn test_extract_column_value_complex_json() {
         let item = Item {
             id: Some("123".to_string()),
             name: Some("Test".to_string()),
+            updated_at: None,
             column_values: vec![ColumnValue {
                 id: Some("complex".to_string()),
                 value: Some(r#"{"nested":{"value":"deep"}}"#.to_string()),
@@ -667,6 +956,7 @@ mod tests {
         let item = Item {
             id: Some("123".to_string()),
             name: Some("Test".to_string()),
+            updated_at: None,
             column_values: vec![],
         };
 
@@ -687,4 +977,117 @@ mod tests {
         let val = extract_column_value(&item, "text__1");
         assert_eq!(val, "ACME"); // Should return first match
     }
+
+    #[test]
+    fn test_range_queries_pass_query_validation() {
+        let board = Board {
+            id: None,
+            name: None,
+            columns: Vec::new(),
+            groups: Some(vec![
+                Group {
+                    id: "group_2024".to_string(),
+                    title: "2024".to_string(),
+                    items_page: None,
+                },
+                Group {
+                    id: "group_2025".to_string(),
+                    title: "2025".to_string(),
+                    items_page: None,
+                },
+            ]),
+        };
+        // Monday 2024-12-30 to Friday 2025-01-03
+        let start = NaiveDate::from_ymd_opt(2024, 12, 30).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 1, 3).unwrap();
+        let dates =
+            calculate_working_dates(start, count_days_in_range(start, end, true) as i64, true);
+
+        let queries = range_queries(&board, &dates);
+        assert_eq!(
+            queries,
+            [
+                (
+                    "group_2024".to_string(),
+                    vec!["2024-12-30".to_string(), "2024-12-31".to_string()]
+                ),
+                (
+                    "group_2025".to_string(),
+                    vec![
+                        "2025-01-01".to_string(),
+                        "2025-01-02".to_string(),
+                        "2025-01-03".to_string()
+                    ]
+                ),
+            ]
+        );
+        let config = Config::default();
+        for (group_id, date_strings) in &queries {
+            validate_query_params(
+                &config.board_id,
+                group_id,
+                create_test_user().id,
+                date_strings,
+                MAX_ITEMS_PAGE_LIMIT,
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_items_in_range() {
+        let mut other_user = create_test_item("5", "2025-09-16", "ACME Corp", "PROJ-001");
+        other_user
+            .column_values
+            .retain(|col| col.id.as_deref() != Some("person"));
+        let items = vec![
+            create_test_item("4", "2025-09-17", "Globex", "GLX-1"),
+            create_test_item("3", "2025-09-16", "acme corp", "PROJ-002"),
+            create_test_item("2", "2025-09-15", "ACME Corp", "PROJ-001"),
+            create_test_item("2", "2025-09-15", "ACME Corp", "PROJ-001"),
+            create_test_item("1", "2025-09-22", "ACME Corp", "PROJ-001"),
+            other_user,
+        ];
+        // Monday 15 to Wednesday 17
+        let dates: Vec<NaiveDate> = (15..=17)
+            .map(|day| NaiveDate::from_ymd_opt(2025, 9, day).unwrap())
+            .collect();
+        let ids = |items: Vec<Item>| -> Vec<String> {
+            items.into_iter().filter_map(|item| item.id).collect()
+        };
+
+        assert_eq!(
+            ids(items_in_range(items.clone(), 12345, &dates, None, None)),
+            ["2", "3", "4"]
+        );
+        assert_eq!(
+            ids(items_in_range(
+                items.clone(),
+                12345,
+                &dates,
+                Some("ACME CORP"),
+                None
+            )),
+            ["2", "3"]
+        );
+        assert_eq!(
+            ids(items_in_range(
+                items,
+                12345,
+                &dates,
+                Some("ACME Corp"),
+                Some("proj-002")
+            )),
+            ["3"]
+        );
+    }
+
+    #[test]
+    fn test_range_table() {
+        let items = vec![create_test_item("9", "2025-09-15", "ACME Corp", "PROJ-001")];
+        let table = range_table(&items, &Config::default());
+        assert!(table.contains("2025-09-15"));
+        assert!(table.contains("PROJ-001"));
+        assert!(table.ends_with("(1 row)\n"));
+    }
 }
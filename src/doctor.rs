@@ -0,0 +1,325 @@
+//! `claim doctor`: diagnostic checks for common configuration issues
+
+use crate::cache::EntryCache;
+use crate::config::{Config, DEFAULT_BOARD_ID};
+use crate::monday::MondayClient;
+use crate::utils;
+use chrono::{Datelike, Utc};
+
+/// Clock skew (in seconds) above which a warning is shown
+const CLOCK_SKEW_WARN_SECS: i64 = 60;
+/// Clock skew (in seconds) above which the check fails
+const CLOCK_SKEW_FAIL_SECS: i64 = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    fn label(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "✅ PASS",
+            CheckStatus::Warn => "⚠️  WARN",
+            CheckStatus::Fail => "❌ FAIL",
+        }
+    }
+}
+
+/// Outcome of a single diagnostic check
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+    pub remediation: Option<String>,
+}
+
+impl CheckResult {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        CheckResult {
+            name,
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            remediation: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, remediation: impl Into<String>) -> Self {
+        CheckResult {
+            name,
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+            remediation: Some(remediation.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, remediation: impl Into<String>) -> Self {
+        CheckResult {
+            name,
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            remediation: Some(remediation.into()),
+        }
+    }
+
+    fn print(&self) {
+        println!("{}  {:<22} {}", self.status.label(), self.name, self.detail);
+        if let Some(ref remediation) = self.remediation {
+            println!("          ↳ {}", remediation);
+        }
+    }
+}
+
+/// Runs all diagnostic checks, printing each result as it completes.
+/// Returns `true` when no check failed.
+pub async fn run_doctor(verbose: bool) -> bool {
+    println!("🩺 Running claim diagnostics...\n");
+
+    let mut results = Vec::new();
+    let mut record = |result: CheckResult| {
+        result.print();
+        results.push(result);
+    };
+
+    // Config file
+    let config_path = Config::get_config_path()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "<unknown>".to_string());
+    let config = match Config::load() {
+        Ok(config) => {
+            record(CheckResult::pass("Config file", config_path));
+            Some(config)
+        }
+        Err(e) => {
+            record(CheckResult::fail(
+                "Config file",
+                format!("{} ({})", e, config_path),
+                "Run `claim` to set up your API key, or fix the JSON in the config file",
+            ));
+            None
+        }
+    };
+
+    // API key
+    let api_key = config
+        .as_ref()
+        .map(|c| c.api_key.trim().to_string())
+        .unwrap_or_default();
+    if api_key.is_empty() {
+        record(CheckResult::fail(
+            "API key present",
+            "API key is empty",
+            "Run `claim` and enter a Monday.com API key (Profile → Developers → My access tokens)",
+        ));
+    } else {
+        record(CheckResult::pass(
+            "API key present",
+            utils::mask_api_key(&api_key),
+        ));
+    }
+
+    // API key validity, board and year group
+    if !api_key.is_empty() {
+        let client = MondayClient::new(api_key);
+        let api_ok = match client.get_current_user_verbose(verbose).await {
+            Ok(user) => {
+                record(CheckResult::pass(
+                    "API key valid",
+                    format!("authenticated as {} ({})", user.name, user.email),
+                ));
+                true
+            }
+            Err(e) => {
+                record(CheckResult::fail(
+                    "API key valid",
+                    e.to_string(),
+                    "Generate a new API key in Monday.com and update the config file",
+                ));
+                false
+            }
+        };
+
+        if api_ok {
+            let board_id = config
+                .as_ref()
+                .map_or(DEFAULT_BOARD_ID, |c| c.board_id.as_str());
+            match client.get_board_with_groups(board_id, verbose).await {
+                Ok(board) => {
+                    record(CheckResult::pass(
+                        "Board accessible",
+                        format!(
+                            "{} ({})",
+                            board.name.clone().unwrap_or_else(|| board_id.to_string()),
+                            board_id
+                        ),
+                    ));
+
+                    let today = config
+                        .as_ref()
+                        .map_or_else(|| Config::default().today(), Config::today);
+                    let year = today.year().to_string();
+                    record(check_year_group(&board, &year));
+                }
+                Err(e) => {
+                    record(CheckResult::fail(
+                        "Board accessible",
+                        e.to_string(),
+                        format!(
+                            "Ask the board owner to give your account access to board {}",
+                            board_id
+                        ),
+                    ));
+                }
+            }
+
+            match client.get_server_time().await {
+                Ok(Some(server_time)) => {
+                    let skew = (Utc::now() - server_time).num_seconds();
+                    record(check_clock_skew(skew));
+                }
+                Ok(None) => record(CheckResult::warn(
+                    "Clock skew",
+                    "server did not report its time",
+                    "No action needed unless dates are assigned to the wrong day",
+                )),
+                Err(e) => record(CheckResult::warn(
+                    "Clock skew",
+                    e.to_string(),
+                    "Check your network connection",
+                )),
+            }
+        }
+    }
+
+    // Cache
+    let cache_path = EntryCache::get_cache_path()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|| "<unknown>".to_string());
+    match EntryCache::load() {
+        Ok(cache) => {
+            record(CheckResult::pass("Cache file", cache_path));
+            let count: usize = cache.entries.values().map(|v| v.len()).sum();
+            if count == 0 {
+                record(CheckResult::warn(
+                    "Cache entries",
+                    "cache is empty",
+                    "Run `claim add -r` or press 'u' in the interactive UI to populate it",
+                ));
+            } else {
+                record(CheckResult::pass(
+                    "Cache entries",
+                    format!("{} entries for {} user(s)", count, cache.entries.len()),
+                ));
+            }
+        }
+        Err(e) => {
+            record(CheckResult::warn(
+                "Cache file",
+                format!("{} ({})", e, cache_path),
+                "Delete the cache file; it will be rebuilt automatically",
+            ));
+        }
+    }
+
+    print_summary(&results)
+}
+
+fn check_year_group(board: &crate::monday::Board, year: &str) -> CheckResult {
+    let found = board
+        .groups
+        .as_ref()
+        .map(|groups| groups.iter().any(|g| g.title == year))
+        .unwrap_or(false);
+
+    if found {
+        CheckResult::pass("Year group", format!("group '{}' exists", year))
+    } else {
+        CheckResult::warn(
+            "Year group",
+            format!(
+                "no group titled '{}', entries go to the default group",
+                year
+            ),
+            format!("Create a group named '{}' on the board", year),
+        )
+    }
+}
+
+fn check_clock_skew(skew_secs: i64) -> CheckResult {
+    let detail = format!("local clock is {:+}s from server time", skew_secs);
+    if skew_secs.abs() >= CLOCK_SKEW_FAIL_SECS {
+        CheckResult::fail(
+            "Clock skew",
+            detail,
+            "Synchronize your system clock (e.g. enable NTP)",
+        )
+    } else if skew_secs.abs() >= CLOCK_SKEW_WARN_SECS {
+        CheckResult::warn(
+            "Clock skew",
+            detail,
+            "Consider synchronizing your system clock",
+        )
+    } else {
+        CheckResult::pass("Clock skew", detail)
+    }
+}
+
+/// Prints the totals line and returns `true` when nothing failed
+fn print_summary(results: &[CheckResult]) -> bool {
+    let count = |status| results.iter().filter(|r| r.status == status).count();
+    let failed = count(CheckStatus::Fail);
+
+    println!(
+        "\n{} passed, {} warnings, {} failed",
+        count(CheckStatus::Pass),
+        count(CheckStatus::Warn),
+        failed
+    );
+
+    failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monday::{Board, Group};
+
+    #[test]
+    fn test_check_clock_skew() {
+        assert_eq!(check_clock_skew(3).status, CheckStatus::Pass);
+        assert_eq!(check_clock_skew(-90).status, CheckStatus::Warn);
+        assert_eq!(check_clock_skew(600).status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_check_year_group() {
+        let board = Board {
+            id: Some("1".to_string()),
+            name: Some("Claims".to_string()),
+            columns: Vec::new(),
+            groups: Some(vec![Group {
+                id: "g1".to_string(),
+                title: "2025".to_string(),
+                items_page: None,
+            }]),
+        };
+
+        assert_eq!(check_year_group(&board, "2025").status, CheckStatus::Pass);
+        assert_eq!(check_year_group(&board, "2026").status, CheckStatus::Warn);
+    }
+
+    #[test]
+    fn test_print_summary() {
+        let mut results = vec![
+            CheckResult::pass("a", ""),
+            CheckResult::warn("b", "", "fix"),
+        ];
+        assert!(print_summary(&results));
+
+        results.push(CheckResult::fail("c", "", "fix"));
+        assert!(!print_summary(&results));
+    }
+}
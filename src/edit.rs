@@ -0,0 +1,377 @@
+//! `claim edit`: changes the columns of an existing entry in place
+
+use crate::add::extract_date_from_item;
+use crate::config::Config;
+use crate::init::parse_activity_type;
+use crate::monday::{
+    is_user_item, update_column_mutation, update_item_mutation, Item, MondayClient, MondayUser,
+};
+use crate::query::{extract_column_value, extract_status_label};
+use crate::utils::{self, map_activity_type_to_value, normalize_date, validate_date};
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate};
+use serde_json::json;
+use std::io;
+
+/// The new values given on the command line
+#[derive(Debug, Default, Clone, PartialEq)]
+struct EditFields {
+    date: Option<NaiveDate>,
+    activity_value: Option<u8>,
+    customer: Option<String>,
+    work_item: Option<String>,
+    hours: Option<f64>,
+    comment: Option<String>,
+}
+
+/// A column whose value changes
+#[derive(Debug, Clone, PartialEq)]
+struct FieldChange {
+    label: &'static str,
+    column_id: &'static str,
+    old: String,
+    new: String,
+    value: serde_json::Value,
+}
+
+/// Handles `claim edit -x ID [-D DATE] [-t TYPE] [-c CUSTOMER] [-w WI] [-H HOURS] [-k COMMENT]`
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_edit_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    item_id: &str,
+    date: Option<String>,
+    activity_type: Option<String>,
+    customer: Option<String>,
+    work_item: Option<String>,
+    hours: Option<f64>,
+    comment: Option<String>,
+    dry_run: bool,
+    yes: bool,
+    verbose: bool,
+) -> Result<()> {
+    let fields = parse_fields(
+        config,
+        date,
+        activity_type,
+        customer,
+        work_item,
+        hours,
+        comment,
+    )?;
+    if fields == EditFields::default() {
+        return Err(anyhow!(
+            "Nothing to change. Pass at least one of -D, -t, -c, -w, -H or -k"
+        ));
+    }
+
+    let item = client
+        .get_item_by_id(item_id, verbose)
+        .await?
+        .ok_or_else(|| anyhow!("No entry found with ID {}", item_id))?;
+    if !is_user_item(&item, user.id) {
+        return Err(anyhow!(
+            "Entry {} belongs to another user; only your own entries can be edited",
+            item_id
+        ));
+    }
+    if let Some(old_date) = extract_date_from_item(&item) {
+        config.ensure_week_unlocked(old_date)?;
+        if let Some(new_date) = fields.date {
+            config.ensure_week_unlocked(new_date)?;
+            if new_date.year() != old_date.year() {
+                println!(
+                    "⚠️  The entry stays in its {} group; run `claim migrate` to move it",
+                    old_date.year()
+                );
+            }
+        }
+    }
+
+    let changes = field_changes(&item, config, &fields);
+    if changes.is_empty() {
+        println!(
+            "✅ Entry {} already has these values; nothing to change",
+            item_id
+        );
+        return Ok(());
+    }
+
+    println!("\nChanges to entry {}:", item_id);
+    for change in &changes {
+        println!(
+            "  {:<14} {} → {}",
+            format!("{}:", change.label),
+            display_value(&change.old),
+            display_value(&change.new)
+        );
+    }
+
+    let column_values = serde_json::Value::Object(
+        changes
+            .iter()
+            .map(|change| (change.column_id.to_string(), change.value.clone()))
+            .collect(),
+    );
+    // A single change only sends that column
+    let single = match changes.as_slice() {
+        [change] => Some(change),
+        _ => None,
+    };
+    if dry_run {
+        let mutation = match single {
+            Some(change) => {
+                update_column_mutation(&config.board_id, item_id, change.column_id, &change.value)
+            }
+            None => update_item_mutation(&config.board_id, item_id, &column_values),
+        };
+        println!("\n📋 GraphQL Mutation that would be executed:");
+        println!("{}", mutation);
+        println!("Dry run: nothing was changed. Run without --dry-run to update the entry.");
+        return Ok(());
+    }
+
+    if !yes {
+        println!("\nDo you want to proceed? (y/N)");
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+        if confirmation.trim().to_lowercase() != "y" {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    }
+    match single {
+        Some(change) => {
+            client
+                .update_item_column(
+                    &config.board_id,
+                    item_id,
+                    change.column_id,
+                    &change.value,
+                    verbose,
+                )
+                .await?
+        }
+        None => {
+            client
+                .update_item_verbose(&config.board_id, item_id, &column_values, verbose)
+                .await?
+        }
+    }
+
+    println!(
+        "✅ Updated entry {} ({} field{} changed)",
+        item_id,
+        changes.len(),
+        if changes.len() == 1 { "" } else { "s" }
+    );
+    Ok(())
+}
+
+/// Validates the command-line values
+fn parse_fields(
+    config: &Config,
+    date: Option<String>,
+    activity_type: Option<String>,
+    customer: Option<String>,
+    work_item: Option<String>,
+    hours: Option<f64>,
+    comment: Option<String>,
+) -> Result<EditFields> {
+    let date = match date {
+        Some(date) => {
+            validate_date(&date, config.today())?;
+            Some(NaiveDate::parse_from_str(
+                &normalize_date(&date, config.today()),
+                "%Y-%m-%d",
+            )?)
+        }
+        None => None,
+    };
+    let activity_value = match activity_type {
+        Some(activity_type) => Some(map_activity_type_to_value(&parse_activity_type(
+            &activity_type,
+        )?)),
+        None => None,
+    };
+    let hours = match hours {
+        Some(hours) if !(hours > 0.0 && hours <= 24.0) => {
+            return Err(anyhow!("Hours must be between 0 and 24, got {}", hours))
+        }
+        Some(hours) => Some(match config.round_hours_to {
+            Some(nearest) => utils::round_to_nearest(hours, nearest),
+            None => hours,
+        }),
+        None => None,
+    };
+
+    Ok(EditFields {
+        date,
+        activity_value,
+        customer,
+        work_item,
+        hours,
+        comment,
+    })
+}
+
+/// The columns of `item` that `fields` changes, with old and new values
+fn field_changes(item: &Item, config: &Config, fields: &EditFields) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    let mut push = |label, column_id, old: String, new: String, value| {
+        if old != new {
+            changes.push(FieldChange {
+                label,
+                column_id,
+                old,
+                new,
+                value,
+            });
+        }
+    };
+
+    if let Some(date) = fields.date {
+        push(
+            "Date",
+            "date4",
+            extract_date_from_item(item)
+                .map(|d| d.to_string())
+                .unwrap_or_default(),
+            date.to_string(),
+            json!({ "date": date.format("%Y-%m-%d").to_string() }),
+        );
+    }
+    if let Some(activity_value) = fields.activity_value {
+        push(
+            "Activity type",
+            "status",
+            extract_status_label(item, config),
+            config.activity_label(activity_value),
+            json!({ "index": activity_value }),
+        );
+    }
+    for (label, column_id, new) in [
+        ("Customer", "text__1", &fields.customer),
+        ("Work item", "text8__1", &fields.work_item),
+        ("Comment", "text2__1", &fields.comment),
+    ] {
+        if let Some(new) = new {
+            push(
+                label,
+                column_id,
+                extract_column_value(item, column_id),
+                new.clone(),
+                json!(new),
+            );
+        }
+    }
+    if let Some(hours) = fields.hours {
+        let old = extract_column_value(item, "numbers__1")
+            .trim_matches('"')
+            .to_string();
+        let old_hours = old.parse::<f64>().ok();
+        if old_hours != Some(hours) {
+            push(
+                "Hours",
+                "numbers__1",
+                old,
+                hours.to_string(),
+                json!(hours.to_string()),
+            );
+        }
+    }
+    changes
+}
+
+fn display_value(value: &str) -> &str {
+    if value.is_empty() {
+        "(empty)"
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monday::ColumnValue;
+
+    fn column(id: &str, value: &str, text: &str) -> ColumnValue {
+        ColumnValue {
+            id: Some(id.to_string()),
+            value: Some(value.to_string()),
+            text: Some(text.to_string()),
+        }
+    }
+
+    fn entry() -> Item {
+        Item {
+            id: Some("12345".to_string()),
+            column_values: vec![
+                column("date4", r#"{"date": "2025-09-15"}"#, "2025-09-15"),
+                column("status", r#"{"index": 1}"#, "billable"),
+                column("text__1", "\"ACME\"", "ACME"),
+                column("text8__1", "\"PROJ-1\"", "PROJ-1"),
+                column("numbers__1", "\"4\"", "4"),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_field_changes_only_lists_changed_columns() {
+        let fields = EditFields {
+            customer: Some("ACME".to_string()),
+            work_item: Some("PROJ-2".to_string()),
+            hours: Some(8.0),
+            ..Default::default()
+        };
+        let changes = field_changes(&entry(), &Config::default(), &fields);
+
+        let columns: Vec<&str> = changes.iter().map(|c| c.column_id).collect();
+        assert_eq!(columns, ["text8__1", "numbers__1"]);
+        assert_eq!(changes[0].old, "PROJ-1");
+        assert_eq!(changes[0].value, json!("PROJ-2"));
+        assert_eq!(changes[1].old, "4");
+        assert_eq!(changes[1].value, json!("8"));
+    }
+
+    #[test]
+    fn test_field_changes_date_and_activity() {
+        let fields = EditFields {
+            date: NaiveDate::from_ymd_opt(2025, 9, 16),
+            activity_value: Some(2),
+            hours: Some(4.0),
+            ..Default::default()
+        };
+        let changes = field_changes(&entry(), &Config::default(), &fields);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].value, json!({"date": "2025-09-16"}));
+        assert_eq!(changes[1].old, "billable");
+        assert_eq!(changes[1].new, "holding");
+        assert_eq!(changes[1].value, json!({"index": 2}));
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        let config = Config::default();
+        let fields = parse_fields(
+            &config,
+            Some("2025.09.15".to_string()),
+            Some("presales".to_string()),
+            None,
+            None,
+            Some(7.5),
+            None,
+        )
+        .unwrap();
+        assert_eq!(fields.date, NaiveDate::from_ymd_opt(2025, 9, 15));
+        assert_eq!(fields.activity_value, Some(7));
+        assert_eq!(fields.hours, Some(7.5));
+
+        assert!(parse_fields(&config, None, Some("party".into()), None, None, None, None).is_err());
+        assert!(parse_fields(&config, None, None, None, None, Some(25.0), None).is_err());
+    }
+}
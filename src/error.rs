@@ -58,6 +58,9 @@ pub enum ConfigError {
 
     #[error("Failed to create config directory: {0}")]
     DirectoryCreationFailed(String),
+
+    #[error("Profile '{0}' not found. Add it with `claim config profile add {0}`")]
+    ProfileNotFound(String),
 }
 
 /// API-related errors
@@ -0,0 +1,316 @@
+//! `claim export`: writes the entries of a date range as CSV or JSON
+
+use crate::config::Config;
+use crate::formatters::ExportFormat;
+use crate::monday::{is_user_item, Item, MondayClient, MondayUser};
+use crate::query::{export_items, extract_item_date};
+use crate::utils::{self, get_year_group_id};
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate, Weekday};
+use clap::ValueEnum;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Upper bound on the items read from one year group for ranges longer
+/// than a month
+const MAX_RANGE_ITEMS: usize = 10_000;
+
+/// Period covered by each file written with `--output-dir`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ExportGroupBy {
+    /// One file per day with entries
+    #[default]
+    Day,
+    /// One file per ISO week, in the folder of its Monday
+    Week,
+    /// One file per month
+    Month,
+}
+
+/// Handles `claim export [--from DATE] [--to DATE] [--format csv|json]
+/// [--output-file PATH | --output-dir PATH [--group-by day|week|month]]`
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_export_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    from: Option<String>,
+    to: Option<String>,
+    format: ExportFormat,
+    output_file: Option<PathBuf>,
+    output_dir: Option<PathBuf>,
+    group_by: ExportGroupBy,
+    verbose: bool,
+) -> Result<()> {
+    let (from, to) = export_range(config.today(), from, to)?;
+    let mut items = query_user_items_in_range(client, config, user, from, to, verbose).await?;
+    items.sort_by_key(|item| (extract_item_date(item), item.id.clone()));
+
+    if let Some(dir) = output_dir {
+        return export_to_dir(&items, config, format, &dir, group_by);
+    }
+
+    let rendered = export_items(&items, config, format)?;
+    match output_file {
+        Some(path) => {
+            std::fs::write(&path, rendered)
+                .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+            println!(
+                "✅ Exported {} entries from {} to {} to {}",
+                items.len(),
+                from,
+                to,
+                path.display()
+            );
+        }
+        None => print!("{}", rendered),
+    }
+    Ok(())
+}
+
+/// Writes one file per day, week or month under `dir`, e.g.
+/// `dir/2025/09/claim-2025-09-15.json`; periods without entries get no file
+fn export_to_dir(
+    items: &[Item],
+    config: &Config,
+    format: ExportFormat,
+    dir: &Path,
+    group_by: ExportGroupBy,
+) -> Result<()> {
+    let extension = match format {
+        ExportFormat::Csv => "csv",
+        ExportFormat::Json => "json",
+    };
+    let mut groups: BTreeMap<PathBuf, Vec<Item>> = BTreeMap::new();
+    for item in items {
+        let Some(date) = extract_item_date(item)
+            .and_then(|date| NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok())
+        else {
+            continue;
+        };
+        groups
+            .entry(group_path(date, group_by, extension))
+            .or_default()
+            .push(item.clone());
+    }
+
+    for (relative, items) in &groups {
+        let path = dir.join(relative);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| anyhow!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        std::fs::write(&path, export_items(items, config, format)?)
+            .map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+    }
+    println!(
+        "✅ Exported {} entries to {} files in {}",
+        items.len(),
+        groups.len(),
+        dir.display()
+    );
+    Ok(())
+}
+
+/// Path of the file holding the entries of `date`, relative to the output
+/// directory: `YYYY/MM/claim-YYYY-MM-DD`, `claim-YYYY-Www` (in the folder
+/// of the week's Monday) or `claim-YYYY-MM`
+fn group_path(date: NaiveDate, group_by: ExportGroupBy, extension: &str) -> PathBuf {
+    let (folder, name) = match group_by {
+        ExportGroupBy::Day => (date, date.format("%Y-%m-%d").to_string()),
+        ExportGroupBy::Week => {
+            let monday = utils::get_week_start(date, Weekday::Mon);
+            let week = date.iso_week();
+            (monday, format!("{}-W{:02}", week.year(), week.week()))
+        }
+        ExportGroupBy::Month => (date, date.format("%Y-%m").to_string()),
+    };
+    PathBuf::from(folder.format("%Y").to_string())
+        .join(folder.format("%m").to_string())
+        .join(format!("claim-{}.{}", name, extension))
+}
+
+/// First and last day to export; the current month up to today by default
+fn export_range(
+    today: NaiveDate,
+    from: Option<String>,
+    to: Option<String>,
+) -> Result<(NaiveDate, NaiveDate)> {
+    let parse = |date: &str| -> Result<NaiveDate> {
+        utils::validate_date(date, today)?;
+        Ok(NaiveDate::parse_from_str(
+            &utils::normalize_date(date, today),
+            "%Y-%m-%d",
+        )?)
+    };
+    let from = match from {
+        Some(from) => parse(&from)?,
+        None => today.with_day(1).unwrap_or(today),
+    };
+    let to = match to {
+        Some(to) => parse(&to)?,
+        None => today.max(from),
+    };
+    if to < from {
+        return Err(anyhow!("--to {} is before --from {}", to, from));
+    }
+    Ok((from, to))
+}
+
+/// Items of `user` dated from `from` to `to`, read from the group of every
+/// year in the range. Ranges up to a month filter by date on the server;
+/// longer ones page through the whole group with a cursor.
+pub(crate) async fn query_user_items_in_range(
+    client: &MondayClient,
+    config: &Config,
+    user: &MondayUser,
+    from: NaiveDate,
+    to: NaiveDate,
+    verbose: bool,
+) -> Result<Vec<Item>> {
+    let board_id = config.board_id.as_str();
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let dates: Vec<NaiveDate> = from.iter_days().take_while(|d| *d <= to).collect();
+
+    let mut items: Vec<Item> = Vec::new();
+    for year in from.year()..=to.year() {
+        let group_id = get_year_group_id(&board, &year.to_string());
+        if dates.len() > 31 {
+            items.extend(
+                client
+                    .query_all_items_in_group(board_id, &group_id, MAX_RANGE_ITEMS, verbose)
+                    .await?,
+            );
+        } else {
+            let date_strings: Vec<String> = dates
+                .iter()
+                .filter(|d| d.year() == year)
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .collect();
+            items.extend(
+                client
+                    .query_items_with_filters(
+                        board_id,
+                        &group_id,
+                        user.id,
+                        &date_strings,
+                        500,
+                        verbose,
+                    )
+                    .await?,
+            );
+        }
+    }
+
+    Ok(items
+        .into_iter()
+        .filter(|item| is_user_item(item, user.id))
+        .filter(|item| {
+            extract_item_date(item)
+                .and_then(|date| NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok())
+                .is_some_and(|date| date >= from && date <= to)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_export_range() {
+        let today = date(9, 17);
+        assert_eq!(
+            export_range(today, None, None).unwrap(),
+            (date(9, 1), date(9, 17))
+        );
+        assert_eq!(
+            export_range(today, Some("2025-10-01".into()), None).unwrap(),
+            (date(10, 1), date(10, 1))
+        );
+        assert_eq!(
+            export_range(today, Some("2024/12/15".into()), Some("2025.01.15".into())).unwrap(),
+            (NaiveDate::from_ymd_opt(2024, 12, 15).unwrap(), date(1, 15))
+        );
+        assert!(export_range(today, Some("2025-09-10".into()), Some("2025-09-01".into())).is_err());
+        assert!(export_range(today, None, Some("soon".into())).is_err());
+    }
+
+    #[test]
+    fn test_group_path() {
+        let path = |date: NaiveDate, group_by| group_path(date, group_by, "json");
+        assert_eq!(
+            path(date(9, 15), ExportGroupBy::Day),
+            Path::new("2025/09/claim-2025-09-15.json")
+        );
+        assert_eq!(
+            path(date(9, 17), ExportGroupBy::Week),
+            Path::new("2025/09/claim-2025-W38.json")
+        );
+        assert_eq!(
+            path(date(9, 17), ExportGroupBy::Month),
+            Path::new("2025/09/claim-2025-09.json")
+        );
+        // Wednesday 2025-01-01 is in week 1 of 2025, which starts on Monday
+        // 2024-12-30
+        assert_eq!(
+            path(date(1, 1), ExportGroupBy::Week),
+            Path::new("2024/12/claim-2025-W01.json")
+        );
+    }
+
+    #[test]
+    fn test_export_to_dir() {
+        let item = |id: &str, date: &str| Item {
+            id: Some(id.to_string()),
+            name: None,
+            updated_at: None,
+            column_values: vec![crate::monday::ColumnValue {
+                id: Some("date4".to_string()),
+                value: Some(format!(r#"{{"date":"{}"}}"#, date)),
+                text: Some(date.to_string()),
+            }],
+        };
+        let items = vec![
+            item("1", "2025-09-15"),
+            item("2", "2025-09-15"),
+            item("3", "2025-09-16"),
+        ];
+        let dir = tempfile::tempdir().unwrap();
+
+        export_to_dir(
+            &items,
+            &Config::default(),
+            ExportFormat::Csv,
+            dir.path(),
+            ExportGroupBy::Day,
+        )
+        .unwrap();
+        let monday =
+            std::fs::read_to_string(dir.path().join("2025/09/claim-2025-09-15.csv")).unwrap();
+        assert_eq!(monday.lines().count(), 3);
+        assert!(dir.path().join("2025/09/claim-2025-09-16.csv").exists());
+
+        export_to_dir(
+            &items,
+            &Config::default(),
+            ExportFormat::Json,
+            dir.path(),
+            ExportGroupBy::Month,
+        )
+        .unwrap();
+        let month = std::fs::read_to_string(dir.path().join("2025/09/claim-2025-09.json")).unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&month)
+                .unwrap()
+                .as_array()
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+}
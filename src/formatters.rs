@@ -0,0 +1,247 @@
+//! Alternative output formats for tabular command output
+
+use clap::ValueEnum;
+
+/// Output format for commands that print tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// The default human-readable layout
+    #[default]
+    Table,
+    /// PostgreSQL `psql` aligned table style
+    Psql,
+}
+
+/// Machine-readable output for scripts and spreadsheets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// A JSON array with one object per entry
+    Json,
+    /// RFC 4180 CSV with a header row
+    Csv,
+}
+
+/// Renders rows in the style of PostgreSQL's aligned `psql` output:
+///
+/// ```text
+///  date       | customer | hours
+/// ------------+----------+-------
+///  2025-09-15 | ACME     |   8.0
+/// (1 row)
+/// ```
+pub struct PsqlTableFormatter {
+    columns: Vec<String>,
+    right_aligned: Vec<bool>,
+    rows: Vec<Vec<String>>,
+}
+
+impl PsqlTableFormatter {
+    pub fn new(columns: &[&str]) -> Self {
+        PsqlTableFormatter {
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            right_aligned: vec![false; columns.len()],
+            rows: Vec::new(),
+        }
+    }
+
+    /// Right-aligns the values of `column`, as psql does for numbers
+    pub fn align_right(mut self, column: usize) -> Self {
+        if let Some(aligned) = self.right_aligned.get_mut(column) {
+            *aligned = true;
+        }
+        self
+    }
+
+    /// Appends a row; missing cells are left empty and extra cells ignored
+    pub fn add_row(&mut self, mut row: Vec<String>) {
+        row.resize(self.columns.len(), String::new());
+        self.rows.push(row);
+    }
+
+    /// Width of each column: the widest of its header and values
+    fn column_widths(&self) -> Vec<usize> {
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(i, header)| {
+                self.rows
+                    .iter()
+                    .map(|row| row[i].chars().count())
+                    .chain(std::iter::once(header.chars().count()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    /// Renders the table, including the trailing "(N rows)" line
+    pub fn render(&self) -> String {
+        let widths = self.column_widths();
+
+        let format_line = |cells: &[String], align_right: &[bool]| {
+            cells
+                .iter()
+                .zip(&widths)
+                .zip(align_right)
+                .map(|((cell, width), right)| {
+                    if *right {
+                        format!(" {:>width$} ", cell, width = width)
+                    } else {
+                        format!(" {:<width$} ", cell, width = width)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("|")
+                .trim_end()
+                .to_string()
+        };
+
+        let mut lines = vec![format_line(&self.columns, &vec![false; widths.len()])];
+        lines.push(
+            widths
+                .iter()
+                .map(|w| "-".repeat(w + 2))
+                .collect::<Vec<_>>()
+                .join("+"),
+        );
+        for row in &self.rows {
+            lines.push(format_line(row, &self.right_aligned));
+        }
+        lines.push(format!(
+            "({} {})",
+            self.rows.len(),
+            if self.rows.len() == 1 { "row" } else { "rows" }
+        ));
+
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Renders rows as RFC 4180 CSV: a header row, CRLF line endings, and
+/// fields quoted when they contain a comma, a quote or a line break
+pub struct CsvFormatter {
+    columns: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl CsvFormatter {
+    pub fn new(columns: &[&str]) -> Self {
+        CsvFormatter {
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Appends a row; missing cells are left empty and extra cells ignored
+    pub fn add_row(&mut self, mut row: Vec<String>) {
+        row.resize(self.columns.len(), String::new());
+        self.rows.push(row);
+    }
+
+    pub fn render(&self) -> String {
+        std::iter::once(&self.columns)
+            .chain(&self.rows)
+            .map(|row| {
+                row.iter()
+                    .map(|field| csv_field(field))
+                    .collect::<Vec<_>>()
+                    .join(",")
+                    + "\r\n"
+            })
+            .collect()
+    }
+}
+
+/// Quotes `value` for CSV when needed, doubling embedded quotes
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Splits one CSV line into its fields, undoing the quoting of `csv_field`
+pub fn parse_csv_record(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.trim_end_matches(['\r', '\n']).chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                in_quotes = true;
+            }
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_psql_table_render() {
+        let mut table = PsqlTableFormatter::new(&["date", "customer", "hours"]).align_right(2);
+        table.add_row(vec!["2025-09-15".into(), "ACME".into(), "8.0".into()]);
+        table.add_row(vec![
+            "2025-09-16".into(),
+            "Globex Corp".into(),
+            "10.5".into(),
+        ]);
+
+        assert_eq!(
+            table.render(),
+            " date       | customer    | hours\n\
+             ------------+-------------+-------\n \
+             2025-09-15 | ACME        |   8.0\n \
+             2025-09-16 | Globex Corp |  10.5\n\
+             (2 rows)\n"
+        );
+    }
+
+    #[test]
+    fn test_psql_table_pads_short_rows() {
+        let mut table = PsqlTableFormatter::new(&["a", "b"]);
+        table.add_row(vec!["x".into()]);
+
+        assert_eq!(table.render(), " a | b\n---+---\n x |\n(1 row)\n");
+    }
+
+    #[test]
+    fn test_csv_render() {
+        let mut csv = CsvFormatter::new(&["customer", "comment", "hours"]);
+        csv.add_row(vec!["ACME".into(), "call, \"urgent\"".into(), "8".into()]);
+        csv.add_row(vec!["Globex".into(), "two\nlines".into()]);
+
+        assert_eq!(
+            csv.render(),
+            "customer,comment,hours\r\n\
+             ACME,\"call, \"\"urgent\"\"\",8\r\n\
+             Globex,\"two\nlines\",\r\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_record() {
+        assert_eq!(
+            parse_csv_record("2025-09-15,billable,ACME,PROJ-1,8\r\n"),
+            ["2025-09-15", "billable", "ACME", "PROJ-1", "8"]
+        );
+        assert_eq!(
+            parse_csv_record("ACME,\"call, \"\"urgent\"\"\",,8"),
+            ["ACME", "call, \"urgent\"", "", "8"]
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! `claim init`: guided first-time setup
+
+use crate::config::Config;
+use crate::doctor;
+use crate::monday::{Board, BoardColumn, MondayClient};
+use crate::utils;
+use anyhow::{anyhow, Result};
+use std::io::{self, IsTerminal, Write};
+
+/// Columns claim expects on the board: (purpose, column id)
+const EXPECTED_COLUMNS: [(&str, &str); 7] = [
+    ("Date", "date4"),
+    ("Person", "person"),
+    ("Activity type (status)", "status"),
+    ("Customer", "text__1"),
+    ("Work item", "text8__1"),
+    ("Hours", "numbers__1"),
+    ("Comment", "text2__1"),
+];
+
+/// Runs the guided setup and returns the saved configuration
+pub async fn run_init(verbose: bool) -> Result<Config> {
+    println!("👋 Welcome to claim! This will set up your configuration.\n");
+
+    // Keep any other settings when re-running init
+    let existing = Config::load().ok();
+
+    // 1. API key
+    println!("Step 1/6: Monday.com API key");
+    if let Some(config) = &existing {
+        println!(
+            "Current key: {} (press Enter to keep it)",
+            utils::mask_api_key(&config.api_key)
+        );
+    }
+    let api_key = Config::prompt_for_api_key();
+    let mut config = match existing {
+        Some(mut config) => {
+            if !api_key.is_empty() && api_key != config.api_key {
+                config.api_key = api_key;
+                // The cached user and time zone belong to the previous account
+                config.cached_user = None;
+                config.workspace_timezone = None;
+            }
+            config
+        }
+        None => Config::new(api_key),
+    };
+    if config.api_key.is_empty() {
+        return Err(anyhow!("API key cannot be empty"));
+    }
+
+    // 2. Connection test
+    println!("\nStep 2/6: Testing connection to Monday.com...");
+    let client = MondayClient::new(config.api_key.clone());
+    client.test_connection_verbose(verbose).await.map_err(|e| {
+        anyhow!(
+            "Failed to validate API key: {}. Please check your API key and try again.",
+            e
+        )
+    })?;
+    println!("✅ Connected");
+
+    // 3-4. Board selection
+    println!("\nStep 3/6: Looking for boards...");
+    let boards = client.list_user_boards(verbose).await?;
+    if boards.is_empty() {
+        return Err(anyhow!("The API key cannot access any boards"));
+    }
+    println!("\nStep 4/6: Select your time-tracking board");
+    let board = select_board(&boards, &config.board_id, &mut io::stdout())?;
+
+    // 5. Column mapping
+    println!(
+        "\nStep 5/6: Checking the columns of '{}'",
+        board.name.as_deref().unwrap_or_default()
+    );
+    let mapping = map_columns(&board.columns);
+    for (purpose, id, column) in &mapping {
+        match column {
+            Some(column) => println!("  ✅ {:<24} → {} ({})", purpose, column.title, id),
+            None => println!("  ❌ {:<24} → no column with id {}", purpose, id),
+        }
+    }
+    let missing = mapping.iter().filter(|(_, _, c)| c.is_none()).count();
+    if missing > 0 {
+        println!(
+            "\n⚠️  {} expected column(s) are missing; adding and querying entries will not work correctly on this board.",
+            missing
+        );
+    }
+    if !confirm("Does this mapping look right? (Y/n)")? {
+        return Err(anyhow!(
+            "Setup cancelled. Check the board's columns and run `claim init` again."
+        ));
+    }
+
+    config.board_id = board.id.clone().unwrap_or_default();
+    config.board_id_missing = false;
+
+    // 6. Defaults
+    println!("\nStep 6/6: Defaults for new entries");
+    let hours = prompt(&format!(
+        "Default hours per entry [{}]: ",
+        config.default_hours_per_day()
+    ))?;
+    if !hours.is_empty() {
+        let hours: f64 = hours
+            .parse()
+            .map_err(|_| anyhow!("Invalid number of hours: {}", hours))?;
+        config.default_hours_per_day = Some(hours);
+    }
+
+    let activity = prompt(&format!(
+        "Default activity type [{}]: ",
+        config.default_activity_type()
+    ))?;
+    if !activity.is_empty() {
+        config.default_activity_type = Some(parse_activity_type(&activity)?);
+    }
+
+    config.save()?;
+    println!("\n✅ Configuration saved");
+    if let Some(path) = Config::get_config_path() {
+        println!("   {}", path.display());
+    }
+
+    println!();
+    doctor::run_doctor(verbose).await;
+    Ok(config)
+}
+
+/// Asks for the time-tracking board when the config file does not name one,
+/// instead of silently using the default board, and saves the choice. The
+/// prompt goes to stderr; without a terminal on stdin, or when `can_prompt`
+/// is false (machine-readable output), it fails with instructions instead.
+pub async fn choose_missing_board(
+    client: &MondayClient,
+    config: &mut Config,
+    can_prompt: bool,
+    verbose: bool,
+) -> Result<()> {
+    if !config.board_id_missing {
+        return Ok(());
+    }
+    if !can_prompt || !io::stdin().is_terminal() {
+        return Err(anyhow!(
+            "No board_id in the config file. Run `claim init`, or `claim boards` and then `claim config set board_id ID`"
+        ));
+    }
+
+    let mut out = io::stderr();
+    writeln!(
+        out,
+        "No board_id in the config file. Select your time-tracking board:"
+    )?;
+    let boards = client.list_user_boards(verbose).await?;
+    if boards.is_empty() {
+        return Err(anyhow!("The API key cannot access any boards"));
+    }
+    let board = select_board(&boards, &config.board_id, &mut out)?;
+
+    config.board_id = board.id.clone().unwrap_or_default();
+    config.board_id_missing = false;
+    config.save()?;
+    writeln!(
+        out,
+        "✅ Using board '{}' ({})\n",
+        board.name.as_deref().unwrap_or_default(),
+        config.board_id
+    )?;
+    Ok(())
+}
+
+/// Lists the boards on `out` and asks for one; Enter keeps `current` when
+/// present
+fn select_board<'a>(boards: &'a [Board], current: &str, out: &mut dyn Write) -> Result<&'a Board> {
+    for (i, line) in crate::boards::board_lines(boards, current, "default")
+        .iter()
+        .enumerate()
+    {
+        writeln!(out, "  {}. {}", i + 1, line)?;
+    }
+
+    let default = boards.iter().position(|b| b.id.as_deref() == Some(current));
+    let selection = prompt_to(out, &format!("Board number (1-{}): ", boards.len()))?;
+
+    let index = match (selection.is_empty(), default) {
+        (true, Some(index)) => index,
+        _ => selection
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .filter(|i| *i < boards.len())
+            .ok_or_else(|| anyhow!("Invalid board selection: {}", selection))?,
+    };
+    Ok(&boards[index])
+}
+
+/// Pairs each expected column with the board column of the same id
+pub(crate) fn map_columns(
+    columns: &[BoardColumn],
+) -> Vec<(&'static str, &'static str, Option<&BoardColumn>)> {
+    EXPECTED_COLUMNS
+        .iter()
+        .map(|(purpose, id)| (*purpose, *id, columns.iter().find(|c| c.id == *id)))
+        .collect()
+}
+
+/// Accepts an activity type name or its number
+pub(crate) fn parse_activity_type(value: &str) -> Result<String> {
+    let value = value.trim().to_lowercase();
+    if let Ok(number) = value.parse::<u8>() {
+        if number <= utils::MAX_ACTIVITY_VALUE {
+            return Ok(utils::map_activity_value_to_name(number));
+        }
+    }
+    if (0..=utils::MAX_ACTIVITY_VALUE).any(|v| utils::map_activity_value_to_name(v) == value) {
+        return Ok(value);
+    }
+    Err(anyhow!("Unknown activity type: {}", value))
+}
+
+fn prompt(message: &str) -> Result<String> {
+    prompt_to(&mut io::stdout(), message)
+}
+
+fn prompt_to(out: &mut dyn Write, message: &str) -> Result<String> {
+    write!(out, "{}", message)?;
+    out.flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+fn confirm(message: &str) -> Result<bool> {
+    let answer = prompt(&format!("{} ", message))?.to_lowercase();
+    Ok(answer.is_empty() || answer == "y" || answer == "yes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(id: &str, title: &str) -> BoardColumn {
+        BoardColumn {
+            id: id.to_string(),
+            title: title.to_string(),
+            column_type: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_map_columns() {
+        let columns = vec![column("date4", "Date"), column("numbers__1", "Hours")];
+        let mapping = map_columns(&columns);
+
+        assert_eq!(mapping.len(), EXPECTED_COLUMNS.len());
+        assert_eq!(mapping[0].2.map(|c| c.title.as_str()), Some("Date"));
+        assert!(mapping[1].2.is_none());
+        assert_eq!(mapping[5].2.map(|c| c.title.as_str()), Some("Hours"));
+    }
+
+    #[test]
+    fn test_parse_activity_type() {
+        assert_eq!(parse_activity_type("Billable").unwrap(), "billable");
+        assert_eq!(parse_activity_type("7").unwrap(), "presales");
+        assert!(parse_activity_type("13").is_err());
+        assert!(parse_activity_type("coffee").is_err());
+    }
+}
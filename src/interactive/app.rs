@@ -1,15 +1,23 @@
 //! Application state and logic for the interactive UI
 
 use anyhow::Result;
-use chrono::{Datelike, Local, NaiveDate};
+use chrono::{DateTime, Datelike, Local, NaiveDate};
 use crossterm::event::{KeyCode, KeyEvent};
-
-use crate::cache::EntryCache;
-use crate::monday::{Item, MondayClient, MondayUser};
+use ratatui::widgets::ListState;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::task::JoinHandle;
+
+use crate::cache::{CachedEntry, EntryCache};
+use crate::config::Config;
+use crate::monday::{diff_column_values, Item, MondayUser};
 use crate::utils;
 
-use super::form::FormData;
+use super::form::{FormData, CACHE_PAGE_SIZE};
 use super::messages::{Message, MessageType};
+use super::simulation::AppClient;
+use super::ui::ColorScheme;
 
 /// Application mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,6 +34,323 @@ pub enum AppMode {
     Help,
     /// Report view
     Report,
+    /// Quick-log popup for the selected day
+    QuickLog,
+    /// Go-to popup for jumping to a date or ISO week
+    Goto,
+    /// Rename popup for changing the selected entry's work item
+    Rename,
+    /// Full-screen list of the entry cache for pruning it
+    CacheManage,
+    /// Find and replace popup for renaming a customer or work item across the week
+    BulkEdit,
+    /// An undo or redo runs in the background
+    Undoing,
+    /// Confirmation before deleting every selected entry
+    BatchDelete,
+    /// Calendar of the month with the hours of every working day
+    MonthView,
+    /// Popup filtering the loaded entries by customer, work item or comment
+    Search,
+    /// Confirmation before copying the week's entries to the next week
+    DuplicateWeek,
+}
+
+/// Chart shown next to the weekly summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartView {
+    /// Bar chart of hours logged per day
+    DailyHours,
+    /// Share of hours per activity type
+    ActivityShare,
+}
+
+impl ChartView {
+    pub fn toggle(self) -> Self {
+        match self {
+            ChartView::DailyHours => ChartView::ActivityShare,
+            ChartView::ActivityShare => ChartView::DailyHours,
+        }
+    }
+}
+
+/// Column changed by the find and replace popup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BulkEditField {
+    #[default]
+    Customer,
+    WorkItem,
+}
+
+impl BulkEditField {
+    pub fn label(self) -> &'static str {
+        match self {
+            BulkEditField::Customer => "customer",
+            BulkEditField::WorkItem => "work item",
+        }
+    }
+
+    fn column_id(self) -> &'static str {
+        match self {
+            BulkEditField::Customer => "text__1",
+            BulkEditField::WorkItem => "text8__1",
+        }
+    }
+
+    fn value_of(self, entry: &ClaimEntry) -> &str {
+        match self {
+            BulkEditField::Customer => &entry.customer,
+            BulkEditField::WorkItem => &entry.work_item,
+        }
+    }
+
+    fn toggle(self) -> Self {
+        match self {
+            BulkEditField::Customer => BulkEditField::WorkItem,
+            BulkEditField::WorkItem => BulkEditField::Customer,
+        }
+    }
+}
+
+/// One entry changed by the find and replace popup
+#[derive(Debug, Clone)]
+pub struct BulkEditMatch {
+    pub entry_id: String,
+    pub date: NaiveDate,
+    pub old: String,
+    pub new: String,
+}
+
+/// Input of the find and replace popup
+#[derive(Debug, Clone, Default)]
+pub struct BulkEdit {
+    pub find: String,
+    pub replace: String,
+    pub field: BulkEditField,
+    /// Whether typing goes to the replace text (otherwise the find text)
+    pub editing_replace: bool,
+    /// Entries to update, shown for confirmation once the find text is submitted
+    pub preview: Option<Vec<BulkEditMatch>>,
+}
+
+impl BulkEdit {
+    /// Entries of `claims` whose field contains the find text, with the value
+    /// after replacing it; entries in locked weeks are left out
+    fn matches(&self, claims: &[ClaimEntry], config: &Config) -> Vec<BulkEditMatch> {
+        claims
+            .iter()
+            .filter(|entry| locked_week_error(config, entry.date).is_none())
+            .filter_map(|entry| {
+                let old = self.field.value_of(entry);
+                let new = old.replace(&self.find, &self.replace);
+                (new != old).then(|| BulkEditMatch {
+                    entry_id: entry.id.clone(),
+                    date: entry.date,
+                    old: old.to_string(),
+                    new,
+                })
+            })
+            .collect()
+    }
+}
+
+/// State of the month calendar
+#[derive(Debug, Clone)]
+pub struct MonthView {
+    /// First day of the month shown
+    pub month: NaiveDate,
+    /// Highlighted working day
+    pub selected: NaiveDate,
+    /// Hours logged per day, once the month has loaded
+    pub totals: Option<HashMap<NaiveDate, f64>>,
+}
+
+impl MonthView {
+    /// The month of `date`, with `date` (or the nearest working day of the
+    /// month) highlighted
+    pub fn new(date: NaiveDate) -> Self {
+        let month = date.with_day(1).unwrap_or(date);
+        let mut view = MonthView {
+            month,
+            selected: month,
+            totals: None,
+        };
+        view.selected = view.nearest_working_day(date);
+        view
+    }
+
+    /// Mondays of the ISO weeks overlapping the month, one row each
+    pub fn weeks(&self) -> Vec<NaiveDate> {
+        let last = self.last_day();
+        let mut monday = utils::get_week_start(self.month, chrono::Weekday::Mon);
+        let mut weeks = Vec::new();
+        while monday <= last {
+            weeks.push(monday);
+            monday += chrono::Duration::days(7);
+        }
+        weeks
+    }
+
+    /// Working days of the month, to query in one call
+    pub fn working_days(&self) -> Vec<NaiveDate> {
+        self.month
+            .iter_days()
+            .take_while(|d| *d <= self.last_day())
+            .filter(|d| d.weekday().num_days_from_monday() < 5)
+            .collect()
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        date.year() == self.month.year() && date.month() == self.month.month()
+    }
+
+    fn last_day(&self) -> NaiveDate {
+        let next = self.month + chrono::Duration::days(32);
+        next.with_day(1).unwrap_or(next) - chrono::Duration::days(1)
+    }
+
+    /// Working day of the month closest to `date`
+    fn nearest_working_day(&self, date: NaiveDate) -> NaiveDate {
+        let days = self.working_days();
+        days.iter()
+            .min_by_key(|d| (**d - date).num_days().abs())
+            .copied()
+            .unwrap_or(self.month)
+    }
+
+    /// Moves the highlight by `days` (±1 skips the weekend, ±7 changes the
+    /// week); stays put at the edges of the month
+    fn move_by(&mut self, days: i64) {
+        let mut date = self.selected + chrono::Duration::days(days);
+        while date.weekday().num_days_from_monday() >= 5 {
+            date += chrono::Duration::days(days.signum());
+        }
+        if self.contains(date) {
+            self.selected = date;
+        }
+    }
+}
+
+/// Entries of the week starting at `week_start`, as loaded in the background
+pub struct WeekData {
+    pub week_start: NaiveDate,
+    pub result: Result<Vec<ClaimEntry>>,
+}
+
+/// Most operations kept on the undo and redo stacks
+const UNDO_LIMIT: usize = 20;
+
+/// An operation on Monday.com that `Ctrl+Z` can reverse
+#[derive(Debug, Clone)]
+pub enum UndoAction {
+    /// An entry was added; undoing deletes it
+    Created {
+        item_id: String,
+        form_data: FormData,
+    },
+    /// An entry was deleted; undoing adds it again (with a new ID)
+    Deleted {
+        item_id: String,
+        form_data: FormData,
+    },
+    /// An entry was changed from `before` to `after`
+    Updated {
+        item_id: String,
+        before: FormData,
+        after: FormData,
+    },
+}
+
+impl UndoAction {
+    fn item_id(&self) -> &str {
+        match self {
+            UndoAction::Created { item_id, .. }
+            | UndoAction::Deleted { item_id, .. }
+            | UndoAction::Updated { item_id, .. } => item_id,
+        }
+    }
+
+    fn set_item_id(&mut self, id: &str) {
+        match self {
+            UndoAction::Created { item_id, .. }
+            | UndoAction::Deleted { item_id, .. }
+            | UndoAction::Updated { item_id, .. } => *item_id = id.to_string(),
+        }
+    }
+
+    /// Dates the operation touches, to refuse undoing into a locked week
+    fn dates(&self, today: NaiveDate) -> Vec<NaiveDate> {
+        let forms = match self {
+            UndoAction::Created { form_data, .. } | UndoAction::Deleted { form_data, .. } => {
+                vec![form_data]
+            }
+            UndoAction::Updated { before, after, .. } => vec![before, after],
+        };
+        forms
+            .into_iter()
+            .filter_map(|form| parse_form_date(&form.date, today))
+            .collect()
+    }
+
+    /// E.g. "adding 8h billable on 2025-09-15"
+    fn describe(&self) -> String {
+        let (verb, form) = match self {
+            UndoAction::Created { form_data, .. } => ("adding", form_data),
+            UndoAction::Deleted { form_data, .. } => ("deleting", form_data),
+            UndoAction::Updated { after, .. } => ("editing", after),
+        };
+        format!(
+            "{} {}h {} on {}",
+            verb, form.hours, form.activity_type, form.date
+        )
+    }
+}
+
+/// Outcome of an operation run in the background so the UI keeps drawing
+/// (and the loading spinner keeps turning) while it waits for Monday.com
+pub enum TaskResult {
+    /// The week was reloaded
+    WeekLoaded(WeekData),
+    /// The entry cache was refreshed with the recent billable (customer,
+    /// work item, date) triples, then the week was reloaded
+    CacheRefreshed {
+        result: Result<Vec<(String, String, NaiveDate)>>,
+        /// Only the items since the last refresh were fetched
+        incremental: bool,
+        week: WeekData,
+    },
+    /// A new entry was created from `form`; on success the week was
+    /// reloaded and `success_message` is shown
+    EntrySaved {
+        /// ID of the new item
+        result: Result<String>,
+        form: Box<FormData>,
+        success_message: String,
+        week: Option<WeekData>,
+    },
+    /// The week after the current one was loaded to confirm duplicating
+    /// the current week into it
+    NextWeekChecked(WeekData),
+    /// The entries of the week were copied to the next week, which was
+    /// then loaded; `created` holds one `Created` action per new entry
+    WeekDuplicated {
+        created: Vec<UndoAction>,
+        failures: Vec<String>,
+        week: WeekData,
+    },
+    /// The working days of `month` were loaded for the month view
+    MonthLoaded {
+        month: NaiveDate,
+        result: Result<Vec<ClaimEntry>>,
+    },
+    /// `action` was undone (or redone); on success the week was reloaded
+    /// and `result` is the action for the opposite stack
+    Undone {
+        action: Box<UndoAction>,
+        redo: bool,
+        result: Result<Box<UndoAction>>,
+        week: Option<WeekData>,
+    },
 }
 
 /// Claim entry data structure
@@ -39,6 +364,31 @@ pub struct ClaimEntry {
     pub work_item: String,
     pub hours: f64,
     pub comment: Option<String>,
+    /// Last modification time reported by Monday.com (ISO 8601)
+    pub updated_at: Option<String>,
+}
+
+/// Hours of `entries` grouped by (activity type, customer, work item), one
+/// value per date of `dates`; entries on other dates are left out. Shared by
+/// the report view and `claim summary`.
+pub fn report_hours(
+    entries: &[ClaimEntry],
+    dates: &[NaiveDate],
+) -> std::collections::HashMap<(i32, String, String), Vec<f64>> {
+    let mut report_data = std::collections::HashMap::new();
+    for entry in entries {
+        if let Some(column) = dates.iter().position(|date| *date == entry.date) {
+            let key = (
+                entry.activity_value,
+                entry.customer.clone(),
+                entry.work_item.clone(),
+            );
+            report_data
+                .entry(key)
+                .or_insert_with(|| vec![0.0; dates.len()])[column] += entry.hours;
+        }
+    }
+    report_data
 }
 
 impl ClaimEntry {
@@ -57,6 +407,7 @@ impl ClaimEntry {
             work_item: extract_work_item_from_item(item),
             hours: extract_hours_from_item(item),
             comment: extract_comment_from_item(item),
+            updated_at: item.updated_at.clone(),
         })
     }
 }
@@ -65,28 +416,14 @@ impl App {
     /// Build textual lines for the report rows in the same order as UI rendering.
     /// Returns the text for each data row (excluding header).
     pub fn get_report_rows_text(&self) -> Result<Vec<String>, anyhow::Error> {
-        use std::collections::HashMap;
-
-        // Build report_data like in ui::render_report
-        let mut report_data: HashMap<(i32, String, String), [f64; 5]> = HashMap::new();
-
-        for entry in &self.claims {
-            let key = (
-                entry.activity_value,
-                entry.customer.clone(),
-                entry.work_item.clone(),
-            );
-            let day_index = entry.date.weekday().num_days_from_monday() as usize;
-            if day_index < 5 {
-                report_data.entry(key).or_insert([0.0; 5])[day_index] += entry.hours;
-            }
-        }
+        let dates = self.report_dates();
+        let report_data = self.report_hours(&dates);
 
         let mut billable_data: Vec<_> = report_data
             .iter()
             .filter(|((activity_value, _, _), _)| *activity_value == 1)
             .map(|((_, customer, work_item), hours)| {
-                ((customer.clone(), work_item.clone()), *hours)
+                ((customer.clone(), work_item.clone()), hours.clone())
             })
             .collect();
 
@@ -96,7 +433,7 @@ impl App {
             .map(|((activity_value, customer, work_item), hours)| {
                 (
                     (*activity_value, customer.clone(), work_item.clone()),
-                    *hours,
+                    hours.clone(),
                 )
             })
             .collect();
@@ -119,16 +456,16 @@ impl App {
         let mut rows_text = Vec::new();
 
         // Helper to format a row into text
-        let format_row = |label: String, hours: [f64; 5]| -> String {
+        let format_row = |label: String, hours: Vec<f64>| -> String {
             let mut parts = Vec::new();
             parts.push(label);
-            for i in 0..5 {
-                if hours[i] == 0.0 {
+            for &day_hours in &hours {
+                if day_hours == 0.0 {
                     parts.push(String::new());
-                } else if hours[i] % 1.0 == 0.0 {
-                    parts.push(format!("{:.0}", hours[i]));
+                } else if day_hours % 1.0 == 0.0 {
+                    parts.push(format!("{:.0}", day_hours));
                 } else {
-                    parts.push(format!("{:.2}", hours[i]));
+                    parts.push(format!("{:.2}", day_hours));
                 }
             }
             let total: f64 = hours.iter().sum();
@@ -157,8 +494,8 @@ impl App {
         }
 
         for ((activity_value, customer, work_item), hours) in non_billable_data {
-            // Convert activity_value to name if possible
-            let activity_name = crate::utils::map_activity_value_to_name(activity_value as u8);
+            // Convert activity_value to its display label
+            let activity_name = self.config.activity_label(activity_value as u8);
             let label = if !work_item.is_empty() && !customer.is_empty() {
                 format!("{} - {} ({})", work_item, customer, activity_name)
             } else if !work_item.is_empty() {
@@ -172,10 +509,10 @@ impl App {
         }
 
         // Add totals line
-        let mut day_totals = [0.0; 5];
+        let mut day_totals = vec![0.0; dates.len()];
         for hours in report_data.values() {
-            for i in 0..5 {
-                day_totals[i] += hours[i];
+            for (total, day_hours) in day_totals.iter_mut().zip(hours) {
+                *total += day_hours;
             }
         }
         let total_label = "Total".to_string();
@@ -198,28 +535,14 @@ impl App {
     /// if present, otherwise falls back to customer or activity name. For totals/separators
     /// the full row text is returned.
     pub fn get_report_row_work_item(&self, idx: usize) -> Result<String, anyhow::Error> {
-        use std::collections::HashMap;
-
         // Build the same structures as in get_report_rows_text
-        let mut report_data: HashMap<(i32, String, String), [f64; 5]> = HashMap::new();
-
-        for entry in &self.claims {
-            let key = (
-                entry.activity_value,
-                entry.customer.clone(),
-                entry.work_item.clone(),
-            );
-            let day_index = entry.date.weekday().num_days_from_monday() as usize;
-            if day_index < 5 {
-                report_data.entry(key).or_insert([0.0; 5])[day_index] += entry.hours;
-            }
-        }
+        let report_data = self.report_hours(&self.report_dates());
 
         let mut billable_data: Vec<_> = report_data
             .iter()
             .filter(|((activity_value, _, _), _)| *activity_value == 1)
             .map(|((_, customer, work_item), hours)| {
-                ((customer.clone(), work_item.clone()), *hours)
+                ((customer.clone(), work_item.clone()), hours.clone())
             })
             .collect();
 
@@ -229,7 +552,7 @@ impl App {
             .map(|((activity_value, customer, work_item), hours)| {
                 (
                     (*activity_value, customer.clone(), work_item.clone()),
-                    *hours,
+                    hours.clone(),
                 )
             })
             .collect();
@@ -272,18 +595,11 @@ impl App {
             } else if !customer.is_empty() {
                 items.push(customer);
             } else {
-                let activity_name = crate::utils::map_activity_value_to_name(activity_value as u8);
-                items.push(activity_name.to_string());
+                items.push(self.config.activity_label(activity_value as u8));
             }
         }
 
         // Totals label
-        let mut day_totals = [0.0; 5];
-        for hours in report_data.values() {
-            for i in 0..5 {
-                day_totals[i] += hours[i];
-            }
-        }
         let _total_label = "Total".to_string();
         items.push(_total_label);
 
@@ -311,8 +627,9 @@ pub struct App {
     pub mode: AppMode,
     /// Messages to display
     pub messages: Vec<Message>,
-    /// Monday.com client
-    pub client: MondayClient,
+    /// Monday.com client (or the simulated board with `--simulate`), shared
+    /// with background tasks
+    pub client: Arc<AppClient>,
     /// Current user
     pub user: MondayUser,
     /// Current year group ID (internal Monday.com ID)
@@ -334,25 +651,75 @@ pub struct App {
     pub selected_report_row: Option<usize>,
     /// Marked work items from the report (stored in memory until cleared)
     pub marked_report_items: Vec<String>,
+    /// Loaded configuration (defaults for new entries)
+    pub config: Config,
+    /// Hours being typed in the quick-log popup
+    pub quick_log_hours: String,
+    /// Date or ISO week being typed in the go-to popup
+    pub goto_input: String,
+    /// Work item being typed in the rename popup
+    pub rename_input: String,
+    /// State of the find and replace popup
+    pub bulk_edit: BulkEdit,
+    /// Colors of the UI, from `color_scheme` in the config
+    pub colors: ColorScheme,
+    /// Chart shown next to the weekly summary on narrow terminals
+    pub chart_view: ChartView,
+    /// When the week data was last loaded successfully
+    pub last_refresh: Option<Instant>,
+    /// Selected row of the cache management list
+    pub cache_manage_state: ListState,
+    /// Rows of the cache management list marked for removal
+    pub cache_manage_marked: BTreeSet<usize>,
+    /// When the session started, for the session log
+    pub session_start: DateTime<Local>,
+    /// Entries created during the session
+    pub entries_added: usize,
+    /// Entries edited (or renamed) during the session
+    pub entries_edited: usize,
+    /// Entries deleted during the session
+    pub entries_deleted: usize,
+    /// Background operation in progress; key presses other than quitting
+    /// are ignored until it finishes
+    pub pending_task: Option<JoinHandle<TaskResult>>,
+    /// Operations `Ctrl+Z` reverses, most recent last
+    pub undo_stack: Vec<UndoAction>,
+    /// Undone operations `Ctrl+Y` applies again, most recent last
+    pub redo_stack: Vec<UndoAction>,
+    /// IDs of the entries selected with Space for a batch delete
+    pub selected_entries: HashSet<String>,
+    /// Month calendar opened with `M`
+    pub month_view: MonthView,
+    /// Text being typed in the search popup
+    pub search_input: String,
+    /// Highlighted row of the search results
+    pub search_selected: usize,
 }
 
 impl App {
     /// Create a new App instance
-    pub async fn new(client: MondayClient, user: MondayUser) -> Result<Self> {
-        let today = Local::now().naive_local().date();
-        let current_week_start = get_week_start(today);
+    pub async fn new(client: AppClient, user: MondayUser, config: Config) -> Result<Self> {
+        let today = config.today();
+        let current_week_start = utils::get_business_week_start(today, config.first_day_of_week);
 
-        // Load cache
-        let cache = EntryCache::load().unwrap_or_else(|_| EntryCache::new());
+        // Load cache (a simulated session starts empty and never saves it)
+        let cache = if client.is_simulated() {
+            EntryCache::new()
+        } else {
+            EntryCache::load().unwrap_or_else(|_| EntryCache::new())
+        };
 
         // Get current year and group ID (need to do this before creating app)
         let current_year = today.format("%Y").to_string();
-        let board = client.get_board_with_groups("6500270039", false).await?;
+        let board = client
+            .get_board_with_groups(&config.board_id, false)
+            .await?;
         let group_id = crate::utils::get_year_group_id(&board, &current_year);
+        let colors = ColorScheme::named(&config.color_scheme).unwrap_or_default();
 
         let mut app = App {
             current_week_start,
-            selected_day: Some(today),
+            selected_day: Some(today.max(current_week_start)),
             selected_entry_index: None,
             claims: Vec::new(),
             cache,
@@ -361,7 +728,7 @@ impl App {
                 MessageType::Info,
                 "Initializing...".to_string(),
             )],
-            client,
+            client: Arc::new(client),
             user,
             group_id,
             current_year: current_year.clone(),
@@ -372,68 +739,75 @@ impl App {
             week_start: current_week_start,
             selected_report_row: None,
             marked_report_items: Vec::new(),
+            config,
+            quick_log_hours: String::new(),
+            goto_input: String::new(),
+            rename_input: String::new(),
+            bulk_edit: BulkEdit::default(),
+            colors,
+            chart_view: ChartView::DailyHours,
+            last_refresh: None,
+            cache_manage_state: ListState::default(),
+            cache_manage_marked: BTreeSet::new(),
+            session_start: Local::now(),
+            entries_added: 0,
+            entries_edited: 0,
+            entries_deleted: 0,
+            pending_task: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            selected_entries: HashSet::new(),
+            month_view: MonthView::new(current_week_start),
+            search_input: String::new(),
+            search_selected: 0,
         };
 
-        // Refresh cache on startup (like -r option)
-        app.refresh_cache().await?;
+        // Refresh cache on startup (like -r option) once it is older than
+        // cache_ttl_hours; `u` still refreshes on demand
+        if app.cache.needs_refresh(app.config.cache_ttl_hours) {
+            app.refresh_cache().await?;
+        }
 
         // Load initial data
         app.load_week_data().await?;
 
         Ok(app)
     }
-    /// Refresh cache from Monday.com (like -r option)
+    /// Refresh cache from Monday.com (like -r option): only the items logged
+    /// since the last refresh, or the last 4 weeks when a full refresh is due
     pub async fn refresh_cache(&mut self) -> Result<()> {
+        let since = self.cache.refresh_since(self.config.cache_ttl_hours);
         self.loading = true;
-        self.loading_message = "Refreshing cache from last 4 weeks...".to_string();
-
-        let board_id = "6500270039";
-        let current_year = utils::get_current_year().to_string();
-
-        // Query last 4 weeks (28 days)
-        let today = Local::now().naive_local().date();
-        let start_date = today - chrono::Duration::days(28);
-
-        // Get the group ID for the current year
-        let board = self.client.get_board_with_groups(board_id, false).await?;
-        let group_id = utils::get_year_group_id(&board, &current_year);
-
-        // Query all items for the user in the current year
-        let all_items = self
-            .client
-            .query_items_with_filters(
-                board_id,
-                &group_id,
-                self.user.id,
-                &[], // Empty date filter - get all items for the user
-                500,
-                false,
-            )
-            .await?;
+        self.loading_message = refresh_message(since);
+
+        let entries = fetch_recent_billable(
+            &self.client,
+            &self.config.board_id,
+            self.user.id,
+            self.config.today(),
+            since,
+        )
+        .await?;
+        self.apply_cache_refresh(entries, since.is_some())?;
+        self.loading = false;
+        Ok(())
+    }
 
-        // Extract customer and work item pairs from items, filtering by date range and billable only
-        let mut entries = Vec::new();
-        for item in &all_items {
-            let customer = extract_customer_from_item(item);
-            let work_item = extract_work_item_from_item(item);
-            let date = extract_date_from_item(item);
-            let activity_value = extract_activity_value_from_item(item);
-
-            // Only include billable entries (activity_value == 1)
-            if activity_value == 1 && !customer.is_empty() && !work_item.is_empty() {
-                if let Some(d) = date {
-                    // Only include items within the last 4 weeks
-                    if d >= start_date && d <= today {
-                        entries.push((customer, work_item, d));
-                    }
-                }
-            }
+    /// Stores the refreshed cache entries and reports how many there are
+    fn apply_cache_refresh(
+        &mut self,
+        entries: Vec<(String, String, NaiveDate)>,
+        incremental: bool,
+    ) -> Result<()> {
+        if incremental {
+            self.cache.update_from_delta(self.user.id, &entries);
+        } else {
+            self.cache.update_from_items(self.user.id, &entries);
+        }
+        if !self.client.is_simulated() {
+            self.cache.save()?;
         }
 
-        self.cache.update_from_items(self.user.id, &entries);
-        self.cache.save()?;
-
-        self.loading = false;
         self.messages.push(Message::new(
             MessageType::Success,
             format!(
@@ -441,7 +815,6 @@ impl App {
                 self.cache.get_unique_entries(self.user.id).len()
             ),
         ));
-
         Ok(())
     }
 
@@ -451,36 +824,46 @@ impl App {
         self.messages.clear();
         self.messages.push(Message::new(
             MessageType::Info,
-            "Loading week data...".to_string(),
+            LOADING_WEEK_MESSAGE.to_string(),
         ));
 
-        let board_id = "6500270039";
-        let current_year = utils::get_current_year().to_string();
-
-        // Get the board and group ID
-        let board = self.client.get_board_with_groups(board_id, false).await?;
-        let group_id = utils::get_year_group_id(&board, &current_year);
-
-        // Calculate date range for the week (Monday to Friday)
-        let dates = utils::calculate_working_dates(self.current_week_start, 5);
-
-        // Convert dates to strings for the API
-        let date_strings: Vec<String> = dates
-            .iter()
-            .map(|d| d.format("%Y-%m-%d").to_string())
-            .collect();
-
-        // Query items for the week
-        let items = self
-            .client
-            .query_items_with_filters(board_id, &group_id, self.user.id, &date_strings, 100, false)
-            .await?;
-
-        // Convert items to ClaimEntry
-        self.claims = items.iter().filter_map(ClaimEntry::from_item).collect();
+        let claims = fetch_week(
+            &self.client,
+            &self.config.board_id,
+            self.config.today().year(),
+            self.user.id,
+            self.current_week_start,
+            self.days_in_week(),
+            !self.config.show_weekends,
+        )
+        .await?;
+        self.apply_week(claims);
+        Ok(())
+    }
 
+    /// Shows the loaded entries of the current week
+    fn apply_week(&mut self, claims: Vec<ClaimEntry>) {
+        self.claims = claims;
+        self.last_refresh = Some(Instant::now());
         self.loading = false;
-        self.messages.clear();
+
+        // Keep the selected entry within the entries left on the selected day
+        let entries_on_day = self.selected_day.map_or(0, |day| {
+            self.claims.iter().filter(|e| e.date == day).count()
+        });
+        self.selected_entry_index = self
+            .selected_entry_index
+            .filter(|_| entries_on_day > 0)
+            .map(|idx| idx.min(entries_on_day - 1));
+
+        // Only entries of the shown week stay selected
+        let claims = &self.claims;
+        self.selected_entries
+            .retain(|id| claims.iter().any(|e| &e.id == id));
+
+        // Keep the messages of the action that triggered the reload
+        self.messages
+            .retain(|m| !(m.message_type == MessageType::Info && m.text == LOADING_WEEK_MESSAGE));
         self.messages.push(Message::new(
             MessageType::Success,
             format!(
@@ -489,19 +872,393 @@ impl App {
                 self.current_week_start.format("%b %d, %Y")
             ),
         ));
+    }
+
+    /// Applies loaded week data unless the user has moved to another week
+    fn apply_week_data(&mut self, week: WeekData) {
+        if week.week_start != self.current_week_start {
+            return;
+        }
+        match week.result {
+            Ok(claims) => self.apply_week(claims),
+            Err(e) => self.messages.push(Message::new(
+                MessageType::Error,
+                format!("Failed to load week data: {}", e),
+            )),
+        }
+    }
+
+    /// Future fetching the current week, to run in a background task
+    fn week_fetch(&self) -> impl std::future::Future<Output = WeekData> + Send + 'static {
+        self.week_fetch_at(self.current_week_start)
+    }
+
+    /// Future fetching the week starting at `week_start`
+    fn week_fetch_at(
+        &self,
+        week_start: NaiveDate,
+    ) -> impl std::future::Future<Output = WeekData> + Send + 'static {
+        let client = Arc::clone(&self.client);
+        let board_id = self.config.board_id.clone();
+        let year = self.config.today().year();
+        let user_id = self.user.id;
+        let days = self.days_in_week();
+        let skip_weekends = !self.config.show_weekends;
+        async move {
+            WeekData {
+                week_start,
+                result: fetch_week(
+                    &client,
+                    &board_id,
+                    year,
+                    user_id,
+                    week_start,
+                    days,
+                    skip_weekends,
+                )
+                .await,
+            }
+        }
+    }
+
+    /// Starts `task` in the background with the loading spinner shown
+    fn spawn_task(
+        &mut self,
+        loading_message: &str,
+        task: impl std::future::Future<Output = TaskResult> + Send + 'static,
+    ) {
+        self.loading = true;
+        self.loading_message = loading_message.to_string();
+        self.pending_task = Some(tokio::spawn(task));
+    }
+
+    /// Reloads the current week in the background
+    pub fn start_week_load(&mut self) {
+        self.messages.push(Message::new(
+            MessageType::Info,
+            LOADING_WEEK_MESSAGE.to_string(),
+        ));
+        let week = self.week_fetch();
+        self.spawn_task(LOADING_WEEK_MESSAGE, async move {
+            TaskResult::WeekLoaded(week.await)
+        });
+    }
+
+    /// Refreshes the entry cache and reloads the current week in the background
+    fn start_refresh(&mut self) {
+        let client = Arc::clone(&self.client);
+        let board_id = self.config.board_id.clone();
+        let user_id = self.user.id;
+        let today = self.config.today();
+        let since = self.cache.refresh_since(self.config.cache_ttl_hours);
+        let week = self.week_fetch();
+        self.spawn_task(&refresh_message(since), async move {
+            let result = fetch_recent_billable(&client, &board_id, user_id, today, since).await;
+            TaskResult::CacheRefreshed {
+                result,
+                incremental: since.is_some(),
+                week: week.await,
+            }
+        });
+    }
+
+    /// Creates a new entry in the background and reloads the week when it
+    /// succeeds
+    fn start_save_new_entry(&mut self, form: &FormData, success_message: String) {
+        let client = Arc::clone(&self.client);
+        let board_id = self.config.board_id.clone();
+        let group_id = self.group_id.clone();
+        let user_name = self.user.name.clone();
+        let column_values = new_entry_column_values(form, self.user.id);
+        let form = Box::new(form.clone());
+        let week = self.week_fetch();
+        self.spawn_task("Saving entry...", async move {
+            let result = client
+                .create_item_verbose(&board_id, &group_id, &user_name, &column_values, false)
+                .await;
+            let week = match result {
+                Ok(_) => Some(week.await),
+                Err(_) => None,
+            };
+            TaskResult::EntrySaved {
+                result,
+                form,
+                success_message,
+                week,
+            }
+        });
+    }
+
+    /// Remembers `action` for `Ctrl+Z`; a new operation clears the redo stack
+    fn record_undo(&mut self, action: UndoAction) {
+        push_capped(&mut self.undo_stack, action);
+        self.redo_stack.clear();
+    }
+
+    /// Reverses the last operation (or applies the last undone one again
+    /// with `redo`) in the background
+    fn start_undo(&mut self, redo: bool) {
+        let stack = if redo {
+            &mut self.redo_stack
+        } else {
+            &mut self.undo_stack
+        };
+        let Some(action) = stack.pop() else {
+            self.messages.clear();
+            self.messages.push(Message::new(
+                MessageType::Info,
+                format!("Nothing to {}", if redo { "redo" } else { "undo" }),
+            ));
+            return;
+        };
+        if let Some(err) = action
+            .dates(self.config.today())
+            .into_iter()
+            .find_map(|date| locked_week_error(&self.config, date))
+        {
+            self.messages.clear();
+            self.messages.push(Message::new(MessageType::Error, err));
+            stack.push(action);
+            return;
+        }
 
+        let client = Arc::clone(&self.client);
+        let board_id = self.config.board_id.clone();
+        let group_id = self.group_id.clone();
+        let user = self.user.clone();
+        let week = self.week_fetch();
+        self.mode = AppMode::Undoing;
+        self.messages.clear();
+        let loading_message = if redo { "Redoing..." } else { "Undoing..." };
+        self.spawn_task(loading_message, async move {
+            let result =
+                run_undo_step(&client, &board_id, &group_id, &user, action.clone(), redo).await;
+            let week = match result {
+                Ok(_) => Some(week.await),
+                Err(_) => None,
+            };
+            TaskResult::Undone {
+                action: Box::new(action),
+                redo,
+                result: result.map(Box::new),
+                week,
+            }
+        });
+    }
+
+    /// Processes the background task if it has finished; never blocks
+    pub async fn poll_pending_task(&mut self) -> Result<()> {
+        if !self
+            .pending_task
+            .as_ref()
+            .is_some_and(|task| task.is_finished())
+        {
+            return Ok(());
+        }
+        let Some(task) = self.pending_task.take() else {
+            return Ok(());
+        };
+        let result = task.await?;
+        self.loading = false;
+
+        match result {
+            TaskResult::WeekLoaded(week) => self.apply_week_data(week),
+            TaskResult::NextWeekChecked(week) => match week.result {
+                Ok(next) => {
+                    self.mode = AppMode::DuplicateWeek;
+                    self.messages.clear();
+                    let prompt = if next.is_empty() {
+                        format!(
+                            "Duplicate this week's {} entries to next week? (y/N)",
+                            self.claims.len()
+                        )
+                    } else {
+                        format!(
+                            "Next week already has {} entries. Proceed? (y/N)",
+                            next.len()
+                        )
+                    };
+                    self.messages
+                        .push(Message::new(MessageType::Warning, prompt));
+                }
+                Err(e) => self.messages.push(Message::new(
+                    MessageType::Error,
+                    format!("Failed to load next week: {}", e),
+                )),
+            },
+            TaskResult::WeekDuplicated {
+                created,
+                failures,
+                week,
+            } => {
+                self.messages.clear();
+                let total = created.len() + failures.len();
+                self.entries_added += created.len();
+                if failures.is_empty() {
+                    self.messages.push(Message::new(
+                        MessageType::Success,
+                        format!("Copied {} entries to this week", created.len()),
+                    ));
+                } else {
+                    self.messages.push(Message::new(
+                        MessageType::Error,
+                        format!(
+                            "Copied {} of {} entries; failed: {}",
+                            created.len(),
+                            total,
+                            failures.join(", ")
+                        ),
+                    ));
+                }
+                for action in created {
+                    self.record_undo(action);
+                }
+                self.apply_week_data(week);
+            }
+            TaskResult::MonthLoaded { month, result } => {
+                if month != self.month_view.month {
+                    return Ok(());
+                }
+                match result {
+                    Ok(claims) => {
+                        let mut totals = HashMap::new();
+                        for entry in &claims {
+                            *totals.entry(entry.date).or_insert(0.0) += entry.hours;
+                        }
+                        self.month_view.totals = Some(totals);
+                    }
+                    Err(e) => self.messages.push(Message::new(
+                        MessageType::Error,
+                        format!("Failed to load month: {}", e),
+                    )),
+                }
+            }
+            TaskResult::CacheRefreshed {
+                result,
+                incremental,
+                week,
+            } => {
+                match result {
+                    Ok(entries) => self.apply_cache_refresh(entries, incremental)?,
+                    Err(e) => self.messages.push(Message::new(
+                        MessageType::Error,
+                        format!("Failed to refresh cache: {}", e),
+                    )),
+                }
+                self.apply_week_data(week);
+            }
+            TaskResult::EntrySaved {
+                result,
+                form,
+                success_message,
+                week,
+            } => match result {
+                Ok(item_id) => {
+                    self.entries_added += 1;
+                    self.record_undo(UndoAction::Created {
+                        item_id,
+                        form_data: *form,
+                    });
+                    self.messages
+                        .push(Message::new(MessageType::Success, success_message));
+                    if let Some(week) = week {
+                        self.apply_week_data(week);
+                    }
+                }
+                Err(e) => self.messages.push(Message::new(
+                    MessageType::Error,
+                    format!("Failed to add entry: {}", e),
+                )),
+            },
+            TaskResult::Undone {
+                action,
+                redo,
+                result,
+                week,
+            } => {
+                self.mode = AppMode::Normal;
+                match result {
+                    Ok(done) => {
+                        // Entries added again get a new ID
+                        let (old_id, new_id) = (action.item_id(), done.item_id());
+                        if old_id != new_id {
+                            for other in self.undo_stack.iter_mut().chain(&mut self.redo_stack) {
+                                if other.item_id() == old_id {
+                                    other.set_item_id(new_id);
+                                }
+                            }
+                        }
+                        self.messages.push(Message::new(
+                            MessageType::Success,
+                            format!(
+                                "{} {}",
+                                if redo { "Redid" } else { "Undid" },
+                                done.describe()
+                            ),
+                        ));
+                        if redo {
+                            push_capped(&mut self.undo_stack, *done);
+                        } else {
+                            push_capped(&mut self.redo_stack, *done);
+                        }
+                        if let Some(week) = week {
+                            self.apply_week_data(week);
+                        }
+                    }
+                    Err(e) => {
+                        self.messages.push(Message::new(
+                            MessageType::Error,
+                            format!(
+                                "Failed to {} {}: {}",
+                                if redo { "redo" } else { "undo" },
+                                action.describe(),
+                                e
+                            ),
+                        ));
+                        if redo {
+                            self.redo_stack.push(*action);
+                        } else {
+                            self.undo_stack.push(*action);
+                        }
+                    }
+                }
+            }
+        }
         Ok(())
     }
 
     /// Handle keyboard events
     pub async fn handle_event(&mut self, event: KeyEvent) -> Result<bool> {
+        if self.pending_task.is_some() {
+            // Only quitting is possible while a background operation runs
+            let quit = event.code == KeyCode::Char('q')
+                && event
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL);
+            return Ok(!quit);
+        }
+
         match self.mode {
             AppMode::Normal => self.handle_normal_mode(event).await,
             AppMode::Help => self.handle_help_mode(event),
             AppMode::AddEntry => self.handle_add_mode(event).await,
             AppMode::EditEntry => self.handle_edit_mode(event).await,
             AppMode::DeleteEntry => self.handle_delete_mode(event).await,
+            AppMode::BatchDelete => self.handle_batch_delete_mode(event).await,
+            AppMode::MonthView => self.handle_month_view_mode(event),
+            AppMode::Search => self.handle_search_mode(event),
+            AppMode::DuplicateWeek => self.handle_duplicate_week_mode(event),
             AppMode::Report => self.handle_report_mode(event).await,
+            AppMode::QuickLog => self.handle_quick_log_mode(event).await,
+            AppMode::Goto => self.handle_goto_mode(event).await,
+            AppMode::Rename => self.handle_rename_mode(event).await,
+            AppMode::CacheManage => self.handle_cache_manage_mode(event),
+            AppMode::BulkEdit => self.handle_bulk_edit_mode(event).await,
+            // Only reached if the background task was lost
+            AppMode::Undoing => {
+                self.mode = AppMode::Normal;
+                Ok(true)
+            }
         }
     }
 
@@ -519,16 +1276,141 @@ impl App {
             return Ok(false); // Exit application with Cmd+Q or Ctrl+Q
         }
 
-        match event.code {
-            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => {
-                return Ok(false); // Exit application
-            }
-            KeyCode::Char('?') => {
-                self.mode = AppMode::Help;
-            }
-            // Tab: Navigate weeks forward
-            KeyCode::Tab => {
-                if event
+        // Ctrl+R renames the selected entry's work item
+        if event.code == KeyCode::Char('r')
+            && event
+                .modifiers
+                .contains(crossterm::event::KeyModifiers::CONTROL)
+        {
+            self.start_rename_mode();
+            return Ok(true);
+        }
+
+        // Ctrl+Z undoes the last add, edit or delete; Ctrl+Y or Ctrl+Shift+Z
+        // redoes it
+        if event
+            .modifiers
+            .contains(crossterm::event::KeyModifiers::CONTROL)
+        {
+            let shift = event
+                .modifiers
+                .contains(crossterm::event::KeyModifiers::SHIFT);
+            match event.code {
+                KeyCode::Char('z') if !shift => {
+                    self.start_undo(false);
+                    return Ok(true);
+                }
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Char('Z') => {
+                    self.start_undo(true);
+                    return Ok(true);
+                }
+                KeyCode::Char('z') => {
+                    self.start_undo(true);
+                    return Ok(true);
+                }
+                // Ctrl+D copies the week's entries to the next week
+                KeyCode::Char('d') => {
+                    self.start_duplicate_week();
+                    return Ok(true);
+                }
+                _ => {}
+            }
+        }
+
+        // Ctrl+F finds and replaces a customer or work item across the week
+        if event.code == KeyCode::Char('f')
+            && event
+                .modifiers
+                .contains(crossterm::event::KeyModifiers::CONTROL)
+        {
+            self.bulk_edit = BulkEdit::default();
+            self.mode = AppMode::BulkEdit;
+            self.messages.clear();
+            self.messages.push(Message::new(
+                MessageType::Info,
+                "Find and replace - Tab to switch fields, Enter to preview, Esc to cancel"
+                    .to_string(),
+            ));
+            return Ok(true);
+        }
+
+        match event.code {
+            // Esc clears the selection before it exits
+            KeyCode::Esc if !self.selected_entries.is_empty() => {
+                self.selected_entries.clear();
+                self.messages.clear();
+                self.messages.push(Message::new(
+                    MessageType::Info,
+                    "Selection cleared".to_string(),
+                ));
+            }
+            KeyCode::Esc | KeyCode::Char('Q') => {
+                return Ok(false); // Exit application
+            }
+            // Select or unselect the focused entry for a batch delete
+            KeyCode::Char(' ') => {
+                self.toggle_entry_selection();
+            }
+            // Quick-log hours for the selected day
+            KeyCode::Char('q') => {
+                self.start_quick_log_mode();
+            }
+            // Go to a date or ISO week
+            KeyCode::Char('g') => {
+                self.goto_input.clear();
+                self.mode = AppMode::Goto;
+                self.messages.clear();
+                self.messages.push(Message::new(
+                    MessageType::Info,
+                    "Go to - enter YYYY-MM-DD or YYYY-WN, Enter to jump, Esc to cancel".to_string(),
+                ));
+            }
+            KeyCode::Char('?') | KeyCode::F(1) => {
+                self.mode = AppMode::Help;
+            }
+            // Search the loaded entries
+            KeyCode::Char('/') => {
+                self.search_input.clear();
+                self.search_selected = 0;
+                self.mode = AppMode::Search;
+                self.messages.clear();
+                self.messages.push(Message::new(
+                    MessageType::Info,
+                    "Search - type to filter, ↑↓ to pick, Enter to jump, Esc to cancel".to_string(),
+                ));
+            }
+            // Month calendar
+            KeyCode::Char('M') => {
+                let date = self.selected_day.unwrap_or(self.current_week_start);
+                self.start_month_view(date);
+            }
+            // Manage the entry cache
+            KeyCode::Char('C') => {
+                self.start_cache_manage_mode();
+            }
+            // Toggle between the daily hours and activity share charts
+            KeyCode::Char('c') => {
+                self.chart_view = self.chart_view.toggle();
+            }
+            // Vim-style navigation: h/l for weeks, j/k for entries
+            KeyCode::Char('h') if self.config.vi_mode => {
+                self.previous_week().await?;
+            }
+            KeyCode::Char('l') if self.config.vi_mode => {
+                self.next_week().await?;
+            }
+            KeyCode::Char('j') if self.config.vi_mode => {
+                self.select_next_entry();
+            }
+            KeyCode::Char('k') if self.config.vi_mode => {
+                self.select_previous_entry();
+            }
+            KeyCode::Char('h') => {
+                self.mode = AppMode::Help;
+            }
+            // Tab: Navigate weeks forward
+            KeyCode::Tab => {
+                if event
                     .modifiers
                     .contains(crossterm::event::KeyModifiers::SHIFT)
                 {
@@ -569,10 +1451,15 @@ impl App {
             KeyCode::Char('5') => {
                 self.select_day(4);
             }
+            KeyCode::Char('6') if self.config.show_weekends => {
+                self.select_day(5);
+            }
+            KeyCode::Char('7') if self.config.show_weekends => {
+                self.select_day(6);
+            }
             // Update data (refresh cache and reload)
             KeyCode::Char('u') | KeyCode::Char('U') => {
-                self.refresh_cache().await?;
-                self.load_week_data().await?;
+                self.start_refresh();
             }
             // Show report view
             KeyCode::Char('p') | KeyCode::Char('P') => {
@@ -591,29 +1478,42 @@ impl App {
                 self.start_add_mode();
             }
             // Edit entry (only if an entry is selected)
-            KeyCode::Char('e') | KeyCode::Char('E') | KeyCode::Enter => {
-                if self.selected_entry_index.is_some() {
-                    self.start_edit_mode();
-                }
+            KeyCode::Char('e') | KeyCode::Char('E') | KeyCode::Enter
+                if self.selected_entry_index.is_some() =>
+            {
+                self.start_edit_mode();
+            }
+            // Delete the selected entries
+            KeyCode::Char('D') if !self.selected_entries.is_empty() => {
+                self.mode = AppMode::BatchDelete;
+                self.messages.clear();
+                self.messages.push(Message::new(
+                    MessageType::Warning,
+                    format!(
+                        "⚠️  DELETE {} SELECTED ENTRIES - Press 'y' to confirm, any other key to cancel",
+                        self.selected_entries.len()
+                    ),
+                ));
             }
             // Delete entry
-            KeyCode::Char('d') | KeyCode::Char('D') => {
-                if self.selected_entry_index.is_some() {
-                    self.mode = AppMode::DeleteEntry;
-                    self.messages.clear();
-                    self.messages.push(Message::new(
-                        MessageType::Warning,
-                        "⚠️  DELETE CONFIRMATION - Press 'y' to confirm, any other key to cancel"
-                            .to_string(),
-                    ));
-                }
+            KeyCode::Char('d') | KeyCode::Char('D') if self.selected_entry_index.is_some() => {
+                self.mode = AppMode::DeleteEntry;
+                self.messages.clear();
+                self.messages.push(Message::new(
+                    MessageType::Warning,
+                    "⚠️  DELETE CONFIRMATION - Press 'y' to confirm, any other key to cancel"
+                        .to_string(),
+                ));
             }
-            // Jump to current week
+            // Jump to current week (honours the configured first day of the week)
             KeyCode::Home => {
-                let today = Local::now().naive_local().date();
-                self.current_week_start = get_week_start(today);
-                self.selected_day = Some(today);
-                self.load_week_data().await?;
+                let today = self.config.today();
+                self.current_week_start =
+                    utils::get_business_week_start(today, self.config.first_day_of_week);
+                // On a Sunday of a Sunday-first week the displayed week starts
+                // tomorrow; select its Monday instead of a day outside it
+                self.selected_day = Some(today.max(self.current_week_start));
+                self.start_week_load();
             }
             _ => {}
         }
@@ -631,6 +1531,136 @@ impl App {
         Ok(true)
     }
 
+    /// Rows of the cache management list, most recently used first
+    pub fn cache_manage_entries(&self) -> Vec<CachedEntry> {
+        self.cache.get_sorted_entries(self.user.id)
+    }
+
+    fn start_cache_manage_mode(&mut self) {
+        let count = self.cache_manage_entries().len();
+        self.cache_manage_marked.clear();
+        self.cache_manage_state
+            .select(if count == 0 { None } else { Some(0) });
+        self.mode = AppMode::CacheManage;
+        self.messages.clear();
+        self.messages.push(Message::new(
+            MessageType::Info,
+            format!(
+                "Cache: {} entries - Del to mark for removal, 'a' to clear all, 's' to save",
+                count
+            ),
+        ));
+    }
+
+    /// Handle events in cache management mode
+    fn handle_cache_manage_mode(&mut self, event: KeyEvent) -> Result<bool> {
+        let entries = self.cache_manage_entries();
+        let selected = self.cache_manage_state.selected();
+
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+                self.messages.clear();
+                if !self.cache_manage_marked.is_empty() {
+                    self.messages.push(Message::new(
+                        MessageType::Info,
+                        format!(
+                            "Discarded {} unsaved cache removal(s)",
+                            self.cache_manage_marked.len()
+                        ),
+                    ));
+                    self.cache_manage_marked.clear();
+                }
+            }
+            KeyCode::Up => {
+                if let Some(idx) = selected {
+                    self.cache_manage_state.select(Some(idx.saturating_sub(1)));
+                }
+            }
+            KeyCode::Down => {
+                if let Some(idx) = selected {
+                    if idx + 1 < entries.len() {
+                        self.cache_manage_state.select(Some(idx + 1));
+                    }
+                }
+            }
+            KeyCode::PageUp => {
+                if let Some(idx) = selected {
+                    self.cache_manage_state
+                        .select(Some(idx.saturating_sub(CACHE_PAGE_SIZE)));
+                }
+            }
+            KeyCode::PageDown => {
+                if let Some(idx) = selected {
+                    let last = entries.len().saturating_sub(1);
+                    self.cache_manage_state
+                        .select(Some((idx + CACHE_PAGE_SIZE).min(last)));
+                }
+            }
+            // Toggle the removal mark of the selected entry
+            KeyCode::Delete => {
+                if let Some(idx) = selected {
+                    if !self.cache_manage_marked.remove(&idx) {
+                        self.cache_manage_marked.insert(idx);
+                    }
+                }
+            }
+            // Mark every entry for removal
+            KeyCode::Char('a') => {
+                self.cache_manage_marked = (0..entries.len()).collect();
+                self.messages.clear();
+                self.messages.push(Message::new(
+                    MessageType::Warning,
+                    format!(
+                        "All {} entries marked for removal - press 's' to save",
+                        entries.len()
+                    ),
+                ));
+            }
+            KeyCode::Char('s') => {
+                self.save_cache_removals(&entries)?;
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// Removes the marked entries from the cache and saves it
+    fn save_cache_removals(&mut self, entries: &[CachedEntry]) -> Result<()> {
+        self.messages.clear();
+        if self.cache_manage_marked.is_empty() {
+            self.messages.push(Message::new(
+                MessageType::Info,
+                "No entries marked for removal".to_string(),
+            ));
+            return Ok(());
+        }
+
+        let removed = self.cache_manage_marked.len();
+        for idx in std::mem::take(&mut self.cache_manage_marked) {
+            if let Some(entry) = entries.get(idx) {
+                self.cache
+                    .remove_entry(self.user.id, &entry.customer, &entry.work_item);
+            }
+        }
+        if !self.client.is_simulated() {
+            self.cache.save()?;
+        }
+
+        let remaining = self.cache_manage_entries().len();
+        self.cache_manage_state
+            .select(match self.cache_manage_state.selected() {
+                _ if remaining == 0 => None,
+                Some(idx) => Some(idx.min(remaining - 1)),
+                None => Some(0),
+            });
+        self.messages.push(Message::new(
+            MessageType::Success,
+            format!("Removed {} cache entries, {} left", removed, remaining),
+        ));
+        Ok(())
+    }
+
     /// Handle events in report mode
     async fn handle_report_mode(&mut self, event: KeyEvent) -> Result<bool> {
         match event.code {
@@ -653,7 +1683,7 @@ impl App {
                                 } else {
                                     self.messages.push(Message::new(
                                         MessageType::Success,
-                                        format!("Copied item to clipboard"),
+                                        "Copied item to clipboard".to_string(),
                                     ));
                                 }
                             }
@@ -823,7 +1853,39 @@ impl App {
     /// Handle events in add mode
     async fn handle_add_mode(&mut self, event: KeyEvent) -> Result<bool> {
         if let Some(form) = &mut self.form_data {
+            if form.activity_popup {
+                form.handle_activity_popup_key(event.code);
+                return Ok(true);
+            }
+
             match event.code {
+                KeyCode::Enter | KeyCode::Char(' ')
+                    if form.current_field == super::form::FormField::ActivityType
+                        && !form.focus_on_cache
+                        && !form.focus_on_quick_buffer
+                        && !form.focus_on_activity =>
+                {
+                    form.open_activity_popup();
+                }
+                KeyCode::PageDown if form.focus_on_cache => {
+                    let cache_size = self
+                        .cache
+                        .get_unique_entries_matching(
+                            self.user.id,
+                            form.cache_sort,
+                            form.cache_query(),
+                        )
+                        .len();
+                    form.selected_cache_index = (form.selected_cache_index + CACHE_PAGE_SIZE)
+                        .min(cache_size.saturating_sub(1));
+                }
+                KeyCode::PageUp if form.focus_on_cache => {
+                    form.selected_cache_index =
+                        form.selected_cache_index.saturating_sub(CACHE_PAGE_SIZE);
+                }
+                KeyCode::Char('s') if form.focus_on_cache => {
+                    form.cycle_cache_sort();
+                }
                 KeyCode::Esc => {
                     self.form_data = None;
                     self.mode = AppMode::Normal;
@@ -894,37 +1956,33 @@ impl App {
                         form.update_cursor_for_field();
                     }
                 }
-                KeyCode::Left => {
+                KeyCode::Left
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.move_cursor_left();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.move_cursor_left();
                 }
-                KeyCode::Right => {
+                KeyCode::Right
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.move_cursor_right();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.move_cursor_right();
                 }
-                KeyCode::Home => {
+                KeyCode::Home
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.move_cursor_to_start();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.move_cursor_to_start();
                 }
-                KeyCode::End => {
+                KeyCode::End
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.move_cursor_to_end();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.move_cursor_to_end();
                 }
                 KeyCode::Up => {
                     if form.focus_on_activity {
@@ -960,7 +2018,14 @@ impl App {
                         form.current_field = super::form::FormField::Date;
                         form.update_cursor_for_field();
                     } else if form.focus_on_cache {
-                        let cache_size = self.cache.get_unique_entries(self.user.id).len();
+                        let cache_size = self
+                            .cache
+                            .get_unique_entries_matching(
+                                self.user.id,
+                                form.cache_sort,
+                                form.cache_query(),
+                            )
+                            .len();
                         if form.selected_cache_index < cache_size.saturating_sub(1) {
                             form.selected_cache_index += 1;
                         }
@@ -981,17 +2046,49 @@ impl App {
                         }
                     } else if form.focus_on_quick_buffer {
                         // Apply selected cache entry from quick buffer using selected_cache_index
-                        let entries = self.cache.get_unique_entries(self.user.id);
+                        let entries = self.cache.get_unique_entries_matching(
+                            self.user.id,
+                            form.cache_sort,
+                            form.cache_query(),
+                        );
                         if let Some(entry) = entries.get(form.selected_cache_index) {
                             form.apply_cache_entry(entry.customer.clone(), entry.work_item.clone());
+                            self.cache
+                                .mark_used(self.user.id, &entry.customer, &entry.work_item);
                         }
                     } else if form.focus_on_cache {
                         // Apply selected cache entry
-                        let entries = self.cache.get_unique_entries(self.user.id);
+                        let entries = self.cache.get_unique_entries_matching(
+                            self.user.id,
+                            form.cache_sort,
+                            form.cache_query(),
+                        );
                         if let Some(entry) = entries.get(form.selected_cache_index) {
                             form.apply_cache_entry(entry.customer.clone(), entry.work_item.clone());
+                            self.cache
+                                .mark_used(self.user.id, &entry.customer, &entry.work_item);
                         }
                     } else {
+                        let rounded = self
+                            .config
+                            .round_hours_to
+                            .and_then(|nearest| form.round_hours(nearest));
+
+                        if let Some(err) = parse_form_date(&form.date, self.config.today())
+                            .and_then(|date| locked_week_error(&self.config, date))
+                        {
+                            self.messages.clear();
+                            self.messages.push(Message::new(MessageType::Error, err));
+                            return Ok(true);
+                        }
+
+                        if let Some(warning) = unconfirmed_work_item_warning(&self.config, form) {
+                            self.messages.clear();
+                            self.messages
+                                .push(Message::new(MessageType::Warning, warning));
+                            return Ok(true);
+                        }
+
                         // Save the form
                         match form.validate() {
                             Ok(_) => {
@@ -1000,26 +2097,13 @@ impl App {
                                 self.form_data = None;
                                 self.mode = AppMode::Normal;
                                 self.messages.clear();
+                                self.push_rounding_notice(rounded);
 
-                                // Save to Monday.com
-                                let result = self.save_new_entry(&form_clone).await;
-
-                                match result {
-                                    Ok(_) => {
-                                        self.messages.push(Message::new(
-                                            MessageType::Success,
-                                            "Entry added successfully".to_string(),
-                                        ));
-                                        // Refresh week data to show new entry
-                                        let _ = self.load_week_data().await;
-                                    }
-                                    Err(e) => {
-                                        self.messages.push(Message::new(
-                                            MessageType::Error,
-                                            format!("Failed to add entry: {}", e),
-                                        ));
-                                    }
-                                }
+                                // Save to Monday.com, then refresh week data to show the new entry
+                                self.start_save_new_entry(
+                                    &form_clone,
+                                    "Entry added successfully".to_string(),
+                                );
                             }
                             Err(err) => {
                                 self.messages.clear();
@@ -1040,33 +2124,61 @@ impl App {
                             form.set_activity_by_number(digit as u8);
                         } else if form.focus_on_quick_buffer {
                             // Quick buffer: choose cached entry by number
-                            let entries = self.cache.get_unique_entries(self.user.id);
+                            let entries = self.cache.get_unique_entries_matching(
+                                self.user.id,
+                                form.cache_sort,
+                                form.cache_query(),
+                            );
                             if digit < entries.len() && digit < 10 {
                                 let entry = &entries[digit];
                                 form.apply_cache_entry(
                                     entry.customer.clone(),
                                     entry.work_item.clone(),
                                 );
+                                self.cache.mark_used(
+                                    self.user.id,
+                                    &entry.customer,
+                                    &entry.work_item,
+                                );
                             }
                         } else if form.current_field == super::form::FormField::QuickSelection {
                             // Quick selection field: choose cached entry by number
-                            let entries = self.cache.get_unique_entries(self.user.id);
+                            let entries = self.cache.get_unique_entries_matching(
+                                self.user.id,
+                                form.cache_sort,
+                                form.cache_query(),
+                            );
                             if digit < entries.len() && digit < 10 {
                                 let entry = &entries[digit];
                                 form.apply_cache_entry(
                                     entry.customer.clone(),
                                     entry.work_item.clone(),
                                 );
+                                self.cache.mark_used(
+                                    self.user.id,
+                                    &entry.customer,
+                                    &entry.work_item,
+                                );
                             }
                         } else if form.focus_on_cache {
-                            // Cache panel active: choose cached entry by number
-                            let entries = self.cache.get_unique_entries(self.user.id);
-                            if digit < entries.len() && digit < 10 {
-                                let entry = &entries[digit];
+                            // Cache panel active: choose an entry on the current page by number
+                            let entries = self.cache.get_unique_entries_page(
+                                self.user.id,
+                                form.cache_sort,
+                                form.cache_query(),
+                                form.cache_page(),
+                                CACHE_PAGE_SIZE,
+                            );
+                            if let Some(entry) = entries.get(digit) {
                                 form.apply_cache_entry(
                                     entry.customer.clone(),
                                     entry.work_item.clone(),
                                 );
+                                self.cache.mark_used(
+                                    self.user.id,
+                                    &entry.customer,
+                                    &entry.work_item,
+                                );
                             }
                         } else {
                             // Panels not focused: treat as normal input
@@ -1089,21 +2201,19 @@ impl App {
                         }
                     }
                 }
-                KeyCode::Backspace => {
+                KeyCode::Backspace
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.delete_char_before();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.delete_char_before();
                 }
-                KeyCode::Delete => {
+                KeyCode::Delete
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.delete_char_at();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.delete_char_at();
                 }
                 _ => {}
             }
@@ -1114,7 +2224,39 @@ impl App {
     /// Handle events in edit mode
     async fn handle_edit_mode(&mut self, event: KeyEvent) -> Result<bool> {
         if let Some(form) = &mut self.form_data {
+            if form.activity_popup {
+                form.handle_activity_popup_key(event.code);
+                return Ok(true);
+            }
+
             match event.code {
+                KeyCode::Enter | KeyCode::Char(' ')
+                    if form.current_field == super::form::FormField::ActivityType
+                        && !form.focus_on_cache
+                        && !form.focus_on_quick_buffer
+                        && !form.focus_on_activity =>
+                {
+                    form.open_activity_popup();
+                }
+                KeyCode::PageDown if form.focus_on_cache => {
+                    let cache_size = self
+                        .cache
+                        .get_unique_entries_matching(
+                            self.user.id,
+                            form.cache_sort,
+                            form.cache_query(),
+                        )
+                        .len();
+                    form.selected_cache_index = (form.selected_cache_index + CACHE_PAGE_SIZE)
+                        .min(cache_size.saturating_sub(1));
+                }
+                KeyCode::PageUp if form.focus_on_cache => {
+                    form.selected_cache_index =
+                        form.selected_cache_index.saturating_sub(CACHE_PAGE_SIZE);
+                }
+                KeyCode::Char('s') if form.focus_on_cache => {
+                    form.cycle_cache_sort();
+                }
                 KeyCode::Esc => {
                     self.form_data = None;
                     self.editing_entry_id = None;
@@ -1184,37 +2326,33 @@ impl App {
                         form.update_cursor_for_field();
                     }
                 }
-                KeyCode::Left => {
+                KeyCode::Left
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.move_cursor_left();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.move_cursor_left();
                 }
-                KeyCode::Right => {
+                KeyCode::Right
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.move_cursor_right();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.move_cursor_right();
                 }
-                KeyCode::Home => {
+                KeyCode::Home
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.move_cursor_to_start();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.move_cursor_to_start();
                 }
-                KeyCode::End => {
+                KeyCode::End
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.move_cursor_to_end();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.move_cursor_to_end();
                 }
                 KeyCode::Up => {
                     if form.focus_on_activity {
@@ -1245,7 +2383,14 @@ impl App {
                         let idx = form.selected_activity_index.min(max);
                         form.activity_type = types[idx].name.to_string();
                     } else if form.focus_on_quick_buffer || form.focus_on_cache {
-                        let cache_size = self.cache.get_unique_entries(self.user.id).len();
+                        let cache_size = self
+                            .cache
+                            .get_unique_entries_matching(
+                                self.user.id,
+                                form.cache_sort,
+                                form.cache_query(),
+                            )
+                            .len();
                         if form.selected_cache_index < cache_size.saturating_sub(1) {
                             form.selected_cache_index += 1;
                         }
@@ -1266,39 +2411,94 @@ impl App {
                         }
                     } else if form.focus_on_quick_buffer {
                         // Apply selected cache entry from quick buffer using selected_cache_index
-                        let entries = self.cache.get_unique_entries(self.user.id);
+                        let entries = self.cache.get_unique_entries_matching(
+                            self.user.id,
+                            form.cache_sort,
+                            form.cache_query(),
+                        );
                         if let Some(entry) = entries.get(form.selected_cache_index) {
                             form.apply_cache_entry(entry.customer.clone(), entry.work_item.clone());
+                            self.cache
+                                .mark_used(self.user.id, &entry.customer, &entry.work_item);
                         }
                     } else if form.focus_on_cache {
                         // Apply selected cache entry
-                        let entries = self.cache.get_unique_entries(self.user.id);
+                        let entries = self.cache.get_unique_entries_matching(
+                            self.user.id,
+                            form.cache_sort,
+                            form.cache_query(),
+                        );
                         if let Some(entry) = entries.get(form.selected_cache_index) {
                             form.apply_cache_entry(entry.customer.clone(), entry.work_item.clone());
+                            self.cache
+                                .mark_used(self.user.id, &entry.customer, &entry.work_item);
                         }
                     } else {
+                        let rounded = self
+                            .config
+                            .round_hours_to
+                            .and_then(|nearest| form.round_hours(nearest));
+
+                        // Neither the entry's current week nor its new week may be locked
+                        let original_date = self
+                            .claims
+                            .iter()
+                            .find(|e| self.editing_entry_id.as_deref() == Some(e.id.as_str()))
+                            .map(|e| e.date);
+                        if let Some(err) = original_date
+                            .into_iter()
+                            .chain(parse_form_date(&form.date, self.config.today()))
+                            .find_map(|date| locked_week_error(&self.config, date))
+                        {
+                            self.messages.clear();
+                            self.messages.push(Message::new(MessageType::Error, err));
+                            return Ok(true);
+                        }
+
+                        if let Some(warning) = unconfirmed_work_item_warning(&self.config, form) {
+                            self.messages.clear();
+                            self.messages
+                                .push(Message::new(MessageType::Warning, warning));
+                            return Ok(true);
+                        }
+
                         // Save the form
                         match form.validate() {
                             Ok(_) => {
                                 // Clone form data and entry ID before async call
                                 let form_clone = form.clone();
                                 let entry_id_clone = self.editing_entry_id.clone();
+                                let before = self
+                                    .claims
+                                    .iter()
+                                    .find(|e| Some(&e.id) == entry_id_clone.as_ref())
+                                    .map(form_from_claim);
                                 self.form_data = None;
                                 self.editing_entry_id = None;
                                 self.mode = AppMode::Normal;
                                 self.messages.clear();
+                                self.push_rounding_notice(rounded);
 
                                 // Update on Monday.com
                                 let result = self.update_entry(&form_clone, &entry_id_clone).await;
 
                                 match result {
-                                    Ok(_) => {
+                                    Ok(changed) => {
+                                        if let (true, Some(item_id), Some(before)) =
+                                            (changed, entry_id_clone, before)
+                                        {
+                                            self.record_undo(UndoAction::Updated {
+                                                item_id,
+                                                before,
+                                                after: form_clone,
+                                            });
+                                        }
                                         self.messages.push(Message::new(
                                             MessageType::Success,
                                             "Entry updated successfully".to_string(),
                                         ));
                                         // Refresh week data to show updated entry
-                                        let _ = self.load_week_data().await;
+                                        self.start_week_load();
                                     }
                                     Err(e) => {
                                         self.messages.push(Message::new(
@@ -1327,33 +2527,61 @@ impl App {
                             form.set_activity_by_number(digit as u8);
                         } else if form.focus_on_quick_buffer {
                             // Quick buffer: choose cached entry by number
-                            let entries = self.cache.get_unique_entries(self.user.id);
+                            let entries = self.cache.get_unique_entries_matching(
+                                self.user.id,
+                                form.cache_sort,
+                                form.cache_query(),
+                            );
                             if digit < entries.len() && digit < 10 {
                                 let entry = &entries[digit];
                                 form.apply_cache_entry(
                                     entry.customer.clone(),
                                     entry.work_item.clone(),
                                 );
+                                self.cache.mark_used(
+                                    self.user.id,
+                                    &entry.customer,
+                                    &entry.work_item,
+                                );
                             }
                         } else if form.current_field == super::form::FormField::QuickSelection {
                             // Quick selection field: choose cached entry by number
-                            let entries = self.cache.get_unique_entries(self.user.id);
+                            let entries = self.cache.get_unique_entries_matching(
+                                self.user.id,
+                                form.cache_sort,
+                                form.cache_query(),
+                            );
                             if digit < entries.len() && digit < 10 {
                                 let entry = &entries[digit];
                                 form.apply_cache_entry(
                                     entry.customer.clone(),
                                     entry.work_item.clone(),
                                 );
+                                self.cache.mark_used(
+                                    self.user.id,
+                                    &entry.customer,
+                                    &entry.work_item,
+                                );
                             }
                         } else if form.focus_on_cache {
-                            // Cache panel active: choose cached entry by number
-                            let entries = self.cache.get_unique_entries(self.user.id);
-                            if digit < entries.len() && digit < 10 {
-                                let entry = &entries[digit];
+                            // Cache panel active: choose an entry on the current page by number
+                            let entries = self.cache.get_unique_entries_page(
+                                self.user.id,
+                                form.cache_sort,
+                                form.cache_query(),
+                                form.cache_page(),
+                                CACHE_PAGE_SIZE,
+                            );
+                            if let Some(entry) = entries.get(digit) {
                                 form.apply_cache_entry(
                                     entry.customer.clone(),
                                     entry.work_item.clone(),
                                 );
+                                self.cache.mark_used(
+                                    self.user.id,
+                                    &entry.customer,
+                                    &entry.work_item,
+                                );
                             }
                         } else {
                             // Panels not focused: treat as normal input
@@ -1376,21 +2604,19 @@ impl App {
                         }
                     }
                 }
-                KeyCode::Backspace => {
+                KeyCode::Backspace
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.delete_char_before();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.delete_char_before();
                 }
-                KeyCode::Delete => {
+                KeyCode::Delete
                     if !form.focus_on_cache
                         && !form.focus_on_quick_buffer
-                        && !form.focus_on_activity
-                    {
-                        form.delete_char_at();
-                    }
+                        && !form.focus_on_activity =>
+                {
+                    form.delete_char_at();
                 }
                 _ => {}
             }
@@ -1398,43 +2624,634 @@ impl App {
         Ok(true)
     }
 
-    /// Handle events in delete mode
-    async fn handle_delete_mode(&mut self, event: KeyEvent) -> Result<bool> {
+    /// Handle events in quick-log mode
+    async fn handle_quick_log_mode(&mut self, event: KeyEvent) -> Result<bool> {
         match event.code {
-            KeyCode::Char('y') | KeyCode::Char('Y') => {
-                // Get the selected entry ID
-                if let Some(day) = self.selected_day {
-                    if let Some(idx) = self.selected_entry_index {
-                        let entries_on_day: Vec<_> =
-                            self.claims.iter().filter(|e| e.date == day).collect();
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+                self.messages.clear();
+                self.messages.push(Message::new(
+                    MessageType::Info,
+                    "Quick-log cancelled".to_string(),
+                ));
+            }
+            KeyCode::Backspace => {
+                self.quick_log_hours.pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() || c == '.' || c == ',' => {
+                self.quick_log_hours.push(if c == ',' { '.' } else { c });
+            }
+            KeyCode::Enter => {
+                let form = self.build_quick_log_form();
+                if let Some(err) = parse_form_date(&form.date, self.config.today())
+                    .and_then(|date| locked_week_error(&self.config, date))
+                {
+                    self.messages.clear();
+                    self.messages.push(Message::new(MessageType::Error, err));
+                    return Ok(true);
+                }
+                match form.validate() {
+                    Ok(_) => {
+                        self.mode = AppMode::Normal;
+                        self.messages.clear();
+                        let success_message = format!(
+                            "Logged {}h {} on {}",
+                            form.hours, form.activity_type, form.date
+                        );
+                        self.start_save_new_entry(&form, success_message);
+                    }
+                    Err(err) => {
+                        self.messages.clear();
+                        self.messages.push(Message::new(
+                            MessageType::Error,
+                            format!("Validation error: {}", err),
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// Handle events in go-to mode
+    async fn handle_goto_mode(&mut self, event: KeyEvent) -> Result<bool> {
+        match event.code {
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+                self.messages.clear();
+                self.messages.push(Message::new(
+                    MessageType::Info,
+                    "Go to cancelled".to_string(),
+                ));
+            }
+            KeyCode::Backspace => {
+                self.goto_input.pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() || matches!(c, '-' | '.' | '/' | 'W' | 'w') => {
+                self.goto_input.push(c.to_ascii_uppercase());
+            }
+            KeyCode::Enter => {
+                match utils::parse_date_or_week(&self.goto_input, self.config.today()) {
+                    Ok(date) => {
+                        self.mode = AppMode::Normal;
+                        self.current_week_start =
+                            utils::get_business_week_start(date, self.config.first_day_of_week);
+                        self.selected_day = Some(date.max(self.current_week_start));
+                        self.selected_entry_index = None;
+                        self.start_week_load();
+                    }
+                    Err(e) => {
+                        self.messages.clear();
+                        self.messages
+                            .push(Message::new(MessageType::Error, e.to_string()));
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// Handle events in rename mode
+    async fn handle_rename_mode(&mut self, event: KeyEvent) -> Result<bool> {
+        match event.code {
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+                self.editing_entry_id = None;
+                self.messages.clear();
+                self.messages.push(Message::new(
+                    MessageType::Info,
+                    "Rename cancelled".to_string(),
+                ));
+            }
+            KeyCode::Backspace => {
+                self.rename_input.pop();
+            }
+            KeyCode::Char(c) => {
+                self.rename_input.push(c);
+            }
+            KeyCode::Enter => {
+                let work_item = self.rename_input.trim().to_string();
+                if work_item.is_empty() {
+                    self.messages.clear();
+                    self.messages.push(Message::new(
+                        MessageType::Error,
+                        "Work item cannot be empty".to_string(),
+                    ));
+                    return Ok(true);
+                }
+
+                let Some(entry_id) = self.editing_entry_id.take() else {
+                    self.mode = AppMode::Normal;
+                    return Ok(true);
+                };
+                self.mode = AppMode::Normal;
+                self.messages.clear();
+
+                let entry_date = self
+                    .claims
+                    .iter()
+                    .find(|e| e.id == entry_id)
+                    .map(|e| e.date);
+                if let Some(err) = entry_date.and_then(|date| locked_week_error(&self.config, date))
+                {
+                    self.messages.push(Message::new(MessageType::Error, err));
+                    return Ok(true);
+                }
+
+                match self
+                    .client
+                    .update_item_column(
+                        &self.config.board_id,
+                        &entry_id,
+                        "text8__1",
+                        &serde_json::json!(work_item),
+                        false,
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        self.entries_edited += 1;
+                        self.messages.push(Message::new(
+                            MessageType::Success,
+                            format!("Work item renamed to {}", work_item),
+                        ));
+                        self.start_week_load();
+                    }
+                    Err(e) => {
+                        self.messages.push(Message::new(
+                            MessageType::Error,
+                            format!("Failed to rename entry: {}", e),
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// Handle events in the find and replace popup
+    async fn handle_bulk_edit_mode(&mut self, event: KeyEvent) -> Result<bool> {
+        if self.bulk_edit.preview.is_some() {
+            match event.code {
+                KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    self.apply_bulk_edit().await;
+                }
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N') => {
+                    self.bulk_edit.preview = None;
+                }
+                _ => {}
+            }
+            return Ok(true);
+        }
+
+        let input = if self.bulk_edit.editing_replace {
+            &mut self.bulk_edit.replace
+        } else {
+            &mut self.bulk_edit.find
+        };
+        match event.code {
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+                self.messages.clear();
+                self.messages.push(Message::new(
+                    MessageType::Info,
+                    "Find and replace cancelled".to_string(),
+                ));
+            }
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.bulk_edit.editing_replace = !self.bulk_edit.editing_replace;
+            }
+            KeyCode::Up | KeyCode::Down => {
+                self.bulk_edit.field = self.bulk_edit.field.toggle();
+            }
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => {
+                input.push(c);
+            }
+            KeyCode::Enter => {
+                self.messages.clear();
+                if self.bulk_edit.find.is_empty() {
+                    self.messages.push(Message::new(
+                        MessageType::Error,
+                        "Find text cannot be empty".to_string(),
+                    ));
+                    return Ok(true);
+                }
+                let matches = self.bulk_edit.matches(&self.claims, &self.config);
+                if matches.is_empty() {
+                    self.messages.push(Message::new(
+                        MessageType::Warning,
+                        format!(
+                            "No {} in this week contains \"{}\"",
+                            self.bulk_edit.field.label(),
+                            self.bulk_edit.find
+                        ),
+                    ));
+                    return Ok(true);
+                }
+                self.messages.push(Message::new(
+                    MessageType::Info,
+                    format!(
+                        "{} entries will be updated - Enter/y to apply, Esc/n to go back",
+                        matches.len()
+                    ),
+                ));
+                self.bulk_edit.preview = Some(matches);
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// Updates every entry of the find and replace preview and reports how
+    /// many succeeded
+    async fn apply_bulk_edit(&mut self) {
+        let Some(matches) = self.bulk_edit.preview.take() else {
+            return;
+        };
+        self.mode = AppMode::Normal;
+
+        let column_id = self.bulk_edit.field.column_id();
+        let mut failures = Vec::new();
+        for m in &matches {
+            let column_values = serde_json::json!({ column_id: m.new });
+            match self
+                .client
+                .update_item_verbose(&self.config.board_id, &m.entry_id, &column_values, false)
+                .await
+            {
+                Ok(_) => self.entries_edited += 1,
+                Err(e) => failures.push(format!("{}: {}", m.date.format("%Y-%m-%d"), e)),
+            }
+        }
+
+        let updated = matches.len() - failures.len();
+        self.messages.clear();
+        if failures.is_empty() {
+            self.messages.push(Message::new(
+                MessageType::Success,
+                format!("Updated {} entries", updated),
+            ));
+        } else {
+            self.messages.push(Message::new(
+                MessageType::Error,
+                format!(
+                    "Updated {} of {} entries; failed: {}",
+                    updated,
+                    matches.len(),
+                    failures.join(", ")
+                ),
+            ));
+        }
+        if updated > 0 {
+            self.start_week_load();
+        }
+    }
+
+    /// Loads the next week to confirm copying the current week's entries
+    /// into it
+    fn start_duplicate_week(&mut self) {
+        self.messages.clear();
+        let next_week = self.current_week_start + chrono::Duration::days(7);
+        let error = if self.claims.is_empty() {
+            Some("This week has no entries to duplicate".to_string())
+        } else {
+            locked_week_error(&self.config, next_week)
+        };
+        if let Some(err) = error {
+            self.messages.push(Message::new(MessageType::Error, err));
+            return;
+        }
+        let week = self.week_fetch_at(next_week);
+        self.spawn_task("Checking next week...", async move {
+            TaskResult::NextWeekChecked(week.await)
+        });
+    }
+
+    /// Handle events in the duplicate week confirmation
+    fn handle_duplicate_week_mode(&mut self, event: KeyEvent) -> Result<bool> {
+        self.mode = AppMode::Normal;
+        self.messages.clear();
+        if !matches!(event.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+            self.messages.push(Message::new(
+                MessageType::Info,
+                "Duplicate week cancelled".to_string(),
+            ));
+            return Ok(true);
+        }
+
+        let forms: Vec<FormData> = self
+            .claims
+            .iter()
+            .map(|entry| {
+                let mut form = form_from_claim(entry);
+                form.date = (entry.date + chrono::Duration::days(7))
+                    .format("%Y-%m-%d")
+                    .to_string();
+                form
+            })
+            .collect();
+
+        // Show the next week once the copies are saved
+        self.current_week_start += chrono::Duration::days(7);
+        self.selected_day = Some(self.current_week_start);
+        self.selected_entry_index = None;
+
+        let client = Arc::clone(&self.client);
+        let board_id = self.config.board_id.clone();
+        let group_id = self.group_id.clone();
+        let user = self.user.clone();
+        let week = self.week_fetch();
+        self.spawn_task("Duplicating week...", async move {
+            let mut created = Vec::new();
+            let mut failures = Vec::new();
+            for form in forms {
+                let column_values = new_entry_column_values(&form, user.id);
+                match client
+                    .create_item_verbose(&board_id, &group_id, &user.name, &column_values, false)
+                    .await
+                {
+                    Ok(item_id) => created.push(UndoAction::Created {
+                        item_id,
+                        form_data: form,
+                    }),
+                    Err(e) => failures.push(format!("{}: {}", form.date, e)),
+                }
+            }
+            TaskResult::WeekDuplicated {
+                created,
+                failures,
+                week: week.await,
+            }
+        });
+        Ok(true)
+    }
+
+    /// Loaded entries whose customer, work item or comment contains the
+    /// search text (ignoring case), in date order
+    pub fn search_results(&self) -> Vec<&ClaimEntry> {
+        let needle = self.search_input.trim().to_lowercase();
+        let mut results: Vec<&ClaimEntry> = self
+            .claims
+            .iter()
+            .filter(|e| {
+                [
+                    e.customer.as_str(),
+                    e.work_item.as_str(),
+                    e.comment.as_deref().unwrap_or(""),
+                ]
+                .iter()
+                .any(|field| field.to_lowercase().contains(&needle))
+            })
+            .collect();
+        results.sort_by_key(|e| e.date);
+        results
+    }
+
+    /// Handle events in the search popup
+    fn handle_search_mode(&mut self, event: KeyEvent) -> Result<bool> {
+        match event.code {
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+                self.messages.clear();
+            }
+            KeyCode::Up => {
+                self.search_selected = self.search_selected.saturating_sub(1);
+            }
+            KeyCode::Down if self.search_selected + 1 < self.search_results().len() => {
+                self.search_selected += 1;
+            }
+            KeyCode::Backspace => {
+                self.search_input.pop();
+                self.search_selected = 0;
+            }
+            KeyCode::Char(c) => {
+                self.search_input.push(c);
+                self.search_selected = 0;
+            }
+            KeyCode::Enter => {
+                let Some(entry) = self.search_results().get(self.search_selected).copied() else {
+                    self.messages.clear();
+                    self.messages.push(Message::new(
+                        MessageType::Warning,
+                        format!("No entry matches \"{}\"", self.search_input),
+                    ));
+                    return Ok(true);
+                };
+                let (id, date) = (entry.id.clone(), entry.date);
+                self.mode = AppMode::Normal;
+                self.messages.clear();
+                self.selected_day = Some(date);
+                self.selected_entry_index = self
+                    .get_entries_for_date(date)
+                    .iter()
+                    .position(|e| e.id == id);
+                let week_start =
+                    utils::get_business_week_start(date, self.config.first_day_of_week);
+                if week_start != self.current_week_start {
+                    self.current_week_start = week_start;
+                    self.start_week_load();
+                }
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// Opens the month calendar on the month of `date` and loads its hours
+    /// in the background
+    fn start_month_view(&mut self, date: NaiveDate) {
+        self.month_view = MonthView::new(date);
+        self.mode = AppMode::MonthView;
+        self.messages.clear();
+        self.messages.push(Message::new(
+            MessageType::Info,
+            "Month view - arrows to move, Enter/a to add an entry on the day, Esc to return"
+                .to_string(),
+        ));
+
+        let client = Arc::clone(&self.client);
+        let board_id = self.config.board_id.clone();
+        let user_id = self.user.id;
+        let month = self.month_view.month;
+        let days = self.month_view.working_days();
+        self.spawn_task("Loading month...", async move {
+            TaskResult::MonthLoaded {
+                month,
+                result: fetch_days(&client, &board_id, user_id, &days).await,
+            }
+        });
+    }
+
+    /// Handle events in the month view
+    fn handle_month_view_mode(&mut self, event: KeyEvent) -> Result<bool> {
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('M') => {
+                self.mode = AppMode::Normal;
+                self.messages.clear();
+            }
+            KeyCode::Left => self.month_view.move_by(-1),
+            KeyCode::Right => self.month_view.move_by(1),
+            KeyCode::Up => self.month_view.move_by(-7),
+            KeyCode::Down => self.month_view.move_by(7),
+            KeyCode::Tab => {
+                let next = self.month_view.month + chrono::Duration::days(32);
+                self.start_month_view(next.with_day(1).unwrap_or(next));
+            }
+            KeyCode::BackTab => {
+                self.start_month_view(self.month_view.month - chrono::Duration::days(1));
+            }
+            // Show the week of the day and add an entry on it
+            KeyCode::Enter | KeyCode::Char('a') => {
+                let day = self.month_view.selected;
+                self.current_week_start =
+                    utils::get_business_week_start(day, self.config.first_day_of_week);
+                self.selected_day = Some(day);
+                self.selected_entry_index = None;
+                self.start_week_load();
+                self.start_add_mode();
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    /// Selects the focused entry, or unselects it when it already is
+    fn toggle_entry_selection(&mut self) {
+        let Some(entry_id) = self.focused_entry().map(|entry| entry.id.clone()) else {
+            return;
+        };
+        if !self.selected_entries.remove(&entry_id) {
+            self.selected_entries.insert(entry_id);
+        }
+        self.messages.clear();
+        self.messages.push(Message::new(
+            MessageType::Info,
+            format!(
+                "{} entries selected - D to delete them, Esc to clear the selection",
+                self.selected_entries.len()
+            ),
+        ));
+    }
+
+    /// Entry under the cursor
+    fn focused_entry(&self) -> Option<&ClaimEntry> {
+        let day = self.selected_day?;
+        let idx = self.selected_entry_index?;
+        self.claims.iter().filter(|e| e.date == day).nth(idx)
+    }
+
+    /// Selected entries in date order, for the batch delete confirmation
+    pub fn selected_claims(&self) -> Vec<&ClaimEntry> {
+        let mut entries: Vec<&ClaimEntry> = self
+            .claims
+            .iter()
+            .filter(|e| self.selected_entries.contains(&e.id))
+            .collect();
+        entries.sort_by_key(|e| e.date);
+        entries
+    }
+
+    /// Handle events in batch delete mode
+    async fn handle_batch_delete_mode(&mut self, event: KeyEvent) -> Result<bool> {
+        self.mode = AppMode::Normal;
+        self.messages.clear();
+        if !matches!(event.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+            // Any other key cancels the delete and keeps the selection
+            self.messages.push(Message::new(
+                MessageType::Info,
+                "Delete cancelled".to_string(),
+            ));
+            return Ok(true);
+        }
+
+        let entries: Vec<ClaimEntry> = self.selected_claims().into_iter().cloned().collect();
+        let mut deleted = 0;
+        let mut failures = Vec::new();
+        for entry in &entries {
+            let result = match locked_week_error(&self.config, entry.date) {
+                Some(err) => Err(anyhow::anyhow!(err)),
+                None => self.client.delete_item(&entry.id, false).await.map(|_| ()),
+            };
+            match result {
+                Ok(()) => {
+                    deleted += 1;
+                    self.entries_deleted += 1;
+                    self.selected_entries.remove(&entry.id);
+                    self.record_undo(UndoAction::Deleted {
+                        item_id: entry.id.clone(),
+                        form_data: form_from_claim(entry),
+                    });
+                }
+                Err(e) => failures.push(format!("{}: {}", entry.date.format("%Y-%m-%d"), e)),
+            }
+        }
+
+        if failures.is_empty() {
+            self.messages.push(Message::new(
+                MessageType::Success,
+                format!("Deleted {} entries", deleted),
+            ));
+        } else {
+            self.messages.push(Message::new(
+                MessageType::Error,
+                format!(
+                    "Deleted {} of {} entries; failed: {}",
+                    deleted,
+                    entries.len(),
+                    failures.join(", ")
+                ),
+            ));
+        }
+        if deleted > 0 {
+            self.start_week_load();
+        }
+        Ok(true)
+    }
+
+    /// Handle events in delete mode
+    async fn handle_delete_mode(&mut self, event: KeyEvent) -> Result<bool> {
+        match event.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                // Get the selected entry ID
+                if let Some(day) = self.selected_day {
+                    if let Some(idx) = self.selected_entry_index {
+                        let entries_on_day: Vec<_> =
+                            self.claims.iter().filter(|e| e.date == day).collect();
 
                         if let Some(entry) = entries_on_day.get(idx) {
                             let entry_id = entry.id.clone();
+                            let form_data = form_from_claim(entry);
 
                             // Delete from Monday.com
                             self.mode = AppMode::Normal;
                             self.messages.clear();
 
+                            if let Some(err) = locked_week_error(&self.config, entry.date) {
+                                self.messages.push(Message::new(MessageType::Error, err));
+                                return Ok(true);
+                            }
+
                             match self.client.delete_item(&entry_id, false).await {
                                 Ok(_) => {
+                                    self.entries_deleted += 1;
+                                    self.record_undo(UndoAction::Deleted {
+                                        item_id: entry_id,
+                                        form_data,
+                                    });
                                     self.messages.push(Message::new(
                                         MessageType::Success,
                                         "Entry deleted successfully".to_string(),
                                     ));
 
-                                    // Refresh week data to update the view
-                                    let _ = self.load_week_data().await;
-
-                                    // Adjust selection if needed
-                                    let remaining_entries: Vec<_> =
-                                        self.claims.iter().filter(|e| e.date == day).collect();
-
-                                    if remaining_entries.is_empty() {
-                                        self.selected_entry_index = None;
-                                    } else if idx >= remaining_entries.len() {
-                                        self.selected_entry_index =
-                                            Some(remaining_entries.len() - 1);
-                                    }
+                                    // Refresh week data to update the view; the
+                                    // selection is adjusted once it is loaded
+                                    self.start_week_load();
                                 }
                                 Err(e) => {
                                     self.messages.push(Message::new(
@@ -1465,7 +3282,8 @@ impl App {
         self.current_week_start -= chrono::Duration::days(7);
         self.selected_day = Some(self.current_week_start);
         self.selected_entry_index = None;
-        self.load_week_data().await
+        self.start_week_load();
+        Ok(())
     }
 
     /// Navigate to next week
@@ -1473,10 +3291,42 @@ impl App {
         self.current_week_start += chrono::Duration::days(7);
         self.selected_day = Some(self.current_week_start);
         self.selected_entry_index = None;
-        self.load_week_data().await
+        self.start_week_load();
+        Ok(())
+    }
+
+    /// Number of days shown in the week view: 7 when `show_weekends` is
+    /// enabled, otherwise 5 (Monday to Friday)
+    pub fn days_in_week(&self) -> i64 {
+        if self.config.show_weekends {
+            7
+        } else {
+            5
+        }
     }
 
-    /// Select a specific day of the week (0 = Monday, 4 = Friday)
+    /// Days of the current week shown as report columns: the days of the
+    /// week view, in display order starting on `week_starts_on`
+    pub fn report_dates(&self) -> Vec<NaiveDate> {
+        let mut dates = utils::calculate_working_dates(
+            self.current_week_start,
+            self.days_in_week(),
+            !self.config.show_weekends,
+        );
+        dates.sort_by_key(|date| utils::days_since_week_start(*date, self.config.week_starts_on));
+        dates
+    }
+
+    /// Hours of each report row (activity type, customer, work item), one
+    /// value per date of `dates`
+    pub fn report_hours(
+        &self,
+        dates: &[NaiveDate],
+    ) -> std::collections::HashMap<(i32, String, String), Vec<f64>> {
+        report_hours(&self.claims, dates)
+    }
+
+    /// Select a specific day of the week (0 = Monday, 4 = Friday, 6 = Sunday)
     fn select_day(&mut self, day_offset: i64) {
         self.selected_day = Some(self.current_week_start + chrono::Duration::days(day_offset));
         self.selected_entry_index = None;
@@ -1489,9 +3339,10 @@ impl App {
                 self.selected_day = Some(current_day - chrono::Duration::days(1));
                 self.selected_entry_index = None;
             } else {
-                // At Monday, move to previous week's Friday
+                // At Monday, move to the last shown day of the previous week
                 self.previous_week().await?;
-                self.selected_day = Some(self.current_week_start + chrono::Duration::days(4));
+                self.selected_day =
+                    Some(self.current_week_start + chrono::Duration::days(self.days_in_week() - 1));
             }
         }
         Ok(())
@@ -1500,13 +3351,13 @@ impl App {
     /// Select the next day in the week
     async fn select_next_day(&mut self) -> Result<()> {
         if let Some(current_day) = self.selected_day {
-            let weekday = current_day.weekday().num_days_from_monday();
-            if weekday < 4 {
-                // Friday is day 4
+            let weekday = current_day.weekday().num_days_from_monday() as i64;
+            if weekday < self.days_in_week() - 1 {
+                // Friday is day 4, Sunday is day 6
                 self.selected_day = Some(current_day + chrono::Duration::days(1));
                 self.selected_entry_index = None;
             } else {
-                // At Friday, move to next week's Monday
+                // At the last shown day, move to next week's Monday
                 self.next_week().await?;
                 self.selected_day = Some(self.current_week_start);
             }
@@ -1563,6 +3414,21 @@ impl App {
         self.claims.iter().filter(|e| e.date == date).collect()
     }
 
+    /// Tells the user when the hours they entered were rounded
+    fn push_rounding_notice(&mut self, rounded: Option<(f64, f64)>) {
+        if let (Some((from, to)), Some(nearest)) = (rounded, self.config.round_hours_to) {
+            self.messages.push(Message::new(
+                MessageType::Info,
+                format!(
+                    "Hours rounded from {} to {} ({} rounding enabled)",
+                    from,
+                    to,
+                    utils::rounding_label(nearest)
+                ),
+            ));
+        }
+    }
+
     /// Get total hours for the current week
     #[allow(dead_code)]
     pub fn get_week_total_hours(&self) -> f64 {
@@ -1577,11 +3443,7 @@ impl App {
         if let Some(day) = self.selected_day {
             form.date = day.format("%Y-%m-%d").to_string();
         } else {
-            form.date = Local::now()
-                .naive_local()
-                .date()
-                .format("%Y-%m-%d")
-                .to_string();
+            form.date = self.config.today().format("%Y-%m-%d").to_string();
         }
 
         self.form_data = Some(form);
@@ -1594,6 +3456,71 @@ impl App {
         ));
     }
 
+    /// Start quick-log mode with hours pre-filled from the config
+    fn start_quick_log_mode(&mut self) {
+        let hours = self.config.default_hours_per_day();
+        self.quick_log_hours = if hours % 1.0 == 0.0 {
+            format!("{:.0}", hours)
+        } else {
+            hours.to_string()
+        };
+        self.mode = AppMode::QuickLog;
+        self.messages.clear();
+        self.messages.push(Message::new(
+            MessageType::Info,
+            "Quick-log - adjust hours, Enter to save, Esc to cancel".to_string(),
+        ));
+    }
+
+    /// Build the entry that quick-log will create: the selected day, the most
+    /// recently used cache entry and the configured default activity type
+    pub fn build_quick_log_form(&self) -> FormData {
+        let mut form = FormData::new();
+        let day = self.selected_day.unwrap_or_else(|| self.config.today());
+        form.date = day.format("%Y-%m-%d").to_string();
+        form.activity_type = self.config.default_activity_type();
+        if let Some(entry) = self.cache.get_unique_entries(self.user.id).first() {
+            form.customer = entry.customer.clone();
+            form.work_item = entry.work_item.clone();
+        }
+        form.hours = self.quick_log_hours.clone();
+        if let Some(nearest) = self.config.round_hours_to {
+            form.round_hours(nearest);
+        }
+        form
+    }
+
+    /// Start rename mode with the selected entry's work item pre-filled
+    fn start_rename_mode(&mut self) {
+        let entry = self
+            .selected_day
+            .zip(self.selected_entry_index)
+            .and_then(|(day, idx)| {
+                self.claims
+                    .iter()
+                    .filter(|e| e.date == day)
+                    .nth(idx)
+                    .cloned()
+            });
+
+        self.messages.clear();
+        match entry {
+            Some(entry) => {
+                self.rename_input = entry.work_item;
+                self.editing_entry_id = Some(entry.id);
+                self.mode = AppMode::Rename;
+                self.messages.push(Message::new(
+                    MessageType::Info,
+                    "Rename work item - Enter to save, Esc to cancel".to_string(),
+                ));
+            }
+            None => self.messages.push(Message::new(
+                MessageType::Warning,
+                "Select an entry to rename first".to_string(),
+            )),
+        }
+    }
+
     /// Start edit mode with selected entry data
     fn start_edit_mode(&mut self) {
         if let Some(day) = self.selected_day {
@@ -1636,117 +3563,20 @@ impl App {
         None
     }
 
-    /// Save a new entry to Monday.com
-    async fn save_new_entry(&self, form: &FormData) -> Result<()> {
-        use crate::utils::map_activity_type_to_value;
-        use serde_json::json;
-
-        let activity_value = map_activity_type_to_value(&form.activity_type);
-        let date_str = &form.date; // date is already a String in YYYY-MM-DD format
-
-        let mut column_values = json!({});
-
-        // Set person column
-        column_values["person"] = json!({
-            "personsAndTeams": [
-                {
-                    "id": self.user.id,
-                    "kind": "person"
-                }
-            ]
-        });
-
-        // Set date column
-        column_values["date4"] = json!({
-            "date": date_str.clone()
-        });
-
-        // Set activity type column
-        column_values["status"] = json!({
-            "index": activity_value
-        });
-
-        // Set customer name
-        if !form.customer.is_empty() {
-            column_values["text__1"] = json!(form.customer);
-        }
-
-        // Set work item
-        if !form.work_item.is_empty() {
-            column_values["text8__1"] = json!(form.work_item);
-        }
-
-        // Set comment
-        if !form.comment.is_empty() {
-            column_values["text2__1"] = json!(form.comment);
-        }
-
-        // Set hours
-        column_values["numbers__1"] = json!(form.hours.to_string());
-
-        // Create the item
-        self.client
-            .create_item_verbose(
-                "6500270039",
-                &self.group_id,
-                &self.user.name,
-                &column_values,
-                false,
-            )
-            .await?;
-
-        Ok(())
-    }
-
-    /// Update an existing entry on Monday.com
-    async fn update_entry(&self, form: &FormData, entry_id: &Option<String>) -> Result<()> {
-        use crate::utils::map_activity_type_to_value;
-        use serde_json::json;
-
+    /// Update an existing entry on Monday.com; returns false when nothing
+    /// changed
+    async fn update_entry(&mut self, form: &FormData, entry_id: &Option<String>) -> Result<bool> {
         // Get the entry ID
         let entry_id = entry_id
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("No entry ID for update"))?;
 
-        let activity_value = map_activity_type_to_value(&form.activity_type);
-        let date_str = &form.date; // date is already a String in YYYY-MM-DD format
-
-        let mut column_values = json!({});
-
-        // Set date column
-        column_values["date4"] = json!({
-            "date": date_str.clone()
-        });
-
-        // Set activity type column
-        column_values["status"] = json!({
-            "index": activity_value
-        });
-
-        // Set customer name
-        if !form.customer.is_empty() {
-            column_values["text__1"] = json!(form.customer);
-        }
-
-        // Set work item
-        if !form.work_item.is_empty() {
-            column_values["text8__1"] = json!(form.work_item);
-        }
-
-        // Set comment
-        if !form.comment.is_empty() {
-            column_values["text2__1"] = json!(form.comment);
+        let changed =
+            update_item_from_form(&self.client, &self.config.board_id, entry_id, form).await?;
+        if changed {
+            self.entries_edited += 1;
         }
-
-        // Set hours
-        column_values["numbers__1"] = json!(form.hours.to_string());
-
-        // Update the item
-        self.client
-            .update_item_verbose(entry_id, &column_values, false)
-            .await?;
-
-        Ok(())
+        Ok(changed)
     }
 
     /// Reload data from Monday.com
@@ -1765,7 +3595,7 @@ impl App {
         let items = self
             .client
             .query_items_with_filters(
-                "6500270039",
+                &self.config.board_id,
                 &self.group_id,
                 self.user.id,
                 &[start_str, end_str],
@@ -1784,6 +3614,7 @@ impl App {
                 let work_item = extract_work_item_from_item(&item);
                 let comment = extract_comment_from_item(&item);
                 let hours = extract_hours_from_item(&item);
+                let updated_at = item.updated_at.clone();
                 let id = item.id.unwrap_or_default();
 
                 self.claims.push(ClaimEntry {
@@ -1795,6 +3626,7 @@ impl App {
                     work_item,
                     comment,
                     hours,
+                    updated_at,
                 });
             }
         }
@@ -1803,14 +3635,168 @@ impl App {
         Ok(())
     }
 }
-/// Get the Monday of the week containing the given date
-fn get_week_start(date: NaiveDate) -> NaiveDate {
-    let weekday = date.weekday().num_days_from_monday();
-    date - chrono::Duration::days(weekday as i64)
+
+/// Writes `form` to the item `entry_id`, sending only the columns that
+/// changed; returns false when nothing changed
+async fn update_item_from_form(
+    client: &AppClient,
+    board_id: &str,
+    entry_id: &str,
+    form: &FormData,
+) -> Result<bool> {
+    use crate::utils::map_activity_type_to_value;
+    use serde_json::json;
+
+    let activity_value = map_activity_type_to_value(&form.activity_type);
+    let date_str = &form.date; // date is already a String in YYYY-MM-DD format
+
+    let mut column_values = json!({});
+
+    // Set date column
+    column_values["date4"] = json!({
+        "date": date_str.clone()
+    });
+
+    // Set activity type column
+    column_values["status"] = json!({
+        "index": activity_value
+    });
+
+    // Set customer name
+    if !form.customer.is_empty() {
+        column_values["text__1"] = json!(form.customer);
+    }
+
+    // Set work item
+    if !form.work_item.is_empty() {
+        column_values["text8__1"] = json!(form.work_item);
+    }
+
+    // Set comment
+    if !form.comment.is_empty() {
+        column_values["text2__1"] = json!(form.comment);
+    }
+
+    // Set hours
+    column_values["numbers__1"] = json!(form.hours.to_string());
+
+    // Only send the columns that changed (falls back to all of them when
+    // the current item cannot be read)
+    if let Ok(Some(item)) = client.get_item_by_id(entry_id, false).await {
+        if let Some(current) = ClaimEntry::from_item(&item) {
+            column_values = diff_column_values(&entry_column_values(&current), &column_values);
+        }
+    }
+    if column_values
+        .as_object()
+        .is_some_and(|columns| columns.is_empty())
+    {
+        return Ok(false);
+    }
+
+    // Update the item, with the single-column mutation when only one changed
+    match column_values.as_object() {
+        Some(columns) if columns.len() == 1 => {
+            let (column_id, value) = columns.iter().next().unwrap();
+            client
+                .update_item_column(board_id, entry_id, column_id, value, false)
+                .await?;
+        }
+        _ => {
+            client
+                .update_item_verbose(board_id, entry_id, &column_values, false)
+                .await?;
+        }
+    }
+    Ok(true)
+}
+
+/// Undoes `action` (or applies it again with `redo`) and returns it for the
+/// opposite stack, with the new ID when an entry was added again
+async fn run_undo_step(
+    client: &AppClient,
+    board_id: &str,
+    group_id: &str,
+    user: &MondayUser,
+    mut action: UndoAction,
+    redo: bool,
+) -> Result<UndoAction> {
+    let recreate = match (&action, redo) {
+        (UndoAction::Created { form_data, .. }, true)
+        | (UndoAction::Deleted { form_data, .. }, false) => Some(form_data.clone()),
+        (UndoAction::Created { item_id, .. }, false)
+        | (UndoAction::Deleted { item_id, .. }, true) => {
+            client.delete_item(item_id, false).await?;
+            None
+        }
+        (
+            UndoAction::Updated {
+                item_id, before, ..
+            },
+            false,
+        ) => {
+            update_item_from_form(client, board_id, item_id, before).await?;
+            None
+        }
+        (UndoAction::Updated { item_id, after, .. }, true) => {
+            update_item_from_form(client, board_id, item_id, after).await?;
+            None
+        }
+    };
+    if let Some(form) = recreate {
+        let column_values = new_entry_column_values(&form, user.id);
+        let item_id = client
+            .create_item_verbose(board_id, group_id, &user.name, &column_values, false)
+            .await?;
+        action.set_item_id(&item_id);
+    }
+    Ok(action)
+}
+
+/// Pushes `action`, dropping the oldest one beyond `UNDO_LIMIT`
+fn push_capped(stack: &mut Vec<UndoAction>, action: UndoAction) {
+    stack.push(action);
+    if stack.len() > UNDO_LIMIT {
+        stack.remove(0);
+    }
+}
+
+/// Form with the values of an existing entry
+fn form_from_claim(entry: &ClaimEntry) -> FormData {
+    FormData::from_entry(
+        entry.date,
+        entry.activity_type.clone(),
+        entry.customer.clone(),
+        entry.work_item.clone(),
+        entry.hours,
+        entry.comment.clone(),
+    )
 }
 
 // Helper functions to extract data from Monday.com items
 
+/// Column values of an existing entry, shaped like the update payload built
+/// by `App::update_entry`
+fn entry_column_values(entry: &ClaimEntry) -> serde_json::Value {
+    use serde_json::json;
+
+    let mut column_values = json!({
+        "date4": { "date": entry.date.format("%Y-%m-%d").to_string() },
+        "status": { "index": entry.activity_value },
+        "numbers__1": entry.hours.to_string(),
+    });
+    if !entry.customer.is_empty() {
+        column_values["text__1"] = json!(entry.customer);
+    }
+    if !entry.work_item.is_empty() {
+        column_values["text8__1"] = json!(entry.work_item);
+    }
+    if let Some(comment) = entry.comment.as_ref().filter(|c| !c.is_empty()) {
+        column_values["text2__1"] = json!(comment);
+    }
+    column_values
+}
+
 fn extract_date_from_item(item: &Item) -> Option<NaiveDate> {
     for col in &item.column_values {
         if col.id.as_deref() == Some("date4") {
@@ -1864,6 +3850,219 @@ fn extract_work_item_from_item(item: &Item) -> String {
     String::new()
 }
 
+/// Parses a form date (YYYY-MM-DD, YYYY.MM.DD, YYYY/MM/DD or a relative date
+/// resolved against `today`)
+fn parse_form_date(date: &str, today: NaiveDate) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(&utils::normalize_date(date, today), "%Y-%m-%d").ok()
+}
+
+/// Message shown while the week is reloaded in the background
+const LOADING_WEEK_MESSAGE: &str = "Loading week data...";
+
+/// Fetches the user's entries of the `days`-day week starting at `week_start`
+/// from the group of `year`
+async fn fetch_week(
+    client: &AppClient,
+    board_id: &str,
+    year: i32,
+    user_id: i64,
+    week_start: NaiveDate,
+    days: i64,
+    skip_weekends: bool,
+) -> Result<Vec<ClaimEntry>> {
+    let current_year = year.to_string();
+
+    // Get the board and group ID
+    let board = client.get_board_with_groups(board_id, false).await?;
+    let group_id = utils::get_year_group_id(&board, &current_year);
+
+    // Date range for the week (Monday to Friday, or to Sunday when weekends are shown)
+    let date_strings: Vec<String> = utils::calculate_working_dates(week_start, days, skip_weekends)
+        .iter()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect();
+
+    let items = client
+        .query_items_with_filters(board_id, &group_id, user_id, &date_strings, 100, false)
+        .await?;
+    Ok(items.iter().filter_map(ClaimEntry::from_item).collect())
+}
+
+/// Entries of the user dated on any of `days`, read from the group of the
+/// year of the first day with a single query
+async fn fetch_days(
+    client: &AppClient,
+    board_id: &str,
+    user_id: i64,
+    days: &[NaiveDate],
+) -> Result<Vec<ClaimEntry>> {
+    let Some(first) = days.first() else {
+        return Ok(Vec::new());
+    };
+    let board = client.get_board_with_groups(board_id, false).await?;
+    let group_id = utils::get_year_group_id(&board, &first.year().to_string());
+    let date_strings: Vec<String> = days
+        .iter()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect();
+
+    let items = client
+        .query_items_with_filters(board_id, &group_id, user_id, &date_strings, 500, false)
+        .await?;
+    Ok(items.iter().filter_map(ClaimEntry::from_item).collect())
+}
+
+/// Loading message of a cache refresh starting at `since`
+fn refresh_message(since: Option<NaiveDate>) -> String {
+    match since {
+        Some(date) => format!("Refreshing cache since {}...", date),
+        None => "Refreshing cache from last 4 weeks...".to_string(),
+    }
+}
+
+/// Billable (customer, work item, date) triples of the user from the last 4
+/// weeks, or only from `since` on for an incremental refresh
+async fn fetch_recent_billable(
+    client: &AppClient,
+    board_id: &str,
+    user_id: i64,
+    today: NaiveDate,
+    since: Option<NaiveDate>,
+) -> Result<Vec<(String, String, NaiveDate)>> {
+    let current_year = today.year().to_string();
+    let start_date = today - chrono::Duration::days(28);
+
+    // Get the group ID for the current year
+    let board = client.get_board_with_groups(board_id, false).await?;
+    let group_id = utils::get_year_group_id(&board, &current_year);
+
+    let all_items = match since {
+        // Only the items dated since the last refresh, filtered on the server
+        Some(since) => {
+            client
+                .query_items_after_date(
+                    board_id,
+                    &group_id,
+                    user_id,
+                    since.max(start_date),
+                    today,
+                    500,
+                    false,
+                )
+                .await?
+        }
+        // Query all items for the user in the current year
+        None => {
+            client
+                .query_items_with_filters(
+                    board_id,
+                    &group_id,
+                    user_id,
+                    &[], // Empty date filter - get all items for the user
+                    500,
+                    false,
+                )
+                .await?
+        }
+    };
+
+    // Extract customer and work item pairs from items, filtering by date range and billable only
+    let mut entries = Vec::new();
+    for item in &all_items {
+        let customer = extract_customer_from_item(item);
+        let work_item = extract_work_item_from_item(item);
+        let date = extract_date_from_item(item);
+        let activity_value = extract_activity_value_from_item(item);
+
+        // Only include billable entries (activity_value == 1)
+        if activity_value == 1 && !customer.is_empty() && !work_item.is_empty() {
+            if let Some(d) = date {
+                // Only include items within the last 4 weeks
+                if d >= start_date && d <= today {
+                    entries.push((customer, work_item, d));
+                }
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// Column values of a new entry created from `form` for the user `user_id`
+fn new_entry_column_values(form: &FormData, user_id: i64) -> serde_json::Value {
+    use crate::utils::map_activity_type_to_value;
+    use serde_json::json;
+
+    let activity_value = map_activity_type_to_value(&form.activity_type);
+    let date_str = &form.date; // date is already a String in YYYY-MM-DD format
+
+    let mut column_values = json!({});
+
+    // Set person column
+    column_values["person"] = json!({
+        "personsAndTeams": [
+            {
+                "id": user_id,
+                "kind": "person"
+            }
+        ]
+    });
+
+    // Set date column
+    column_values["date4"] = json!({
+        "date": date_str.clone()
+    });
+
+    // Set activity type column
+    column_values["status"] = json!({
+        "index": activity_value
+    });
+
+    // Set customer name
+    if !form.customer.is_empty() {
+        column_values["text__1"] = json!(form.customer);
+    }
+
+    // Set work item
+    if !form.work_item.is_empty() {
+        column_values["text8__1"] = json!(form.work_item);
+    }
+
+    // Set comment
+    if !form.comment.is_empty() {
+        column_values["text2__1"] = json!(form.comment);
+    }
+
+    // Set hours
+    column_values["numbers__1"] = json!(form.hours.to_string());
+    column_values
+}
+
+/// Returns the error to show when `date` falls in a locked week
+fn locked_week_error(config: &Config, date: NaiveDate) -> Option<String> {
+    config
+        .ensure_week_unlocked(date)
+        .err()
+        .map(|e| e.to_string())
+}
+
+/// Warns once about a work item outside `allowed_work_items`; saving again
+/// with the same work item confirms it
+fn unconfirmed_work_item_warning(config: &Config, form: &mut FormData) -> Option<String> {
+    let work_item = form.work_item.trim();
+    if work_item.is_empty()
+        || config.is_work_item_allowed(work_item)
+        || form.confirmed_work_item.as_deref() == Some(work_item)
+    {
+        return None;
+    }
+
+    form.confirmed_work_item = Some(work_item.to_string());
+    Some(format!(
+        "Work item {} is not in the allowed list. Press Enter again to save anyway",
+        work_item
+    ))
+}
+
 fn extract_hours_from_item(item: &Item) -> f64 {
     for col in &item.column_values {
         if col.id.as_deref() == Some("numbers__1") {
@@ -1,17 +1,20 @@
 //! Entry details panel rendering
 
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Gauge, Paragraph},
     Frame,
 };
 
 use super::app::{App, ClaimEntry};
+use super::ui::ColorScheme;
+use crate::utils;
 
-/// Render entry details panel
-pub fn render(f: &mut Frame, app: &App, area: Rect) {
+/// Render entry details panel, with the progress towards the weekly target
+/// on its last line
+pub fn render(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     let entry = get_selected_entry(app);
 
     let lines = if let Some(entry) = entry {
@@ -20,7 +23,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 Span::styled(
                     "Date: ",
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(colors.header)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
@@ -32,11 +35,11 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 Span::styled(
                     "Activity: ",
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(colors.header)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    &entry.activity_type,
+                    app.config.activity_label(entry.activity_value as u8),
                     get_activity_color(&entry.activity_type),
                 ),
             ]),
@@ -44,7 +47,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 Span::styled(
                     "Customer: ",
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(colors.header)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
@@ -60,7 +63,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 Span::styled(
                     "Work Item: ",
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(colors.header)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
@@ -76,7 +79,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 Span::styled(
                     "Hours: ",
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(colors.header)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
@@ -88,7 +91,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 Span::styled(
                     "Comment: ",
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(colors.header)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
@@ -96,6 +99,23 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                     Style::default().fg(Color::Gray),
                 ),
             ]),
+            Line::from(vec![
+                Span::styled(
+                    "Last modified: ",
+                    Style::default()
+                        .fg(colors.header)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    entry
+                        .updated_at
+                        .as_deref()
+                        .and_then(utils::parse_timestamp)
+                        .map(utils::format_relative_time)
+                        .unwrap_or_else(|| "-".to_string()),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]),
         ]
     } else {
         vec![
@@ -111,14 +131,55 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         ]
     };
 
-    let paragraph = Paragraph::new(lines).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title(" Entry Details ")
-            .style(Style::default().fg(Color::Cyan)),
-    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Entry Details ")
+        .style(Style::default().fg(colors.header));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+    render_week_progress(f, app, chunks[1]);
+}
+
+/// Horizontal bar of the hours logged this week against the weekly target,
+/// with the numbers to its right
+fn render_week_progress(f: &mut Frame, app: &App, area: Rect) {
+    let total: f64 = app.claims.iter().map(|e| e.hours).sum();
+    let target = app.config.weekly_target_hours();
+    let ratio = if target > 0.0 { total / target } else { 0.0 };
+    let color = if ratio > 1.0 {
+        Color::Magenta
+    } else if ratio >= 0.95 {
+        Color::Green
+    } else if ratio >= 0.7 {
+        Color::Yellow
+    } else {
+        Color::Red
+    };
 
-    f.render_widget(paragraph, area);
+    let label = format!(" {:.1} / {:.1} h", total, target);
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(10),
+            Constraint::Length(label.chars().count() as u16),
+        ])
+        .split(area);
+
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(color).bg(Color::DarkGray))
+        .ratio(ratio.clamp(0.0, 1.0))
+        .label(format!("{:.0}%", ratio * 100.0));
+    f.render_widget(gauge, chunks[0]);
+    f.render_widget(
+        Paragraph::new(label).style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        chunks[1],
+    );
 }
 
 /// Get the currently selected entry
@@ -1,6 +1,12 @@
 //! Form handling for add/edit operations
 
 use chrono::NaiveDate;
+use crossterm::event::KeyCode;
+
+use crate::cache::CacheSort;
+
+/// Number of cached entries shown per page of the cache panel
+pub const CACHE_PAGE_SIZE: usize = 9;
 
 /// Form field types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -81,9 +87,16 @@ pub struct FormData {
     pub focus_on_cache: bool,
     pub focus_on_quick_buffer: bool,
     pub focus_on_activity: bool,
+    /// Cursor in the cache list, across all pages
     pub selected_cache_index: usize,
+    /// Order of the entries in the cache panel
+    pub cache_sort: CacheSort,
     pub selected_activity_index: usize,
     pub cursor_position: usize,
+    /// Whether the activity type selection popup is open
+    pub activity_popup: bool,
+    /// Work item outside the allowed list the user was already warned about
+    pub confirmed_work_item: Option<String>,
 }
 
 impl FormData {
@@ -101,8 +114,11 @@ impl FormData {
             focus_on_quick_buffer: false,
             focus_on_activity: false,
             selected_cache_index: 0,
+            cache_sort: CacheSort::default(),
             selected_activity_index: 1,
             cursor_position: 0,
+            activity_popup: false,
+            confirmed_work_item: None,
         }
     }
 
@@ -128,8 +144,11 @@ impl FormData {
             focus_on_quick_buffer: false,
             focus_on_activity: false,
             selected_cache_index: 0,
+            cache_sort: CacheSort::default(),
             selected_activity_index: 1,
             cursor_position: date_str.len(), // Start at end of date field
+            activity_popup: false,
+            confirmed_work_item: None,
         }
     }
 
@@ -158,6 +177,68 @@ impl FormData {
         }
     }
 
+    /// Page of the cache panel that holds the cursor
+    pub fn cache_page(&self) -> usize {
+        self.selected_cache_index / CACHE_PAGE_SIZE
+    }
+
+    /// Text filtering the cache panel: the customer or work item being typed
+    pub fn cache_query(&self) -> &str {
+        match self.current_field {
+            FormField::Customer | FormField::WorkItem => self.get_field_value(self.current_field),
+            _ => "",
+        }
+    }
+
+    /// Switch the cache panel to the next sort order, back at the first entry
+    pub fn cycle_cache_sort(&mut self) {
+        self.cache_sort = self.cache_sort.next();
+        self.selected_cache_index = 0;
+    }
+
+    /// Open the activity type popup with the current activity highlighted
+    pub fn open_activity_popup(&mut self) {
+        self.selected_activity_index = super::activity_types::get_all_activity_types()
+            .iter()
+            .position(|t| t.name == self.activity_type)
+            .unwrap_or(1);
+        self.activity_popup = true;
+    }
+
+    /// Handle a key while the activity type popup is open: Up/Down move,
+    /// Enter or a digit selects and moves on to the customer, Esc closes
+    pub fn handle_activity_popup_key(&mut self, code: KeyCode) {
+        let types = super::activity_types::get_all_activity_types();
+        let max = types.len().saturating_sub(1);
+
+        match code {
+            KeyCode::Up => {
+                self.selected_activity_index = self.selected_activity_index.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.selected_activity_index = (self.selected_activity_index + 1).min(max);
+            }
+            KeyCode::Enter => {
+                if let Some(t) = types.get(self.selected_activity_index) {
+                    self.activity_type = t.name.to_string();
+                }
+                self.close_activity_popup_to_customer();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                self.set_activity_by_number(c.to_digit(10).unwrap() as u8);
+                self.close_activity_popup_to_customer();
+            }
+            KeyCode::Esc => self.activity_popup = false,
+            _ => {}
+        }
+    }
+
+    fn close_activity_popup_to_customer(&mut self) {
+        self.activity_popup = false;
+        self.current_field = FormField::Customer;
+        self.update_cursor_for_field();
+    }
+
     /// Get the current field value
     pub fn get_field_value(&self, field: FormField) -> &str {
         match field {
@@ -211,6 +292,18 @@ impl FormData {
         self.current_field = FormField::Hours;
     }
 
+    /// Rounds the hours field to the nearest `nearest` increment. Returns the
+    /// original and rounded values when the field changed.
+    pub fn round_hours(&mut self, nearest: f64) -> Option<(f64, f64)> {
+        let hours = self.hours.trim().parse::<f64>().ok()?;
+        let rounded = crate::utils::round_to_nearest(hours, nearest);
+        if rounded == hours {
+            return None;
+        }
+        self.hours = rounded.to_string();
+        Some((hours, rounded))
+    }
+
     /// Validate form data
     pub fn validate(&self) -> Result<(), String> {
         if self.date.is_empty() {
@@ -247,15 +340,23 @@ impl FormData {
 
     /// Insert character at cursor position
     pub fn insert_char(&mut self, c: char) {
+        // The activity type is picked from a list, never typed
+        if self.current_field == FormField::ActivityType {
+            return;
+        }
         let pos = self.cursor_position;
         let field = self.get_current_field_mut();
         let pos = pos.min(field.len());
         field.insert(pos, c);
         self.cursor_position = pos + 1;
+        self.selected_cache_index = 0;
     }
 
     /// Delete character before cursor (backspace)
     pub fn delete_char_before(&mut self) {
+        if self.current_field == FormField::ActivityType {
+            return;
+        }
         let cursor_pos = self.cursor_position;
         if cursor_pos > 0 {
             let field = self.get_current_field_mut();
@@ -265,16 +366,21 @@ impl FormData {
                 self.cursor_position = pos - 1;
             }
         }
+        self.selected_cache_index = 0;
     }
 
     /// Delete character at cursor (delete key)
     pub fn delete_char_at(&mut self) {
+        if self.current_field == FormField::ActivityType {
+            return;
+        }
         let cursor_pos = self.cursor_position;
         let field = self.get_current_field_mut();
         let pos = cursor_pos.min(field.len());
         if pos < field.len() {
             field.remove(pos);
         }
+        self.selected_cache_index = 0;
     }
 
     /// Move cursor left
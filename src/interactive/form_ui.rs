@@ -10,10 +10,12 @@ use ratatui::{
 
 use super::activity_types;
 use super::app::{App, AppMode};
-use super::form::FormField;
+use super::form::{FormField, CACHE_PAGE_SIZE};
+use super::ui::ColorScheme;
+use crate::cache::CacheSort;
 
 /// Render the form editor
-pub fn render_form(f: &mut Frame, app: &App, area: Rect) {
+pub fn render_form(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     if let Some(form) = &app.form_data {
         // Optional quick-select buffer (opt-in via env CLAIM_QUICK_SELECT_BUFFER)
         let title = match app.mode {
@@ -39,7 +41,7 @@ pub fn render_form(f: &mut Frame, app: &App, area: Rect) {
 
             let label_style = if is_current {
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(colors.header)
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default().fg(Color::White)
@@ -91,7 +93,7 @@ pub fn render_form(f: &mut Frame, app: &App, area: Rect) {
             let hint = match form.current_field {
                 FormField::QuickSelection => "Quick entry: press 0-9 to select from Recent Entries",
                 FormField::Date => "Format: YYYY-MM-DD (e.g., 2024-01-15)",
-                FormField::ActivityType => "Press 0-9 to select from list →",
+                FormField::ActivityType => "Press Space/Enter to pick from a list, or 0-9 →",
                 FormField::Customer => "Enter customer name or press 0-9 to select from cache →",
                 FormField::WorkItem => "Enter work item code or press 0-9 to select from cache →",
                 FormField::Hours => "Enter hours (e.g., 8, 4.5)",
@@ -112,26 +114,26 @@ pub fn render_form(f: &mut Frame, app: &App, area: Rect) {
 }
 
 /// Render context-aware right panel based on current field
-pub fn render_context_panel(f: &mut Frame, app: &App, area: Rect) {
+pub fn render_context_panel(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     if let Some(form) = &app.form_data {
         // Determine what to show based on current field
         match form.current_field {
             FormField::ActivityType => {
-                render_activity_type_panel(f, form.activity_type.as_str(), area);
+                render_activity_type_panel(f, colors, form.activity_type.as_str(), area);
             }
             FormField::Customer | FormField::WorkItem => {
-                render_cache_panel_with_selection(f, app, area);
+                render_cache_panel_with_selection(f, app, colors, area);
             }
             _ => {
                 // For other fields, show cache as reference
-                render_cache_panel_with_selection(f, app, area);
+                render_cache_panel_with_selection(f, app, colors, area);
             }
         }
     }
 }
 
 /// Render activity type selection panel
-fn render_activity_type_panel(f: &mut Frame, current_type: &str, area: Rect) {
+fn render_activity_type_panel(f: &mut Frame, colors: &ColorScheme, current_type: &str, area: Rect) {
     let activity_types = activity_types::get_all_activity_types();
 
     let items: Vec<ListItem> = activity_types
@@ -157,32 +159,43 @@ fn render_activity_type_panel(f: &mut Frame, current_type: &str, area: Rect) {
         Block::default()
             .borders(Borders::ALL)
             .title(" Activity Types (press 0-9) ")
-            .style(Style::default().fg(Color::Cyan)),
+            .style(Style::default().fg(colors.header)),
     );
 
     f.render_widget(list, area);
 }
 
-/// Render cache panel with selection highlighting
-pub fn render_cache_panel_with_selection(f: &mut Frame, app: &App, area: Rect) {
-    let entries = app.cache.get_unique_entries(app.user.id);
-    let selected_index = if let Some(form) = &app.form_data {
-        if form.focus_on_cache {
-            Some(form.selected_cache_index)
-        } else {
-            None
-        }
-    } else {
-        None
+/// Render cache panel with selection highlighting, one page at a time
+pub fn render_cache_panel_with_selection(
+    f: &mut Frame,
+    app: &App,
+    colors: &ColorScheme,
+    area: Rect,
+) {
+    let (sort, query, page, selected_index) = match &app.form_data {
+        Some(form) => (
+            form.cache_sort,
+            form.cache_query(),
+            form.cache_page(),
+            form.focus_on_cache.then_some(form.selected_cache_index),
+        ),
+        None => (CacheSort::default(), "", 0, None),
     };
-
-    // Only show 9 billable entries (already filtered during cache refresh)
+    let total = app
+        .cache
+        .get_unique_entries_matching(app.user.id, sort, query)
+        .len();
+    let entries =
+        app.cache
+            .get_unique_entries_page(app.user.id, sort, query, page, CACHE_PAGE_SIZE);
+    let page_start = page * CACHE_PAGE_SIZE;
+
+    // Only billable entries are cached (already filtered during cache refresh)
     let items: Vec<ListItem> = entries
         .iter()
-        .take(9)
         .enumerate()
         .map(|(i, entry)| {
-            let is_selected = selected_index == Some(i);
+            let is_selected = selected_index == Some(page_start + i);
             let style = if is_selected {
                 Style::default()
                     .fg(Color::Yellow)
@@ -192,31 +205,47 @@ pub fn render_cache_panel_with_selection(f: &mut Frame, app: &App, area: Rect) {
             };
 
             let prefix = if is_selected { "▶ " } else { "  " };
-            let number = if i < 10 {
-                format!("{}", i)
-            } else {
-                " ".to_string()
-            };
             let content = format!(
-                "{}{} {} | {}",
-                prefix, number, entry.customer, entry.work_item
+                "{}{} {}",
+                prefix,
+                i,
+                app.config
+                    .cache_entry_label(&entry.customer, &entry.work_item)
             );
 
             ListItem::new(content).style(style)
         })
         .collect();
 
+    let range = if total == 0 && !query.trim().is_empty() {
+        format!("No recent entries matching \"{}\"", query.trim())
+    } else if total == 0 {
+        "No recent entries".to_string()
+    } else {
+        format!(
+            "{}-{} of {} recent entries, {}",
+            page_start + 1,
+            page_start + entries.len(),
+            total,
+            sort.label()
+        )
+    };
+    let more = if page_start + entries.len() < total {
+        " (↓ for more)"
+    } else {
+        ""
+    };
     let title = if selected_index.is_some() {
-        " Recent Entries (↑↓ to select, Enter to use) "
+        format!(" {}{} · Enter to use, s to sort ", range, more)
     } else {
-        " Recent Entries (press 0-9 to select) "
+        format!(" {}{} · press 0-9 to select ", range, more)
     };
 
     let list = List::new(items).block(
         Block::default()
             .borders(Borders::ALL)
             .title(title)
-            .style(Style::default().fg(Color::Cyan)),
+            .style(Style::default().fg(colors.header)),
     );
 
     f.render_widget(list, area);
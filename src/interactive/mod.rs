@@ -11,6 +11,8 @@ pub mod events;
 pub mod form;
 pub mod form_ui;
 pub mod messages;
+pub mod month_view;
+pub mod simulation;
 pub mod summary_chart;
 pub mod ui;
 pub mod utils;
@@ -32,20 +34,22 @@ use std::io::IsTerminal;
 
 use crate::config::Config;
 use crate::monday::MondayClient;
+use crate::sessions::{append_session, SessionRecord};
+use simulation::{AppClient, SimulatedApp};
 
 /// Restores terminal mode even when startup fails before the UI loop begins.
-struct TerminalCleanup {
+pub(crate) struct TerminalCleanup {
     active: bool,
 }
 
 impl TerminalCleanup {
-    fn activate() -> Result<Self> {
+    pub(crate) fn activate() -> Result<Self> {
         enable_raw_mode()?;
         execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
         Ok(Self { active: true })
     }
 
-    fn restore(&mut self) -> Result<()> {
+    pub(crate) fn restore(&mut self) -> Result<()> {
         if self.active {
             disable_raw_mode()?;
             execute!(
@@ -66,20 +70,53 @@ impl Drop for TerminalCleanup {
     }
 }
 
-/// Run the interactive UI application
-pub async fn run_interactive() -> Result<()> {
+/// Run the interactive UI application. With `simulate`, the UI runs on
+/// sample data without loading the config or contacting Monday.com.
+/// `color_scheme` and `week_starts_on` override the settings in the config.
+pub async fn run_interactive(
+    simulate: bool,
+    color_scheme: Option<String>,
+    week_starts_on: Option<chrono::Weekday>,
+    no_cache_refresh: bool,
+) -> Result<()> {
     if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
         return Err(anyhow::anyhow!(
             "Interactive mode requires a TTY. Run `claim` in a terminal session."
         ));
     }
 
-    // Load configuration
-    let config = Config::load()?;
-    let client = MondayClient::new(config.api_key.clone());
+    let (client, user, mut config) = if simulate {
+        let config = Config::default();
+        let sim = SimulatedApp::new(config.today());
+        let user = sim.current_user();
+        (AppClient::Simulated(sim), user, config)
+    } else {
+        // Load configuration
+        let mut config = Config::load()?;
+        let client =
+            MondayClient::new(config.api_key.clone()).with_retry_policy(config.retry_policy());
+
+        // Get current user (cached in the config file for an hour)
+        let (user, _) = crate::load_current_user(&client, &mut config, false).await?;
+        (AppClient::Live(client), user, config)
+    };
 
-    // Get current user
-    let user = client.get_current_user_verbose(false).await?;
+    if let Some(name) = color_scheme {
+        config.color_scheme = name;
+    }
+    if let Some(day) = week_starts_on {
+        config.week_starts_on = day;
+    }
+    if no_cache_refresh {
+        config.cache_ttl_hours = 0;
+    }
+    if ui::ColorScheme::named(&config.color_scheme).is_none() {
+        return Err(anyhow::anyhow!(
+            "Unknown color scheme '{}'. Available schemes: {}",
+            config.color_scheme,
+            ui::ColorScheme::NAMES.join(", ")
+        ));
+    }
 
     // Setup terminal with guaranteed cleanup on all return paths
     let mut terminal_cleanup = TerminalCleanup::activate()?;
@@ -87,10 +124,8 @@ pub async fn run_interactive() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Create app and run
-    let res = {
-        let mut app = App::new(client, user).await?;
-        run_app(&mut terminal, &mut app).await
-    };
+    let mut app = App::new(client, user, config).await?;
+    let res = run_app(&mut terminal, &mut app).await;
 
     // Explicit cleanup before function returns (Drop remains a fallback)
     terminal.show_cursor()?;
@@ -100,6 +135,27 @@ pub async fn run_interactive() -> Result<()> {
         eprintln!("Error: {:?}", err);
     }
 
+    // Keep the pairs picked from the cache during the session at the top
+    if !app.client.is_simulated() {
+        if let Err(e) = app.cache.save() {
+            eprintln!("Failed to save the entry cache: {}", e);
+        }
+    }
+
+    if let Some(path) = &app.config.session_log {
+        let record = SessionRecord {
+            start: app.session_start,
+            end: chrono::Local::now(),
+            entries_added: app.entries_added,
+            entries_edited: app.entries_edited,
+            entries_deleted: app.entries_deleted,
+            week: app.session_start.format("%G-W%V").to_string(),
+        };
+        if let Err(e) = append_session(path, &record) {
+            eprintln!("Failed to write the session log: {}", e);
+        }
+    }
+
     Ok(())
 }
 
@@ -114,6 +170,9 @@ async fn run_app<B: ratatui::backend::Backend>(
         // Draw UI
         terminal.draw(|f| ui::draw(f, app))?;
 
+        // Pick up the result of a background operation without waiting for it
+        app.poll_pending_task().await?;
+
         // Handle events
         if let Some(event) = event_handler.next()? {
             if !app.handle_event(event).await? {
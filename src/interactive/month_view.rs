@@ -0,0 +1,93 @@
+//! Month view component: a calendar of the hours logged per working day
+
+use chrono::Datelike;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Row, Table},
+    Frame,
+};
+
+use super::app::App;
+use super::ui::ColorScheme;
+use super::utils::format_hours;
+
+/// Render the month calendar: one row per ISO week, one column per working day
+pub fn render(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let view = &app.month_view;
+    let [min_hours, max_hours] = app.config.expected_daily_hours;
+
+    let header = Row::new(
+        ["Week", "Mon", "Tue", "Wed", "Thu", "Fri", "Total"]
+            .into_iter()
+            .map(|label| Cell::from(label).style(Style::default().add_modifier(Modifier::BOLD))),
+    )
+    .height(1);
+
+    let mut rows = Vec::new();
+    for monday in view.weeks() {
+        let mut cells = vec![Cell::from(format!("W{:02}", monday.iso_week().week()))];
+        let mut week_total = 0.0;
+
+        for offset in 0..5 {
+            let date = monday + chrono::Duration::days(offset);
+            if !view.contains(date) {
+                cells.push(Cell::from(""));
+                continue;
+            }
+
+            let hours = view
+                .totals
+                .as_ref()
+                .map(|totals| totals.get(&date).copied().unwrap_or(0.0));
+            week_total += hours.unwrap_or(0.0);
+
+            let color = match hours {
+                None => Color::DarkGray,
+                Some(0.0) => Color::Gray,
+                Some(hours) if hours < min_hours => Color::Yellow,
+                Some(hours) if hours > max_hours => colors.day_empty,
+                Some(_) => colors.day_full,
+            };
+            let mut style = Style::default().fg(color);
+            if date == view.selected {
+                style = style.bg(colors.day_selected).add_modifier(Modifier::BOLD);
+            }
+            if date == app.config.today() {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+
+            let text = match hours {
+                Some(hours) => format!("{:>2}  {}", date.day(), format_hours(hours)),
+                None => format!("{:>2}  …", date.day()),
+            };
+            cells.push(Cell::from(text).style(style));
+        }
+
+        cells.push(
+            Cell::from(format_hours(week_total)).style(
+                Style::default()
+                    .fg(colors.header)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        );
+        rows.push(Row::new(cells).height(2));
+    }
+
+    let mut widths = vec![Constraint::Length(6)];
+    widths.extend((0..5).map(|_| Constraint::Percentage(17)));
+    widths.push(Constraint::Length(10));
+
+    let title = format!(" {} ", view.month.format("%B %Y"));
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(colors.header)),
+        )
+        .column_spacing(1);
+
+    f.render_widget(table, area);
+}
@@ -0,0 +1,397 @@
+//! Offline sample data for `claim --simulate`
+//!
+//! `SimulatedApp` stands in for the Monday.com API: it serves five weeks of
+//! hardcoded entries for two sample users and keeps changes in memory only.
+
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate};
+use serde_json::{json, Value};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::monday::{is_user_item, Board, ColumnValue, Group, Item, MondayClient, MondayUser};
+use crate::utils;
+
+/// Artificial latency of every simulated API call
+const SIMULATED_DELAY: Duration = Duration::from_millis(200);
+
+/// Number of weeks of sample entries, ending with the current week
+const SAMPLE_WEEKS: i64 = 5;
+
+/// Customer/work item pairs used for the sample billable entries
+const SAMPLE_PROJECTS: [(&str, &str); 3] = [
+    ("ACME", "PROJ-123"),
+    ("Globex", "GLX-42"),
+    ("Initech", "TPS-7"),
+];
+
+/// Where the interactive UI sends its requests: the Monday.com API, or the
+/// in-memory sample data when running with `--simulate`
+pub enum AppClient {
+    Live(MondayClient),
+    Simulated(SimulatedApp),
+}
+
+impl AppClient {
+    pub fn is_simulated(&self) -> bool {
+        matches!(self, AppClient::Simulated(_))
+    }
+
+    pub async fn get_board_with_groups(&self, board_id: &str, verbose: bool) -> Result<Board> {
+        match self {
+            AppClient::Live(client) => client.get_board_with_groups(board_id, verbose).await,
+            AppClient::Simulated(sim) => sim.get_board_with_groups(board_id).await,
+        }
+    }
+
+    pub async fn query_items_with_filters(
+        &self,
+        board_id: &str,
+        group_id: &str,
+        user_id: i64,
+        dates: &[String],
+        limit: usize,
+        verbose: bool,
+    ) -> Result<Vec<Item>> {
+        match self {
+            AppClient::Live(client) => {
+                client
+                    .query_items_with_filters(board_id, group_id, user_id, dates, limit, verbose)
+                    .await
+            }
+            AppClient::Simulated(sim) => sim.query_items(user_id, dates, limit).await,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn query_items_after_date(
+        &self,
+        board_id: &str,
+        group_id: &str,
+        user_id: i64,
+        since: NaiveDate,
+        until: NaiveDate,
+        limit: usize,
+        verbose: bool,
+    ) -> Result<Vec<Item>> {
+        match self {
+            AppClient::Live(client) => {
+                client
+                    .query_items_after_date(
+                        board_id, group_id, user_id, since, until, limit, verbose,
+                    )
+                    .await
+            }
+            AppClient::Simulated(sim) => {
+                let items = sim.query_items(user_id, &[], usize::MAX).await?;
+                Ok(items
+                    .into_iter()
+                    .filter(|item| {
+                        item_date(item)
+                            .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+                            .is_some_and(|date| date >= since && date <= until)
+                    })
+                    .take(limit)
+                    .collect())
+            }
+        }
+    }
+
+    pub async fn create_item_verbose(
+        &self,
+        board_id: &str,
+        group_id: &str,
+        item_name: &str,
+        column_values: &Value,
+        verbose: bool,
+    ) -> Result<String> {
+        match self {
+            AppClient::Live(client) => {
+                client
+                    .create_item_verbose(board_id, group_id, item_name, column_values, verbose)
+                    .await
+            }
+            AppClient::Simulated(sim) => sim.create_item(item_name, column_values).await,
+        }
+    }
+
+    pub async fn update_item_verbose(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        column_values: &Value,
+        verbose: bool,
+    ) -> Result<()> {
+        match self {
+            AppClient::Live(client) => {
+                client
+                    .update_item_verbose(board_id, item_id, column_values, verbose)
+                    .await
+            }
+            AppClient::Simulated(sim) => sim.update_item(item_id, column_values).await,
+        }
+    }
+
+    pub async fn update_item_column(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        column_id: &str,
+        value: &Value,
+        verbose: bool,
+    ) -> Result<()> {
+        match self {
+            AppClient::Live(client) => {
+                client
+                    .update_item_column(board_id, item_id, column_id, value, verbose)
+                    .await
+            }
+            AppClient::Simulated(sim) => {
+                sim.update_item(item_id, &json!({ column_id: value })).await
+            }
+        }
+    }
+
+    pub async fn get_item_by_id(&self, item_id: &str, verbose: bool) -> Result<Option<Item>> {
+        match self {
+            AppClient::Live(client) => client.get_item_by_id(item_id, verbose).await,
+            AppClient::Simulated(sim) => sim.get_item(item_id).await,
+        }
+    }
+
+    pub async fn delete_item(&self, item_id: &str, verbose: bool) -> Result<String> {
+        match self {
+            AppClient::Live(client) => client.delete_item(item_id, verbose).await,
+            AppClient::Simulated(sim) => sim.delete_item(item_id).await,
+        }
+    }
+}
+
+/// In-memory board with sample entries for two users
+pub struct SimulatedApp {
+    users: Vec<MondayUser>,
+    year: String,
+    items: Mutex<Vec<Item>>,
+    next_id: Mutex<u64>,
+}
+
+impl SimulatedApp {
+    /// Builds the sample board for the five weeks ending with the week of `today`
+    pub fn new(today: NaiveDate) -> Self {
+        let users = vec![
+            MondayUser {
+                id: 1001,
+                name: "Alex Demo".to_string(),
+                email: "alex.demo@example.com".to_string(),
+            },
+            MondayUser {
+                id: 1002,
+                name: "Sam Sample".to_string(),
+                email: "sam.sample@example.com".to_string(),
+            },
+        ];
+
+        let sim = SimulatedApp {
+            year: today.year().to_string(),
+            items: Mutex::new(Vec::new()),
+            next_id: Mutex::new(1),
+            users,
+        };
+        sim.add_sample_entries(today);
+        sim
+    }
+
+    /// The user the interactive UI runs as
+    pub fn current_user(&self) -> MondayUser {
+        self.users[0].clone()
+    }
+
+    /// Fills the board: the first user has a mix of billable work and every
+    /// other activity type, the second user logs plain billable days
+    fn add_sample_entries(&self, today: NaiveDate) {
+        let monday = utils::get_week_start(today, chrono::Weekday::Mon)
+            - chrono::Duration::weeks(SAMPLE_WEEKS - 1);
+        let days = utils::calculate_working_dates(monday, SAMPLE_WEEKS * 5, true);
+        let (alex, sam) = (self.users[0].id, self.users[1].id);
+
+        // Every activity type except billable, spread over alternate days
+        let mut other_activities = (0..=utils::MAX_ACTIVITY_VALUE).filter(|v| *v != 1);
+
+        for (i, date) in days.iter().enumerate() {
+            let (customer, work_item) = SAMPLE_PROJECTS[i % SAMPLE_PROJECTS.len()];
+
+            let other_activity = if i % 2 == 0 {
+                other_activities.next()
+            } else {
+                None
+            };
+            match other_activity {
+                Some(activity) => {
+                    self.push_sample(alex, *date, 1, customer, work_item, 4.0);
+                    self.push_sample(alex, *date, activity, "", "", 4.0);
+                }
+                None => {
+                    self.push_sample(alex, *date, 1, customer, work_item, 6.0);
+                    let (customer, work_item) = SAMPLE_PROJECTS[(i + 1) % SAMPLE_PROJECTS.len()];
+                    self.push_sample(alex, *date, 1, customer, work_item, 2.0);
+                }
+            }
+
+            self.push_sample(sam, *date, 1, "Globex", "GLX-42", 8.0);
+        }
+    }
+
+    fn push_sample(
+        &self,
+        user_id: i64,
+        date: NaiveDate,
+        activity: u8,
+        customer: &str,
+        work_item: &str,
+        hours: f64,
+    ) {
+        let mut column_values = json!({
+            "person": { "personsAndTeams": [{ "id": user_id, "kind": "person" }] },
+            "date4": { "date": date.format("%Y-%m-%d").to_string() },
+            "status": { "index": activity },
+            "numbers__1": hours.to_string(),
+        });
+        if !customer.is_empty() {
+            column_values["text__1"] = json!(customer);
+            column_values["text8__1"] = json!(work_item);
+        }
+
+        let name = self
+            .users
+            .iter()
+            .find(|u| u.id == user_id)
+            .map(|u| u.name.clone())
+            .unwrap_or_default();
+        let item = self.new_item(&name, &column_values);
+        self.items.lock().unwrap().push(item);
+    }
+
+    fn new_item(&self, name: &str, column_values: &Value) -> Item {
+        let mut next_id = self.next_id.lock().unwrap();
+        let mut item = Item {
+            id: Some(next_id.to_string()),
+            name: Some(name.to_string()),
+            updated_at: Some(chrono::Utc::now().to_rfc3339()),
+            column_values: Vec::new(),
+        };
+        *next_id += 1;
+        apply_column_values(&mut item, column_values);
+        item
+    }
+
+    async fn get_board_with_groups(&self, board_id: &str) -> Result<Board> {
+        tokio::time::sleep(SIMULATED_DELAY).await;
+        Ok(Board {
+            id: Some(board_id.to_string()),
+            name: Some("Claims (simulated)".to_string()),
+            columns: Vec::new(),
+            groups: Some(vec![Group {
+                id: "simulated_group".to_string(),
+                title: self.year.clone(),
+                items_page: None,
+            }]),
+        })
+    }
+
+    async fn query_items(&self, user_id: i64, dates: &[String], limit: usize) -> Result<Vec<Item>> {
+        tokio::time::sleep(SIMULATED_DELAY).await;
+        let items = self.items.lock().unwrap();
+        Ok(items
+            .iter()
+            .filter(|item| is_user_item(item, user_id))
+            .filter(|item| dates.is_empty() || dates.iter().any(|d| item_date(item) == Some(d)))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn create_item(&self, name: &str, column_values: &Value) -> Result<String> {
+        tokio::time::sleep(SIMULATED_DELAY).await;
+        let item = self.new_item(name, column_values);
+        let id = item.id.clone().unwrap_or_default();
+        self.items.lock().unwrap().push(item);
+        Ok(id)
+    }
+
+    async fn update_item(&self, item_id: &str, column_values: &Value) -> Result<()> {
+        tokio::time::sleep(SIMULATED_DELAY).await;
+        let mut items = self.items.lock().unwrap();
+        let item = items
+            .iter_mut()
+            .find(|item| item.id.as_deref() == Some(item_id))
+            .ok_or_else(|| anyhow!("Item {} not found", item_id))?;
+        apply_column_values(item, column_values);
+        item.updated_at = Some(chrono::Utc::now().to_rfc3339());
+        Ok(())
+    }
+
+    async fn get_item(&self, item_id: &str) -> Result<Option<Item>> {
+        tokio::time::sleep(SIMULATED_DELAY).await;
+        let items = self.items.lock().unwrap();
+        Ok(items
+            .iter()
+            .find(|item| item.id.as_deref() == Some(item_id))
+            .cloned())
+    }
+
+    async fn delete_item(&self, item_id: &str) -> Result<String> {
+        tokio::time::sleep(SIMULATED_DELAY).await;
+        let mut items = self.items.lock().unwrap();
+        let before = items.len();
+        items.retain(|item| item.id.as_deref() != Some(item_id));
+        if items.len() == before {
+            return Err(anyhow!("Item {} not found", item_id));
+        }
+        Ok(format!("Item {} deleted successfully", item_id))
+    }
+}
+
+/// Date column text of an item
+fn item_date(item: &Item) -> Option<&String> {
+    item.column_values
+        .iter()
+        .find(|col| col.id.as_deref() == Some("date4"))
+        .and_then(|col| col.text.as_ref())
+}
+
+/// Sets columns from a `create_item`/`change_multiple_column_values` payload,
+/// filling in `text` the way Monday.com renders each column type
+fn apply_column_values(item: &mut Item, column_values: &Value) {
+    let Some(columns) = column_values.as_object() else {
+        return;
+    };
+
+    for (id, value) in columns {
+        let text = match id.as_str() {
+            "date4" => value["date"].as_str().unwrap_or_default().to_string(),
+            "status" => value["index"]
+                .as_u64()
+                .map(|i| utils::map_activity_value_to_name(i as u8))
+                .unwrap_or_default(),
+            "person" => String::new(),
+            _ => value
+                .as_str()
+                .map(str::to_string)
+                .unwrap_or_else(|| value.to_string()),
+        };
+        let column = ColumnValue {
+            id: Some(id.clone()),
+            value: Some(value.to_string()),
+            text: Some(text),
+        };
+
+        match item
+            .column_values
+            .iter_mut()
+            .find(|col| col.id.as_deref() == Some(id))
+        {
+            Some(existing) => *existing = column,
+            None => item.column_values.push(column),
+        }
+    }
+}
@@ -1,21 +1,289 @@
-//! Summary chart component for displaying activity type distribution
+//! Summary chart component for displaying activity type distribution and
+//! hours logged per day
 
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols::Marker,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{
+        canvas::{self, Canvas},
+        Bar, BarChart, BarGroup, Block, Borders, Paragraph,
+    },
     Frame,
 };
 use std::collections::HashMap;
 
-use super::app::App;
-use super::utils::{format_hours, get_activity_color};
+use super::app::{App, ChartView, ClaimEntry};
+use super::ui::ColorScheme;
+use super::utils::{format_hours, get_activity_color, get_weekday_name};
+use crate::utils::map_activity_value_to_name;
 
-/// Render the summary chart
-pub fn render(f: &mut Frame, app: &App, area: Rect) {
+/// Bar values are stored in tenths of an hour since `BarChart` only takes integers
+const BAR_SCALE: f64 = 10.0;
+
+/// Terminal width from which both charts are shown side by side
+const WIDE_LAYOUT_MIN_WIDTH: u16 = 150;
+
+/// Activities named in the activity share legend; the rest are merged into "other"
+const SHARE_MAX_SLICES: usize = 3;
+
+/// Status index of billable work
+const BILLABLE_ACTIVITY_VALUE: i32 = 1;
+
+/// Render the summary chart: activity distribution on the left, and the hours
+/// by day and/or the activity share on the right depending on the width
+pub fn render(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    if area.width >= WIDE_LAYOUT_MIN_WIDTH {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(40),
+                Constraint::Percentage(30),
+                Constraint::Percentage(30),
+            ])
+            .split(area);
+
+        render_activity_summary(f, app, colors, chunks[0]);
+        render_daily_hours_chart(f, app, colors, chunks[1]);
+        render_activity_share(f, app, colors, chunks[2]);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    render_activity_summary(f, app, colors, chunks[0]);
+    match app.chart_view {
+        ChartView::DailyHours => render_daily_hours_chart(f, app, colors, chunks[1]),
+        ChartView::ActivityShare => render_activity_share(f, app, colors, chunks[1]),
+    }
+}
+
+/// Hours counted for an entry: vacation/illness without hours count as 8 hours
+fn effective_hours(entry: &ClaimEntry) -> f64 {
+    if entry.hours > 0.0 {
+        entry.hours
+    } else if entry.activity_type.to_lowercase().contains("vacation")
+        || entry.activity_type.to_lowercase().contains("illness")
+    {
+        8.0
+    } else {
+        entry.hours
+    }
+}
+
+/// One segment of the activity share bar
+struct ShareSlice {
+    label: String,
+    color: Color,
+    hours: f64,
+    billable: bool,
+}
+
+/// Groups the week's hours by activity, largest first, merging everything
+/// past `SHARE_MAX_SLICES` into "other"
+fn activity_share_slices(app: &App) -> Vec<ShareSlice> {
+    let mut totals: HashMap<i32, f64> = HashMap::new();
+    for entry in &app.claims {
+        *totals.entry(entry.activity_value).or_insert(0.0) += effective_hours(entry);
+    }
+
+    let mut totals: Vec<(i32, f64)> = totals.into_iter().filter(|(_, h)| *h > 0.0).collect();
+    totals.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+
+    let mut slices: Vec<ShareSlice> = totals
+        .iter()
+        .take(SHARE_MAX_SLICES)
+        .map(|(value, hours)| ShareSlice {
+            label: app.config.activity_label(*value as u8),
+            color: get_activity_color(&map_activity_value_to_name(*value as u8)),
+            hours: *hours,
+            billable: *value == BILLABLE_ACTIVITY_VALUE,
+        })
+        .collect();
+
+    let other: f64 = totals.iter().skip(SHARE_MAX_SLICES).map(|(_, h)| h).sum();
+    if other > 0.0 {
+        slices.push(ShareSlice {
+            label: "other".to_string(),
+            color: Color::Gray,
+            hours: other,
+            billable: false,
+        });
+    }
+
+    slices
+}
+
+/// Render the share of hours per activity as a horizontal "pie" bar
+/// (▓ billable, ░ non-billable) with a legend
+fn render_activity_share(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Activity Share ")
+        .border_style(Style::default().fg(colors.header));
+    let width = block.inner(area).width as usize;
+
+    let slices = activity_share_slices(app);
+    let total: f64 = slices.iter().map(|s| s.hours).sum();
+
+    let mut lines = Vec::new();
+    if total > 0.0 {
+        let mut bar = Vec::new();
+        let mut used = 0;
+        for (i, slice) in slices.iter().enumerate() {
+            // The last slice takes the rounding remainder so the bar fills the width
+            let cells = if i + 1 == slices.len() {
+                width.saturating_sub(used)
+            } else {
+                ((slice.hours / total) * width as f64).round() as usize
+            }
+            .min(width.saturating_sub(used));
+            used += cells;
+
+            let symbol = if slice.billable { "▓" } else { "░" };
+            bar.push(Span::styled(
+                symbol.repeat(cells),
+                Style::default().fg(slice.color),
+            ));
+        }
+        lines.push(Line::from(bar.clone()));
+        lines.push(Line::from(bar));
+        lines.push(Line::from(""));
+
+        let mut legend = Vec::new();
+        for (i, slice) in slices.iter().enumerate() {
+            if i > 0 {
+                legend.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
+            }
+            legend.push(Span::styled(
+                format!("({:.0}%) {}", slice.hours / total * 100.0, slice.label),
+                Style::default().fg(slice.color),
+            ));
+        }
+        lines.push(Line::from(legend));
+    } else {
+        lines.push(Line::from(Span::styled(
+            "No entries for this week",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .wrap(ratatui::widgets::Wrap { trim: true })
+        .block(block);
+
+    f.render_widget(paragraph, area);
+}
+
+/// Render a bar per working day with a reference line at the daily target
+fn render_daily_hours_chart(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let week_start = app.current_week_start;
+    let week_end = week_start + chrono::Duration::days(4);
+    let daily_target = app.config.weekly_target_hours() / 5.0;
+
+    let day_hours: Vec<(chrono::NaiveDate, f64)> = (0..5)
+        .map(|i| {
+            let date = week_start + chrono::Duration::days(i);
+            let hours = app
+                .claims
+                .iter()
+                .filter(|e| e.date == date)
+                .map(|e| e.hours)
+                .sum();
+            (date, hours)
+        })
+        .collect();
+
+    // Leave headroom above the tallest bar or the target line
+    let max_hours = day_hours
+        .iter()
+        .map(|(_, h)| *h)
+        .fold(daily_target, f64::max)
+        * 1.2;
+
+    let bars: Vec<Bar> = day_hours
+        .iter()
+        .map(|(date, hours)| {
+            let color = day_color(*hours, daily_target);
+            Bar::default()
+                .value((hours * BAR_SCALE).round() as u64)
+                .text_value(format!("{:.1}", hours))
+                .label(Line::from(get_weekday_name(chrono::Datelike::weekday(
+                    date,
+                ))))
+                .style(Style::default().fg(color))
+                .value_style(
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                )
+        })
+        .collect();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " {} – {} ",
+            week_start.format("%b %d"),
+            week_end.format("%b %d")
+        ))
+        .border_style(Style::default().fg(colors.header));
+    let inner = block.inner(area);
+
+    let bar_gap = 1;
+    let bar_width = (inner.width.saturating_sub(bar_gap * 4) / 5).max(1);
+
+    let chart = BarChart::default()
+        .block(block)
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(bar_gap)
+        .max((max_hours * BAR_SCALE).round() as u64);
+
+    f.render_widget(chart, area);
+
+    // Draw the daily target over the bars (the last inner row holds the day labels)
+    let bars_area = Rect {
+        height: inner.height.saturating_sub(1),
+        ..inner
+    };
+    if bars_area.height > 0 && daily_target > 0.0 {
+        let target_line = Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([0.0, f64::from(bars_area.width)])
+            .y_bounds([0.0, max_hours])
+            .paint(|ctx| {
+                ctx.draw(&canvas::Line {
+                    x1: 0.0,
+                    y1: daily_target,
+                    x2: f64::from(bars_area.width),
+                    y2: daily_target,
+                    color: Color::White,
+                });
+            });
+        f.render_widget(target_line, bars_area);
+    }
+}
+
+/// Green at or above the daily target, yellow from 75%, red below that
+fn day_color(hours: f64, daily_target: f64) -> Color {
+    if daily_target <= 0.0 || hours >= daily_target {
+        Color::Green
+    } else if hours >= daily_target * 0.75 {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
+}
+
+/// Render the activity type distribution for the week
+fn render_activity_summary(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     // Calculate activity type distribution
-    let mut activity_totals: HashMap<String, f64> = HashMap::new();
+    let mut activity_totals: HashMap<i32, f64> = HashMap::new();
     let mut total_hours = 0.0;
     // Track which days have entries
     let mut days_with_entries = std::collections::HashSet::new();
@@ -24,19 +292,9 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         days_with_entries.insert(entry.date);
 
         // Handle vacation/illness without hours as 8 hours
-        let hours = if entry.hours > 0.0 {
-            entry.hours
-        } else if entry.activity_type.to_lowercase().contains("vacation")
-            || entry.activity_type.to_lowercase().contains("illness")
-        {
-            8.0
-        } else {
-            entry.hours
-        };
+        let hours = effective_hours(entry);
 
-        *activity_totals
-            .entry(entry.activity_type.clone())
-            .or_insert(0.0) += hours;
+        *activity_totals.entry(entry.activity_value).or_insert(0.0) += hours;
         total_hours += hours;
     }
 
@@ -52,10 +310,13 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let total_hours_with_blanks = total_hours + (blank_days as f64 * 8.0);
 
     // Sort by hours (descending), then by activity type name for stable ordering
-    let mut activities: Vec<_> = activity_totals.into_iter().collect();
+    let mut activities: Vec<_> = activity_totals
+        .into_iter()
+        .map(|(value, hours)| (app.config.activity_label(value as u8), value, hours))
+        .collect();
     activities.sort_by(|a, b| {
         // First compare by hours (descending)
-        match b.1.partial_cmp(&a.1).unwrap() {
+        match b.2.partial_cmp(&a.2).unwrap() {
             std::cmp::Ordering::Equal => {
                 // If hours are equal, sort by activity type name (ascending) for stability
                 a.0.cmp(&b.0)
@@ -68,16 +329,18 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     let mut lines = Vec::new();
 
     if total_hours > 0.0 {
-        for (activity_type, hours) in activities {
+        for (activity_label, activity_value, hours) in activities {
             let percentage = (hours / total_hours) * 100.0;
             let bar_width = ((percentage / 100.0) * 30.0) as usize; // Max 30 chars for bar
 
-            let color = get_activity_color(&activity_type);
+            let color = get_activity_color(&crate::utils::map_activity_value_to_name(
+                activity_value as u8,
+            ));
             let bar = "█".repeat(bar_width);
 
             let line = Line::from(vec![
                 Span::styled(
-                    format!("{:15} ", activity_type),
+                    format!("{:15} ", activity_label),
                     Style::default().fg(Color::White),
                 ),
                 Span::styled(bar, Style::default().fg(color)),
@@ -104,7 +367,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         let total_color = if total_hours_with_blanks > 40.0 {
             Color::Red
         } else {
-            Color::Cyan
+            colors.header
         };
 
         let total_text = if total_hours_with_blanks > 40.0 {
@@ -140,7 +403,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         Block::default()
             .borders(Borders::ALL)
             .title(" Weekly Summary ")
-            .border_style(Style::default().fg(Color::Cyan)),
+            .border_style(Style::default().fg(colors.header)),
     );
 
     f.render_widget(paragraph, area);
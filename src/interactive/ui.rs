@@ -1,22 +1,121 @@
 //! Main UI rendering logic
 
-use chrono::Datelike;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row},
+    widgets::{Block, Borders, Cell, List, ListItem, Paragraph, Row},
     Frame,
 };
 
 use super::app::{App, AppMode};
 use super::messages::MessageType;
-use super::utils::get_message_style;
-use super::{entry_details, form_ui, summary_chart, week_view};
+use super::utils::format_elapsed;
+use super::{entry_details, form_ui, month_view, summary_chart, week_view};
+
+/// Colors of the TUI, selected with `--color-scheme` or `color_scheme` in
+/// the config file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorScheme {
+    /// Header, borders and highlighted labels
+    pub header: Color,
+    /// Background of the selected day
+    pub day_selected: Color,
+    /// Daily total of a working day without hours
+    pub day_empty: Color,
+    /// Daily total of a day with at least 8 hours
+    pub day_full: Color,
+    /// Background of the selected entry
+    pub entry_selected: Color,
+    /// Background of entries selected with Space for a batch delete
+    pub entry_marked: Color,
+    pub message_info: Color,
+    pub message_error: Color,
+    pub message_success: Color,
+    pub message_warning: Color,
+}
+
+impl ColorScheme {
+    /// Names of the built-in schemes
+    pub const NAMES: [&'static str; 4] = ["default", "solarized", "nord", "high-contrast"];
+
+    /// Returns the built-in scheme called `name`
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            "solarized" => Some(ColorScheme {
+                header: Color::Rgb(38, 139, 210),
+                day_selected: Color::Rgb(7, 54, 66),
+                day_empty: Color::Rgb(220, 50, 47),
+                day_full: Color::Rgb(133, 153, 0),
+                entry_selected: Color::Rgb(7, 54, 66),
+                entry_marked: Color::Rgb(108, 113, 196),
+                message_info: Color::Rgb(42, 161, 152),
+                message_error: Color::Rgb(220, 50, 47),
+                message_success: Color::Rgb(133, 153, 0),
+                message_warning: Color::Rgb(181, 137, 0),
+            }),
+            "nord" => Some(ColorScheme {
+                header: Color::Rgb(136, 192, 208),
+                day_selected: Color::Rgb(67, 76, 94),
+                day_empty: Color::Rgb(191, 97, 106),
+                day_full: Color::Rgb(163, 190, 140),
+                entry_selected: Color::Rgb(76, 86, 106),
+                entry_marked: Color::Rgb(180, 142, 173),
+                message_info: Color::Rgb(129, 161, 193),
+                message_error: Color::Rgb(191, 97, 106),
+                message_success: Color::Rgb(163, 190, 140),
+                message_warning: Color::Rgb(235, 203, 139),
+            }),
+            "high-contrast" => Some(ColorScheme {
+                header: Color::White,
+                day_selected: Color::Blue,
+                day_empty: Color::LightRed,
+                day_full: Color::LightGreen,
+                entry_selected: Color::Blue,
+                entry_marked: Color::Magenta,
+                message_info: Color::White,
+                message_error: Color::LightRed,
+                message_success: Color::LightGreen,
+                message_warning: Color::LightYellow,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Style of a message of the given type
+    pub fn message_style(&self, message_type: MessageType) -> Style {
+        let color = match message_type {
+            MessageType::Info => self.message_info,
+            MessageType::Success => self.message_success,
+            MessageType::Warning => self.message_warning,
+            MessageType::Error => self.message_error,
+        };
+        Style::default().fg(color)
+    }
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        ColorScheme {
+            header: Color::Cyan,
+            day_selected: Color::DarkGray,
+            day_empty: Color::Red,
+            day_full: Color::Green,
+            entry_selected: Color::DarkGray,
+            entry_marked: Color::Magenta,
+            message_info: Color::Cyan,
+            message_error: Color::Red,
+            message_success: Color::Green,
+            message_warning: Color::Yellow,
+        }
+    }
+}
 
 /// Main draw function
 pub fn draw(f: &mut Frame, app: &App) {
     let size = f.size();
+    let colors = &app.colors;
 
     // Create main layout
     let chunks = Layout::default()
@@ -30,49 +129,90 @@ pub fn draw(f: &mut Frame, app: &App) {
         .split(size);
 
     // Render header
-    render_header(f, app, chunks[0]);
+    render_header(f, app, colors, chunks[0]);
 
     // Render main content based on mode
     match app.mode {
-        AppMode::Help => render_help(f, chunks[1]),
-        AppMode::Report => render_report(f, app, chunks[1]),
-        _ => render_main_content(f, app, chunks[1]),
+        AppMode::Help => render_help(f, app, colors, chunks[1]),
+        AppMode::Report => render_report(f, app, colors, chunks[1]),
+        AppMode::CacheManage => render_cache_manage(f, app, colors, chunks[1]),
+        AppMode::MonthView => month_view::render(f, app, colors, chunks[1]),
+        _ => render_main_content(f, app, colors, chunks[1]),
     }
 
     // Render messages
-    render_messages(f, app, chunks[2]);
+    render_messages(f, app, colors, chunks[2]);
 
     // Render footer
-    render_footer(f, app, chunks[3]);
+    render_footer(f, app, colors, chunks[3]);
+
+    // Render quick-log popup on top of the week view
+    if app.mode == AppMode::QuickLog {
+        render_quick_log_popup(f, app, colors, size);
+    }
+
+    // Render go-to popup on top of the week view
+    if app.mode == AppMode::Goto {
+        render_goto_popup(f, app, colors, size);
+    }
+
+    // Render rename popup on top of the week view
+    if app.mode == AppMode::Rename {
+        render_rename_popup(f, app, colors, size);
+    }
+
+    if app.mode == AppMode::BulkEdit {
+        render_bulk_edit_popup(f, app, colors, size);
+    }
+
+    if app.mode == AppMode::Search {
+        render_search_popup(f, app, colors, size);
+    }
+
+    if app.mode == AppMode::BatchDelete {
+        render_batch_delete_popup(f, app, colors, size);
+    }
+
+    // Render the activity type picker on top of the add/edit form
+    if app
+        .form_data
+        .as_ref()
+        .is_some_and(|form| form.activity_popup)
+    {
+        render_activity_popup(f, app, colors, size);
+    }
 
     // Render loading overlay if loading
     if app.loading {
-        render_loading_overlay(f, app, size);
+        render_loading_overlay(f, app, colors, size);
     }
 }
 /// Render the header
-fn render_header(f: &mut Frame, app: &App, area: Rect) {
+fn render_header(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     let title = format!(
-        " Claim Manager - {} ({}) - Year: {} ",
-        app.user.name, app.user.email, app.current_year
+        " {} - {} ({}) - Year: {} ",
+        app.config.display_name(),
+        app.user.name,
+        app.user.email,
+        app.current_year
     );
     let header = Paragraph::new(title)
         .style(
             Style::default()
-                .fg(Color::Cyan)
+                .fg(colors.header)
                 .add_modifier(Modifier::BOLD),
         )
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(Style::default().fg(colors.header)),
         );
 
     f.render_widget(header, area);
 }
 
 /// Render the main content area
-fn render_main_content(f: &mut Frame, app: &App, area: Rect) {
+fn render_main_content(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     // Check if we're in form mode
     let in_form_mode = matches!(app.mode, AppMode::AddEntry | AppMode::EditEntry);
 
@@ -96,13 +236,13 @@ fn render_main_content(f: &mut Frame, app: &App, area: Rect) {
             .split(main_chunks[0]);
 
         // Render week view (smaller)
-        week_view::render(f, app, content_chunks[0]);
+        week_view::render(f, app, colors, content_chunks[0]);
 
         // Render form editor
-        form_ui::render_form(f, app, content_chunks[1]);
+        form_ui::render_form(f, app, colors, content_chunks[1]);
 
         // Render context-aware panel (activity types or cache based on current field)
-        form_ui::render_context_panel(f, app, main_chunks[1]);
+        form_ui::render_context_panel(f, app, colors, main_chunks[1]);
     } else {
         // Normal mode layout - no side panel, use full width
         let content_chunks = Layout::default()
@@ -115,50 +255,76 @@ fn render_main_content(f: &mut Frame, app: &App, area: Rect) {
             .split(area);
 
         // Render week view
-        week_view::render(f, app, content_chunks[0]);
+        week_view::render(f, app, colors, content_chunks[0]);
 
         // Render entry details panel
-        entry_details::render(f, app, content_chunks[1]);
+        entry_details::render(f, app, colors, content_chunks[1]);
 
         // Render summary chart
-        summary_chart::render(f, app, content_chunks[2]);
+        summary_chart::render(f, app, colors, content_chunks[2]);
     }
 }
 
 /// Render the help screen
-fn render_help(f: &mut Frame, area: Rect) {
+fn render_help(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let vi_mode = app.config.vi_mode;
     let help_text = vec![
         Line::from(Span::styled(
             "Keyboard Shortcuts",
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(Color::Cyan),
+                .fg(colors.header),
         )),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Navigation:",
             Style::default().add_modifier(Modifier::BOLD),
         )]),
-        Line::from("  ←/→ or h/l    Navigate between weeks"),
-        Line::from("  ↑/↓ or j/k    Navigate between entries"),
-        Line::from("  1-5           Jump to specific day of week"),
+        Line::from(if vi_mode {
+            "  Tab or h/l    Navigate between weeks"
+        } else {
+            "  Tab/Shift+Tab Navigate between weeks"
+        }),
+        Line::from("  ←/→           Navigate between days"),
+        Line::from(if vi_mode {
+            "  ↑/↓ or j/k    Navigate between entries"
+        } else {
+            "  ↑/↓           Navigate between entries"
+        }),
+        Line::from("  1-5 (6-7)     Jump to specific day of week (weekends with show_weekends)"),
         Line::from("  Home          Jump to current week"),
+        Line::from("  g             Go to a date (YYYY-MM-DD) or ISO week (YYYY-WN)"),
+        Line::from("  c             Toggle daily hours / activity share chart"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Actions:",
             Style::default().add_modifier(Modifier::BOLD),
         )]),
+        Line::from("  q             Quick-log hours for the selected day"),
         Line::from("  a             Add new entry"),
         Line::from("  e             Edit selected entry"),
+        Line::from("  Ctrl+R        Rename selected entry's work item"),
+        Line::from("  Ctrl+F        Find and replace a customer or work item in the week"),
+        Line::from("  /             Search entries by customer, work item or comment"),
+        Line::from("  M             Month view with the hours of every working day"),
+        Line::from("  Space         Select or unselect the entry (D deletes the selection)"),
+        Line::from("  Ctrl+D        Duplicate this week's entries to next week"),
+        Line::from("  Ctrl+Z        Undo the last add, edit or delete"),
+        Line::from("  Ctrl+Y        Redo (also Ctrl+Shift+Z)"),
         Line::from("  d             Delete selected entry"),
         Line::from("  r             Refresh data from Monday.com"),
+        Line::from("  C             Manage the recent entries cache"),
         Line::from(""),
         Line::from(vec![Span::styled(
             "General:",
             Style::default().add_modifier(Modifier::BOLD),
         )]),
-        Line::from("  q             Quit application"),
-        Line::from("  ? or h        Show this help"),
+        Line::from("  Q or Ctrl+Q   Quit application"),
+        Line::from(if vi_mode {
+            "  ? or F1       Show this help"
+        } else {
+            "  ?, h or F1    Show this help"
+        }),
         Line::from("  Esc           Cancel current operation"),
         Line::from(""),
         Line::from(Span::styled(
@@ -171,21 +337,21 @@ fn render_help(f: &mut Frame, area: Rect) {
         Block::default()
             .borders(Borders::ALL)
             .title(" Help ")
-            .border_style(Style::default().fg(Color::Cyan)),
+            .border_style(Style::default().fg(colors.header)),
     );
 
     f.render_widget(paragraph, area);
 }
 
 /// Render the messages pane
-fn render_messages(f: &mut Frame, app: &App, area: Rect) {
+fn render_messages(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     let mut lines = Vec::new();
 
     // Show recent messages (last 3)
     let recent_messages: Vec<_> = app.messages.iter().rev().take(3).collect();
 
     for msg in recent_messages.iter().rev() {
-        let mut style = get_message_style(msg.message_type);
+        let mut style = colors.message_style(msg.message_type);
 
         // Add blinking effect for delete confirmation in DeleteEntry mode
         if app.mode == AppMode::DeleteEntry && msg.message_type == MessageType::Warning {
@@ -209,26 +375,69 @@ fn render_messages(f: &mut Frame, app: &App, area: Rect) {
         Block::default()
             .borders(Borders::ALL)
             .title(" Messages ")
-            .border_style(Style::default().fg(Color::Cyan)),
+            .border_style(Style::default().fg(colors.header)),
     );
 
     f.render_widget(paragraph, area);
 }
 
 /// Render the footer with keyboard shortcuts
-fn render_footer(f: &mut Frame, app: &App, area: Rect) {
+fn render_footer(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let activity_popup = app
+        .form_data
+        .as_ref()
+        .is_some_and(|form| form.activity_popup);
     let shortcuts = match app.mode {
+        AppMode::AddEntry | AppMode::EditEntry if activity_popup => {
+            "[↑↓] Select  [0-9] Pick by number  [Enter] Choose  [Esc] Close"
+        }
+        AppMode::Normal if !app.selected_entries.is_empty() => {
+            "[Space] Select/unselect  [D] Delete selected  [Esc] Clear selection  [←→] Days  [↑↓] Entries"
+        }
+        AppMode::Normal if app.config.vi_mode => {
+            "[Tab/l] Next week  [Shift+Tab/h] Prev week  [←→] Days  [↑↓/jk] Entries  [Enter/e] Edit  [a]dd  [d]elete  [u]pdate  [p]rint  [q]uick-log  [g]oto  [c]hart  [?/F1] help  [Q]uit"
+        }
         AppMode::Normal => {
-            "[Tab] Next week  [Shift+Tab] Prev week  [←→] Days  [↑↓] Entries  [Enter/e] Edit  [a]dd  [d]elete  [u]pdate  [p]rint  [?] help  [q]uit"
+            "[Tab] Next week  [Shift+Tab] Prev week  [←→] Days  [↑↓] Entries  [Enter/e] Edit  [a]dd  [d]elete  [u]pdate  [p]rint  [q]uick-log  [g]oto  [c]hart  [?] help  [Q]uit"
         }
         AppMode::AddEntry => "[Esc] Cancel add",
         AppMode::EditEntry => "[Esc] Cancel edit",
-        AppMode::DeleteEntry => "[y] Confirm  [n/Esc] Cancel",
+        AppMode::DeleteEntry | AppMode::BatchDelete | AppMode::DuplicateWeek => {
+            "[y] Confirm  [n/Esc] Cancel"
+        }
+        AppMode::Undoing => "Please wait...",
+        AppMode::Search => "[Type] Filter  [↑↓] Select  [Enter] Jump to entry  [Esc] Cancel",
+        AppMode::MonthView => "[←→] Days  [↑↓] Weeks  [Tab/Shift+Tab] Next/prev month  [Enter/a] Add entry on day  [Esc/q/M] Return to normal view",
         AppMode::Help => "Press any key to return",
+        AppMode::QuickLog => "[0-9.] Hours  [Backspace] Delete  [Enter] Save  [Esc] Cancel",
+        AppMode::Rename => "[Type] Work item  [Backspace] Delete  [Enter] Save  [Esc] Cancel",
+        AppMode::BulkEdit if app.bulk_edit.preview.is_some() => "[Enter/y] Apply  [Esc/n] Back",
+        AppMode::BulkEdit => "[Type] Text  [Tab] Find/Replace  [↑↓] Field  [Enter] Preview  [Esc] Cancel",
+        AppMode::Goto => "[YYYY-MM-DD / YYYY-WN] Target  [Backspace] Delete  [Enter] Go  [Esc] Cancel",
+        AppMode::CacheManage => "[↑↓/PgUp/PgDn] Select  [Del] Mark/unmark  [a] Mark all  [s] Save  [Esc/q] Return to normal view",
         AppMode::Report => "[↑↓] Select row  [c] Copy row  [m] Mark/unmark  [C] Copy marked  [Tab] Next week  [Shift+Tab] Prev week  [Esc/p/q] Return to normal view",
     };
 
-    let footer = Paragraph::new(shortcuts)
+    // The refresh indicator comes first so it stays visible on narrow terminals
+    let mut spans = Vec::new();
+    if let Some(last_refresh) = app.last_refresh {
+        let elapsed = last_refresh.elapsed();
+        let minutes = elapsed.as_secs() / 60;
+        let color = if minutes >= app.config.stale_data_error_minutes {
+            colors.message_error
+        } else if minutes >= app.config.stale_data_warning_minutes {
+            colors.message_warning
+        } else {
+            Color::DarkGray
+        };
+        spans.push(Span::styled(
+            format!("(refreshed {} ago)  ", format_elapsed(elapsed)),
+            Style::default().fg(color),
+        ));
+    }
+    spans.push(Span::raw(shortcuts));
+
+    let footer = Paragraph::new(Line::from(spans))
         .style(Style::default().fg(Color::Gray))
         .block(
             Block::default()
@@ -239,9 +448,64 @@ fn render_footer(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(footer, area);
 }
 
+/// Render the cache management list: recently used entries in green,
+/// entries unused for over a month in gray, marked entries struck through
+fn render_cache_manage(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let entries = app.cache_manage_entries();
+    let today = app.config.today();
+
+    let items: Vec<ListItem> = entries
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| {
+            let age = Some((today - entry.last_used.date_naive()).num_days());
+            let mut style = match age {
+                Some(days) if days <= 7 => Style::default().fg(Color::Green),
+                Some(days) if days > 30 => Style::default().fg(Color::DarkGray),
+                _ => Style::default(),
+            };
+            let marked = app.cache_manage_marked.contains(&idx);
+            if marked {
+                style = style.fg(Color::Red).add_modifier(Modifier::CROSSED_OUT);
+            }
+
+            ListItem::new(Line::from(Span::styled(
+                format!(
+                    "{} {:<24} {:<20} {:>5}x  {}",
+                    if marked { "✗" } else { " " },
+                    entry.customer,
+                    entry.work_item,
+                    entry.use_count,
+                    entry.last_used.format("%Y-%m-%d")
+                ),
+                style,
+            )))
+        })
+        .collect();
+
+    let title = format!(
+        " Entry Cache - {} entries, {} marked for removal ",
+        entries.len(),
+        app.cache_manage_marked.len()
+    );
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.header)),
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("▶ ");
+
+    // Scrolling follows the selection, so a copy of the state is enough
+    let mut state = app.cache_manage_state.clone();
+    f.render_stateful_widget(list, area, &mut state);
+}
+
 /// Render the cache panel showing recent entries
 #[allow(dead_code)]
-fn render_cache_panel(f: &mut Frame, app: &App, area: Rect) {
+fn render_cache_panel(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     let entries = app.cache.get_unique_entries(app.user.id);
 
     // Take the most recent 9 billable entries (already filtered during cache refresh)
@@ -252,7 +516,7 @@ fn render_cache_panel(f: &mut Frame, app: &App, area: Rect) {
             "Recent Entries",
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(Color::Cyan),
+                .fg(colors.header),
         )),
         Line::from(""),
     ];
@@ -270,19 +534,23 @@ fn render_cache_panel(f: &mut Frame, app: &App, area: Rect) {
     } else {
         for entry in recent_entries {
             lines.push(Line::from(vec![Span::styled(
-                format!("• {}", entry.customer),
+                format!(
+                    "• {}",
+                    app.config
+                        .cache_entry_label(&entry.customer, &entry.work_item)
+                ),
                 Style::default().fg(Color::White),
             )]));
-            lines.push(Line::from(vec![Span::styled(
-                format!("  {}", entry.work_item),
-                Style::default().fg(Color::Gray),
-            )]));
         }
 
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
-            format!("Total: {} entries", entries.len()),
-            Style::default().fg(Color::Cyan),
+            format!(
+                "1-{} of {} recent entries",
+                entries.len().min(9),
+                entries.len()
+            ),
+            Style::default().fg(colors.header),
         )));
     }
 
@@ -291,20 +559,380 @@ fn render_cache_panel(f: &mut Frame, app: &App, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .title(" Quick Select ")
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(Style::default().fg(colors.header)),
         )
         .wrap(ratatui::widgets::Wrap { trim: true });
 
     f.render_widget(paragraph, area);
 }
 
+/// Render the quick-log popup
+fn render_quick_log_popup(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let form = app.build_quick_log_form();
+
+    let popup_width = 50.min(area.width);
+    let popup_height = 9.min(area.height);
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let label_style = Style::default().fg(Color::Gray);
+    let value_style = Style::default().fg(Color::White);
+    let entry = if form.customer.is_empty() && form.work_item.is_empty() {
+        "-".to_string()
+    } else {
+        format!("{} / {}", form.customer, form.work_item)
+    };
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("Date:      ", label_style),
+            Span::styled(form.date.clone(), value_style),
+        ]),
+        Line::from(vec![
+            Span::styled("Activity:  ", label_style),
+            Span::styled(form.activity_type.clone(), value_style),
+        ]),
+        Line::from(vec![
+            Span::styled("Entry:     ", label_style),
+            Span::styled(entry, value_style),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Hours:     ", label_style),
+            Span::styled(
+                format!("{}_", app.quick_log_hours),
+                Style::default()
+                    .fg(colors.message_warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Enter to save, Esc to cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Quick Log ")
+            .border_style(Style::default().fg(colors.message_success))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, popup_area);
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Render the go-to popup
+fn render_goto_popup(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let popup_width = 44.min(area.width);
+    let popup_height = 6.min(area.height);
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("Go to:  ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}_", app.goto_input),
+                Style::default()
+                    .fg(colors.message_warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "e.g. 2025-09-15 or 2025-W38",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Go To ")
+            .border_style(Style::default().fg(colors.message_success))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, popup_area);
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Most search results shown at once
+const SEARCH_ROWS: usize = 15;
+
+/// Render the search popup with the matching entries
+fn render_search_popup(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let results = app.search_results();
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Search:  ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}_", app.search_input),
+                Style::default()
+                    .fg(colors.message_warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+    ];
+    if results.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No matching entries",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    // Scroll so the highlighted result stays in view
+    let first = app.search_selected.saturating_sub(SEARCH_ROWS - 1);
+    let shown = results.iter().enumerate().skip(first).take(SEARCH_ROWS);
+    lines.extend(shown.map(|(i, e)| {
+        let mut style = Style::default().fg(Color::White);
+        if i == app.search_selected {
+            style = style.bg(colors.entry_selected).add_modifier(Modifier::BOLD);
+        }
+        Line::from(vec![
+            Span::styled(
+                format!("{}  ", e.date.format("%a %Y-%m-%d")),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(
+                format!(
+                    "{}h {} / {}{}",
+                    e.hours,
+                    e.customer,
+                    e.work_item,
+                    e.comment
+                        .as_deref()
+                        .map(|c| format!(" - {}", c))
+                        .unwrap_or_default()
+                ),
+                style,
+            ),
+        ])
+    }));
+
+    let popup_width = 70.min(area.width);
+    let popup_height = (lines.len() as u16 + 2).min(area.height);
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Search Entries ")
+            .border_style(Style::default().fg(colors.message_success))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, popup_area);
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Render the rename popup
+fn render_rename_popup(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let popup_width = 50.min(area.width);
+    let popup_height = 5.min(area.height);
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("Work item:  ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}_", app.rename_input),
+                Style::default()
+                    .fg(colors.message_warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Enter to save, Esc to cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Rename Work Item ")
+            .border_style(Style::default().fg(colors.message_success))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, popup_area);
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Render the find and replace popup, or the entries it will change
+fn render_bulk_edit_popup(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let bulk_edit = &app.bulk_edit;
+    let active = Style::default()
+        .fg(colors.message_warning)
+        .add_modifier(Modifier::BOLD);
+    let inactive = Style::default().fg(Color::White);
+
+    let lines = match &bulk_edit.preview {
+        Some(matches) => {
+            let mut lines = vec![
+                Line::from(Span::styled(
+                    format!(
+                        "{} entries will change their {}:",
+                        matches.len(),
+                        bulk_edit.field.label()
+                    ),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+                Line::from(""),
+            ];
+            lines.extend(matches.iter().map(|m| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{}  ", m.date.format("%a %m-%d")),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(m.old.clone(), Style::default().fg(colors.message_error)),
+                    Span::raw(" → "),
+                    Span::styled(m.new.clone(), Style::default().fg(colors.message_success)),
+                ])
+            }));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Enter/y to apply, Esc/n to go back",
+                Style::default().fg(Color::DarkGray),
+            )));
+            lines
+        }
+        None => vec![
+            Line::from(vec![
+                Span::styled("Field:    ", Style::default().fg(Color::Gray)),
+                Span::styled(format!("◀ {} ▶", bulk_edit.field.label()), inactive),
+            ]),
+            Line::from(vec![
+                Span::styled("Find:     ", Style::default().fg(Color::Gray)),
+                if bulk_edit.editing_replace {
+                    Span::styled(bulk_edit.find.clone(), inactive)
+                } else {
+                    Span::styled(format!("{}_", bulk_edit.find), active)
+                },
+            ]),
+            Line::from(vec![
+                Span::styled("Replace:  ", Style::default().fg(Color::Gray)),
+                if bulk_edit.editing_replace {
+                    Span::styled(format!("{}_", bulk_edit.replace), active)
+                } else {
+                    Span::styled(bulk_edit.replace.clone(), inactive)
+                },
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Tab switches Find/Replace, ↑↓ the field, Enter to preview",
+                Style::default().fg(Color::DarkGray),
+            )),
+        ],
+    };
+
+    let popup_width = 70.min(area.width);
+    let popup_height = (lines.len() as u16 + 2).min(area.height);
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Find and Replace in Week ")
+            .border_style(Style::default().fg(colors.message_success))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, popup_area);
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Confirmation listing the entries selected for a batch delete
+fn render_batch_delete_popup(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let entries = app.selected_claims();
+    let hours: f64 = entries.iter().map(|e| e.hours).sum();
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("Delete {} entries ({}h)?", entries.len(), hours),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    lines.extend(entries.iter().map(|e| {
+        Line::from(vec![
+            Span::styled(
+                format!("{}  ", e.date.format("%a %m-%d")),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(
+                format!("{:<12} ", e.id),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::raw(format!(
+                "{}h {} {} {}",
+                e.hours, e.activity_type, e.customer, e.work_item
+            )),
+        ])
+    }));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "y to delete, any other key to cancel",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let popup_width = 70.min(area.width);
+    let popup_height = (lines.len() as u16 + 2).min(area.height);
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Delete Selected Entries ")
+            .border_style(Style::default().fg(colors.message_error))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, popup_area);
+    f.render_widget(paragraph, popup_area);
+}
+
 /// Render loading overlay with spinner
-fn render_loading_overlay(f: &mut Frame, app: &App, area: Rect) {
+fn render_loading_overlay(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     use std::time::{SystemTime, UNIX_EPOCH};
 
     // Create a centered popup
-    let popup_width = 50;
-    let popup_height = 5;
+    let popup_width = 50.min(area.width);
+    let popup_height = 5.min(area.height);
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
     let popup_y = (area.height.saturating_sub(popup_height)) / 2;
 
@@ -330,7 +958,7 @@ fn render_loading_overlay(f: &mut Frame, app: &App, area: Rect) {
             Span::styled(
                 format!("{} ", spinner),
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(colors.header)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(&app.loading_message, Style::default().fg(Color::White)),
@@ -343,7 +971,7 @@ fn render_loading_overlay(f: &mut Frame, app: &App, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .title(" Loading ")
-                .border_style(Style::default().fg(Color::Cyan))
+                .border_style(Style::default().fg(colors.header))
                 .style(Style::default().bg(Color::Black)),
         )
         .alignment(ratatui::layout::Alignment::Center);
@@ -353,6 +981,60 @@ fn render_loading_overlay(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(paragraph, popup_area);
 }
 
+/// Render the activity type selection popup over the add/edit form
+fn render_activity_popup(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
+    let Some(form) = &app.form_data else {
+        return;
+    };
+    let types = super::activity_types::get_all_activity_types();
+
+    // Create a centered popup, one line per activity type plus borders
+    let popup_width = 40.min(area.width);
+    let popup_height = (types.len() as u16 + 2).min(area.height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect {
+        x: popup_x,
+        y: popup_y,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    let lines: Vec<Line> = types
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            let selected = i == form.selected_activity_index;
+            let marker = if selected { "▶ " } else { "  " };
+            let style = if selected {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(colors.header)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Line::from(Span::styled(
+                format!("{}{:>2} - {}", marker, t.number, t.display_name),
+                style,
+            ))
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Activity Type ")
+            .border_style(Style::default().fg(colors.header))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    // Clear the area first
+    f.render_widget(ratatui::widgets::Clear, popup_area);
+    f.render_widget(paragraph, popup_area);
+}
+
 // Made with Bob
 
 /// Format hours without .00 unless the decimal is non-zero
@@ -367,32 +1049,24 @@ fn format_hours(hours: f64) -> String {
 }
 
 /// Render the report view
-fn render_report(f: &mut Frame, app: &App, area: Rect) {
+fn render_report(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     use ratatui::widgets::Table;
-    use std::collections::HashMap;
+
+    // One column per day, in display order (Sunday first with
+    // `week_starts_on` set to Sunday)
+    let dates = app.report_dates();
 
     // Group entries by activity type and customer/work_item combination
     // Key: (activity_value, customer, work_item)
-    let mut report_data: HashMap<(i32, String, String), [f64; 5]> = HashMap::new();
-
-    for entry in &app.claims {
-        let key = (
-            entry.activity_value,
-            entry.customer.clone(),
-            entry.work_item.clone(),
-        );
-        let day_index = entry.date.weekday().num_days_from_monday() as usize;
-
-        if day_index < 5 {
-            report_data.entry(key).or_insert([0.0; 5])[day_index] += entry.hours;
-        }
-    }
+    let report_data = app.report_hours(&dates);
 
     // Separate billable (activity_value == 1) from non-billable entries
     let mut billable_data: Vec<_> = report_data
         .iter()
         .filter(|((activity_value, _, _), _)| *activity_value == 1)
-        .map(|((_, customer, work_item), hours)| ((customer.clone(), work_item.clone()), *hours))
+        .map(|((_, customer, work_item), hours)| {
+            ((customer.clone(), work_item.clone()), hours.clone())
+        })
         .collect();
 
     let mut non_billable_data: Vec<_> = report_data
@@ -401,7 +1075,7 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
         .map(|((activity_value, customer, work_item), hours)| {
             (
                 (*activity_value, customer.clone(), work_item.clone()),
-                *hours,
+                hours.clone(),
             )
         })
         .collect();
@@ -422,10 +1096,10 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
     });
 
     // Calculate column totals (sum of each day)
-    let mut day_totals = [0.0; 5];
+    let mut day_totals = vec![0.0; dates.len()];
     for hours in report_data.values() {
-        for i in 0..5 {
-            day_totals[i] += hours[i];
+        for (total, day_hours) in day_totals.iter_mut().zip(hours) {
+            *total += day_hours;
         }
     }
 
@@ -433,33 +1107,22 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
     let mut rows = Vec::new();
     let mut current_row_index = 0;
 
-    // Calculate dates for the week
-    let monday = app.current_week_start;
-    let dates = [
-        monday,
-        monday + chrono::Duration::days(1),
-        monday + chrono::Duration::days(2),
-        monday + chrono::Duration::days(3),
-        monday + chrono::Duration::days(4),
-    ];
-
     // Header row with dates above weekday names (dd/MMM format)
+    let mut header = vec![Cell::from("Work Item / Customer")];
+    header.extend(
+        dates
+            .iter()
+            .map(|date| Cell::from(date.format("%d/%b\n%A").to_string())),
+    );
+    header.push(Cell::from("Total"));
     rows.push(
-        Row::new(vec![
-            Cell::from("Work Item / Customer"),
-            Cell::from(format!("{}\nMonday", dates[0].format("%d/%b"))),
-            Cell::from(format!("{}\nTuesday", dates[1].format("%d/%b"))),
-            Cell::from(format!("{}\nWednesday", dates[2].format("%d/%b"))),
-            Cell::from(format!("{}\nThursday", dates[3].format("%d/%b"))),
-            Cell::from(format!("{}\nFriday", dates[4].format("%d/%b"))),
-            Cell::from("Total"),
-        ])
-        .style(
-            Style::default()
-                .add_modifier(Modifier::BOLD)
-                .fg(Color::Cyan),
-        )
-        .height(2), // Make header 2 rows tall to show both date and weekday
+        Row::new(header)
+            .style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(colors.header),
+            )
+            .height(2), // Make header 2 rows tall to show both date and weekday
     );
 
     // Billable entries
@@ -472,8 +1135,6 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
             customer
         };
 
-        let row_total: f64 = hours.iter().sum();
-
         // Determine row style based on selection
         let row_style = if app.selected_report_row == Some(current_row_index) {
             Style::default().bg(Color::DarkGray).fg(Color::White)
@@ -486,7 +1147,7 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
                 if app
                     .marked_report_items
                     .iter()
-                    .any(|m| item_key.len() > 0 && label.contains(m))
+                    .any(|m| !item_key.is_empty() && label.contains(m))
                 {
                     format!("* {}", label)
                 } else {
@@ -496,38 +1157,7 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
             Err(_) => label.clone(),
         };
 
-        rows.push(
-            Row::new(vec![
-                Cell::from(display_label),
-                Cell::from(if hours[0] == 0.0 {
-                    String::new()
-                } else {
-                    format_hours(hours[0])
-                }),
-                Cell::from(if hours[1] == 0.0 {
-                    String::new()
-                } else {
-                    format_hours(hours[1])
-                }),
-                Cell::from(if hours[2] == 0.0 {
-                    String::new()
-                } else {
-                    format_hours(hours[2])
-                }),
-                Cell::from(if hours[3] == 0.0 {
-                    String::new()
-                } else {
-                    format_hours(hours[3])
-                }),
-                Cell::from(if hours[4] == 0.0 {
-                    String::new()
-                } else {
-                    format_hours(hours[4])
-                }),
-                Cell::from(format_hours(row_total)),
-            ])
-            .style(row_style),
-        );
+        rows.push(Row::new(report_cells(display_label, &hours)).style(row_style));
 
         current_row_index += 1;
     }
@@ -535,23 +1165,12 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
     // Non-billable entries (if any)
     if !non_billable_data.is_empty() {
         // Add separator row
-        rows.push(Row::new(vec![
-            Cell::from(""),
-            Cell::from(""),
-            Cell::from(""),
-            Cell::from(""),
-            Cell::from(""),
-            Cell::from(""),
-            Cell::from(""),
-        ]));
+        rows.push(Row::new(vec![Cell::from(""); dates.len() + 2]));
         current_row_index += 1;
 
         for ((activity_value, customer, work_item), hours) in non_billable_data {
-            // Get activity type name
-            let activity_name = crate::interactive::activity_types::get_activity_type_by_number(
-                activity_value as u8,
-            )
-            .unwrap_or("unknown");
+            // Get activity type display label
+            let activity_name = app.config.activity_label(activity_value as u8);
 
             let label = if !work_item.is_empty() && !customer.is_empty() {
                 format!("{} - {} ({})", work_item, customer, activity_name)
@@ -563,8 +1182,6 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
                 format!("({})", activity_name)
             };
 
-            let row_total: f64 = hours.iter().sum();
-
             // Determine row style based on selection
             let row_style = if app.selected_report_row == Some(current_row_index) {
                 Style::default().bg(Color::DarkGray).fg(Color::White)
@@ -577,7 +1194,7 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
                     if app
                         .marked_report_items
                         .iter()
-                        .any(|m| item_key.len() > 0 && label.contains(m))
+                        .any(|m| !item_key.is_empty() && label.contains(m))
                     {
                         format!("* {}", label)
                     } else {
@@ -587,55 +1204,22 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
                 Err(_) => label.clone(),
             };
 
-            rows.push(
-                Row::new(vec![
-                    Cell::from(display_label),
-                    Cell::from(if hours[0] == 0.0 {
-                        String::new()
-                    } else {
-                        format_hours(hours[0])
-                    }),
-                    Cell::from(if hours[1] == 0.0 {
-                        String::new()
-                    } else {
-                        format_hours(hours[1])
-                    }),
-                    Cell::from(if hours[2] == 0.0 {
-                        String::new()
-                    } else {
-                        format_hours(hours[2])
-                    }),
-                    Cell::from(if hours[3] == 0.0 {
-                        String::new()
-                    } else {
-                        format_hours(hours[3])
-                    }),
-                    Cell::from(if hours[4] == 0.0 {
-                        String::new()
-                    } else {
-                        format_hours(hours[4])
-                    }),
-                    Cell::from(format_hours(row_total)),
-                ])
-                .style(row_style),
-            );
+            rows.push(Row::new(report_cells(display_label, &hours)).style(row_style));
             current_row_index += 1;
         }
     }
 
     // Total row
     let grand_total: f64 = day_totals.iter().sum();
+    let mut total_cells = vec![Cell::from("Total")];
+    total_cells.extend(
+        day_totals
+            .iter()
+            .map(|hours| Cell::from(format_hours(*hours))),
+    );
+    total_cells.push(Cell::from(format_hours(grand_total)));
     rows.push(
-        Row::new(vec![
-            Cell::from("Total"),
-            Cell::from(format_hours(day_totals[0])),
-            Cell::from(format_hours(day_totals[1])),
-            Cell::from(format_hours(day_totals[2])),
-            Cell::from(format_hours(day_totals[3])),
-            Cell::from(format_hours(day_totals[4])),
-            Cell::from(format_hours(grand_total)),
-        ])
-        .style(
+        Row::new(total_cells).style(
             Style::default()
                 .add_modifier(Modifier::BOLD)
                 .fg(Color::Yellow),
@@ -649,20 +1233,26 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
         }
     }
 
-    let widths = vec![
-        Constraint::Percentage(35),
-        Constraint::Percentage(10),
-        Constraint::Percentage(10),
-        Constraint::Percentage(10),
-        Constraint::Percentage(10),
-        Constraint::Percentage(10),
-        Constraint::Percentage(15),
-    ];
+    // The day columns share half of the width
+    let day_width = 50 / dates.len().max(1) as u16;
+    let mut widths = vec![Constraint::Percentage(35)];
+    widths.extend(dates.iter().map(|_| Constraint::Percentage(day_width)));
+    widths.push(Constraint::Percentage(15));
 
+    let first_day = dates
+        .iter()
+        .min()
+        .copied()
+        .unwrap_or(app.current_week_start);
+    let last_day = dates
+        .iter()
+        .max()
+        .copied()
+        .unwrap_or(app.current_week_start);
     let title = format!(
         " Weekly Report - {} to {} ",
-        app.current_week_start.format("%b %d"),
-        (app.current_week_start + chrono::Duration::days(4)).format("%b %d, %Y")
+        first_day.format("%b %d"),
+        last_day.format("%b %d, %Y")
     );
 
     let table = Table::new(rows, widths)
@@ -670,9 +1260,24 @@ fn render_report(f: &mut Frame, app: &App, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .title(title)
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(Style::default().fg(colors.header)),
         )
         .column_spacing(1);
 
     f.render_widget(table, area);
 }
+
+/// Cells of a report row: the label, the hours of each day (blank when
+/// none) and the row total
+fn report_cells(label: String, hours: &[f64]) -> Vec<Cell<'static>> {
+    let mut cells = vec![Cell::from(label)];
+    cells.extend(hours.iter().map(|hours| {
+        Cell::from(if *hours == 0.0 {
+            String::new()
+        } else {
+            format_hours(*hours)
+        })
+    }));
+    cells.push(Cell::from(format_hours(hours.iter().sum())));
+    cells
+}
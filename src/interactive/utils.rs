@@ -1,7 +1,6 @@
 //! Utility functions for the interactive UI
 
-use super::messages::MessageType;
-use ratatui::style::{Color, Style};
+use ratatui::style::Color;
 
 /// Get color for activity type
 pub fn get_activity_color(activity_type: &str) -> Color {
@@ -18,16 +17,6 @@ pub fn get_activity_color(activity_type: &str) -> Color {
     }
 }
 
-/// Get style for message type
-pub fn get_message_style(message_type: MessageType) -> Style {
-    match message_type {
-        MessageType::Info => Style::default().fg(Color::Cyan),
-        MessageType::Success => Style::default().fg(Color::Green),
-        MessageType::Warning => Style::default().fg(Color::Yellow),
-        MessageType::Error => Style::default().fg(Color::Red),
-    }
-}
-
 /// Truncate string to fit width
 pub fn truncate_str(s: &str, max_width: usize) -> String {
     if s.len() <= max_width {
@@ -44,6 +33,18 @@ pub fn format_hours(hours: f64) -> String {
     format!("{:.1}h", hours)
 }
 
+/// Format an elapsed time as "Ns", "Nm" or "Nh" depending on its magnitude
+pub fn format_elapsed(elapsed: std::time::Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h", secs / 3600)
+    }
+}
+
 /// Get weekday name
 pub fn get_weekday_name(weekday: chrono::Weekday) -> &'static str {
     match weekday {
@@ -10,17 +10,18 @@ use ratatui::{
 };
 
 use super::app::{App, ClaimEntry};
+use super::ui::ColorScheme;
 use super::utils::{format_hours, get_activity_color, get_weekday_name, truncate_str};
 
 /// Render the week view
-pub fn render(f: &mut Frame, app: &App, area: Rect) {
+pub fn render(f: &mut Frame, app: &App, colors: &ColorScheme, area: Rect) {
     let current_week_start = app.current_week_start;
 
     // Create header with weekday names and dates
     let mut header_cells = vec![Cell::from("")]; // Empty cell for row labels
     let mut dates = Vec::new();
 
-    for i in 0..5 {
+    for i in 0..app.days_in_week() {
         let date = current_week_start + chrono::Duration::days(i);
         dates.push(date);
 
@@ -28,14 +29,14 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         let day = date.day();
 
         let is_selected = app.selected_day == Some(date);
-        let is_today = date == chrono::Local::now().naive_local().date();
+        let is_today = date == app.config.today();
 
         let mut style = Style::default();
         if is_today {
-            style = style.fg(Color::Cyan).add_modifier(Modifier::BOLD);
+            style = style.fg(colors.header).add_modifier(Modifier::BOLD);
         }
         if is_selected {
-            style = style.bg(Color::DarkGray);
+            style = style.bg(colors.day_selected);
         }
 
         header_cells.push(Cell::from(format!("{} {}", weekday, day)).style(style));
@@ -68,7 +69,19 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
                 let is_selected =
                     app.selected_day == Some(*date) && app.selected_entry_index == Some(row_idx);
 
-                let cell_content = format_entry_cell(entry, is_selected);
+                let marked = app.selected_entries.contains(&entry.id);
+                let background = if is_selected {
+                    Some(colors.entry_selected)
+                } else {
+                    marked.then_some(colors.entry_marked)
+                };
+
+                let cell_content = format_entry_cell(
+                    entry,
+                    &app.config.activity_label(entry.activity_value as u8),
+                    background,
+                    marked,
+                );
                 cells.push(cell_content);
                 row_total += entry.hours;
             } else {
@@ -110,19 +123,23 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
             }
         }
 
-        // For blank days (no entries), calculate as 8 hours for the total with blanks
-        if entries.is_empty() {
+        // For blank working days (no entries), calculate as 8 hours for the
+        // total with blanks; blank weekend days count as zero
+        let is_weekend = date.weekday().num_days_from_monday() >= 5;
+        if entries.is_empty() && !is_weekend {
             week_total_with_blanks += 8.0;
         } else {
             week_total_with_blanks += daily_total;
         }
 
         let style = if daily_total >= 8.0 {
-            Style::default().fg(Color::Green)
+            Style::default().fg(colors.day_full)
         } else if daily_total > 0.0 {
             Style::default().fg(Color::Yellow)
+        } else if is_weekend {
+            Style::default().fg(Color::DarkGray)
         } else {
-            Style::default().fg(Color::Red)
+            Style::default().fg(colors.day_empty)
         };
 
         total_cells.push(Cell::from(format_hours(daily_total)).style(style));
@@ -133,7 +150,7 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
     } else {
         Style::default()
-            .fg(Color::Cyan)
+            .fg(colors.header)
             .add_modifier(Modifier::BOLD)
     };
 
@@ -152,20 +169,22 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     );
 
     // Create the table
-    let widths = vec![
-        Constraint::Length(8),      // Row label
-        Constraint::Percentage(16), // Monday
-        Constraint::Percentage(16), // Tuesday
-        Constraint::Percentage(16), // Wednesday
-        Constraint::Percentage(16), // Thursday
-        Constraint::Percentage(16), // Friday
-        Constraint::Length(10),     // Total
-    ];
-
+    // One column per shown day between the row label and the total
+    let day_width = if app.config.show_weekends { 11 } else { 16 };
+    let mut widths = vec![Constraint::Length(8)]; // Row label
+    widths.extend(dates.iter().map(|_| Constraint::Percentage(day_width)));
+    widths.push(Constraint::Length(10)); // Total
+
+    let lock = if app.config.is_week_locked(current_week_start) {
+        "🔒 "
+    } else {
+        ""
+    };
     let title = format!(
-        " Week of {} - {} ",
+        " {}Week of {} - {} ",
+        lock,
         current_week_start.format("%b %d"),
-        (current_week_start + chrono::Duration::days(4)).format("%b %d, %Y")
+        (current_week_start + chrono::Duration::days(app.days_in_week() - 1)).format("%b %d, %Y")
     );
 
     let table = Table::new(rows, widths)
@@ -174,16 +193,26 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .title(title)
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(Style::default().fg(colors.header)),
         )
         .column_spacing(1);
 
     f.render_widget(table, area);
 }
 
-/// Format an entry for display in a cell
-fn format_entry_cell(entry: &ClaimEntry, is_selected: bool) -> Cell<'_> {
-    let activity_type = truncate_str(&entry.activity_type, 12);
+/// Format an entry for display in a cell, showing its activity as `activity_label`
+/// on the `selected` background, with a check mark when it is `marked` for a
+/// batch delete
+fn format_entry_cell<'a>(
+    entry: &'a ClaimEntry,
+    activity_label: &str,
+    selected: Option<Color>,
+    marked: bool,
+) -> Cell<'a> {
+    let mut activity_type = truncate_str(activity_label, 12);
+    if marked {
+        activity_type = format!("✓ {}", activity_type);
+    }
     let customer = truncate_str(&entry.customer, 12);
     let work_item = truncate_str(&entry.work_item, 12);
     let hours = format_hours(entry.hours);
@@ -218,8 +247,8 @@ fn format_entry_cell(entry: &ClaimEntry, is_selected: bool) -> Cell<'_> {
     )));
 
     let mut style = Style::default();
-    if is_selected {
-        style = style.bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+    if let Some(background) = selected {
+        style = style.bg(background).add_modifier(Modifier::BOLD);
     }
 
     Cell::from(lines).style(style)
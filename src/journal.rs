@@ -0,0 +1,202 @@
+//! `claim journal`: free-form daily notes kept next to the config file and
+//! never sent to Monday.com
+
+use crate::config::Config;
+use crate::monday::{is_user_item, Item, MondayClient, MondayUser};
+use crate::query::{extract_column_value, extract_item_date, extract_status_label};
+use crate::utils::{self, get_year_group_id};
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate};
+use directories::ProjectDirs;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Directory holding one `YYYY-MM-DD.md` file per journal day
+fn journal_dir() -> Result<PathBuf> {
+    ProjectDirs::from("com", "yourname", "claim")
+        .map(|proj_dirs| proj_dirs.config_dir().join("journal"))
+        .ok_or_else(|| anyhow!("Could not determine config directory"))
+}
+
+fn journal_path(dir: &Path, date: NaiveDate) -> PathBuf {
+    dir.join(format!("{}.md", date.format("%Y-%m-%d")))
+}
+
+/// Handles `claim journal [--date DATE] [--edit]`
+pub async fn handle_journal(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    date: Option<String>,
+    edit: bool,
+    verbose: bool,
+) -> Result<()> {
+    let date = match date {
+        Some(date) => utils::parse_date_or_week(&date, config.today())?,
+        None => config.today(),
+    };
+    let date_str = date.format("%Y-%m-%d").to_string();
+
+    let board_id = config.board_id.as_str();
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let group_id = get_year_group_id(&board, &date.year().to_string());
+    let items: Vec<Item> = client
+        .query_items_with_filters(
+            board_id,
+            &group_id,
+            user.id,
+            std::slice::from_ref(&date_str),
+            500,
+            verbose,
+        )
+        .await?
+        .into_iter()
+        .filter(|item| is_user_item(item, user.id))
+        .filter(|item| extract_item_date(item).as_deref() == Some(date_str.as_str()))
+        .collect();
+    let entries: Vec<String> = items.iter().map(|item| entry_line(item, config)).collect();
+
+    println!("\n=== JOURNAL {} ({}) ===", date_str, date.format("%A"));
+    if entries.is_empty() {
+        println!("No entries logged on this day");
+    }
+    for line in &entries {
+        println!("{}", line);
+    }
+
+    let dir = journal_dir()?;
+    let path = journal_path(&dir, date);
+
+    if edit {
+        if !path.exists() {
+            fs::create_dir_all(&dir)?;
+            fs::write(&path, journal_template(date, &entries))?;
+        }
+        open_editor(&path)?;
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(notes) => {
+            println!("\n--- Notes ({}) ---", path.display());
+            print!("{}", notes);
+        }
+        Err(_) => println!(
+            "\nNo journal notes for {}. Add some with `claim journal --date {} --edit`.",
+            date_str, date_str
+        ),
+    }
+    Ok(())
+}
+
+/// Handles `claim journal --list [--week DATE]`
+pub fn handle_list(config: &Config, week: Option<String>) -> Result<()> {
+    let date = match week {
+        Some(week) => utils::parse_date_or_week(&week, config.today())?,
+        None => config.today(),
+    };
+    let first_day = utils::get_week_start(date, config.week_starts_on);
+    let dir = journal_dir()?;
+
+    println!(
+        "\n=== JOURNAL for the week of {} ===",
+        first_day.format("%Y-%m-%d")
+    );
+    for (day, has_notes) in week_journal_days(&dir, first_day) {
+        println!(
+            "{} {}  {}",
+            day.format("%a"),
+            day.format("%Y-%m-%d"),
+            if has_notes { "📝" } else { "-" }
+        );
+    }
+    Ok(())
+}
+
+/// Each day of the week starting at `first_day` and whether it has notes
+fn week_journal_days(dir: &Path, first_day: NaiveDate) -> Vec<(NaiveDate, bool)> {
+    (0..7)
+        .map(|offset| first_day + chrono::Duration::days(offset))
+        .map(|day| (day, journal_path(dir, day).exists()))
+        .collect()
+}
+
+/// One line per Monday.com entry, e.g. "- billable ACME / PROJ-123 8h"
+fn entry_line(item: &Item, config: &Config) -> String {
+    let customer = extract_column_value(item, "text__1");
+    let work_item = extract_column_value(item, "text8__1");
+    let mut line = format!("- {}", extract_status_label(item, config));
+    if !customer.is_empty() || !work_item.is_empty() {
+        line.push_str(&format!(" {} / {}", customer, work_item));
+    }
+    format!("{} {}h", line, extract_column_value(item, "numbers__1"))
+}
+
+/// Initial content of a new journal file: the day's entries for reference,
+/// followed by room for notes
+fn journal_template(date: NaiveDate, entries: &[String]) -> String {
+    let mut text = format!("# {}\n\n## Entries\n\n", date.format("%Y-%m-%d (%A)"));
+    if entries.is_empty() {
+        text.push_str("- none\n");
+    }
+    for line in entries {
+        text.push_str(line);
+        text.push('\n');
+    }
+    text.push_str("\n## Notes\n\n");
+    text
+}
+
+/// Opens `path` in `$VISUAL`/`$EDITOR` (falling back to `vi`) and waits
+fn open_editor(path: &Path) -> Result<()> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let mut parts = editor.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| anyhow!("The EDITOR environment variable is empty"))?;
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+        .map_err(|e| anyhow!("Failed to start editor '{}': {}", editor, e))?;
+    if !status.success() {
+        return Err(anyhow!("Editor '{}' exited with {}", editor, status));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_journal_template() {
+        let date = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+        let template = journal_template(date, &["- billable ACME / PROJ-123 8h".to_string()]);
+
+        assert!(template.starts_with("# 2025-09-15 (Monday)\n"));
+        assert!(template.contains("## Entries\n\n- billable ACME / PROJ-123 8h\n"));
+        assert!(template.ends_with("## Notes\n\n"));
+        assert!(journal_template(date, &[]).contains("- none\n"));
+    }
+
+    #[test]
+    fn test_week_journal_days() {
+        let dir = tempfile::tempdir().unwrap();
+        let monday = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+        fs::write(
+            journal_path(dir.path(), monday + chrono::Duration::days(2)),
+            "notes",
+        )
+        .unwrap();
+
+        let days = week_journal_days(dir.path(), monday);
+        assert_eq!(days.len(), 7);
+        assert_eq!(days.iter().filter(|(_, has_notes)| *has_notes).count(), 1);
+        assert!(days[2].1);
+        assert_eq!(days[6].0, NaiveDate::from_ymd_opt(2025, 9, 21).unwrap());
+    }
+}
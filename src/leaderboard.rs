@@ -0,0 +1,215 @@
+//! `claim leaderboard`: billable hours of every team member for a week or
+//! a month
+
+use crate::config::Config;
+use crate::monday::{is_user_item, Item, MondayClient};
+use crate::query::{extract_column_value, extract_item_date, extract_status_value};
+use crate::target::{month_working_dates, parse_month};
+use crate::truncate_string;
+use crate::utils::{self, calculate_working_dates, get_year_group_id};
+use anyhow::Result;
+use chrono::{Datelike, Weekday};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// One team member's totals for the period
+#[derive(Debug, Clone, PartialEq)]
+struct LeaderboardRow {
+    name: String,
+    total_hours: f64,
+    billable_hours: f64,
+}
+
+impl LeaderboardRow {
+    fn billable_percent(&self) -> f64 {
+        if self.total_hours > 0.0 {
+            self.billable_hours / self.total_hours * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Handles `claim leaderboard [--week DATE | --month YYYY-MM]`
+pub async fn handle_leaderboard_command(
+    client: &MondayClient,
+    config: &Config,
+    week: Option<String>,
+    month: Option<String>,
+    board_id: Option<String>,
+    anonymize: bool,
+    verbose: bool,
+) -> Result<()> {
+    let (title, dates) = match month {
+        Some(month) => {
+            let first_day = parse_month(&month)?;
+            (
+                format!("month {}", first_day.format("%Y-%m")),
+                month_working_dates(first_day),
+            )
+        }
+        None => {
+            let date = match week {
+                Some(week) => utils::parse_date_or_week(&week, config.today())?,
+                None => config.today(),
+            };
+            let monday = utils::get_week_start(date, Weekday::Mon);
+            (
+                format!("week of {}", monday.format("%Y-%m-%d")),
+                calculate_working_dates(monday, 5, true),
+            )
+        }
+    };
+    let date_strings: Vec<String> = dates
+        .iter()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect();
+
+    let board_id = board_id.unwrap_or_else(|| config.board_id.clone());
+    let board = client.get_board_with_groups(&board_id, verbose).await?;
+    let group_id = get_year_group_id(&board, &dates[0].year().to_string());
+
+    let members = client.get_team_members(verbose).await?;
+    println!(
+        "Collecting hours of {} team member(s) for the {}...",
+        members.len(),
+        title
+    );
+
+    let mut member_items = Vec::new();
+    for member in &members {
+        let items: Vec<Item> = match client
+            .query_items_with_filters(&board_id, &group_id, member.id, &date_strings, 500, verbose)
+            .await
+        {
+            Ok(items) => items
+                .into_iter()
+                .filter(|item| is_user_item(item, member.id))
+                .filter(|item| extract_item_date(item).is_some_and(|d| date_strings.contains(&d)))
+                .collect(),
+            Err(e) => {
+                eprintln!("⚠️  Could not read the entries of {}: {}", member.name, e);
+                continue;
+            }
+        };
+        let name = if anonymize {
+            anonymize_name(&member.name)
+        } else {
+            member.name.clone()
+        };
+        member_items.push((name, items));
+    }
+
+    display_leaderboard(&title, &leaderboard_rows(member_items));
+    Ok(())
+}
+
+/// Totals per member, sorted by billable hours (highest first)
+fn leaderboard_rows(member_items: Vec<(String, Vec<Item>)>) -> Vec<LeaderboardRow> {
+    let mut rows: Vec<LeaderboardRow> = member_items
+        .into_iter()
+        .map(|(name, items)| {
+            let mut row = LeaderboardRow {
+                name,
+                total_hours: 0.0,
+                billable_hours: 0.0,
+            };
+            for item in &items {
+                let hours = extract_column_value(item, "numbers__1")
+                    .trim_matches('"')
+                    .parse::<f64>()
+                    .unwrap_or(0.0);
+                row.total_hours += hours;
+                if extract_status_value(item) == "billable" {
+                    row.billable_hours += hours;
+                }
+            }
+            row
+        })
+        .collect();
+    rows.sort_by(|a, b| {
+        b.billable_hours
+            .total_cmp(&a.billable_hours)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    rows
+}
+
+/// Stable pseudonym for `name`, e.g. "Member 3f2a", for sharing screenshots
+fn anonymize_name(name: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    name.hash(&mut hasher);
+    format!("Member {:04x}", hasher.finish() & 0xffff)
+}
+
+fn display_leaderboard(title: &str, rows: &[LeaderboardRow]) {
+    println!("\n=== LEADERBOARD for the {} ===", title);
+    if rows.is_empty() {
+        println!("No team members found");
+        return;
+    }
+
+    println!(
+        "\n{:<4} {:<25} {:>8} {:>10} {:>10}",
+        "#", "Name", "Total", "Billable", "Billable %"
+    );
+    println!("{}", "-".repeat(61));
+    for (rank, row) in rows.iter().enumerate() {
+        println!(
+            "{:<4} {:<25} {:>7.1}h {:>9.1}h {:>9.1}%",
+            rank + 1,
+            truncate_string(&row.name, 23),
+            row.total_hours,
+            row.billable_hours,
+            row.billable_percent()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monday::ColumnValue;
+
+    fn entry(status_index: u8, hours: &str) -> Item {
+        Item {
+            column_values: vec![
+                ColumnValue {
+                    id: Some("status".to_string()),
+                    value: Some(format!(r#"{{"index": {}}}"#, status_index)),
+                    text: None,
+                },
+                ColumnValue {
+                    id: Some("numbers__1".to_string()),
+                    value: Some(format!("\"{}\"", hours)),
+                    text: Some(hours.to_string()),
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_leaderboard_rows() {
+        let rows = leaderboard_rows(vec![
+            ("Alex".to_string(), vec![entry(1, "4"), entry(2, "4")]),
+            ("Sam".to_string(), vec![entry(1, "8"), entry(1, "8")]),
+            ("Kim".to_string(), vec![]),
+        ]);
+
+        let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["Sam", "Alex", "Kim"]);
+        assert_eq!(rows[0].billable_percent(), 100.0);
+        assert_eq!(rows[1].total_hours, 8.0);
+        assert_eq!(rows[1].billable_percent(), 50.0);
+        assert_eq!(rows[2].billable_percent(), 0.0);
+    }
+
+    #[test]
+    fn test_anonymize_name() {
+        let alias = anonymize_name("Alex Smith");
+        assert_eq!(alias, anonymize_name("Alex Smith"));
+        assert!(alias.starts_with("Member "));
+        assert!(!alias.contains("Alex"));
+    }
+}
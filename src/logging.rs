@@ -3,7 +3,9 @@
 //! This module sets up structured logging with different levels and outputs.
 //! Logs can be controlled via the RUST_LOG environment variable.
 
-use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use crate::monday::API_ERROR_TARGET;
+use std::path::Path;
+use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
 
 /// Initialize the logging system
 ///
@@ -44,13 +46,17 @@ pub fn init() {
         .with(env_filter)
         .with(
             fmt::layer()
+                .with_writer(std::io::stderr)
                 .with_target(true)
                 .with_thread_ids(false)
                 .with_thread_names(false)
                 .with_line_number(true)
                 .with_file(false),
         )
-        .init();
+        // Keeps a subscriber that is already set instead of panicking, e.g.
+        // when called as the fallback of a failed `init_with_config`
+        .try_init()
+        .ok();
 }
 
 /// Initialize logging with file output
@@ -105,6 +111,90 @@ pub fn init_with_file(log_file_name: &str) -> Result<(), Box<dyn std::error::Err
     Ok(())
 }
 
+/// Initialize logging from the settings stored in the config file
+///
+/// Console output goes to stderr so it never mixes with command output, and
+/// defaults to warnings only (or `RUST_LOG` when present), so that info-level
+/// audit logs do not clutter the CLI or the interactive UI. When `log_file` is
+/// set, a daily rotating appender is added that records everything at
+/// `log_level` (or `RUST_LOG` when present).
+///
+/// # Arguments
+///
+/// * `log_file` - Optional path of the log file; rotated files are written
+///   next to it with a date suffix (e.g. `claim.log.2025-09-15`)
+/// * `log_level` - Level for the `claim` crate in the log file (e.g. "info", "debug")
+///
+/// # Examples
+///
+/// ```no_run
+/// use claim::logging;
+/// use std::path::Path;
+///
+/// logging::init_with_config(Some(Path::new("/tmp/claim.log")), "debug")
+///     .expect("Failed to initialize logging");
+/// ```
+pub fn init_with_config(
+    log_file: Option<&Path>,
+    log_level: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let console_filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(format!("warn,{}=off", API_ERROR_TARGET)));
+    let console_layer = fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_target(true)
+        .with_line_number(true)
+        .with_file(false)
+        .with_filter(console_filter);
+
+    let file_layer = match log_file {
+        Some(path) => {
+            let (dir, file_name) = split_log_path(path)?;
+            std::fs::create_dir_all(&dir)?;
+
+            let file_filter = EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| EnvFilter::new(format!("claim={},warn", log_level)));
+
+            Some(
+                fmt::layer()
+                    .with_writer(tracing_appender::rolling::daily(dir, file_name))
+                    .with_ansi(false)
+                    .with_target(true)
+                    .with_filter(file_filter),
+            )
+        }
+        None => None,
+    };
+
+    tracing_subscriber::registry()
+        .with(console_layer)
+        .with(file_layer)
+        .try_init()?;
+
+    Ok(())
+}
+
+/// Splits a log file path into the directory and file name used by the
+/// rolling appender. A bare file name is placed in the current directory.
+fn split_log_path(path: &Path) -> Result<(std::path::PathBuf, String), std::io::Error> {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Invalid log file path: {}", path.display()),
+            )
+        })?;
+
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => std::env::current_dir()?,
+    };
+
+    Ok((dir, file_name))
+}
+
 /// Initialize logging for tests
 ///
 /// This sets up minimal logging suitable for test environments.
@@ -143,6 +233,19 @@ mod tests {
         tracing::error!("error message");
     }
 
+    #[test]
+    fn test_split_log_path() {
+        let (dir, name) = split_log_path(Path::new("/var/log/claim/claim.log")).unwrap();
+        assert_eq!(dir, Path::new("/var/log/claim"));
+        assert_eq!(name, "claim.log");
+
+        let (dir, name) = split_log_path(Path::new("claim.log")).unwrap();
+        assert_eq!(dir, std::env::current_dir().unwrap());
+        assert_eq!(name, "claim.log");
+
+        assert!(split_log_path(Path::new("/")).is_err());
+    }
+
     #[test]
     fn test_structured_logging() {
         init_test();
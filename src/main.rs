@@ -1,15 +1,42 @@
 mod add;
+mod boards;
+mod bulk_add;
 mod cache;
+mod check_api;
+mod completion;
 mod config;
+mod config_command;
+mod copy;
 mod delete;
+mod doctor;
+mod edit;
 mod error;
+mod export;
+mod formatters;
+mod init;
 mod interactive;
+mod journal;
+mod leaderboard;
 mod logging;
+mod migrate;
 mod monday;
+mod notify;
+mod prompts;
 mod query;
+mod query_pager;
+mod recalculate;
+mod report;
 mod selenium;
+mod sessions;
+mod stats;
+mod summary;
+mod sync;
+mod target;
 mod time;
 mod utils;
+mod validate;
+mod week_template;
+mod whoami;
 
 // Re-export error types for convenience
 pub use error::{ApiError, ClaimError, ConfigError, ValidationError};
@@ -17,9 +44,12 @@ pub use error::{ApiError, ClaimError, ConfigError, ValidationError};
 pub use logging::init as init_logging;
 
 use anyhow::{anyhow, Result};
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use config::Config;
+use export::ExportGroupBy;
+use formatters::{ExportFormat, OutputFormat};
 use monday::MondayClient;
+use std::path::PathBuf;
 use std::process;
 
 #[derive(Parser)]
@@ -28,6 +58,57 @@ use std::process;
 #[command(long_version = concat!(env!("CARGO_PKG_VERSION"), " (built: ", env!("BUILD_DATE"), ")"))]
 #[command(version)]
 struct Cli {
+    /// Run the interactive UI on sample data, without an API key or network access
+    #[arg(long = "simulate")]
+    simulate: bool,
+
+    /// Color scheme of the interactive UI: default, solarized, nord or high-contrast
+    /// (overrides `color_scheme` in the config file)
+    #[arg(long = "color-scheme", value_name = "NAME")]
+    color_scheme: Option<String>,
+
+    /// First day of displayed weeks: monday or sunday (overrides
+    /// `week_starts_on` in the config file)
+    #[arg(long = "week-starts-on", value_name = "DAY", global = true, value_parser = parse_week_start_arg)]
+    week_starts_on: Option<chrono::Weekday>,
+
+    /// Use the cached customers and work items even when they are older than
+    /// `cache_ttl_hours` (no automatic refresh)
+    #[arg(long = "no-cache-refresh", global = true)]
+    no_cache_refresh: bool,
+
+    /// Monday.com account to use, from the `profiles` of the config file
+    #[arg(short = 'P', long = "profile", value_name = "NAME", global = true, default_value = config::DEFAULT_PROFILE)]
+    profile: String,
+
+    /// Print the cached customers fuzzy-matching PREFIX and exit (for shell completion)
+    #[arg(
+        long = "complete-customer",
+        value_name = "PREFIX",
+        hide = true,
+        group = "complete"
+    )]
+    complete_customer: Option<String>,
+
+    /// Print the cached work items starting with PREFIX and exit (for shell completion)
+    #[arg(
+        long = "complete-wi",
+        value_name = "PREFIX",
+        hide = true,
+        group = "complete"
+    )]
+    complete_wi: Option<String>,
+
+    /// Print the cached customer<TAB>work item pairs whose customer starts
+    /// with PREFIX and exit (for shell completion)
+    #[arg(
+        long = "complete-pair",
+        value_name = "PREFIX",
+        hide = true,
+        group = "complete"
+    )]
+    complete_pair: Option<String>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -44,6 +125,10 @@ enum Commands {
         #[arg(short = 'D', long = "date")]
         date: Option<String>,
 
+        /// ISO week to use instead of --date (YYYY-WN, e.g. 2025-W38); starts on its Monday
+        #[arg(long = "week-number", conflicts_with = "date")]
+        week_number: Option<String>,
+
         /// Number of days to query (default: 35 for ~5 weeks, skips weekends)
         #[arg(short = 'd', long = "days", default_value_t = 35)]
         days: usize,
@@ -56,6 +141,49 @@ enum Commands {
         #[arg(short = 'w', long = "wi")] // NEW: Work item filter for query
         work_item: Option<String>,
 
+        /// Sort order: date (default) or updated (most recently modified first)
+        #[arg(long = "sort")]
+        sort: Option<String>,
+
+        /// Include Saturdays and Sundays in the queried date range
+        #[arg(long = "include-weekends")]
+        include_weekends: bool,
+
+        /// ISO 8601 date interval to query (START/END, e.g. 2025-09-01/2025-09-30)
+        #[arg(long = "date-range", value_name = "START/END", conflicts_with_all = ["date", "week_number", "days", "last"])]
+        date_range: Option<String>,
+
+        /// Query every working day of a calendar month (YYYY-MM, e.g. 2025-09)
+        #[arg(long = "month", value_name = "YYYY-MM", conflicts_with_all = ["date", "week_number", "days", "date_range", "last"])]
+        month: Option<String>,
+
+        /// Show the N most recent entries regardless of date (combine with -c/-w to narrow down)
+        #[arg(long = "last", value_name = "N", conflicts_with_all = ["date", "week_number", "days", "sort"])]
+        last: Option<usize>,
+
+        /// Output format: table (default) or psql for a PostgreSQL-style aligned table
+        #[arg(long = "format", value_enum, default_value_t = OutputFormat::Table)]
+        format: OutputFormat,
+
+        /// Machine-readable output: json (an array of entries) or csv (RFC 4180
+        /// with a header row); progress messages and the summary are left out
+        #[arg(short = 'o', long = "output", value_enum, conflicts_with_all = ["format", "by_customer", "compare_with", "interactive"])]
+        output: Option<ExportFormat>,
+
+        /// Group entries by customer with per-customer subtotals and a grand total
+        #[arg(long = "by-customer", conflicts_with_all = ["last", "format"])]
+        by_customer: bool,
+
+        /// Show the range side by side with the same weekdays starting in the
+        /// week of DATE (YYYY-MM-DD or YYYY-WN), with the difference per day
+        #[arg(long = "compare-with", value_name = "DATE", conflicts_with_all = ["last", "sort", "by_customer", "format"])]
+        compare_with: Option<String>,
+
+        /// Browse the results in a scrollable view; press / to filter by
+        /// customer, work item or comment as you type
+        #[arg(long = "interactive", conflicts_with_all = ["last", "sort", "by_customer", "format", "compare_with"])]
+        interactive: bool,
+
         /// Verbose output
         #[arg(short = 'v', long = "verbose")]
         verbose: bool,
@@ -66,6 +194,10 @@ enum Commands {
         #[arg(short = 'D', long = "date")]
         date: Option<String>,
 
+        /// ISO week to use instead of --date (YYYY-WN, e.g. 2025-W38); starts on its Monday
+        #[arg(long = "week-number", conflicts_with = "date")]
+        week_number: Option<String>,
+
         /// Activity type (vacation, billable, holding, education, work_reduction, tbd, holiday, presales, illness, paid_not_worked, intellectual_capital, business_development, overhead)
         #[arg(short = 't', long = "type")]
         activity_type: Option<String>,
@@ -74,26 +206,128 @@ enum Commands {
         #[arg(short = 'c', long = "customer")]
         customer: Option<String>,
 
-        /// Work item
+        /// Customer given by the start of its name, completed from
+        /// customer_prefixes in the config file
+        #[arg(long = "customer-prefix", conflicts_with_all = ["customer", "customer_file"])]
+        customer_prefix: Option<String>,
+
+        /// Work item; several comma-separated work items create one entry each
         #[arg(short = 'w', long = "wi")]
         work_item: Option<String>,
 
-        /// Number of hours
-        #[arg(short = 'H', long = "hours")]
-        hours: Option<f64>,
+        /// Number of hours; split equally across several work items, or given
+        /// per work item as e.g. 5:3
+        #[arg(short = 'H', long = "hours", value_parser = add::parse_hours_arg)]
+        hours: Option<add::HoursArg>,
 
         /// Number of working days (default: 1, skips weekends)
         #[arg(short = 'd', long = "days")]
         days: Option<f64>,
 
+        /// Hours logged on each of the --days when --hours is not given
+        #[arg(
+            long = "hours-per-day",
+            value_name = "FLOAT",
+            requires = "days",
+            conflicts_with = "hours"
+        )]
+        hours_per_day: Option<f64>,
+
         /// Comment for the claim
         #[arg(short = 'k', long = "comment")]
         comment: Option<String>,
 
+        /// File with one customer:work_item pair per line; creates one entry per line on --date
+        #[arg(long = "customer-file", conflicts_with_all = ["customer", "work_item", "days"])]
+        customer_file: Option<PathBuf>,
+
+        /// File with one date,hours line per entry to create, e.g. exported from a
+        /// spreadsheet; the other options apply to every entry
+        #[arg(
+            long = "from-file",
+            value_name = "PATH",
+            conflicts_with_all = [
+                "date", "week_number", "hours", "days", "hours_per_day", "customer_file",
+                "include_weekends"
+            ]
+        )]
+        from_file: Option<PathBuf>,
+
+        /// Create entries on Saturdays and Sundays too instead of skipping them
+        #[arg(long = "include-weekends")]
+        include_weekends: bool,
+
         /// Refresh cache by querying the last 4 weeks of entries
         #[arg(short = 'r', long = "refresh-cache")]
         refresh_cache: bool,
 
+        /// Add --hours to the existing entry with this ID instead of creating one
+        #[arg(
+            long = "append-to",
+            value_name = "ENTRY_ID",
+            requires = "hours",
+            conflicts_with_all = [
+                "date", "week_number", "activity_type", "customer", "customer_prefix",
+                "work_item", "days", "comment", "customer_file", "from_file",
+                "include_weekends", "dry_run"
+            ]
+        )]
+        append_to: Option<String>,
+
+        /// Send a notification (or ring the terminal bell) when all entries are created
+        #[arg(long = "notify")]
+        notify: bool,
+
+        /// Print the GraphQL mutations that would be sent without changing anything
+        #[arg(short = 'n', long = "dry-run")]
+        dry_run: bool,
+
+        /// Skip the daily_hours_limit check on the hours already logged on each day
+        #[arg(long = "force")]
+        force: bool,
+
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes")]
+        yes: bool,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Change the columns of an existing entry by ID
+    Edit {
+        /// Item ID to edit
+        #[arg(short = 'x', long = "id")]
+        id: String,
+
+        /// New date (YYYY-MM-DD, YYYY.MM.DD, or YYYY/MM/DD format)
+        #[arg(short = 'D', long = "date")]
+        date: Option<String>,
+
+        /// New activity type (name or number, as for `add`)
+        #[arg(short = 't', long = "type")]
+        activity_type: Option<String>,
+
+        /// New customer name
+        #[arg(short = 'c', long = "customer")]
+        customer: Option<String>,
+
+        /// New work item
+        #[arg(short = 'w', long = "wi")]
+        work_item: Option<String>,
+
+        /// New number of hours
+        #[arg(short = 'H', long = "hours")]
+        hours: Option<f64>,
+
+        /// New comment
+        #[arg(short = 'k', long = "comment")]
+        comment: Option<String>,
+
+        /// Print the GraphQL mutations that would be sent without changing anything
+        #[arg(short = 'n', long = "dry-run")]
+        dry_run: bool,
+
         /// Skip confirmation prompt
         #[arg(short = 'y', long = "yes")]
         yes: bool,
@@ -112,6 +346,15 @@ enum Commands {
         #[arg(short = 'D', long = "date")]
         date: Option<String>,
 
+        /// ISO week to use instead of --date (YYYY-WN, e.g. 2025-W38); starts on its Monday
+        #[arg(long = "week-number", conflicts_with = "date")]
+        week_number: Option<String>,
+
+        /// Delete every entry on the working days from FROM to TO, both
+        /// inclusive (e.g. 2025-09-15 2025-09-19); -c and -w narrow it down
+        #[arg(long = "date-range", num_args = 2, value_names = ["FROM", "TO"], conflicts_with_all = ["delete_id", "date", "week_number"])]
+        date_range: Option<Vec<String>>,
+
         /// Customer name to filter by
         #[arg(short = 'c', long = "customer")]
         customer: Option<String>,
@@ -120,6 +363,416 @@ enum Commands {
         #[arg(short = 'w', long = "wi")]
         work_item: Option<String>,
 
+        /// Print the GraphQL mutations that would be sent without changing anything
+        #[arg(short = 'n', long = "dry-run")]
+        dry_run: bool,
+
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes")]
+        yes: bool,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Compare logged hours against the monthly target (exits with code 2 when below 80%)
+    Target {
+        /// Month to check (YYYY-MM format, default: current month)
+        #[arg(short = 'm', long = "month")]
+        month: Option<String>,
+
+        /// Expected hours per working day (overrides the config default)
+        #[arg(long = "hours-per-day")]
+        hours_per_day: Option<f64>,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Compare the local entry cache with the last 4 weeks on Monday.com
+    Sync {
+        /// Update the cache to match Monday.com
+        #[arg(long = "fix")]
+        fix: bool,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Move entries dated in one year out of another year's group
+    Migrate {
+        /// Year of the group to move entries out of
+        #[arg(long = "from-year")]
+        from_year: i32,
+
+        /// Year of the group to move entries into; only entries dated in this year are moved
+        #[arg(long = "to-year")]
+        to_year: i32,
+
+        /// Only list the entries that would be moved
+        #[arg(long = "dry-run")]
+        dry_run: bool,
+
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes")]
+        yes: bool,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Convert the hours of existing entries to a new working day length
+    Recalculate {
+        /// Day whose entries to recalculate (YYYY-MM-DD)
+        #[arg(
+            short = 'D',
+            long = "date",
+            conflicts_with = "week",
+            required_unless_present = "week"
+        )]
+        date: Option<String>,
+
+        /// Any date in the week to recalculate (YYYY-MM-DD or YYYY-Www)
+        #[arg(short = 'w', long = "week")]
+        week: Option<String>,
+
+        /// New length of a working day in hours (the old one is `hours_per_day`)
+        #[arg(long = "hours-per-day")]
+        hours_per_day: f64,
+
+        /// Update hours keeping the days, or only show the days the hours amount to
+        #[arg(long = "direction", value_enum, default_value_t = recalculate::Direction::HoursFromDays)]
+        direction: recalculate::Direction,
+
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes")]
+        yes: bool,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Check that the API schema and board columns match what claim uses
+    CheckApi {
+        /// Board whose columns to check (default: `board_id` from the config file)
+        #[arg(long = "board-id")]
+        board_id: Option<String>,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Copy the entries of one day to other days
+    Copy {
+        /// Day whose entries to copy (YYYY-MM-DD)
+        #[arg(long = "from")]
+        from: String,
+
+        /// First day to copy the entries to (YYYY-MM-DD)
+        #[arg(long = "to")]
+        to: String,
+
+        /// Number of working days to copy to, starting at --to (skips weekends)
+        #[arg(short = 'd', long = "days", default_value_t = 1)]
+        days: usize,
+
+        /// Only copy entries whose customer contains this text
+        #[arg(short = 'c', long = "customer")]
+        customer: Option<String>,
+
+        /// Only copy entries whose work item contains this text
+        #[arg(short = 'w', long = "wi")]
+        work_item: Option<String>,
+
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes")]
+        yes: bool,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Total hours of a week, month or year by activity type, customer and work item
+    Summary {
+        /// Week to summarize: current (default), last, YYYY-WN or any date in it
+        #[arg(short = 'w', long = "week", conflicts_with_all = ["month", "year"])]
+        week: Option<String>,
+
+        /// Month to summarize (YYYY-MM format)
+        #[arg(short = 'm', long = "month", conflicts_with = "year")]
+        month: Option<String>,
+
+        /// Year to summarize (YYYY)
+        #[arg(short = 'Y', long = "year")]
+        year: Option<i32>,
+
+        /// Only include billable entries
+        #[arg(long = "billable-only")]
+        billable_only: bool,
+
+        /// Also post the summary to this Slack incoming webhook (saved for --slack)
+        #[arg(long = "slack-webhook", value_name = "URL")]
+        slack_webhook: Option<String>,
+
+        /// Post the summary to the saved Slack webhook
+        #[arg(long = "slack", conflicts_with = "slack_webhook")]
+        slack: bool,
+
+        /// Format the Slack message with blocks (header, one section per row, total)
+        #[arg(long = "rich")]
+        rich: bool,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Create entries from the rows of a CSV file
+    BulkAdd {
+        /// CSV file with the columns date,activity_type,customer,work_item,hours,comment
+        #[arg(short = 'f', long = "file")]
+        file: PathBuf,
+
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes")]
+        yes: bool,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Report days with missing hours, unexpected totals or duplicate entries
+    Validate {
+        /// First day to check (default: Monday of the current week)
+        #[arg(long = "from")]
+        from: Option<String>,
+
+        /// Last day to check (default: today, at most the end of the --from week)
+        #[arg(long = "to")]
+        to: Option<String>,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Write the entries of a date range as CSV or JSON, e.g. for HR systems
+    Export {
+        /// First day to export (default: first day of the current month)
+        #[arg(long = "from")]
+        from: Option<String>,
+
+        /// Last day to export (default: today)
+        #[arg(long = "to")]
+        to: Option<String>,
+
+        /// Output format: csv (RFC 4180 with a header row) or json (an array of entries)
+        #[arg(long = "format", value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
+
+        /// File to write instead of standard output
+        #[arg(long = "output-file", value_name = "PATH")]
+        output_file: Option<PathBuf>,
+
+        /// Directory to write one file per day, week or month into, in
+        /// YEAR/MONTH folders (e.g. PATH/2025/09/claim-2025-09-15.json)
+        #[arg(
+            long = "output-dir",
+            value_name = "PATH",
+            conflicts_with = "output_file"
+        )]
+        output_dir: Option<PathBuf>,
+
+        /// Period of each file written with --output-dir
+        #[arg(long = "group-by", value_enum, default_value_t = ExportGroupBy::Day, requires = "output_dir")]
+        group_by: ExportGroupBy,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Monthly report of billable hours by customer and work item and
+    /// non-billable hours by activity type, one column per day
+    Report {
+        /// Month to report on (YYYY-MM, default: the previous month)
+        #[arg(short = 'm', long = "month")]
+        month: Option<String>,
+
+        /// Machine-readable output: csv (one row per day, activity type,
+        /// customer and work item, for pivot tables) or json
+        #[arg(short = 'o', long = "output", value_enum)]
+        output: Option<ExportFormat>,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// List the boards the API key can access, to find the ID for `board_id`
+    Boards {
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Rank team members by billable hours for a week or a month
+    Leaderboard {
+        /// Any date in the week to rank (YYYY-MM-DD or YYYY-Www, default: current week)
+        #[arg(short = 'w', long = "week", conflicts_with = "month")]
+        week: Option<String>,
+
+        /// Month to rank (YYYY-MM format)
+        #[arg(short = 'm', long = "month")]
+        month: Option<String>,
+
+        /// Board to read (default: `board_id` from the config file)
+        #[arg(long = "board-id")]
+        board_id: Option<String>,
+
+        /// Replace names with stable pseudonyms, e.g. for screenshots
+        #[arg(long = "anonymize")]
+        anonymize: bool,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Show a day's entries with free-form notes kept in a local journal
+    Journal {
+        /// Day to show (YYYY-MM-DD, default: today)
+        #[arg(short = 'D', long = "date", conflicts_with = "list")]
+        date: Option<String>,
+
+        /// Open the day's notes in $EDITOR
+        #[arg(short = 'e', long = "edit", conflicts_with = "list")]
+        edit: bool,
+
+        /// List which days of a week have journal notes
+        #[arg(long = "list")]
+        list: bool,
+
+        /// Any date (YYYY-MM-DD) or ISO week (YYYY-WN) of the week to list (default: this week)
+        #[arg(long = "week", requires = "list")]
+        week: Option<String>,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Show statistics about logged time
+    Stats {
+        /// Show the current and longest streak of working days with complete entries
+        #[arg(long = "streak")]
+        streak: bool,
+
+        /// Find the longest streak within this ISO 8601 interval instead of the current year (START/END)
+        #[arg(long = "date-range", value_name = "START/END")]
+        date_range: Option<String>,
+
+        /// Output format: table (default) or psql for a PostgreSQL-style aligned table
+        #[arg(long = "format", value_enum, default_value_t = OutputFormat::Table)]
+        format: OutputFormat,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// View the interactive UI session log (see `session_log` in the config file)
+    Sessions {
+        #[command(subcommand)]
+        action: SessionsCommands,
+    },
+    /// Troubleshooting helpers for Monday.com support requests
+    Debug {
+        #[command(subcommand)]
+        action: DebugCommands,
+    },
+    /// Save, apply and list recurring weekly schedules
+    #[command(visible_alias = "template")]
+    WeekTemplate {
+        #[command(subcommand)]
+        action: WeekTemplateCommands,
+    },
+    /// Lock a finalized week so its entries can no longer be added, edited or deleted
+    Lock {
+        /// Any date in the week (YYYY-MM-DD, YYYY.MM.DD, YYYY/MM/DD) or an ISO week (YYYY-WN)
+        #[arg(short = 'w', long = "week")]
+        week: String,
+    },
+    /// Unlock a previously locked week
+    Unlock {
+        /// Any date in the week (YYYY-MM-DD, YYYY.MM.DD, YYYY/MM/DD) or an ISO week (YYYY-WN)
+        #[arg(short = 'w', long = "week")]
+        week: String,
+    },
+    /// Diagnose common configuration issues (exits with code 1 if any check fails)
+    Doctor {
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Guided setup: API key, board and column check, default hours and activity type
+    Init {
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Show the authenticated user, the board and local settings (start here
+    /// when diagnosing authentication or configuration problems)
+    #[command(name = "whoami")]
+    WhoAmI {
+        /// Print the information as JSON
+        #[arg(long = "json")]
+        json: bool,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// View or change local settings
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommands,
+    },
+    /// Print the JSON Schema of the config file
+    #[command(hide = true)]
+    JsonSchema,
+    /// Print a shell completion script
+    Completion {
+        /// Shell to generate the script for
+        #[arg(short = 's', long = "shell", required_unless_present = "cached")]
+        shell: Option<clap_complete::Shell>,
+
+        /// Print the cached customers or work items (used by the scripts)
+        #[arg(long = "cached", hide = true, conflicts_with = "shell")]
+        cached: Option<completion::CachedValues>,
+    },
+}
+
+#[derive(Subcommand)]
+enum WeekTemplateCommands {
+    /// Capture the entries of a week as a template
+    Save {
+        /// Template name
+        name: String,
+
+        /// Any date in the source week (YYYY-MM-DD, YYYY.MM.DD, or YYYY/MM/DD format; default: this week)
+        #[arg(short = 'f', long = "from", visible_alias = "week")]
+        from: Option<String>,
+
+        /// Verbose output
+        #[arg(short = 'v', long = "verbose")]
+        verbose: bool,
+    },
+    /// Re-create a template's entries in another week
+    Apply {
+        /// Template name
+        name: String,
+
+        /// Any date in the target week (YYYY-MM-DD, YYYY.MM.DD, or YYYY/MM/DD format)
+        #[arg(short = 't', long = "to", visible_alias = "week")]
+        to: String,
+
+        /// Only apply the first N weekdays of the template (default: 5, Mon-Fri)
+        #[arg(short = 'd', long = "days", default_value_t = 5)]
+        days: u32,
+
         /// Skip confirmation prompt
         #[arg(short = 'y', long = "yes")]
         yes: bool,
@@ -128,35 +781,278 @@ enum Commands {
         #[arg(short = 'v', long = "verbose")]
         verbose: bool,
     },
+    /// List saved week templates
+    List,
+}
+
+#[derive(Subcommand)]
+enum SessionsCommands {
+    /// List recorded sessions with the entries added, edited and deleted
+    List {
+        /// Only sessions started on or after this date (YYYY-MM-DD) or ISO week (YYYY-WN)
+        #[arg(long = "since")]
+        since: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum DebugCommands {
+    /// Print the id sent in the X-Client-Request-ID header of the most recent API request
+    LastRequestId,
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Write logs to a file (rotated daily) in addition to the console
+    SetLogFile {
+        /// Path of the log file
+        path: PathBuf,
+
+        /// Log level for the file (trace, debug, info, warn, error)
+        #[arg(short = 'l', long = "level")]
+        level: Option<String>,
+    },
+    /// Stop writing logs to a file
+    DisableLog,
+    /// Limit how many recent customer/work item pairs are cached per user
+    SetMaxCacheEntries {
+        /// Maximum number of cached entries (default: 50)
+        max: usize,
+    },
+    /// Round entered hours to the nearest increment (0.25, 0.5, 1.0 or off)
+    SetRounding {
+        /// Rounding increment in hours, or "off" to disable rounding
+        value: String,
+    },
+    /// Show an activity type under a custom name (the board value is unchanged)
+    SetActivityLabel {
+        /// Activity type index (0-12, e.g. 1 for billable)
+        index: u8,
+
+        /// Display name; an empty string restores the built-in name
+        label: String,
+    },
+    /// Add a work item to the allowed list checked by `add` and the interactive forms
+    AddWorkItem {
+        /// Work item code, e.g. PROJ-123
+        item: String,
+    },
+    /// List the allowed work items
+    ListWorkItems,
+    /// Change the text of a `claim add` prompt (an empty text restores the built-in one)
+    SetPrompt {
+        /// Prompt to change: date, activity_type, customer, work_item, comment, hours or days
+        field: String,
+
+        /// New prompt text
+        text: String,
+    },
+    /// Use a built-in set of `claim add` prompts: en, de or fr
+    SetPromptLanguage {
+        /// Language of the prompts (en, de or fr)
+        language: String,
+    },
+    /// Save the Slack incoming webhook `claim summary --slack` posts to
+    SetSlackWebhook {
+        /// Incoming webhook URL (https://hooks.slack.com/services/...)
+        url: String,
+    },
+    /// Show weekly tables starting on Monday or Sunday (working days stay Monday to Friday)
+    SetWeekStart {
+        /// First day of the displayed week: monday or sunday
+        day: String,
+    },
+    /// Print the config file location and its settings (API keys masked)
+    Show,
+    /// Change one setting, e.g. `claim config set board_id 12345`
+    Set {
+        /// Setting name as in the config file
+        key: String,
+
+        /// New value (JSON for numbers, booleans and lists; `null` unsets it)
+        value: String,
+    },
+    /// Delete the config file; the setup wizard runs on the next command
+    Reset {
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long = "yes")]
+        yes: bool,
+    },
+    /// Manage the Monday.com accounts selected with --profile
+    Profile {
+        #[command(subcommand)]
+        action: ProfileCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProfileCommands {
+    /// Add a profile with its own API key and board
+    Add {
+        /// Profile name
+        name: String,
+
+        /// Monday.com API key (prompted for when not given)
+        #[arg(long = "api-key")]
+        api_key: Option<String>,
+
+        /// Board ID (asked for on first use when not given)
+        #[arg(long = "board-id")]
+        board_id: Option<String>,
+
+        /// Customer used by `claim add` when none is given
+        #[arg(long = "default-customer")]
+        default_customer: Option<String>,
+    },
+    /// List the profiles
+    List,
+}
+
+/// The clap command, named as the binary was installed so help, version
+/// and completion scripts show that name
+fn cli_command() -> clap::Command {
+    let command = Cli::command();
+    match utils::binary_name() {
+        Some(name) => command.name(name),
+        None => command,
+    }
 }
 
 #[tokio::main]
 async fn main() {
-    // Initialize logging (controlled by RUST_LOG environment variable)
-    logging::init();
+    // Initialize logging (controlled by RUST_LOG and the config file log settings)
+    let log_config = Config::load().unwrap_or_default();
+    if let Err(e) = logging::init_with_config(log_config.log_file.as_deref(), &log_config.log_level)
+    {
+        eprintln!("Warning: Failed to initialize file logging: {}", e);
+        logging::init();
+    }
 
     tracing::debug!("Application starting");
 
-    let cli = Cli::parse();
+    let cli = match Cli::from_arg_matches(&cli_command().get_matches()) {
+        Ok(cli) => cli,
+        Err(e) => e.exit(),
+    };
 
     match run(cli).await {
         Ok(_) => (),
         Err(e) => {
             eprintln!("Error: {}", e);
+            eprintln!("Hint: run `claim doctor` to diagnose configuration issues.");
             process::exit(1);
         }
     }
 }
 
 async fn run(cli: Cli) -> Result<()> {
+    // Completion lookups only read the cache, before any command runs
+    let prefix_lookup = [
+        (completion::PrefixLookup::Customer, &cli.complete_customer),
+        (completion::PrefixLookup::WorkItem, &cli.complete_wi),
+        (completion::PrefixLookup::Pair, &cli.complete_pair),
+    ]
+    .into_iter()
+    .find_map(|(lookup, prefix)| prefix.as_deref().map(|prefix| (lookup, prefix)));
+    if let Some((lookup, prefix)) = prefix_lookup {
+        return completion::print_prefix_matches(lookup, prefix);
+    }
+
     // Determine if verbose mode is enabled
     let verbose = match &cli.command {
         Some(Commands::Query { verbose, .. }) => *verbose,
         Some(Commands::Add { verbose, .. }) => *verbose,
+        Some(Commands::Edit { verbose, .. }) => *verbose,
         Some(Commands::Delete { verbose, .. }) => *verbose,
+        Some(Commands::Target { verbose, .. }) => *verbose,
+        Some(Commands::Stats { verbose, .. }) => *verbose,
+        Some(Commands::Sync { verbose, .. }) => *verbose,
+        Some(Commands::Journal { verbose, .. }) => *verbose,
+        Some(Commands::Migrate { verbose, .. }) => *verbose,
+        Some(Commands::Recalculate { verbose, .. }) => *verbose,
+        Some(Commands::CheckApi { verbose, .. }) => *verbose,
+        Some(Commands::Copy { verbose, .. }) => *verbose,
+        Some(Commands::Summary { verbose, .. }) => *verbose,
+        Some(Commands::BulkAdd { verbose, .. }) => *verbose,
+        Some(Commands::Validate { verbose, .. }) => *verbose,
+        Some(Commands::Export { verbose, .. }) => *verbose,
+        Some(Commands::Report { verbose, .. }) => *verbose,
+        Some(Commands::Boards { verbose }) => *verbose,
+        Some(Commands::Leaderboard { verbose, .. }) => *verbose,
+        Some(Commands::WeekTemplate { action }) => match action {
+            WeekTemplateCommands::Save { verbose, .. } => *verbose,
+            WeekTemplateCommands::Apply { verbose, .. } => *verbose,
+            WeekTemplateCommands::List => false,
+        },
+        Some(Commands::Doctor { verbose }) => *verbose,
+        Some(Commands::WhoAmI { verbose, .. }) => *verbose,
+        Some(Commands::Init { verbose }) => *verbose,
+        Some(Commands::Config { .. })
+        | Some(Commands::Sessions { .. })
+        | Some(Commands::Debug { .. })
+        | Some(Commands::JsonSchema)
+        | Some(Commands::Completion { .. }) => false,
+        Some(Commands::Lock { .. }) | Some(Commands::Unlock { .. }) => false,
         None => false,
     };
 
+    if cli.color_scheme.is_some() && cli.command.is_some() {
+        return Err(anyhow!("--color-scheme only applies to the interactive UI"));
+    }
+    let color_scheme = cli.color_scheme;
+    let week_starts_on = cli.week_starts_on;
+    let no_cache_refresh = cli.no_cache_refresh;
+    config::set_active_profile(&cli.profile);
+
+    if cli.simulate {
+        if cli.command.is_some() {
+            return Err(anyhow!("--simulate only applies to the interactive UI"));
+        }
+        if let Err(e) =
+            interactive::run_interactive(true, color_scheme, week_starts_on, no_cache_refresh).await
+        {
+            eprintln!("Interactive UI error: {}", e);
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Config commands only touch the local config file, so they run before
+    // any API key prompt or network call
+    let command = match cli.command {
+        Some(Commands::Config { action }) => return handle_config_command(action),
+        Some(Commands::JsonSchema) => return config_command::print_json_schema(),
+        Some(Commands::Completion { shell, cached }) => {
+            return match (shell, cached) {
+                (_, Some(values)) => completion::print_cached_values(values),
+                (Some(shell), None) => completion::print_completion(cli_command(), shell),
+                (None, None) => unreachable!("clap requires --shell or --cached"),
+            };
+        }
+        Some(Commands::Sessions {
+            action: SessionsCommands::List { since },
+        }) => return sessions::handle_list(since),
+        Some(Commands::Debug {
+            action: DebugCommands::LastRequestId,
+        }) => return print_last_request_id(),
+        Some(Commands::Lock { week }) => return config_command::lock_week(&week),
+        Some(Commands::Unlock { week }) => return config_command::unlock_week(&week),
+        Some(Commands::WeekTemplate {
+            action: WeekTemplateCommands::List,
+        }) => return week_template::handle_list(),
+        Some(Commands::Doctor { verbose }) => {
+            if !doctor::run_doctor(verbose).await {
+                process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Commands::WhoAmI { json, verbose }) => {
+            return whoami::handle_whoami_command(json, verbose).await
+        }
+        Some(Commands::Init { verbose }) => return init::run_init(verbose).await.map(|_| ()),
+        command => command,
+    };
+
     // Load configuration
     let config = match Config::load() {
         Ok(config) => {
@@ -165,72 +1061,154 @@ async fn run(cli: Cli) -> Result<()> {
             }
             config
         }
+        // An unknown --profile must not start the setup wizard
+        Err(e) if e.is::<ConfigError>() => return Err(e),
         Err(_) => {
-            println!("No API key found. Let's set one up!");
-            let api_key = Config::prompt_for_api_key();
+            println!("No API key found. Let's set one up!\n");
+            init::run_init(verbose).await?
+        }
+    };
 
-            if api_key.is_empty() {
-                return Err(anyhow!("API key cannot be empty"));
-            }
+    // Commands whose standard output is machine-readable get no banner or
+    // prompts on it
+    let machine_output = matches!(
+        command,
+        Some(Commands::Query {
+            output: Some(_),
+            ..
+        }) | Some(Commands::Export {
+            output_file: None,
+            output_dir: None,
+            ..
+        }) | Some(Commands::Report {
+            output: Some(_),
+            ..
+        })
+    );
 
-            let config = Config::new(api_key.clone());
+    let mut config = config;
+    let client = MondayClient::new(config.api_key.clone()).with_retry_policy(config.retry_policy());
+    // `claim boards` lists the IDs to choose from, so it works without one
+    if !matches!(command, Some(Commands::Boards { .. })) {
+        init::choose_missing_board(&client, &mut config, !machine_output, verbose).await?;
+    }
+    let (user, cached) = load_current_user(&client, &mut config, verbose).await?;
+    // Applied after the user cache is saved, so the override is not persisted
+    if let Some(day) = week_starts_on {
+        config.week_starts_on = day;
+    }
+    if no_cache_refresh {
+        config.cache_ttl_hours = 0;
+    }
+    let current_year = config.today().format("%Y").to_string();
 
-            // Test the API key before saving
-            println!("Testing connection to Monday.com...");
-            let client = MondayClient::new(api_key);
-            match client.test_connection_verbose(verbose).await {
-                Ok(_) => {
-                    config.save()?;
-                    println!("API key validated and saved successfully!");
-                    config
-                }
-                Err(e) => {
-                    return Err(anyhow!(
-                        "Failed to validate API key: {}. Please check your API key and try again.",
-                        e
-                    ));
-                }
+    // Print user info with year, except before machine-readable output
+    if !machine_output {
+        println!(
+            "\nRunning for user id {}, user name {}, email {} for year {}{}",
+            user.id,
+            user.name,
+            user.email,
+            current_year,
+            if verbose && cached {
+                " (cached user)"
+            } else {
+                ""
             }
-        }
-    };
-
-    let client = MondayClient::new(config.api_key.clone());
-    let user = client.get_current_user_verbose(verbose).await?;
-    let current_year = utils::get_current_year().to_string();
+        );
+    }
 
-    // Print user info with year
-    println!(
-        "\nRunning for user id {}, user name {}, email {} for year {}",
-        user.id, user.name, user.email, current_year
-    );
+    tracing::info!(user_id = user.id, "Authenticated as {}", user.name);
 
     // Handle commands
-    match cli.command {
+    match command {
         Some(Commands::Query {
             limit,
             date,
+            week_number,
             days,
             customer,  // NEW: Pass customer filter
             work_item, // NEW: Pass work item filter
+            sort,
+            include_weekends,
+            date_range,
+            month,
+            last,
+            format,
+            output,
+            by_customer,
+            compare_with,
+            interactive,
             verbose,
         }) => {
+            let (date, days) = match (date_range, month) {
+                (_, Some(month)) => {
+                    let (first_day, days) = query::month_query_range(&month, include_weekends)?;
+                    (Some(first_day.format("%Y-%m-%d").to_string()), days)
+                }
+                (Some(range), None) => {
+                    let (start, end) = utils::parse_date_range(&range, config.today())?;
+                    let days = utils::count_days_in_range(start, end, !include_weekends);
+                    if days == 0 {
+                        return Err(anyhow!("The date range {} has no working days", range));
+                    }
+                    (Some(start.format("%Y-%m-%d").to_string()), days)
+                }
+                (None, None) => (utils::resolve_date_or_week(date, week_number)?, days),
+            };
             query::handle_query_command(
-                &client, &user, limit, date, days, customer, work_item, verbose,
+                &client,
+                &user,
+                limit,
+                date,
+                days,
+                customer,
+                work_item,
+                sort,
+                include_weekends,
+                last,
+                format,
+                output,
+                by_customer,
+                compare_with,
+                interactive,
+                &config,
+                verbose,
             )
             .await?;
         }
         Some(Commands::Add {
             date,
+            week_number,
             activity_type,
             customer,
+            customer_prefix,
             work_item,
             hours,
             days,
+            hours_per_day,
             comment,
+            customer_file,
+            from_file,
+            include_weekends,
             refresh_cache,
+            append_to,
+            notify,
+            dry_run,
+            force,
             yes,
             verbose,
         }) => {
+            if let Some(entry_id) = append_to {
+                add::append_hours_to_entry(&client, &user, &config, &entry_id, hours, verbose)
+                    .await?;
+                return Ok(());
+            }
+            let date = utils::resolve_date_or_week(date, week_number)?;
+            let customer = match customer_prefix {
+                Some(prefix) => Some(add::resolve_customer_prefix_arg(&config, &prefix)?),
+                None => customer,
+            };
             add::handle_add_command(
                 &client,
                 &user,
@@ -241,21 +1219,77 @@ async fn run(cli: Cli) -> Result<()> {
                 work_item,
                 hours,
                 days,
+                hours_per_day,
                 comment,
+                customer_file,
+                from_file,
+                include_weekends,
+                &config,
                 refresh_cache,
+                notify,
+                dry_run,
+                force,
+                yes,
+                verbose,
+            )
+            .await?;
+        }
+        Some(Commands::Edit {
+            id,
+            date,
+            activity_type,
+            customer,
+            work_item,
+            hours,
+            comment,
+            dry_run,
+            yes,
+            verbose,
+        }) => {
+            edit::handle_edit_command(
+                &client,
+                &user,
+                &config,
+                &id,
+                date,
+                activity_type,
+                customer,
+                work_item,
+                hours,
+                comment,
+                dry_run,
                 yes,
                 verbose,
             )
             .await?;
         }
+        Some(Commands::Delete {
+            date_range: Some(range),
+            customer,
+            work_item,
+            dry_run,
+            yes,
+            verbose,
+            ..
+        }) => {
+            let (start, end) = utils::parse_date_pair(&range[0], &range[1], config.today())?;
+            delete::delete_date_range(
+                &client, &user, &config, start, end, customer, work_item, dry_run, yes, verbose,
+            )
+            .await?;
+        }
         Some(Commands::Delete {
             delete_id,
             date,
+            week_number,
             customer,
             work_item,
+            dry_run,
             yes,
             verbose,
+            ..
         }) => {
+            let date = utils::resolve_date_or_week(date, week_number)?;
             delete::handle_delete_command(
                 &client,
                 &user,
@@ -264,15 +1298,253 @@ async fn run(cli: Cli) -> Result<()> {
                 date,
                 customer,
                 work_item,
+                &config,
+                dry_run,
                 yes,
                 verbose,
             )
             .await?;
         }
+        Some(Commands::Target {
+            month,
+            hours_per_day,
+            verbose,
+        }) => {
+            let hours_per_day = hours_per_day.unwrap_or_else(|| config.hours_per_day());
+            let below_target = target::handle_target_command(
+                &client,
+                &user,
+                &config.board_id,
+                month,
+                hours_per_day,
+                config.today(),
+                verbose,
+            )
+            .await?;
+            if below_target {
+                process::exit(2);
+            }
+        }
+        Some(Commands::Stats {
+            streak,
+            date_range,
+            format,
+            verbose,
+        }) => {
+            let date_range = date_range
+                .map(|range| utils::parse_date_range(&range, config.today()))
+                .transpose()?;
+            stats::handle_stats_command(
+                &client, &user, &config, streak, date_range, format, verbose,
+            )
+            .await?;
+        }
+        Some(Commands::Sync { fix, verbose }) => {
+            sync::handle_sync_command(&client, &user, &config, fix, verbose).await?;
+        }
+        Some(Commands::Migrate {
+            from_year,
+            to_year,
+            dry_run,
+            yes,
+            verbose,
+        }) => {
+            migrate::handle_migrate_command(
+                &client,
+                &config.board_id,
+                from_year,
+                to_year,
+                dry_run,
+                yes,
+                verbose,
+            )
+            .await?;
+        }
+        Some(Commands::Recalculate {
+            date,
+            week,
+            hours_per_day,
+            direction,
+            yes,
+            verbose,
+        }) => {
+            recalculate::handle_recalculate_command(
+                &client,
+                &user,
+                &config,
+                date,
+                week,
+                hours_per_day,
+                direction,
+                yes,
+                verbose,
+            )
+            .await?;
+        }
+        Some(Commands::CheckApi { board_id, verbose }) => {
+            check_api::handle_check_api_command(&client, &config, board_id, verbose).await?;
+        }
+        Some(Commands::Copy {
+            from,
+            to,
+            days,
+            customer,
+            work_item,
+            yes,
+            verbose,
+        }) => {
+            copy::handle_copy_command(
+                &client, &user, &config, &from, &to, days, customer, work_item, yes, verbose,
+            )
+            .await?;
+        }
+        Some(Commands::Summary {
+            week,
+            month,
+            year,
+            billable_only,
+            slack_webhook,
+            slack,
+            rich,
+            verbose,
+        }) => {
+            summary::handle_summary_command(
+                &client,
+                &user,
+                &config,
+                week,
+                month,
+                year,
+                billable_only,
+                summary::SlackTarget {
+                    webhook: slack_webhook,
+                    saved: slack,
+                    rich,
+                },
+                verbose,
+            )
+            .await?;
+        }
+        Some(Commands::BulkAdd { file, yes, verbose }) => {
+            bulk_add::handle_bulk_add_command(&client, &user, &config, &file, yes, verbose).await?;
+        }
+        Some(Commands::Boards { verbose }) => {
+            boards::handle_boards_command(&client, &config, verbose).await?;
+        }
+        Some(Commands::Validate { from, to, verbose }) => {
+            let violations =
+                validate::handle_validate_command(&client, &user, &config, from, to, verbose)
+                    .await?;
+            if violations {
+                process::exit(1);
+            }
+        }
+        Some(Commands::Export {
+            from,
+            to,
+            format,
+            output_file,
+            output_dir,
+            group_by,
+            verbose,
+        }) => {
+            export::handle_export_command(
+                &client,
+                &user,
+                &config,
+                from,
+                to,
+                format,
+                output_file,
+                output_dir,
+                group_by,
+                verbose,
+            )
+            .await?;
+        }
+        Some(Commands::Report {
+            month,
+            output,
+            verbose,
+        }) => {
+            report::handle_report_command(&client, &user, &config, month, output, verbose).await?;
+        }
+        Some(Commands::Leaderboard {
+            week,
+            month,
+            board_id,
+            anonymize,
+            verbose,
+        }) => {
+            leaderboard::handle_leaderboard_command(
+                &client, &config, week, month, board_id, anonymize, verbose,
+            )
+            .await?;
+        }
+        Some(Commands::Journal {
+            date,
+            edit,
+            list,
+            week,
+            verbose,
+        }) => {
+            if list {
+                journal::handle_list(&config, week)?;
+            } else {
+                journal::handle_journal(&client, &user, &config, date, edit, verbose).await?;
+            }
+        }
+        Some(Commands::WeekTemplate { action }) => match action {
+            WeekTemplateCommands::Save {
+                name,
+                from,
+                verbose,
+            } => {
+                week_template::handle_save(
+                    &client,
+                    &user,
+                    &config,
+                    &name,
+                    from.as_deref(),
+                    verbose,
+                )
+                .await?;
+            }
+            WeekTemplateCommands::Apply {
+                name,
+                to,
+                days,
+                yes,
+                verbose,
+            } => {
+                week_template::handle_apply(
+                    &client, &user, &config, &name, &to, days, yes, verbose,
+                )
+                .await?;
+            }
+            WeekTemplateCommands::List => unreachable!("listing templates is handled above"),
+        },
+        Some(Commands::Config { .. })
+        | Some(Commands::JsonSchema)
+        | Some(Commands::Sessions { .. })
+        | Some(Commands::Debug { .. })
+        | Some(Commands::Doctor { .. })
+        | Some(Commands::WhoAmI { .. })
+        | Some(Commands::Init { .. })
+        | Some(Commands::Lock { .. })
+        | Some(Commands::Unlock { .. })
+        | Some(Commands::Completion { .. }) => {
+            unreachable!(
+                "config, sessions, debug, lock, doctor, whoami, init and completion commands are handled above"
+            )
+        }
         None => {
             // Launch interactive UI when no command is provided
             println!("Launching interactive UI...\n");
-            if let Err(e) = interactive::run_interactive().await {
+            if let Err(e) =
+                interactive::run_interactive(false, color_scheme, week_starts_on, no_cache_refresh)
+                    .await
+            {
                 eprintln!("Interactive UI error: {}", e);
                 process::exit(1);
             }
@@ -282,6 +1554,85 @@ async fn run(cli: Cli) -> Result<()> {
     Ok(())
 }
 
+/// Returns the current Monday.com user, served from the config file cache
+/// while it is fresh. The flag is true when the cached user was used.
+pub(crate) async fn load_current_user(
+    client: &MondayClient,
+    config: &mut Config,
+    verbose: bool,
+) -> Result<(monday::MondayUser, bool)> {
+    let now = chrono::Local::now();
+    if let Some(user) = config.cached_user(now) {
+        return Ok((user, true));
+    }
+
+    let user = client.get_current_user_verbose(verbose).await?;
+    config.set_cached_user(&user, now);
+
+    // Refresh the workspace time zone along with the user
+    match client.get_account_timezone(verbose).await {
+        Ok(tz) => config.workspace_timezone = Some(tz),
+        Err(e) => tracing::warn!("Failed to fetch the workspace time zone: {}", e),
+    }
+    if let Err(e) = config.save() {
+        tracing::warn!("Failed to cache the current user: {}", e);
+    }
+    Ok((user, false))
+}
+
+/// Parses the `--week-starts-on` value
+fn parse_week_start_arg(value: &str) -> Result<chrono::Weekday, String> {
+    utils::parse_week_start(value).map_err(|e| e.to_string())
+}
+
+/// Handles `claim debug last-request-id`
+fn print_last_request_id() -> Result<()> {
+    match monday::load_last_request()? {
+        Some(request) => {
+            println!("{}", request.request_id);
+            eprintln!(
+                "Sent {} ({})",
+                request.sent_at.format("%Y-%m-%d %H:%M:%S"),
+                request.operation
+            );
+        }
+        None => println!("No API request has been recorded yet"),
+    }
+    Ok(())
+}
+
+fn handle_config_command(action: ConfigCommands) -> Result<()> {
+    match action {
+        ConfigCommands::SetLogFile { path, level } => config_command::set_log_file(path, level),
+        ConfigCommands::DisableLog => config_command::disable_log(),
+        ConfigCommands::SetRounding { value } => config_command::set_rounding(&value),
+        ConfigCommands::SetMaxCacheEntries { max } => config_command::set_max_cache_entries(max),
+        ConfigCommands::SetActivityLabel { index, label } => {
+            config_command::set_activity_label(index, &label)
+        }
+        ConfigCommands::AddWorkItem { item } => config_command::add_work_item(&item),
+        ConfigCommands::ListWorkItems => config_command::list_work_items(),
+        ConfigCommands::SetWeekStart { day } => config_command::set_week_start(&day),
+        ConfigCommands::SetSlackWebhook { url } => config_command::set_slack_webhook(&url),
+        ConfigCommands::SetPrompt { field, text } => config_command::set_prompt(&field, &text),
+        ConfigCommands::SetPromptLanguage { language } => {
+            config_command::set_prompt_language(&language)
+        }
+        ConfigCommands::Show => config_command::show_config(),
+        ConfigCommands::Set { key, value } => config_command::set_config(&key, &value),
+        ConfigCommands::Reset { yes } => config_command::reset_config(yes),
+        ConfigCommands::Profile { action } => match action {
+            ProfileCommands::Add {
+                name,
+                api_key,
+                board_id,
+                default_customer,
+            } => config_command::add_profile(&name, api_key, board_id, default_customer),
+            ProfileCommands::List => config_command::list_profiles(),
+        },
+    }
+}
+
 // Re-export utility functions for use in other modules
 pub use utils::{
     calculate_working_dates, get_year_group_id, map_activity_type_to_value,
@@ -291,7 +1642,6 @@ pub use utils::{
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Datelike;
 
     #[test]
     fn test_mask_api_key() {
@@ -302,17 +1652,19 @@ mod tests {
 
     #[test]
     fn test_normalize_date() {
-        assert_eq!(normalize_date("2025-09-15"), "2025-09-15");
-        assert_eq!(normalize_date("2025.09.15"), "2025-09-15");
-        assert_eq!(normalize_date("2025/09/15"), "2025-09-15");
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 9, 17).unwrap();
+        assert_eq!(normalize_date("2025-09-15", today), "2025-09-15");
+        assert_eq!(normalize_date("2025.09.15", today), "2025-09-15");
+        assert_eq!(normalize_date("2025/09/15", today), "2025-09-15");
     }
 
     #[test]
     fn test_validate_date() {
-        assert!(validate_date("2025-09-15").is_ok());
-        assert!(validate_date("2025.09.15").is_ok());
-        assert!(validate_date("2025/09/15").is_ok());
-        assert!(validate_date("invalid-date").is_err());
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 9, 17).unwrap();
+        assert!(validate_date("2025-09-15", today).is_ok());
+        assert!(validate_date("2025.09.15", today).is_ok());
+        assert!(validate_date("2025/09/15", today).is_ok());
+        assert!(validate_date("invalid-date", today).is_err());
     }
 
     #[test]
@@ -325,14 +1677,7 @@ mod tests {
     #[test]
     fn test_calculate_working_dates() {
         let start_date = chrono::NaiveDate::from_ymd_opt(2025, 9, 15).unwrap(); // Monday
-        let dates = calculate_working_dates(start_date, 5);
+        let dates = calculate_working_dates(start_date, 5, true);
         assert_eq!(dates.len(), 5);
     }
-
-    #[test]
-    fn test_get_current_year() {
-        let year = utils::get_current_year();
-        let current_year = chrono::Local::now().year();
-        assert_eq!(year, current_year);
-    }
 }
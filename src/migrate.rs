@@ -0,0 +1,178 @@
+//! `claim migrate`: moves entries filed under the wrong year group
+
+use crate::add::extract_date_from_item;
+use crate::monday::{Board, Item, MondayClient};
+use crate::query::extract_column_value;
+use anyhow::{anyhow, Result};
+use chrono::Datelike;
+use std::io;
+
+/// Upper bound on the items read from the source group
+const MAX_MIGRATE_ITEMS: usize = 10_000;
+
+/// Handles `claim migrate --from-year YEAR --to-year YEAR`
+pub async fn handle_migrate_command(
+    client: &MondayClient,
+    board_id: &str,
+    from_year: i32,
+    to_year: i32,
+    dry_run: bool,
+    yes: bool,
+    verbose: bool,
+) -> Result<()> {
+    if from_year == to_year {
+        return Err(anyhow!("--from-year and --to-year must differ"));
+    }
+
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let from_group = find_group(&board, from_year)?;
+    let to_group = find_group(&board, to_year)?;
+
+    println!(
+        "Looking for entries dated {} in group '{}' ({})...",
+        to_year, from_year, from_group
+    );
+    let items = client
+        .query_all_items_in_group(board_id, &from_group, MAX_MIGRATE_ITEMS, verbose)
+        .await?;
+    let to_move = items_dated_in(&items, to_year);
+
+    if to_move.is_empty() {
+        println!(
+            "✅ No entries in group '{}' are dated {}",
+            from_year, to_year
+        );
+        return Ok(());
+    }
+
+    println!(
+        "\n{} of {} entries in group '{}' are dated {}:",
+        to_move.len(),
+        items.len(),
+        from_year,
+        to_year
+    );
+    for item in &to_move {
+        println!(
+            "  {} {:<12} {:<20} {:<15} {}h",
+            item.id.as_deref().unwrap_or("?"),
+            extract_date_from_item(item)
+                .map(|d| d.to_string())
+                .unwrap_or_default(),
+            extract_column_value(item, "text__1"),
+            extract_column_value(item, "text8__1"),
+            extract_column_value(item, "numbers__1")
+        );
+    }
+
+    if dry_run {
+        println!(
+            "\nDry run: nothing was moved. Run without --dry-run to move them to group '{}'.",
+            to_year
+        );
+        return Ok(());
+    }
+
+    if !yes {
+        println!(
+            "\n🚚 Ready to move {} item(s) to group '{}'",
+            to_move.len(),
+            to_year
+        );
+        println!("Do you want to proceed? (y/N)");
+
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+
+        if confirmation.trim().to_lowercase() != "y" {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    }
+
+    let mut moved = 0;
+    for item in &to_move {
+        let Some(item_id) = item.id.as_deref() else {
+            continue;
+        };
+        match client.move_item_to_group(item_id, &to_group, verbose).await {
+            Ok(()) => moved += 1,
+            Err(e) => eprintln!("❌ Failed to move item {}: {}", item_id, e),
+        }
+    }
+
+    println!(
+        "\n✅ Moved {} of {} item(s) to group '{}'",
+        moved,
+        to_move.len(),
+        to_year
+    );
+    Ok(())
+}
+
+/// Id of the group titled `year`; unlike `get_year_group_id` there is no
+/// fallback, since moving items into the wrong group is hard to undo
+fn find_group(board: &Board, year: i32) -> Result<String> {
+    let year = year.to_string();
+    board
+        .groups
+        .as_ref()
+        .and_then(|groups| groups.iter().find(|group| group.title == year))
+        .map(|group| group.id.clone())
+        .ok_or_else(|| anyhow!("The board has no group named '{}'", year))
+}
+
+/// Items whose date column falls in `year`
+fn items_dated_in(items: &[Item], year: i32) -> Vec<&Item> {
+    items
+        .iter()
+        .filter(|item| extract_date_from_item(item).is_some_and(|date| date.year() == year))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monday::{ColumnValue, Group};
+
+    fn item_on(date: &str) -> Item {
+        Item {
+            column_values: vec![ColumnValue {
+                id: Some("date4".to_string()),
+                value: Some(format!(r#"{{"date": "{}"}}"#, date)),
+                text: Some(date.to_string()),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_items_dated_in() {
+        let items = vec![
+            item_on("2024-12-30"),
+            item_on("2025-01-02"),
+            item_on("2025-01-03"),
+            Item::default(),
+        ];
+
+        assert_eq!(items_dated_in(&items, 2025).len(), 2);
+        assert_eq!(items_dated_in(&items, 2024).len(), 1);
+    }
+
+    #[test]
+    fn test_find_group() {
+        let board = Board {
+            id: Some("1".to_string()),
+            name: None,
+            columns: Vec::new(),
+            groups: Some(vec![Group {
+                id: "group_2025".to_string(),
+                title: "2025".to_string(),
+                items_page: None,
+            }]),
+        };
+
+        assert_eq!(find_group(&board, 2025).unwrap(), "group_2025");
+        assert!(find_group(&board, 2024).is_err());
+    }
+}
@@ -1,7 +1,15 @@
+use crate::error::ValidationError;
 use anyhow::{anyhow, Result};
-use reqwest::Client;
+use directories::ProjectDirs;
+use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Target of API errors that are also returned to the caller; they go to the
+/// log file only, since `main` already prints the returned error
+pub const API_ERROR_TARGET: &str = "claim::api_error";
 
 #[derive(Debug, Serialize)]
 struct MondayRequest {
@@ -42,9 +50,36 @@ pub struct Board {
     pub id: Option<String>,
     #[serde(skip_serializing)]
     pub name: Option<String>,
+    /// Columns of the board (id, title and type)
+    #[serde(default, skip_serializing)]
+    pub columns: Vec<BoardColumn>,
     pub groups: Option<Vec<Group>>,
 }
 
+/// One item to create with `MondayClient::batch_create_items`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateItemInput {
+    pub board_id: String,
+    pub group_id: String,
+    pub item_name: String,
+    pub column_values: serde_json::Value,
+}
+
+/// Type and mutation names of the Monday.com GraphQL schema
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SchemaOverview {
+    pub types: Vec<String>,
+    pub mutations: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct BoardColumn {
+    pub id: String,
+    pub title: String,
+    #[serde(rename = "type", default)]
+    pub column_type: String,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct Group {
     #[serde(deserialize_with = "deserialize_string_id")]
@@ -66,6 +101,9 @@ pub struct Item {
     pub id: Option<String>,
     #[serde(default)]
     pub name: Option<String>,
+    /// Last modification time (ISO 8601), when requested in the query
+    #[serde(default)]
+    pub updated_at: Option<String>,
     #[serde(default)]
     pub column_values: Vec<ColumnValue>,
 }
@@ -90,6 +128,55 @@ struct MondayError {
 pub struct MondayClient {
     client: Client,
     api_key: String,
+    retry: RetryPolicy,
+}
+
+/// Wait used for HTTP 429 responses without a `Retry-After` header
+const DEFAULT_RETRY_AFTER_SECS: u64 = 60;
+
+/// How `send_request` retries rate-limited (HTTP 429) and transient server
+/// (HTTP 5xx) responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; 0 disables retrying
+    pub max_retries: u32,
+    /// First wait after a server error, doubled on every further retry
+    pub initial_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Wait before retry number `attempt` (0-based) of a response with
+    /// `status`, or `None` when the response is not retried. Rate limits wait
+    /// for `Retry-After` seconds; server errors back off exponentially.
+    fn delay(
+        &self,
+        status: StatusCode,
+        retry_after: Option<&str>,
+        attempt: u32,
+    ) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let seconds = retry_after
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+            Some(Duration::from_secs(seconds))
+        } else if status.is_server_error() {
+            Some(self.initial_backoff * 2u32.saturating_pow(attempt))
+        } else {
+            None
+        }
+    }
 }
 
 // Custom deserializer to handle both string and integer IDs
@@ -179,6 +266,11 @@ fn parse_item(item_val: &Value) -> Result<Item> {
         item.name = Some(name.to_string());
     }
 
+    // Extract last modification time
+    if let Some(updated_at) = item_val.get("updated_at").and_then(|u| u.as_str()) {
+        item.updated_at = Some(updated_at.to_string());
+    }
+
     // Extract column values
     if let Some(columns_array) = item_val.get("column_values").and_then(|c| c.as_array()) {
         for col_val in columns_array {
@@ -239,7 +331,17 @@ impl MondayClient {
             .build()
             .expect("Failed to build HTTP client with optimized settings");
 
-        MondayClient { client, api_key }
+        MondayClient {
+            client,
+            api_key,
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Replaces the default retry policy for rate limits and server errors
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
     }
 
     pub async fn get_current_user_verbose(&self, verbose: bool) -> Result<MondayUser> {
@@ -292,6 +394,134 @@ impl MondayClient {
             .ok_or_else(|| anyhow!("No user data found in response"))
     }
 
+    /// Returns the time zone of the Monday.com workspace, as configured in
+    /// the current user's profile
+    pub async fn get_account_timezone(&self, verbose: bool) -> Result<chrono_tz::Tz> {
+        let query = r#"
+        {
+            me {
+                time_zone_identifier
+            }
+        }
+        "#;
+
+        if verbose {
+            println!("Sending time zone query:\n{}", query);
+        }
+
+        let response = self
+            .send_request(
+                MondayRequest {
+                    query: query.to_string(),
+                },
+                verbose,
+            )
+            .await?;
+
+        parse_timezone_response(&response)
+    }
+
+    /// Lists the active boards the API key can access, with their columns
+    /// and groups (used by `claim boards` and `claim init`)
+    pub async fn list_user_boards(&self, verbose: bool) -> Result<Vec<Board>> {
+        let query = r#"
+        {
+            boards(limit: 200, state: active) {
+                id
+                name
+                columns {
+                    id
+                    title
+                    type
+                }
+                groups {
+                    id
+                    title
+                }
+            }
+        }
+        "#;
+
+        if verbose {
+            println!("Sending boards query:\n{}", query);
+        }
+
+        let response = self
+            .send_request(
+                MondayRequest {
+                    query: query.to_string(),
+                },
+                verbose,
+            )
+            .await?;
+
+        parse_boards_response(&response)
+    }
+
+    /// Reads the type and mutation names of the GraphQL schema through
+    /// introspection
+    pub async fn get_schema_overview(&self, verbose: bool) -> Result<SchemaOverview> {
+        let query = r#"
+        {
+            __schema {
+                types {
+                    name
+                }
+                mutationType {
+                    fields {
+                        name
+                    }
+                }
+            }
+        }
+        "#;
+
+        if verbose {
+            println!("Sending schema introspection query:\n{}", query);
+        }
+
+        let response = self
+            .send_request(
+                MondayRequest {
+                    query: query.to_string(),
+                },
+                verbose,
+            )
+            .await?;
+
+        parse_schema_response(&response)
+    }
+
+    /// Lists the members of all teams in the account, without duplicates
+    pub async fn get_team_members(&self, verbose: bool) -> Result<Vec<MondayUser>> {
+        let query = r#"
+        {
+            teams {
+                users {
+                    id
+                    name
+                    email
+                }
+            }
+        }
+        "#;
+
+        if verbose {
+            println!("Sending teams query:\n{}", query);
+        }
+
+        let response = self
+            .send_request(
+                MondayRequest {
+                    query: query.to_string(),
+                },
+                verbose,
+            )
+            .await?;
+
+        parse_team_members_response(&response)
+    }
+
     pub async fn query_board_verbose(
         &self,
         board_id: &str,
@@ -311,6 +541,11 @@ impl MondayClient {
             boards(ids: ["{}"]) {{
                 id
                 name
+                columns {{
+                    id
+                    title
+                    type
+                }}
                 groups {{
                     id
                     title
@@ -384,6 +619,7 @@ impl MondayClient {
                         items {{
                             id
                             name
+                            updated_at
                             column_values {{
                                 id
                                 value
@@ -621,6 +857,7 @@ impl MondayClient {
             if let Some(create_item) = data.get("create_item") {
                 if let Some(id) = create_item.get("id") {
                     if let Some(id_str) = id.as_str() {
+                        tracing::info!(item_id = id_str, board_id, group_id, "Created item");
                         return Ok(id_str.to_string());
                     }
                 }
@@ -632,27 +869,65 @@ impl MondayClient {
             Err(anyhow!("No data returned from create item mutation"))
         }
     }
+
+    /// Creates several items with one request of aliased `create_item`
+    /// mutations per `CREATE_BATCH_SIZE` items. Returns one result per item,
+    /// in input order; when a request fails, each of its items gets the error
+    /// and the remaining requests are still sent.
+    pub async fn batch_create_items(
+        &self,
+        items: &[CreateItemInput],
+        verbose: bool,
+    ) -> Vec<Result<String>> {
+        let mut results = Vec::with_capacity(items.len());
+        for chunk in items.chunks(CREATE_BATCH_SIZE) {
+            let query = build_batch_create_mutation(chunk);
+
+            if verbose {
+                println!(
+                    "Sending batch create mutation for {} items:\n{}",
+                    chunk.len(),
+                    query
+                );
+            }
+
+            let chunk_results = match self.send_request(MondayRequest { query }, verbose).await {
+                Ok(response) => {
+                    if verbose {
+                        println!("Batch create response: {}", response);
+                    }
+                    parse_batch_create_response(&response, chunk.len())
+                }
+                Err(e) => Err(e),
+            };
+            match chunk_results {
+                Ok(chunk_results) => {
+                    for (item, id) in chunk.iter().zip(&chunk_results) {
+                        if let Ok(id) = id {
+                            tracing::info!(
+                                item_id = %id,
+                                board_id = %item.board_id,
+                                group_id = %item.group_id,
+                                "Created item"
+                            );
+                        }
+                    }
+                    results.extend(chunk_results);
+                }
+                Err(e) => results.extend(chunk.iter().map(|_| Err(anyhow!("{}", e)))),
+            }
+        }
+        results
+    }
+
     pub async fn update_item_verbose(
         &self,
+        board_id: &str,
         item_id: &str,
         column_values: &serde_json::Value,
         verbose: bool,
     ) -> Result<()> {
-        let query = format!(
-            r#"
-        mutation {{
-            change_multiple_column_values(
-                item_id: {},
-                board_id: "6500270039",
-                column_values: "{}"
-            ) {{
-                id
-            }}
-        }}
-        "#,
-            item_id,
-            column_values.to_string().replace('"', "\\\"")
-        );
+        let query = update_item_mutation(board_id, item_id, column_values);
 
         if verbose {
             println!("Sending update item mutation:\n{}", query);
@@ -683,6 +958,49 @@ impl MondayClient {
             ));
         }
 
+        tracing::info!(item_id, "Updated item");
+        Ok(())
+    }
+
+    /// Sets a single column of an item with `change_column_value`, leaving
+    /// the other columns untouched
+    pub async fn update_item_column(
+        &self,
+        board_id: &str,
+        item_id: &str,
+        column_id: &str,
+        value: &serde_json::Value,
+        verbose: bool,
+    ) -> Result<()> {
+        let query = update_column_mutation(board_id, item_id, column_id, value);
+
+        if verbose {
+            println!("Sending update column mutation:\n{}", query);
+        }
+
+        let request_body = MondayRequest { query };
+        let response = self.send_request(request_body, verbose).await?;
+
+        if verbose {
+            println!("Update column response: {}", response);
+        }
+
+        let monday_response: MondayResponse = serde_json::from_str(&response)
+            .map_err(|e| anyhow!("Failed to parse update column response: {}", e))?;
+
+        if !monday_response.errors.is_empty() {
+            let error_messages: Vec<String> = monday_response
+                .errors
+                .iter()
+                .map(|e| format!("{} (code: {})", e.message, e.error_code))
+                .collect();
+            return Err(anyhow!(
+                "Monday.com API errors: {}",
+                error_messages.join(", ")
+            ));
+        }
+
+        tracing::info!(item_id, column_id, "Updated item column");
         Ok(())
     }
 
@@ -721,6 +1039,7 @@ impl MondayClient {
                                     items {{
                                         id
                                         name
+                                        updated_at
                                         column_values {{
                                             id
                                             value
@@ -745,6 +1064,7 @@ impl MondayClient {
                                     items {{
                                         id
                                         name
+                                        updated_at
                                         column_values {{
                                             id
                                             value
@@ -844,8 +1164,7 @@ impl MondayClient {
             );
         }
 
-        // Monday API rejects items_page limits over 500.
-        let limit = limit.min(500);
+        validate_query_params(board_id, group_id, user_id, dates, limit)?;
 
         // Build query rules dynamically.
         // If no dates are provided, filter only by user (used by TUI cache refresh path).
@@ -884,6 +1203,50 @@ impl MondayClient {
             )
         };
 
+        self.query_items_with_rules(board_id, group_id, limit, &rules, verbose)
+            .await
+    }
+
+    /// Queries the items of a user dated from `since` to `until` (both
+    /// included), filtering the date column on the server so only the items
+    /// logged since the last cache refresh are transferred
+    #[allow(clippy::too_many_arguments)]
+    pub async fn query_items_after_date(
+        &self,
+        board_id: &str,
+        group_id: &str,
+        user_id: i64,
+        since: chrono::NaiveDate,
+        until: chrono::NaiveDate,
+        limit: usize,
+        verbose: bool,
+    ) -> Result<Vec<Item>> {
+        if verbose {
+            println!(
+                "Querying items with server-side filters: user_id={}, dates {} to {}",
+                user_id, since, until
+            );
+        }
+
+        validate_query_params(board_id, group_id, user_id, &[], limit)?;
+        if until < since {
+            return Ok(Vec::new());
+        }
+
+        let rules = date_range_rules(user_id, since, until);
+        self.query_items_with_rules(board_id, group_id, limit, &rules, verbose)
+            .await
+    }
+
+    /// Sends an `items_page` query of one group with the given filter rules
+    async fn query_items_with_rules(
+        &self,
+        board_id: &str,
+        group_id: &str,
+        limit: usize,
+        rules: &str,
+        verbose: bool,
+    ) -> Result<Vec<Item>> {
         // Build the query with server-side filtering
         let query = format!(
             r#"
@@ -903,6 +1266,7 @@ impl MondayClient {
                             items {{
                                 id
                                 name
+                                updated_at
                             column_values {{
                                 id
                                 value
@@ -974,6 +1338,7 @@ impl MondayClient {
             items(ids: ["{}"]) {{
                 id
                 name
+                updated_at
                 column_values {{
                     id
                     value
@@ -1047,19 +1412,66 @@ impl MondayClient {
 
     // NEW METHOD: Delete an item by its ID
     pub async fn delete_item(&self, item_id: &str, verbose: bool) -> Result<String> {
+        let query = delete_item_mutation(item_id);
+
+        if verbose {
+            println!("Sending delete item mutation:\n{}", query);
+        }
+
+        let request_body = MondayRequest {
+            query: query.to_string(),
+        };
+
+        let response = self.send_request(request_body, verbose).await?;
+
+        if verbose {
+            println!("Delete item response: {}", response);
+        }
+
+        let monday_response: MondayResponse = serde_json::from_str(&response)
+            .map_err(|e| anyhow!("Failed to parse delete item response: {}", e))?;
+
+        if !monday_response.errors.is_empty() {
+            let error_messages: Vec<String> = monday_response
+                .errors
+                .iter()
+                .map(|e| format!("{} (code: {})", e.message, e.error_code))
+                .collect();
+            return Err(anyhow!(
+                "Monday.com API errors: {}",
+                error_messages.join(", ")
+            ));
+        }
+
+        // Parse the response to get the deleted item ID
+        if monday_response.data.is_some() {
+            tracing::info!(item_id, "Deleted item");
+            Ok(format!("Item {} deleted successfully", item_id))
+        } else {
+            Err(anyhow!("No data returned from delete item mutation"))
+        }
+    }
+
+    /// Moves an item to another group of its board
+    pub async fn move_item_to_group(
+        &self,
+        item_id: &str,
+        group_id: &str,
+        verbose: bool,
+    ) -> Result<()> {
         let query = format!(
             r#"
         mutation {{
-            delete_item (item_id: {}) {{
+            move_item_to_group (item_id: {}, group_id: "{}") {{
                 id
             }}
         }}
         "#,
-            item_id
+            item_id, group_id
         );
 
         if verbose {
-            println!("Sending delete item mutation:\n{}", query);
+            println!("Sending move item mutation:\n{}", query);
         }
 
         let request_body = MondayRequest {
@@ -1069,11 +1481,11 @@ impl MondayClient {
         let response = self.send_request(request_body, verbose).await?;
 
         if verbose {
-            println!("Delete item response: {}", response);
+            println!("Move item response: {}", response);
         }
 
         let monday_response: MondayResponse = serde_json::from_str(&response)
-            .map_err(|e| anyhow!("Failed to parse delete item response: {}", e))?;
+            .map_err(|e| anyhow!("Failed to parse move item response: {}", e))?;
 
         if !monday_response.errors.is_empty() {
             let error_messages: Vec<String> = monday_response
@@ -1087,11 +1499,11 @@ impl MondayClient {
             ));
         }
 
-        // Parse the response to get the deleted item ID
         if monday_response.data.is_some() {
-            Ok(format!("Item {} deleted successfully", item_id))
+            tracing::info!(item_id, group_id, "Moved item");
+            Ok(())
         } else {
-            Err(anyhow!("No data returned from delete item mutation"))
+            Err(anyhow!("No data returned from move item mutation"))
         }
     }
 
@@ -1100,16 +1512,71 @@ impl MondayClient {
             println!("Sending request to Monday.com API...");
         }
 
-        let response = self
-            .client
-            .post("https://api.monday.com/v2")
-            .header("Authorization", &self.api_key)
-            .header("Content-Type", "application/json")
-            .header("API-Version", "2023-10")
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| anyhow!("Failed to send request to Monday.com: {}", e))?;
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let operation = operation_name(&request_body.query);
+        tracing::debug!(
+            %request_id,
+            %operation,
+            query = %request_body.query,
+            "Sending Monday.com API request"
+        );
+        record_last_request(&LastRequest {
+            request_id: request_id.clone(),
+            operation,
+            sent_at: chrono::Local::now(),
+        });
+
+        let mut attempt = 0;
+        let response = loop {
+            let response = self
+                .client
+                .post("https://api.monday.com/v2")
+                .header("Authorization", &self.api_key)
+                .header("Content-Type", "application/json")
+                .header("API-Version", "2023-10")
+                .header(REQUEST_ID_HEADER, &request_id)
+                .json(&request_body)
+                .send()
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "Failed to send request to Monday.com (request-id: {}): {}",
+                        request_id,
+                        e
+                    )
+                })?;
+
+            let status = response.status();
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok());
+            let Some(mut delay) = self.retry.delay(status, retry_after, attempt) else {
+                break response;
+            };
+            if status.is_server_error() {
+                // Jitter keeps parallel clients from retrying in lockstep
+                delay += delay.mul_f64(rand::random::<f64>() * 0.25);
+            }
+            attempt += 1;
+            tracing::warn!(
+                %status,
+                %request_id,
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                "Retrying Monday.com API request"
+            );
+            if verbose {
+                println!(
+                    "⏳ HTTP {} from Monday.com; retrying in {:.1}s (retry {} of {})",
+                    status,
+                    delay.as_secs_f64(),
+                    attempt,
+                    self.retry.max_retries
+                );
+            }
+            tokio::time::sleep(delay).await;
+        };
 
         if !response.status().is_success() {
             let status = response.status();
@@ -1117,13 +1584,62 @@ impl MondayClient {
                 .text()
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(anyhow!("Monday.com API error ({}): {}", status, error_text));
+            tracing::error!(
+                target: API_ERROR_TARGET,
+                %status,
+                %request_id,
+                "Monday.com API error: {}",
+                error_text
+            );
+            return Err(anyhow!(
+                "API error (request-id: {}, HTTP {}): {}",
+                request_id,
+                status,
+                error_text
+            ));
         }
 
-        response
+        let body = response
             .text()
             .await
-            .map_err(|e| anyhow!("Failed to read response text: {}", e))
+            .map_err(|e| anyhow!("Failed to read response text: {}", e))?;
+
+        tracing::debug!(%request_id, response = %body, "Received Monday.com API response");
+
+        // A request that failed as a whole (no data, only errors) is reported
+        // here with its request id; partial errors are left to the caller
+        if let Some(message) = request_failure(&body) {
+            tracing::error!(target: API_ERROR_TARGET, %request_id, "Monday.com API error: {}", message);
+            return Err(anyhow!(
+                "API error (request-id: {}): {}",
+                request_id,
+                message
+            ));
+        }
+        Ok(body)
+    }
+
+    /// Returns the server time reported in the `Date` header of a lightweight
+    /// API request, or `None` when the header is missing or unparsable
+    pub async fn get_server_time(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+        let response = self
+            .client
+            .post("https://api.monday.com/v2")
+            .header("Authorization", &self.api_key)
+            .header("Content-Type", "application/json")
+            .header("API-Version", "2023-10")
+            .json(&MondayRequest {
+                query: "{ me { id } }".to_string(),
+            })
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to send request to Monday.com: {}", e))?;
+
+        Ok(response
+            .headers()
+            .get(reqwest::header::DATE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_http_date))
     }
 
     pub async fn test_connection_verbose(&self, verbose: bool) -> Result<()> {
@@ -1132,6 +1648,192 @@ impl MondayClient {
     }
 }
 
+/// Header carrying the client-generated id of each API request, to quote
+/// when contacting Monday.com support
+const REQUEST_ID_HEADER: &str = "X-Client-Request-ID";
+
+/// The most recent API request, kept on disk for `claim debug last-request-id`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LastRequest {
+    pub request_id: String,
+    pub operation: String,
+    pub sent_at: chrono::DateTime<chrono::Local>,
+}
+
+fn last_request_path() -> Option<PathBuf> {
+    ProjectDirs::from("com", "yourname", "claim")
+        .map(|proj_dirs| proj_dirs.cache_dir().join("last_request.json"))
+}
+
+/// Saves `request` as the most recent one; failures only affect the debug
+/// command, so they are logged and otherwise ignored
+fn record_last_request(request: &LastRequest) {
+    let Some(path) = last_request_path() else {
+        return;
+    };
+    let result = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::write(&path, serde_json::to_string(request).unwrap_or_default()));
+    if let Err(e) = result {
+        tracing::debug!("Failed to save the last request id: {}", e);
+    }
+}
+
+/// Reads the most recent API request saved by any `claim` command
+pub fn load_last_request() -> Result<Option<LastRequest>> {
+    let path = last_request_path().ok_or_else(|| anyhow!("Could not determine cache directory"))?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let data = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+    Ok(serde_json::from_str(&data).ok())
+}
+
+/// First field selected by a GraphQL query, e.g. "create_item" for
+/// `mutation { create_item(...) { id } }`; aliases are skipped
+fn operation_name(query: &str) -> String {
+    let Some(start) = query.find('{') else {
+        return "unknown".to_string();
+    };
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let rest = query[start + 1..].trim_start();
+    let name: String = rest.chars().take_while(|&c| is_name_char(c)).collect();
+    let after = rest[name.len()..].trim_start();
+    if let Some(aliased) = after.strip_prefix(':') {
+        let field: String = aliased
+            .trim_start()
+            .chars()
+            .take_while(|&c| is_name_char(c))
+            .collect();
+        if !field.is_empty() {
+            return field;
+        }
+    }
+    if name.is_empty() {
+        "unknown".to_string()
+    } else {
+        name
+    }
+}
+
+/// Error messages of a response that carries errors but no data, e.g.
+/// "Complexity budget exhausted (code: COMPLEXITY_BUDGET_EXHAUSTED)"
+fn request_failure(body: &str) -> Option<String> {
+    let json: Value = serde_json::from_str(body).ok()?;
+    if !json["data"].is_null() {
+        return None;
+    }
+
+    let mut messages: Vec<String> = json["errors"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|error| {
+            let message = error["message"].as_str().unwrap_or("Unknown error");
+            match error["extensions"]["code"]
+                .as_str()
+                .or(error["error_code"].as_str())
+            {
+                Some(code) => format!("{} (code: {})", message, code),
+                None => message.to_string(),
+            }
+        })
+        .collect();
+    if let Some(message) = json["error_message"].as_str() {
+        messages.push(match json["error_code"].as_str() {
+            Some(code) => format!("{} (code: {})", message, code),
+            None => message.to_string(),
+        });
+    }
+
+    (!messages.is_empty()).then(|| messages.join(", "))
+}
+
+/// Largest `items_page` limit accepted by the Monday.com API
+pub const MAX_ITEMS_PAGE_LIMIT: usize = 500;
+
+/// Maximum number of `create_item` mutations sent in one batch request
+pub const CREATE_BATCH_SIZE: usize = 50;
+
+/// Filter rules of `query_items_after_date`: items of `user_id` whose
+/// `date4` column is between `since` and `until`
+fn date_range_rules(user_id: i64, since: chrono::NaiveDate, until: chrono::NaiveDate) -> String {
+    format!(
+        r#"
+                                    {{
+                                        column_id: "person"
+                                        compare_value: ["person-{}"]
+                                        operator: any_of
+                                    }},
+                                    {{
+                                        column_id: "date4"
+                                        compare_value: ["{}", "{}"]
+                                        operator: between
+                                    }}
+                "#,
+        user_id,
+        since.format("%Y-%m-%d"),
+        until.format("%Y-%m-%d")
+    )
+}
+
+/// Checks item query parameters before building the GraphQL request, so
+/// malformed input fails early with a clear message instead of an API error
+pub fn validate_query_params(
+    board_id: &str,
+    group_id: &str,
+    user_id: i64,
+    dates: &[String],
+    limit: usize,
+) -> std::result::Result<(), ValidationError> {
+    let invalid = |field: &str, value: String| ValidationError::InvalidFieldValue {
+        field: field.to_string(),
+        value,
+    };
+
+    if board_id.is_empty() || !board_id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid(
+            "board_id",
+            format!("'{}' (expected a numeric id)", board_id),
+        ));
+    }
+
+    if group_id.trim().is_empty() {
+        return Err(ValidationError::MissingField("group_id".to_string()));
+    }
+
+    if user_id <= 0 {
+        return Err(invalid(
+            "user_id",
+            format!("{} (must be positive)", user_id),
+        ));
+    }
+
+    for date in dates {
+        if chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+            return Err(invalid("date", format!("'{}' (expected YYYY-MM-DD)", date)));
+        }
+    }
+
+    if !(1..=MAX_ITEMS_PAGE_LIMIT).contains(&limit) {
+        return Err(invalid(
+            "limit",
+            format!("{} (must be between 1 and {})", limit, MAX_ITEMS_PAGE_LIMIT),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parses an HTTP `Date` header value (RFC 2822 format)
+fn parse_http_date(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc2822(value)
+        .ok()
+        .map(|date| date.with_timezone(&chrono::Utc))
+}
+
 // Helper function to manually parse response if standard parsing fails
 fn manually_parse_response(response: &str) -> Result<MondayResponse, anyhow::Error> {
     let value: Value = serde_json::from_str(response)?;
@@ -1143,6 +1845,7 @@ fn manually_parse_response(response: &str) -> Result<MondayResponse, anyhow::Err
                 let mut board = Board {
                     id: None,
                     name: None,
+                    columns: Vec::new(),
                     groups: None,
                 };
 
@@ -1194,6 +1897,10 @@ fn manually_parse_response(response: &str) -> Result<MondayResponse, anyhow::Err
                                     let mut item = Item {
                                         id: item_id,
                                         name: item_name,
+                                        updated_at: item_val
+                                            .get("updated_at")
+                                            .and_then(|u| u.as_str())
+                                            .map(|s| s.to_string()),
                                         column_values: Vec::new(),
                                     };
 
@@ -1251,7 +1958,264 @@ fn manually_parse_response(response: &str) -> Result<MondayResponse, anyhow::Err
     })
 }
 
+/// Extracts `me.time_zone_identifier` from a time zone query response
+fn parse_timezone_response(response: &str) -> Result<chrono_tz::Tz> {
+    let json: Value = serde_json::from_str(response)
+        .map_err(|e| anyhow!("Failed to parse time zone response: {}", e))?;
+
+    if let Some(errors) = json["errors"].as_array().filter(|e| !e.is_empty()) {
+        let messages: Vec<&str> = errors
+            .iter()
+            .filter_map(|e| e["message"].as_str())
+            .collect();
+        return Err(anyhow!("Monday.com API errors: {}", messages.join(", ")));
+    }
+
+    let name = json["data"]["me"]["time_zone_identifier"]
+        .as_str()
+        .ok_or_else(|| anyhow!("No time zone found in response"))?;
+    name.parse::<chrono_tz::Tz>()
+        .map_err(|e| anyhow!("Unknown time zone '{}': {}", name, e))
+}
+
+fn parse_boards_response(response: &str) -> Result<Vec<Board>> {
+    let mut json: Value = serde_json::from_str(response)
+        .map_err(|e| anyhow!("Failed to parse boards response: {}", e))?;
+
+    if let Some(errors) = json["errors"].as_array().filter(|e| !e.is_empty()) {
+        let messages: Vec<&str> = errors
+            .iter()
+            .filter_map(|e| e["message"].as_str())
+            .collect();
+        return Err(anyhow!("Monday.com API errors: {}", messages.join(", ")));
+    }
+
+    serde_json::from_value(json["data"]["boards"].take())
+        .map_err(|e| anyhow!("Failed to parse boards: {}", e))
+}
+
+/// Parses a `teams { users { ... } }` response into the distinct users,
+/// sorted by name
+fn parse_team_members_response(response: &str) -> Result<Vec<MondayUser>> {
+    let mut json: Value = serde_json::from_str(response)
+        .map_err(|e| anyhow!("Failed to parse teams response: {}", e))?;
+
+    if let Some(errors) = json["errors"].as_array().filter(|e| !e.is_empty()) {
+        let messages: Vec<&str> = errors
+            .iter()
+            .filter_map(|e| e["message"].as_str())
+            .collect();
+        return Err(anyhow!("Monday.com API errors: {}", messages.join(", ")));
+    }
+
+    #[derive(Deserialize)]
+    struct Team {
+        #[serde(default)]
+        users: Vec<MondayUser>,
+    }
+    let teams: Vec<Team> = serde_json::from_value(json["data"]["teams"].take())
+        .map_err(|e| anyhow!("Failed to parse teams: {}", e))?;
+
+    let mut members: Vec<MondayUser> = Vec::new();
+    for user in teams.into_iter().flat_map(|team| team.users) {
+        if !members.iter().any(|m| m.id == user.id) {
+            members.push(user);
+        }
+    }
+    members.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(members)
+}
+
+fn parse_schema_response(response: &str) -> Result<SchemaOverview> {
+    let json: Value = serde_json::from_str(response)
+        .map_err(|e| anyhow!("Failed to parse schema response: {}", e))?;
+
+    if let Some(errors) = json["errors"].as_array().filter(|e| !e.is_empty()) {
+        let messages: Vec<&str> = errors
+            .iter()
+            .filter_map(|e| e["message"].as_str())
+            .collect();
+        return Err(anyhow!("Monday.com API errors: {}", messages.join(", ")));
+    }
+
+    let schema = &json["data"]["__schema"];
+    if schema.is_null() {
+        return Err(anyhow!("The schema response has no __schema data"));
+    }
+    let names = |list: &Value| -> Vec<String> {
+        list.as_array()
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter_map(|entry| entry["name"].as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    Ok(SchemaOverview {
+        types: names(&schema["types"]),
+        mutations: names(&schema["mutationType"]["fields"]),
+    })
+}
+
+/// Alias of the `index`-th mutation in a batch request
+fn batch_alias(index: usize) -> String {
+    format!("create_item_alias_{}", index)
+}
+
+/// Builds a mutation with one aliased `create_item` per item
+fn build_batch_create_mutation(items: &[CreateItemInput]) -> String {
+    let mutations: Vec<String> = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            format!(
+                r#"    {}: create_item(
+        board_id: "{}",
+        group_id: "{}",
+        item_name: "{}",
+        column_values: "{}"
+    ) {{
+        id
+    }}"#,
+                batch_alias(i),
+                item.board_id,
+                item.group_id,
+                item.item_name,
+                item.column_values.to_string().replace('"', "\\\"")
+            )
+        })
+        .collect();
+
+    format!("mutation {{\n{}\n}}", mutations.join("\n"))
+}
+
+/// Builds the `change_multiple_column_values` mutation sent by
+/// `update_item_verbose`
+pub fn update_item_mutation(
+    board_id: &str,
+    item_id: &str,
+    column_values: &serde_json::Value,
+) -> String {
+    format!(
+        r#"
+        mutation {{
+            change_multiple_column_values(
+                item_id: {},
+                board_id: "{}",
+                column_values: "{}"
+            ) {{
+                id
+            }}
+        }}
+        "#,
+        item_id,
+        board_id,
+        column_values.to_string().replace('"', "\\\"")
+    )
+}
+
+/// Builds the `change_column_value` mutation sent by `update_item_column`;
+/// backslashes are escaped as well so text values may contain quotes
+pub fn update_column_mutation(
+    board_id: &str,
+    item_id: &str,
+    column_id: &str,
+    value: &serde_json::Value,
+) -> String {
+    format!(
+        r#"
+        mutation {{
+            change_column_value(
+                item_id: {},
+                board_id: "{}",
+                column_id: "{}",
+                value: "{}"
+            ) {{
+                id
+            }}
+        }}
+        "#,
+        item_id,
+        board_id,
+        column_id,
+        value.to_string().replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+/// Builds the `delete_item` mutation sent by `delete_item`
+pub fn delete_item_mutation(item_id: &str) -> String {
+    format!(
+        r#"
+        mutation {{
+            delete_item (item_id: {}) {{
+                id
+            }}
+        }}
+        "#,
+        item_id
+    )
+}
+
+/// Splits a batch create response into one result per aliased mutation.
+/// Errors carrying a `path` are attributed to that item; errors without one
+/// apply to every item that did not return an ID.
+fn parse_batch_create_response(response: &str, count: usize) -> Result<Vec<Result<String>>> {
+    let json: Value = serde_json::from_str(response)
+        .map_err(|e| anyhow!("Failed to parse batch create response: {}", e))?;
+
+    let mut item_errors: Vec<Vec<String>> = vec![Vec::new(); count];
+    let mut general_errors = Vec::new();
+    for error in json["errors"].as_array().into_iter().flatten() {
+        let message = error["message"]
+            .as_str()
+            .unwrap_or("Unknown error")
+            .to_string();
+        let index = error["path"]
+            .get(0)
+            .and_then(|p| p.as_str())
+            .and_then(|alias| alias.strip_prefix("create_item_alias_"))
+            .and_then(|i| i.parse::<usize>().ok())
+            .filter(|i| *i < count);
+        match index {
+            Some(i) => item_errors[i].push(message),
+            None => general_errors.push(message),
+        }
+    }
+
+    Ok((0..count)
+        .map(|i| {
+            if let Some(id) = json["data"][batch_alias(i)]["id"].as_str() {
+                return Ok(id.to_string());
+            }
+            let messages: Vec<String> = item_errors[i]
+                .iter()
+                .chain(general_errors.iter())
+                .cloned()
+                .collect();
+            if messages.is_empty() {
+                Err(anyhow!("No item ID returned for {}", batch_alias(i)))
+            } else {
+                Err(anyhow!("Monday.com API errors: {}", messages.join(", ")))
+            }
+        })
+        .collect())
+}
+
 // Helper function to filter items by user
+/// The keys of `new` whose values differ from (or are missing in) `old`,
+/// so updates only send the columns that changed
+pub fn diff_column_values(old: &serde_json::Value, new: &serde_json::Value) -> serde_json::Value {
+    let changed: serde_json::Map<String, serde_json::Value> = new
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(key, value)| old.get(key.as_str()) != Some(value))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    serde_json::Value::Object(changed)
+}
+
 pub fn is_user_item(item: &Item, user_id: i64) -> bool {
     for col in &item.column_values {
         if let Some(value) = &col.value {
@@ -1284,6 +2248,118 @@ pub fn is_user_item(item: &Item, user_id: i64) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_diff_column_values() {
+        let old = serde_json::json!({
+            "date4": { "date": "2025-09-15" },
+            "status": { "index": 1 },
+            "text__1": "ACME",
+            "numbers__1": "8",
+        });
+        let new = serde_json::json!({
+            "date4": { "date": "2025-09-15" },
+            "status": { "index": 1 },
+            "text__1": "Globex",
+            "text2__1": "review",
+            "numbers__1": "8",
+        });
+
+        assert_eq!(
+            diff_column_values(&old, &new),
+            serde_json::json!({ "text__1": "Globex", "text2__1": "review" })
+        );
+        assert_eq!(diff_column_values(&new, &new), serde_json::json!({}));
+    }
+
+    fn valid_dates() -> Vec<String> {
+        vec!["2025-09-15".to_string(), "2025-09-16".to_string()]
+    }
+
+    #[test]
+    fn test_validate_query_params_accepts_valid_input() {
+        assert!(validate_query_params("6500270039", "group_1", 42, &valid_dates(), 500).is_ok());
+        assert!(validate_query_params("6500270039", "group_1", 42, &[], 1).is_ok());
+    }
+
+    #[test]
+    fn test_validate_query_params_board_id() {
+        for board_id in ["", "65002x0039", "-1"] {
+            let err = validate_query_params(board_id, "group_1", 42, &[], 10).unwrap_err();
+            assert!(err.to_string().contains("board_id"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_validate_query_params_group_id() {
+        let err = validate_query_params("1", "  ", 42, &[], 10).unwrap_err();
+        assert!(matches!(err, ValidationError::MissingField(ref f) if f == "group_id"));
+    }
+
+    #[test]
+    fn test_validate_query_params_user_id() {
+        for user_id in [0, -5] {
+            let err = validate_query_params("1", "group_1", user_id, &[], 10).unwrap_err();
+            assert!(err.to_string().contains("user_id"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_validate_query_params_dates() {
+        for date in ["2025/09/15", "2025-13-01", "yesterday"] {
+            let dates = vec![date.to_string()];
+            let err = validate_query_params("1", "group_1", 42, &dates, 10).unwrap_err();
+            assert!(err.to_string().contains(date), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_date_range_rules() {
+        let since = chrono::NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+        let until = chrono::NaiveDate::from_ymd_opt(2025, 9, 17).unwrap();
+        let rules: String = date_range_rules(42, since, until)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        assert!(
+            rules.contains(r#"compare_value: ["person-42"]"#),
+            "{}",
+            rules
+        );
+        assert!(
+            rules.contains(
+                r#"column_id: "date4" compare_value: ["2025-09-15", "2025-09-17"] operator: between"#
+            ),
+            "{}",
+            rules
+        );
+    }
+
+    #[test]
+    fn test_validate_query_params_limit() {
+        for limit in [0, 501] {
+            let err = validate_query_params("1", "group_1", 42, &[], limit).unwrap_err();
+            assert!(err.to_string().contains("limit"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_parse_http_date() {
+        let date = parse_http_date("Mon, 15 Sep 2025 10:30:00 GMT").unwrap();
+        assert_eq!(date.to_rfc3339(), "2025-09-15T10:30:00+00:00");
+        assert!(parse_http_date("not a date").is_none());
+    }
+
+    #[test]
+    fn test_parse_item_with_updated_at() {
+        let value: Value = serde_json::from_str(
+            r#"{"id":"1","name":"John","updated_at":"2025-09-15T10:30:00Z","column_values":[]}"#,
+        )
+        .unwrap();
+        let item = parse_item(&value).unwrap();
+        assert_eq!(item.updated_at.as_deref(), Some("2025-09-15T10:30:00Z"));
+    }
+
     #[test]
     fn test_monday_client_new() {
         let client = MondayClient::new("test-key".to_string());
@@ -1345,4 +2421,226 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap().id, "123");
     }
+
+    #[test]
+    fn test_parse_timezone_response() {
+        let response = r#"{"data":{"me":{"time_zone_identifier":"America/New_York"}}}"#;
+        assert_eq!(
+            parse_timezone_response(response).unwrap(),
+            chrono_tz::America::New_York
+        );
+
+        let response = r#"{"data":{"me":{"time_zone_identifier":"Mars/Olympus"}}}"#;
+        assert!(parse_timezone_response(response).is_err());
+
+        let response = r#"{"errors":[{"message":"Not authenticated"}]}"#;
+        let err = parse_timezone_response(response).unwrap_err().to_string();
+        assert!(err.contains("Not authenticated"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_boards_response() {
+        let response = r#"{"data":{"boards":[{"id":"6500270039","name":"Claims","columns":[
+            {"id":"date4","title":"Date","type":"date"},
+            {"id":"numbers__1","title":"Hours","type":"numbers"}],
+            "groups":[{"id":"topics","title":"2025"}]}]}}"#;
+        let boards = parse_boards_response(response).unwrap();
+
+        assert_eq!(boards.len(), 1);
+        assert_eq!(boards[0].id.as_deref(), Some("6500270039"));
+        assert_eq!(boards[0].columns[1].id, "numbers__1");
+        assert_eq!(boards[0].columns[1].column_type, "numbers");
+        assert_eq!(boards[0].groups.as_ref().unwrap()[0].title, "2025");
+
+        let response = r#"{"errors":[{"message":"Not authenticated"}]}"#;
+        assert!(parse_boards_response(response).is_err());
+    }
+
+    #[test]
+    fn test_operation_name() {
+        assert_eq!(operation_name("{ me { id name } }"), "me");
+        assert_eq!(
+            operation_name("mutation { create_item (board_id: 1) { id } }"),
+            "create_item"
+        );
+        assert_eq!(
+            operation_name("mutation {\n item0: create_item(board_id: 1) { id } }"),
+            "create_item"
+        );
+        assert_eq!(
+            operation_name("query { boards(ids: [1]) { id } }"),
+            "boards"
+        );
+        assert_eq!(operation_name(""), "unknown");
+    }
+
+    #[test]
+    fn test_request_failure() {
+        let failed = r#"{"errors":[{"message":"Complexity budget exhausted",
+            "extensions":{"code":"COMPLEXITY_BUDGET_EXHAUSTED"}}],"account_id":1}"#;
+        assert_eq!(
+            request_failure(failed).as_deref(),
+            Some("Complexity budget exhausted (code: COMPLEXITY_BUDGET_EXHAUSTED)")
+        );
+
+        let legacy =
+            r#"{"error_code":"InvalidBoardIdException","error_message":"Board not found"}"#;
+        assert_eq!(
+            request_failure(legacy).as_deref(),
+            Some("Board not found (code: InvalidBoardIdException)")
+        );
+
+        // Partial errors next to data are handled by the caller
+        let partial = r#"{"data":{"item0":{"id":"1"},"item1":null},
+            "errors":[{"message":"Invalid column","path":["item1"]}]}"#;
+        assert!(request_failure(partial).is_none());
+        assert!(request_failure(r#"{"data":{"me":{"id":1}}}"#).is_none());
+    }
+
+    #[test]
+    fn test_parse_team_members_response() {
+        let response = r#"{"data":{"teams":[
+            {"users":[{"id":"2","name":"Sam","email":"sam@example.com"},
+                      {"id":"1","name":"Alex","email":"alex@example.com"}]},
+            {"users":[{"id":"2","name":"Sam","email":"sam@example.com"}]}]}}"#;
+        let members = parse_team_members_response(response).unwrap();
+
+        let names: Vec<&str> = members.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["Alex", "Sam"]);
+        assert_eq!(members[1].id, 2);
+
+        let response = r#"{"errors":[{"message":"Permission denied"}]}"#;
+        assert!(parse_team_members_response(response).is_err());
+    }
+
+    #[test]
+    fn test_parse_schema_response() {
+        let response = r#"{"data":{"__schema":{
+            "types":[{"name":"Board"},{"name":"Item"}],
+            "mutationType":{"fields":[{"name":"create_item"},{"name":"delete_item"}]}}}}"#;
+        let schema = parse_schema_response(response).unwrap();
+
+        assert_eq!(schema.types, ["Board", "Item"]);
+        assert_eq!(schema.mutations, ["create_item", "delete_item"]);
+
+        let response = r#"{"errors":[{"message":"Introspection is disabled"}]}"#;
+        assert!(parse_schema_response(response).is_err());
+        assert!(parse_schema_response(r#"{"data":{}}"#).is_err());
+    }
+
+    #[test]
+    fn test_build_batch_create_mutation() {
+        let item = |group_id: &str, date: &str| CreateItemInput {
+            board_id: "6500270039".to_string(),
+            group_id: group_id.to_string(),
+            item_name: "Ann".to_string(),
+            column_values: serde_json::json!({"date4": {"date": date}}),
+        };
+        let items = vec![item("group_1", "2025-09-15"), item("group_2", "2026-01-02")];
+        let mutation = build_batch_create_mutation(&items);
+
+        assert!(mutation.starts_with("mutation {"));
+        assert!(mutation.contains("create_item_alias_0: create_item("));
+        assert!(mutation.contains("create_item_alias_1: create_item("));
+        assert!(mutation.contains(r#"\"date\":\"2026-01-02\""#));
+        assert!(mutation.contains(r#"group_id: "group_2""#));
+    }
+
+    #[test]
+    fn test_update_and_delete_mutations() {
+        let mutation = update_item_mutation(
+            "6500270039",
+            "12345",
+            &serde_json::json!({"numbers__1": "8"}),
+        );
+        assert!(mutation.contains("change_multiple_column_values("));
+        assert!(mutation.contains("item_id: 12345"));
+        assert!(mutation.contains(r#"column_values: "{\"numbers__1\":\"8\"}""#));
+
+        assert!(delete_item_mutation("12345").contains("delete_item (item_id: 12345)"));
+    }
+
+    #[test]
+    fn test_update_column_mutation_values() {
+        let value_of = |column_id: &str, value: serde_json::Value| {
+            let mutation = update_column_mutation("6500270039", "12345", column_id, &value);
+            assert!(mutation.contains("change_column_value("));
+            assert!(mutation.contains(&format!(r#"column_id: "{}""#, column_id)));
+            let start = mutation.find("value: \"").unwrap() + "value: \"".len();
+            let end = mutation[start..].find("\"\n").unwrap() + start;
+            mutation[start..end].to_string()
+        };
+
+        assert_eq!(
+            value_of("date4", serde_json::json!({"date": "2025-09-15"})),
+            r#"{\"date\":\"2025-09-15\"}"#
+        );
+        assert_eq!(
+            value_of("status", serde_json::json!({"index": 1})),
+            r#"{\"index\":1}"#
+        );
+        assert_eq!(
+            value_of("text__1", serde_json::json!("ACME \"Corp\"")),
+            r#"\"ACME \\\"Corp\\\"\""#
+        );
+        assert_eq!(
+            value_of("numbers__1", serde_json::json!("7.5")),
+            r#"\"7.5\""#
+        );
+    }
+
+    #[test]
+    fn test_parse_batch_create_response() {
+        let response = r#"{
+            "data": {
+                "create_item_alias_0": {"id": "101"},
+                "create_item_alias_1": null,
+                "create_item_alias_2": {"id": "103"}
+            },
+            "errors": [{"message": "Column value invalid", "path": ["create_item_alias_1"]}]
+        }"#;
+        let results = parse_batch_create_response(response, 3).unwrap();
+
+        assert_eq!(results[0].as_ref().unwrap(), "101");
+        let err = results[1].as_ref().unwrap_err().to_string();
+        assert!(err.contains("Column value invalid"), "{}", err);
+        assert_eq!(results[2].as_ref().unwrap(), "103");
+    }
+
+    #[test]
+    fn test_parse_batch_create_response_request_error() {
+        let response = r#"{"errors": [{"message": "Complexity budget exhausted"}]}"#;
+        let results = parse_batch_create_response(response, 2).unwrap();
+
+        assert_eq!(results.len(), 2);
+        for result in results {
+            let err = result.unwrap_err().to_string();
+            assert!(err.contains("Complexity budget exhausted"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_delay() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(
+            policy.delay(StatusCode::TOO_MANY_REQUESTS, Some("5"), 0),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            policy.delay(StatusCode::TOO_MANY_REQUESTS, None, 1),
+            Some(Duration::from_secs(DEFAULT_RETRY_AFTER_SECS))
+        );
+        assert_eq!(
+            policy.delay(StatusCode::BAD_GATEWAY, None, 0),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            policy.delay(StatusCode::SERVICE_UNAVAILABLE, None, 2),
+            Some(Duration::from_secs(4))
+        );
+        assert_eq!(policy.delay(StatusCode::BAD_GATEWAY, None, 3), None);
+        assert_eq!(policy.delay(StatusCode::UNAUTHORIZED, None, 0), None);
+        assert_eq!(policy.delay(StatusCode::OK, None, 0), None);
+    }
 }
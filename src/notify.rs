@@ -0,0 +1,65 @@
+//! Completion notifications for long-running commands (`--notify`)
+//!
+//! With the `desktop-notifications` feature a system notification is sent;
+//! otherwise, or when the notification cannot be shown, the terminal bell is
+//! rung instead.
+
+use std::io::Write;
+
+/// Text of the notification for an operation that succeeded or failed
+fn notification_text(success: bool, message: &str) -> String {
+    let icon = if success { "✅" } else { "❌" };
+    format!("{} claim: {}", icon, message)
+}
+
+/// Notifies the user that an operation finished
+pub fn notify_completion(success: bool, message: &str) {
+    let text = notification_text(success, message);
+    tracing::info!(success, "Completion notification: {}", text);
+
+    if !send_desktop_notification(&text) {
+        ring_bell();
+    }
+}
+
+#[cfg(feature = "desktop-notifications")]
+fn send_desktop_notification(text: &str) -> bool {
+    match notify_rust::Notification::new()
+        .summary("claim")
+        .body(text)
+        .show()
+    {
+        Ok(_) => true,
+        Err(e) => {
+            tracing::warn!("Failed to show desktop notification: {}", e);
+            false
+        }
+    }
+}
+
+#[cfg(not(feature = "desktop-notifications"))]
+fn send_desktop_notification(_text: &str) -> bool {
+    false
+}
+
+fn ring_bell() {
+    print!("\x07");
+    let _ = std::io::stdout().flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_text() {
+        assert_eq!(
+            notification_text(true, "Added 20 entries"),
+            "✅ claim: Added 20 entries"
+        );
+        assert_eq!(
+            notification_text(false, "Add failed — 3 errors"),
+            "❌ claim: Add failed — 3 errors"
+        );
+    }
+}
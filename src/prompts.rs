@@ -0,0 +1,159 @@
+//! Text of the stdin prompts shown by `claim add`, configurable through
+//! `prompts` in the config file
+
+use anyhow::{anyhow, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Prompts of the interactive `claim add` questions; missing fields keep
+/// the built-in English text
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct PromptConfig {
+    pub date_prompt: String,
+    pub activity_type_prompt: String,
+    pub customer_prompt: String,
+    pub work_item_prompt: String,
+    pub comment_prompt: String,
+    pub hours_prompt: String,
+    pub days_prompt: String,
+}
+
+impl Default for PromptConfig {
+    fn default() -> Self {
+        PromptConfig {
+            date_prompt:
+                "Date (YYYY-MM-DD, YYYY.MM.DD, or YYYY/MM/DD, optional - default: today): "
+                    .to_string(),
+            activity_type_prompt:
+                "Activity type (enter number or name, optional - default: billable): ".to_string(),
+            customer_prompt: "Customer name (optional): ".to_string(),
+            work_item_prompt: "Work item (optional): ".to_string(),
+            comment_prompt: "Comment (optional): ".to_string(),
+            hours_prompt: "Number of hours (optional): ".to_string(),
+            days_prompt: "Number of working days (optional, default: 1, skips weekends): "
+                .to_string(),
+        }
+    }
+}
+
+impl PromptConfig {
+    /// Languages with a built-in prompt set
+    pub const LANGUAGES: [&'static str; 3] = ["en", "de", "fr"];
+
+    /// Prompt names accepted by `claim config set-prompt`
+    pub const FIELDS: [&'static str; 7] = [
+        "date",
+        "activity_type",
+        "customer",
+        "work_item",
+        "comment",
+        "hours",
+        "days",
+    ];
+
+    /// Built-in prompt set for `language` ("en", "de" or "fr")
+    pub fn for_language(language: &str) -> Option<Self> {
+        match language.trim().to_lowercase().as_str() {
+            "en" | "english" => Some(PromptConfig::default()),
+            "de" | "german" => Some(PromptConfig {
+                date_prompt:
+                    "Datum (JJJJ-MM-TT, JJJJ.MM.TT oder JJJJ/MM/TT, optional - Standard: heute): "
+                        .to_string(),
+                activity_type_prompt:
+                    "Tätigkeitsart (Nummer oder Name, optional - Standard: billable): "
+                        .to_string(),
+                customer_prompt: "Kundenname (optional): ".to_string(),
+                work_item_prompt: "Arbeitspaket (optional): ".to_string(),
+                comment_prompt: "Kommentar (optional): ".to_string(),
+                hours_prompt: "Anzahl Stunden (optional): ".to_string(),
+                days_prompt: "Anzahl Arbeitstage (optional, Standard: 1, ohne Wochenenden): "
+                    .to_string(),
+            }),
+            "fr" | "french" => Some(PromptConfig {
+                date_prompt:
+                    "Date (AAAA-MM-JJ, AAAA.MM.JJ ou AAAA/MM/JJ, facultatif - par défaut : aujourd'hui) : "
+                        .to_string(),
+                activity_type_prompt:
+                    "Type d'activité (numéro ou nom, facultatif - par défaut : billable) : "
+                        .to_string(),
+                customer_prompt: "Nom du client (facultatif) : ".to_string(),
+                work_item_prompt: "Élément de travail (facultatif) : ".to_string(),
+                comment_prompt: "Commentaire (facultatif) : ".to_string(),
+                hours_prompt: "Nombre d'heures (facultatif) : ".to_string(),
+                days_prompt:
+                    "Nombre de jours ouvrés (facultatif, par défaut : 1, hors week-ends) : "
+                        .to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Sets the prompt `field` (e.g. "customer"); an empty `text` restores
+    /// the built-in English prompt
+    pub fn set(&mut self, field: &str, text: &str) -> Result<()> {
+        let field = field.trim().to_lowercase();
+        let defaults = PromptConfig::default();
+        let (prompt, default) = match field.trim_end_matches("_prompt") {
+            "date" => (&mut self.date_prompt, defaults.date_prompt),
+            "activity_type" => (
+                &mut self.activity_type_prompt,
+                defaults.activity_type_prompt,
+            ),
+            "customer" => (&mut self.customer_prompt, defaults.customer_prompt),
+            "work_item" => (&mut self.work_item_prompt, defaults.work_item_prompt),
+            "comment" => (&mut self.comment_prompt, defaults.comment_prompt),
+            "hours" => (&mut self.hours_prompt, defaults.hours_prompt),
+            "days" => (&mut self.days_prompt, defaults.days_prompt),
+            _ => {
+                return Err(anyhow!(
+                    "Unknown prompt '{}'. Use one of: {}",
+                    field,
+                    Self::FIELDS.join(", ")
+                ))
+            }
+        };
+        *prompt = if text.is_empty() {
+            default
+        } else {
+            text.to_string()
+        };
+        Ok(())
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == PromptConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_languages() {
+        for language in PromptConfig::LANGUAGES {
+            assert!(PromptConfig::for_language(language).is_some());
+        }
+        assert!(PromptConfig::for_language("en").unwrap().is_default());
+        assert_eq!(
+            PromptConfig::for_language("DE").unwrap().customer_prompt,
+            "Kundenname (optional): "
+        );
+        assert!(PromptConfig::for_language("it").is_none());
+    }
+
+    #[test]
+    fn test_set_prompt() {
+        let mut prompts = PromptConfig::default();
+        prompts.set("customer", "Client: ").unwrap();
+        prompts.set("hours_prompt", "Hours: ").unwrap();
+        assert_eq!(prompts.customer_prompt, "Client: ");
+        assert_eq!(prompts.hours_prompt, "Hours: ");
+
+        prompts.set("customer", "").unwrap();
+        prompts.set("hours", "").unwrap();
+        assert!(prompts.is_default());
+        assert!(prompts.set("project", "Project: ").is_err());
+    }
+}
@@ -1,5 +1,9 @@
 use crate::cache::EntryCache;
-use crate::monday::{Item, MondayClient, MondayUser};
+use crate::config::Config;
+use crate::formatters::{CsvFormatter, ExportFormat, OutputFormat, PsqlTableFormatter};
+use crate::monday::{Item, MondayClient, MondayUser, MAX_ITEMS_PAGE_LIMIT};
+use crate::query_pager::{self, PagerRow};
+use crate::utils::{format_relative_time, parse_timestamp};
 use crate::{
     calculate_working_dates, get_year_group_id, map_activity_value_to_name, normalize_date,
     truncate_string, validate_date,
@@ -7,6 +11,7 @@ use crate::{
 use anyhow::Result;
 use chrono::prelude::*;
 use rand::seq::SliceRandom;
+use serde::Serialize;
 use std::io::{self, Write};
 use std::time::{Duration, Instant};
 use tokio::task;
@@ -60,9 +65,33 @@ impl QueryMetrics {
     }
 }
 
+/// Sort order for query results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuerySort {
+    /// Group by entry date (default)
+    Date,
+    /// Most recently modified first
+    Updated,
+}
+
+impl QuerySort {
+    pub fn parse(value: Option<&str>) -> Result<Self> {
+        match value.map(|v| v.trim().to_lowercase()).as_deref() {
+            None | Some("date") => Ok(QuerySort::Date),
+            Some("updated") => Ok(QuerySort::Updated),
+            Some(other) => Err(anyhow::anyhow!(
+                "Invalid sort order: {}. Use 'date' or 'updated'.",
+                other
+            )),
+        }
+    }
+}
+
 // Column ID constants
 const CUSTOMER_COLUMN_ID: &str = "text__1";
 const WORK_ITEM_COLUMN_ID: &str = "text8__1";
+/// Group heading for entries without a customer in `--by-customer` output
+const NO_CUSTOMER_LABEL: &str = "(no customer)";
 
 #[allow(clippy::too_many_arguments)]
 pub async fn handle_query_command(
@@ -73,21 +102,38 @@ pub async fn handle_query_command(
     days: usize,
     customer: Option<String>,  // NEW: Customer filter
     work_item: Option<String>, // NEW: Work item filter
+    sort: Option<String>,
+    include_weekends: bool,
+    last: Option<usize>,
+    format: OutputFormat,
+    output: Option<ExportFormat>,
+    by_customer: bool,
+    compare_with: Option<String>,
+    interactive: bool,
+    config: &Config,
     verbose: bool,
 ) -> Result<()> {
-    let board_id = "6500270039";
+    let board_id = config.board_id.as_str();
+    let sort = QuerySort::parse(sort.as_deref())?;
+
+    if let Some(count) = last {
+        return handle_last_entries(
+            client, user, count, &customer, &work_item, format, output, config, verbose,
+        )
+        .await;
+    }
 
     // Handle date filtering - default to current week + 2 weeks before + 2 weeks after
     let (start_date, target_days) = if let Some(ref date_str) = date {
         // Validate the date format
-        validate_date(date_str)?;
-        let normalized_date = normalize_date(date_str);
+        validate_date(date_str, config.today())?;
+        let normalized_date = normalize_date(date_str, config.today());
         let start_date = chrono::NaiveDate::parse_from_str(&normalized_date, "%Y-%m-%d")?;
         (Some(start_date), days)
     } else {
         // Default to 2 weeks before today for better performance
         // This will query: 2 weeks before + current week + 2 weeks after = ~5 weeks total
-        let today = Local::now().naive_local().date();
+        let today = config.today();
         let start_date = today - chrono::Duration::days(14);
         // Set days to cover the range (14 days before + today + 14 days after = ~29 days)
         // But we'll use 35 to ensure we cover full 5 weeks
@@ -96,11 +142,34 @@ pub async fn handle_query_command(
 
     // Calculate the date range if start date is provided
     let date_range = if let Some(start_date) = start_date {
-        calculate_working_dates(start_date, target_days as i64)
+        calculate_working_dates(start_date, target_days as i64, !include_weekends)
     } else {
         Vec::new()
     };
 
+    if let Some(compare_with) = compare_with {
+        if date_range.len() < 2 {
+            return Err(anyhow::anyhow!(
+                "--compare-with needs a date range (--days 2 or more, or --date-range)"
+            ));
+        }
+        let compare_date = crate::utils::parse_date_or_week(&compare_with, config.today())?;
+        let compare_start = aligned_compare_start(date_range[0], compare_date);
+        let compare_range =
+            calculate_working_dates(compare_start, target_days as i64, !include_weekends);
+        return handle_compare_query(
+            client,
+            user,
+            board_id,
+            &date_range,
+            &compare_range,
+            &customer,
+            &work_item,
+            verbose,
+        )
+        .await;
+    }
+
     if verbose {
         if let Some(_start_date_val) = start_date {
             if target_days > 1 {
@@ -109,12 +178,17 @@ pub async fn handle_query_command(
                     .map(|d| d.format("%Y-%m-%d").to_string())
                     .unwrap_or_default();
                 println!(
-                    "Querying board {} for user '{}' with date range: {} to {} ({} working days)...",
+                    "Querying board {} for user '{}' with date range: {} to {} ({} {})...",
                     board_id,
                     user.name,
                     _start_date_val.format("%Y-%m-%d"),
                     end_date,
-                    target_days
+                    target_days,
+                    if include_weekends {
+                        "days, weekends included"
+                    } else {
+                        "working days"
+                    }
                 );
             } else {
                 println!(
@@ -135,7 +209,7 @@ pub async fn handle_query_command(
         if let Some(ref wi) = work_item {
             println!("Work item filter: {}", wi);
         }
-    } else {
+    } else if output.is_none() {
         // Show brief info even in non-verbose mode
         if let Some(_start_date_val) = start_date {
             if target_days > 1 {
@@ -168,7 +242,7 @@ pub async fn handle_query_command(
     }
 
     // Start the dog walking animation
-    let animation_handle = if !verbose && start_date.is_some() {
+    let animation_handle = if !verbose && output.is_none() && start_date.is_some() {
         Some(start_walking_dog_animation())
     } else {
         None
@@ -179,7 +253,7 @@ pub async fn handle_query_command(
     let mut metrics = QueryMetrics::new();
 
     // Get the current year's group ID - we still need one call to get the group structure
-    let current_year = get_current_year().to_string();
+    let current_year = config.today().year().to_string();
     let board = client.get_board_with_groups(board_id, verbose).await?;
     let group_id = get_year_group_id(&board, &current_year);
 
@@ -217,7 +291,7 @@ pub async fn handle_query_command(
     }
 
     // Apply optional client-side filters that are not enforced by query_items_with_filters.
-    let filtered_items: Vec<Item> = all_items
+    let mut filtered_items: Vec<Item> = all_items
         .into_iter()
         .filter(|item| {
             // Filter by customer if provided
@@ -241,23 +315,8 @@ pub async fn handle_query_command(
         stop_walking_dog_animation(handle).await;
     }
 
-    // Helper function to check if an item matches a filter for a given column
-    fn matches_filter(item: &Item, column_id: &str, filter: &Option<String>) -> bool {
-        if let Some(ref filter_value) = filter {
-            let item_value = extract_column_value(item, column_id);
-            // Explicit empty string check to prevent empty filters from matching empty values
-            if filter_value.is_empty() || item_value.is_empty() {
-                return false;
-            }
-            // Case-insensitive partial match
-            if !item_value
-                .to_lowercase()
-                .contains(&filter_value.to_lowercase())
-            {
-                return false;
-            }
-        }
-        true
+    if sort == QuerySort::Updated {
+        sort_by_updated_desc(&mut filtered_items);
     }
 
     // Determine if we have exact matches
@@ -273,9 +332,66 @@ pub async fn handle_query_command(
     let limited_items: Vec<Item> = filtered_items.iter().take(limit).cloned().collect();
     let filtered_items_len = filtered_items.len();
 
-    // Display the results
-    if !filtered_items.is_empty() {
-        if let Some(_start_date_val) = start_date {
+    // Display the results; JSON and CSV output is printed even when empty
+    if !filtered_items.is_empty() || output.is_some() {
+        if interactive {
+            let mut in_range: Vec<&Item> = filtered_items
+                .iter()
+                .filter(|item| is_item_matching_date_range(item, &date_range))
+                .collect();
+            in_range.sort_by_key(|item| extract_item_date(item));
+            let rows: Vec<PagerRow> = in_range
+                .iter()
+                .map(|item| PagerRow {
+                    date: extract_item_date(item).unwrap_or_default(),
+                    status: extract_status_label(item, config),
+                    customer: extract_column_value(item, CUSTOMER_COLUMN_ID),
+                    work_item: extract_column_value(item, WORK_ITEM_COLUMN_ID),
+                    hours: extract_column_value(item, "numbers__1"),
+                    comment: extract_comment_value(item),
+                })
+                .collect();
+            query_pager::run(&rows, &format!("Claims for {}", user.name))?;
+        } else if by_customer {
+            let mut in_range: Vec<Item> = filtered_items
+                .iter()
+                .filter(|item| {
+                    date_range.is_empty() || is_item_matching_date_range(item, &date_range)
+                })
+                .cloned()
+                .collect();
+            in_range.sort_by_key(extract_item_date);
+            display_by_customer(&in_range, &date_range, &user.name, config);
+        } else if format == OutputFormat::Psql || output.is_some() {
+            // Multi-day queries list every entry in the range, like the summary table
+            let items = if sort != QuerySort::Updated && target_days > 1 {
+                let mut in_range: Vec<Item> = filtered_items
+                    .iter()
+                    .filter(|item| is_item_matching_date_range(item, &date_range))
+                    .cloned()
+                    .collect();
+                in_range.sort_by_key(extract_item_date);
+                in_range
+            } else {
+                limited_items
+            };
+            match output {
+                Some(output) => print!("{}", export_items(&items, config, output)?),
+                None => print!("{}", psql_items_table(&items, config).render()),
+            }
+        } else if sort == QuerySort::Updated {
+            // Sorted by modification time - dates are not grouped
+            display_detailed_items(
+                &limited_items,
+                None,
+                &user.name,
+                filtered_items_len,
+                limit,
+                true,
+                &customer,
+                &work_item,
+            );
+        } else if let Some(_start_date_val) = start_date {
             if target_days > 1 {
                 // Multi-day query - show simplified table
                 let has_filters = customer.is_some() || work_item.is_some();
@@ -283,6 +399,7 @@ pub async fn handle_query_command(
                     &filtered_items,
                     &date_range,
                     &user.name,
+                    config,
                     verbose,
                     has_exact_matches,
                     has_filters,
@@ -346,67 +463,15 @@ pub async fn handle_query_command(
         }
     }
 
-    // Show final message based on results
-    if let Some(query_date) = start_date {
-        if target_days > 1 {
-            let end_date = date_range
-                .last()
-                .map(|d| d.format("%Y-%m-%d").to_string())
-                .unwrap_or_default();
-
-            if has_exact_matches {
-                println!(
-                    "\n✅ Found {} total items matching date range: {} to {}",
-                    filtered_items_len,
-                    query_date.format("%Y-%m-%d"),
-                    end_date
-                );
-            } else if filtered_items_len > 0 {
-                println!(
-                    "\n⚠️  Showing {} items from date range: {} to {}",
-                    filtered_items_len,
-                    query_date.format("%Y-%m-%d"),
-                    end_date
-                );
-            }
-        } else if has_exact_matches {
-            println!(
-                "\n✅ Found {} total items matching date filter: {}",
-                filtered_items_len,
-                query_date.format("%Y-%m-%d")
-            );
-        } else if filtered_items_len > 0 {
-            println!(
-                "\n⚠️  Showing {} items near date: {}",
-                filtered_items_len,
-                query_date.format("%Y-%m-%d")
-            );
-        }
-    }
-
-    // Show final summary message
-    if let Some(query_date) = start_date {
-        if target_days > 1 {
-            let end_date = date_range
-                .last()
-                .map(|d| d.format("%Y-%m-%d").to_string())
-                .unwrap_or_default();
-
-            if has_exact_matches && filtered_items_len > 0 {
-                println!(
-                    "\n✅ Found {} total items matching date range: {} to {}",
-                    filtered_items_len,
-                    query_date.format("%Y-%m-%d"),
-                    end_date
-                );
-            }
-        } else if has_exact_matches && filtered_items_len > 0 {
-            println!(
-                "\n✅ Found {} total items matching date filter: {}",
-                filtered_items_len,
-                query_date.format("%Y-%m-%d")
-            );
-        }
+    if output.is_none() {
+        print_query_summary(
+            &mut io::stdout(),
+            filtered_items_len,
+            start_date,
+            target_days,
+            &date_range,
+            has_exact_matches,
+        )?;
     }
 
     // Persist client-workitem pairs to cache after successful query
@@ -448,6 +513,293 @@ pub async fn handle_query_command(
     Ok(())
 }
 
+/// Handles `claim query --last N`: shows the user's N most recent entries
+/// (by entry date) without requiring a date filter
+#[allow(clippy::too_many_arguments)]
+async fn handle_last_entries(
+    client: &MondayClient,
+    user: &MondayUser,
+    count: usize,
+    customer: &Option<String>,
+    work_item: &Option<String>,
+    format: OutputFormat,
+    output: Option<ExportFormat>,
+    config: &Config,
+    verbose: bool,
+) -> Result<()> {
+    let board_id = config.board_id.as_str();
+    if count == 0 {
+        return Err(anyhow::anyhow!("--last must be at least 1"));
+    }
+
+    if output.is_none() {
+        println!("Querying the last {} entries...", count);
+    }
+
+    let current_year = config.today().year().to_string();
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let group_id = get_year_group_id(&board, &current_year);
+
+    // Monday.com cannot order items by date, so fetch extra items for the
+    // user and sort them client-side
+    let fetch_limit = count.saturating_mul(3).min(MAX_ITEMS_PAGE_LIMIT);
+    let items = client
+        .query_items_with_filters(board_id, &group_id, user.id, &[], fetch_limit, verbose)
+        .await?;
+
+    if verbose {
+        println!("Fetched {} items for user {}", items.len(), user.id);
+    }
+
+    let filtered: Vec<Item> = items
+        .into_iter()
+        .filter(|item| {
+            matches_filter(item, CUSTOMER_COLUMN_ID, customer)
+                && matches_filter(item, WORK_ITEM_COLUMN_ID, work_item)
+        })
+        .collect();
+    let latest = most_recent_items(filtered, count);
+
+    if let Some(output) = output {
+        print!("{}", export_items(&latest, config, output)?);
+        return Ok(());
+    }
+
+    if latest.is_empty() {
+        println!("\nNo items found for user '{}'", user.name);
+        return Ok(());
+    }
+
+    if format == OutputFormat::Psql {
+        print!("{}", psql_items_table(&latest, config).render());
+        return Ok(());
+    }
+
+    display_detailed_items(
+        &latest,
+        None,
+        &user.name,
+        latest.len(),
+        count,
+        true,
+        customer,
+        work_item,
+    );
+    Ok(())
+}
+
+/// Handles `claim query --compare-with DATE`: the primary range and the
+/// comparison range side by side, one row per day
+#[allow(clippy::too_many_arguments)]
+async fn handle_compare_query(
+    client: &MondayClient,
+    user: &MondayUser,
+    board_id: &str,
+    date_range: &[NaiveDate],
+    compare_range: &[NaiveDate],
+    customer: &Option<String>,
+    work_item: &Option<String>,
+    verbose: bool,
+) -> Result<()> {
+    println!(
+        "Comparing {} to {} with {} to {}...",
+        date_range[0],
+        date_range[date_range.len() - 1],
+        compare_range[0],
+        compare_range[compare_range.len() - 1]
+    );
+
+    let primary = fetch_range_items(
+        client, user, board_id, date_range, customer, work_item, verbose,
+    )
+    .await?;
+    let comparison = fetch_range_items(
+        client,
+        user,
+        board_id,
+        compare_range,
+        customer,
+        work_item,
+        verbose,
+    )
+    .await?;
+
+    let primary: Vec<&Item> = primary.iter().collect();
+    let comparison: Vec<&Item> = comparison.iter().collect();
+    display_comparison_table(&primary, &comparison, date_range, compare_range, &user.name);
+    Ok(())
+}
+
+/// Fetches the user's items on the given dates, applying the customer and
+/// work item filters
+async fn fetch_range_items(
+    client: &MondayClient,
+    user: &MondayUser,
+    board_id: &str,
+    date_range: &[NaiveDate],
+    customer: &Option<String>,
+    work_item: &Option<String>,
+    verbose: bool,
+) -> Result<Vec<Item>> {
+    let year = date_range[0].year().to_string();
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let group_id = get_year_group_id(&board, &year);
+
+    let date_strings: Vec<String> = date_range
+        .iter()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect();
+    let items = client
+        .query_items_with_filters(board_id, &group_id, user.id, &date_strings, 500, verbose)
+        .await?;
+
+    Ok(items
+        .into_iter()
+        .filter(|item| {
+            matches_filter(item, CUSTOMER_COLUMN_ID, customer)
+                && matches_filter(item, WORK_ITEM_COLUMN_ID, work_item)
+                && is_item_matching_date_range(item, date_range)
+        })
+        .collect())
+}
+
+/// Start of the comparison range: the day in the week of `compare` with the
+/// same weekday as `primary_start`, so Monday lines up with Monday
+fn aligned_compare_start(primary_start: NaiveDate, compare: NaiveDate) -> NaiveDate {
+    let offset = primary_start.weekday().num_days_from_monday() as i64
+        - compare.weekday().num_days_from_monday() as i64;
+    compare + chrono::Duration::days(offset)
+}
+
+/// First day and number of days of `--month YYYY-MM`: the working days of
+/// the calendar month (every day with `include_weekends`), counted from the
+/// 1st so months starting mid-week are covered up to their last day
+pub(crate) fn month_query_range(month: &str, include_weekends: bool) -> Result<(NaiveDate, usize)> {
+    let first_day = crate::target::parse_month(month)?;
+    let last_day = first_day
+        .checked_add_months(chrono::Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .ok_or_else(|| anyhow::anyhow!("Invalid month: {}", month))?;
+    let days = crate::utils::count_days_in_range(first_day, last_day, !include_weekends);
+    Ok((first_day, days))
+}
+
+/// Hours logged on each date of `date_range`
+fn hours_per_day(items: &[&Item], date_range: &[NaiveDate]) -> Vec<f64> {
+    date_range
+        .iter()
+        .map(|date| {
+            let date_str = date.format("%Y-%m-%d").to_string();
+            items
+                .iter()
+                .filter(|item| extract_item_date(item).as_deref() == Some(date_str.as_str()))
+                .map(|item| item_hours(item))
+                .sum()
+        })
+        .collect()
+}
+
+/// Work items logged on `date`, comma-separated
+fn work_items_on(items: &[&Item], date: NaiveDate) -> String {
+    let date_str = date.format("%Y-%m-%d").to_string();
+    let mut work_items: Vec<String> = items
+        .iter()
+        .filter(|item| extract_item_date(item).as_deref() == Some(date_str.as_str()))
+        .map(|item| extract_column_value(item, WORK_ITEM_COLUMN_ID))
+        .filter(|w| !w.is_empty())
+        .collect();
+    work_items.dedup();
+    work_items.join(", ")
+}
+
+/// e.g. "Total: 40h (primary) vs 35h (comparison), +5h"
+fn comparison_summary(primary: f64, comparison: f64) -> String {
+    format!(
+        "Total: {}h (primary) vs {}h (comparison), {:+}h",
+        primary,
+        comparison,
+        primary - comparison
+    )
+}
+
+/// Prints the primary and comparison days side by side with the difference
+/// in hours, aligned by position in the two ranges
+fn display_comparison_table(
+    primary: &[&Item],
+    comparison: &[&Item],
+    date_range: &[NaiveDate],
+    compare_range: &[NaiveDate],
+    user_name: &str,
+) {
+    println!("\n=== CLAIMS COMPARISON for User {} ===", user_name);
+
+    let primary_hours = hours_per_day(primary, date_range);
+    let compare_hours = hours_per_day(comparison, compare_range);
+
+    println!(
+        "\n{:<4} {:<11} {:>6} {:<18} | {:<11} {:>6} {:<18} | {:>7}",
+        "Day", "Date", "Hours", "Work Items", "Date", "Hours", "Work Items", "Delta"
+    );
+    println!("{}", "-".repeat(95));
+
+    for (i, (date, compare_date)) in date_range.iter().zip(compare_range).enumerate() {
+        let delta = primary_hours[i] - compare_hours[i];
+        println!(
+            "{:<4} {:<11} {:>6.1} {:<18} | {:<11} {:>6.1} {:<18} | {:>7}",
+            date.format("%a"),
+            date.format("%Y-%m-%d"),
+            primary_hours[i],
+            truncate_string(&work_items_on(primary, *date), 18),
+            compare_date.format("%Y-%m-%d"),
+            compare_hours[i],
+            truncate_string(&work_items_on(comparison, *compare_date), 18),
+            format!("{:+.1}h", delta)
+        );
+    }
+    println!("{}", "-".repeat(95));
+
+    let primary_total: f64 = primary_hours.iter().sum();
+    let compare_total: f64 = compare_hours.iter().sum();
+    println!("{}", comparison_summary(primary_total, compare_total));
+}
+
+/// Sorts items by entry date, most recent first, and keeps the first `count`
+/// (items without a date go last)
+fn most_recent_items(mut items: Vec<Item>, count: usize) -> Vec<Item> {
+    items.sort_by_key(|item| std::cmp::Reverse(extract_item_date(item)));
+    items.truncate(count);
+    items
+}
+
+/// Checks if an item matches a filter for a given column
+pub(crate) fn matches_filter(item: &Item, column_id: &str, filter: &Option<String>) -> bool {
+    if let Some(ref filter_value) = filter {
+        let item_value = extract_column_value(item, column_id);
+        // Explicit empty string check to prevent empty filters from matching empty values
+        if filter_value.is_empty() || item_value.is_empty() {
+            return false;
+        }
+        // Case-insensitive partial match
+        if !item_value
+            .to_lowercase()
+            .contains(&filter_value.to_lowercase())
+        {
+            return false;
+        }
+    }
+    true
+}
+
+/// Sorts items by modification time, most recent first (items without a
+/// timestamp go last)
+fn sort_by_updated_desc(items: &mut [Item]) {
+    items.sort_by(|a, b| {
+        let a_time = a.updated_at.as_deref().and_then(parse_timestamp);
+        let b_time = b.updated_at.as_deref().and_then(parse_timestamp);
+        b_time.cmp(&a_time)
+    });
+}
+
 /// Extract date from a Monday.com item
 fn extract_date_from_item(item: &Item) -> Option<NaiveDate> {
     for column in &item.column_values {
@@ -605,7 +957,7 @@ fn map_column_title(column_id: &str) -> &str {
 }
 
 // Helper function to extract specific column value
-fn extract_column_value(item: &Item, column_id: &str) -> String {
+pub(crate) fn extract_column_value(item: &Item, column_id: &str) -> String {
     for col in &item.column_values {
         if let Some(col_id) = &col.id {
             if col_id == column_id {
@@ -644,7 +996,7 @@ fn extract_column_value(item: &Item, column_id: &str) -> String {
 }
 
 // NEW: Helper function to extract comment value from the correct column
-fn extract_comment_value(item: &Item) -> String {
+pub(crate) fn extract_comment_value(item: &Item) -> String {
     for col in &item.column_values {
         if let Some(col_id) = &col.id {
             // FIXED: Use the correct comment column ID "text2__1"
@@ -672,7 +1024,17 @@ fn extract_comment_value(item: &Item) -> String {
 }
 
 // Helper function to extract status value and map it to activity type name
-fn extract_status_value(item: &Item) -> String {
+pub(crate) fn extract_status_value(item: &Item) -> String {
+    extract_status_with(item, map_activity_value_to_name)
+}
+
+/// Extracts the status value as a display label, honoring the configured
+/// `activity_type_labels`
+pub(crate) fn extract_status_label(item: &Item, config: &Config) -> String {
+    extract_status_with(item, |value| config.activity_label(value))
+}
+
+fn extract_status_with(item: &Item, name_of: impl Fn(u8) -> String) -> String {
     for col in &item.column_values {
         if let Some(col_id) = &col.id {
             if col_id == "status" {
@@ -681,7 +1043,7 @@ fn extract_status_value(item: &Item) -> String {
                     if let Ok(parsed_value) = serde_json::from_str::<serde_json::Value>(value) {
                         if let Some(status_index) = parsed_value.get("index") {
                             if let Some(index_num) = status_index.as_u64() {
-                                return map_activity_value_to_name(index_num as u8);
+                                return name_of(index_num as u8);
                             }
                         }
                     }
@@ -703,6 +1065,7 @@ fn display_simplified_table(
     items: &[Item],
     date_range: &[NaiveDate],
     user_name: &str,
+    config: &Config,
     verbose: bool,
     has_exact_matches: bool,
     has_filters: bool,
@@ -767,7 +1130,7 @@ fn display_simplified_table(
             displayed_dates_count += 1;
             displayed_items += date_items.len();
             for item in date_items {
-                let status = extract_status_value(item);
+                let status = extract_status_label(item, config);
                 let customer = extract_column_value(item, CUSTOMER_COLUMN_ID);
                 let work_item = extract_column_value(item, WORK_ITEM_COLUMN_ID);
                 let hours_str = extract_column_value(item, "numbers__1");
@@ -840,6 +1203,247 @@ fn display_simplified_table(
     }
 }
 
+/// Groups items by customer name (alphabetically, entries without a customer
+/// last), keeping the given order within each group
+fn group_by_customer(items: &[Item]) -> Vec<(String, Vec<&Item>)> {
+    let mut groups: std::collections::BTreeMap<(bool, String), Vec<&Item>> =
+        std::collections::BTreeMap::new();
+    for item in items {
+        let customer = extract_column_value(item, CUSTOMER_COLUMN_ID);
+        groups
+            .entry((customer.is_empty(), customer))
+            .or_default()
+            .push(item);
+    }
+
+    groups
+        .into_iter()
+        .map(|((_, customer), items)| {
+            let name = if customer.is_empty() {
+                NO_CUSTOMER_LABEL.to_string()
+            } else {
+                customer
+            };
+            (name, items)
+        })
+        .collect()
+}
+
+/// Hours of an item, 0 when missing or unparsable
+fn item_hours(item: &Item) -> f64 {
+    extract_column_value(item, "numbers__1")
+        .parse::<f64>()
+        .unwrap_or(0.0)
+}
+
+/// Prints `claim query --by-customer`: one section per customer with a
+/// subtotal, followed by the grand total
+fn display_by_customer(items: &[Item], date_range: &[NaiveDate], user_name: &str, config: &Config) {
+    println!("\n=== CLAIMS BY CUSTOMER for User {} ===", user_name);
+    if let (Some(first), Some(last)) = (date_range.first(), date_range.last()) {
+        println!(
+            "Date Range: {} to {}",
+            first.format("%Y-%m-%d"),
+            last.format("%Y-%m-%d")
+        );
+    }
+
+    let mut grand_total = 0.0;
+    for (customer, entries) in group_by_customer(items) {
+        println!("\n=== {} ===", customer);
+        println!(
+            "{:<12} {:<20} {:<15} {:>6}  {:<20}",
+            "Date", "Status", "Work Item", "Hours", "Comment"
+        );
+        println!("{}", "-".repeat(78));
+
+        let mut subtotal = 0.0;
+        for item in entries {
+            let hours = item_hours(item);
+            subtotal += hours;
+            println!(
+                "{:<12} {:<20} {:<15} {:>6.1}  {:<20}",
+                extract_item_date(item).unwrap_or_default(),
+                truncate_string(&extract_status_label(item, config), 20),
+                truncate_string(&extract_column_value(item, WORK_ITEM_COLUMN_ID), 15),
+                hours,
+                truncate_string(&extract_comment_value(item), 20)
+            );
+        }
+
+        println!("{}", "-".repeat(78));
+        println!("{:<49} {:>6.1}", format!("Subtotal {}", customer), subtotal);
+        grand_total += subtotal;
+    }
+
+    println!("\n{}", "=".repeat(78));
+    println!("{:<49} {:>6.1}", "GRAND TOTAL", grand_total);
+}
+
+/// Builds the `--format psql` table of entries, in the order given
+fn psql_items_table(items: &[Item], config: &Config) -> PsqlTableFormatter {
+    let mut table = PsqlTableFormatter::new(&[
+        "date",
+        "status",
+        "customer",
+        "work_item",
+        "hours",
+        "comment",
+    ])
+    .align_right(4);
+
+    for item in items {
+        let hours = extract_column_value(item, "numbers__1")
+            .parse::<f64>()
+            .map(|h| format!("{:.1}", h))
+            .unwrap_or_default();
+        table.add_row(vec![
+            extract_item_date(item).unwrap_or_default(),
+            extract_status_label(item, config),
+            extract_column_value(item, CUSTOMER_COLUMN_ID),
+            extract_column_value(item, WORK_ITEM_COLUMN_ID),
+            hours,
+            extract_comment_value(item),
+        ]);
+    }
+
+    table
+}
+
+/// One entry in `--output json|csv`
+#[derive(Debug, Serialize)]
+struct ExportedItem {
+    id: String,
+    date: String,
+    activity_type: String,
+    customer: String,
+    work_item: String,
+    hours: f64,
+    comment: String,
+}
+
+impl ExportedItem {
+    fn new(item: &Item, config: &Config) -> Self {
+        ExportedItem {
+            id: item.id.clone().unwrap_or_default(),
+            date: extract_item_date(item).unwrap_or_default(),
+            activity_type: extract_status_label(item, config),
+            customer: extract_column_value(item, CUSTOMER_COLUMN_ID),
+            work_item: extract_column_value(item, WORK_ITEM_COLUMN_ID),
+            hours: extract_column_value(item, "numbers__1")
+                .trim_matches('"')
+                .parse()
+                .unwrap_or(0.0),
+            comment: extract_comment_value(item),
+        }
+    }
+}
+
+/// Renders the entries for `--output json|csv` and `claim export`, in the
+/// order given
+pub(crate) fn export_items(
+    items: &[Item],
+    config: &Config,
+    output: ExportFormat,
+) -> Result<String> {
+    let exported: Vec<ExportedItem> = items
+        .iter()
+        .map(|item| ExportedItem::new(item, config))
+        .collect();
+
+    match output {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(&exported)? + "\n"),
+        ExportFormat::Csv => {
+            let mut csv = CsvFormatter::new(&[
+                "id",
+                "date",
+                "activity_type",
+                "customer",
+                "work_item",
+                "hours",
+                "comment",
+            ]);
+            for entry in exported {
+                csv.add_row(vec![
+                    entry.id,
+                    entry.date,
+                    entry.activity_type,
+                    entry.customer,
+                    entry.work_item,
+                    entry.hours.to_string(),
+                    entry.comment,
+                ]);
+            }
+            Ok(csv.render())
+        }
+    }
+}
+
+/// Writes the one-line summary shown after the query results to `out`
+fn print_query_summary(
+    out: &mut dyn Write,
+    filtered_items_len: usize,
+    start_date: Option<NaiveDate>,
+    target_days: usize,
+    date_range: &[NaiveDate],
+    has_exact_matches: bool,
+) -> io::Result<()> {
+    if let Some(message) = query_summary_message(
+        filtered_items_len,
+        start_date,
+        target_days,
+        date_range,
+        has_exact_matches,
+    ) {
+        writeln!(out, "\n{}", message)?;
+    }
+    Ok(())
+}
+
+/// Builds the query summary, or `None` when there is nothing to report
+fn query_summary_message(
+    filtered_items_len: usize,
+    start_date: Option<NaiveDate>,
+    target_days: usize,
+    date_range: &[NaiveDate],
+    has_exact_matches: bool,
+) -> Option<String> {
+    let query_date = start_date?.format("%Y-%m-%d");
+
+    if target_days > 1 {
+        let end_date = date_range
+            .last()
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+
+        if has_exact_matches {
+            Some(format!(
+                "✅ Found {} total items matching date range: {} to {}",
+                filtered_items_len, query_date, end_date
+            ))
+        } else if filtered_items_len > 0 {
+            Some(format!(
+                "⚠️  Showing {} items from date range: {} to {}",
+                filtered_items_len, query_date, end_date
+            ))
+        } else {
+            None
+        }
+    } else if has_exact_matches {
+        Some(format!(
+            "✅ Found {} total items matching date filter: {}",
+            filtered_items_len, query_date
+        ))
+    } else if filtered_items_len > 0 {
+        Some(format!(
+            "⚠️  Showing {} items near date: {}",
+            filtered_items_len, query_date
+        ))
+    } else {
+        None
+    }
+}
+
 // Helper function to display detailed items (original format) - UPDATED to show comments
 #[allow(clippy::too_many_arguments)]
 fn display_detailed_items(
@@ -909,6 +1513,10 @@ fn display_detailed_items(
         let item_id = item.id.as_deref().unwrap_or("Unknown");
         println!("\n{}. {} (ID: {})", index + 1, item_name, item_id);
 
+        if let Some(updated_at) = item.updated_at.as_deref().and_then(parse_timestamp) {
+            println!("   Last modified: {}", format_relative_time(updated_at));
+        }
+
         if !item.column_values.is_empty() {
             println!("   Columns:");
             let max_title_len = item
@@ -961,7 +1569,7 @@ fn display_detailed_items(
 }
 
 // Fixed: Improved date extraction function
-fn extract_item_date(item: &Item) -> Option<String> {
+pub(crate) fn extract_item_date(item: &Item) -> Option<String> {
     for col in &item.column_values {
         if let Some(col_id) = &col.id {
             if col_id == "date4" {
@@ -1009,16 +1617,53 @@ fn extract_item_date(item: &Item) -> Option<String> {
     None
 }
 
-// Helper function to get current year
-fn get_current_year() -> i32 {
-    Local::now().year()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::monday::{ColumnValue, Item};
 
+    #[test]
+    fn test_query_summary_message() {
+        let start = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+        let range = calculate_working_dates(start, 5, true);
+
+        assert_eq!(
+            query_summary_message(3, Some(start), 5, &range, true).unwrap(),
+            "✅ Found 3 total items matching date range: 2025-09-15 to 2025-09-19"
+        );
+        assert_eq!(
+            query_summary_message(2, Some(start), 1, &range[..1], true).unwrap(),
+            "✅ Found 2 total items matching date filter: 2025-09-15"
+        );
+        assert!(query_summary_message(2, Some(start), 1, &range[..1], false)
+            .unwrap()
+            .starts_with("⚠️  Showing 2 items near date"));
+        assert_eq!(
+            query_summary_message(0, Some(start), 5, &range, false),
+            None
+        );
+        assert_eq!(query_summary_message(4, None, 5, &range, true), None);
+    }
+
+    #[test]
+    fn test_print_query_summary_once() {
+        let start = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+        let range = calculate_working_dates(start, 5, true);
+
+        let mut out = Vec::new();
+        print_query_summary(&mut out, 3, Some(start), 5, &range, true).unwrap();
+        let printed = String::from_utf8(out).unwrap();
+        assert_eq!(printed.matches("Found").count(), 1, "{}", printed);
+        assert_eq!(
+            printed,
+            "\n✅ Found 3 total items matching date range: 2025-09-15 to 2025-09-19\n"
+        );
+
+        let mut out = Vec::new();
+        print_query_summary(&mut out, 0, Some(start), 5, &range, false).unwrap();
+        assert!(out.is_empty());
+    }
+
     fn create_test_item_with_date(date: &str) -> Item {
         let mut item = Item::default();
         let date_column = ColumnValue {
@@ -1030,6 +1675,207 @@ mod tests {
         item
     }
 
+    #[test]
+    fn test_group_by_customer() {
+        let item = |customer: &str, hours: &str| Item {
+            column_values: vec![
+                ColumnValue {
+                    id: Some(CUSTOMER_COLUMN_ID.to_string()),
+                    value: Some(format!(r#""{}""#, customer)),
+                    ..Default::default()
+                },
+                ColumnValue {
+                    id: Some("numbers__1".to_string()),
+                    value: Some(format!(r#""{}""#, hours)),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let items = vec![
+            item("Globex", "2"),
+            item("", "8"),
+            item("ACME", "4"),
+            item("Globex", "3.5"),
+        ];
+
+        let groups = group_by_customer(&items);
+        let names: Vec<&str> = groups.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["ACME", "Globex", NO_CUSTOMER_LABEL]);
+
+        let globex: f64 = groups[1].1.iter().map(|i| item_hours(i)).sum();
+        assert_eq!(globex, 5.5);
+    }
+
+    #[test]
+    fn test_psql_items_table() {
+        let mut item = create_test_item_with_date("2025-09-15");
+        for (id, text) in [
+            (CUSTOMER_COLUMN_ID, "ACME"),
+            (WORK_ITEM_COLUMN_ID, "PROJ-123"),
+            ("numbers__1", "8"),
+        ] {
+            item.column_values.push(ColumnValue {
+                id: Some(id.to_string()),
+                value: Some(format!(r#""{}""#, text)),
+                ..Default::default()
+            });
+        }
+
+        let rendered = psql_items_table(&[item], &Config::default()).render();
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert!(lines[0].starts_with(" date       | status"));
+        assert!(lines[2].starts_with(" 2025-09-15 |"));
+        assert!(lines[2].contains("| ACME     | PROJ-123  |   8.0 |"));
+        assert_eq!(lines[3], "(1 row)");
+    }
+
+    #[test]
+    fn test_export_items() {
+        let mut item = create_test_item_with_date("2025-09-15");
+        item.id = Some("42".to_string());
+        for (id, text) in [
+            (CUSTOMER_COLUMN_ID, "ACME, Inc."),
+            (WORK_ITEM_COLUMN_ID, "PROJ-123"),
+            ("numbers__1", "7.5"),
+        ] {
+            item.column_values.push(ColumnValue {
+                id: Some(id.to_string()),
+                value: Some(format!(r#""{}""#, text)),
+                ..Default::default()
+            });
+        }
+        let config = Config::default();
+
+        let json: serde_json::Value = serde_json::from_str(
+            &export_items(&[item.clone()], &config, ExportFormat::Json).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(json[0]["id"], "42");
+        assert_eq!(json[0]["date"], "2025-09-15");
+        assert_eq!(json[0]["customer"], "ACME, Inc.");
+        assert_eq!(json[0]["hours"], 7.5);
+        assert_eq!(json[0]["comment"], "");
+
+        let csv = export_items(&[item], &config, ExportFormat::Csv).unwrap();
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(
+            lines[0],
+            "id,date,activity_type,customer,work_item,hours,comment"
+        );
+        assert!(lines[1].starts_with("42,2025-09-15,"));
+        assert!(lines[1].ends_with(",\"ACME, Inc.\",PROJ-123,7.5,"));
+
+        assert_eq!(
+            export_items(&[], &config, ExportFormat::Json).unwrap(),
+            "[]\n"
+        );
+    }
+
+    #[test]
+    fn test_month_query_range() {
+        let date = |y: i32, m: u32, d: u32| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // September 2025 starts on a Monday, October 2025 on a Wednesday and
+        // February 2026 on a Sunday
+        for (month, first, days) in [
+            ("2025-09", date(2025, 9, 1), 22),
+            ("2025-10", date(2025, 10, 1), 23),
+            ("2026-02", date(2026, 2, 1), 20),
+        ] {
+            let (start, count) = month_query_range(month, false).unwrap();
+            assert_eq!((start, count), (first, days), "{}", month);
+            let dates = calculate_working_dates(start, count as i64, true);
+            assert_eq!(dates.first().unwrap().month(), first.month(), "{}", month);
+            assert_eq!(dates.last().unwrap().month(), first.month(), "{}", month);
+        }
+        let (start, count) = month_query_range("2025-10", false).unwrap();
+        assert_eq!(
+            calculate_working_dates(start, count as i64, true).last(),
+            Some(&date(2025, 10, 31))
+        );
+
+        assert_eq!(
+            month_query_range("2024-02", true).unwrap(),
+            (date(2024, 2, 1), 29)
+        );
+        assert!(month_query_range("2025-13", false).is_err());
+    }
+
+    #[test]
+    fn test_comparison_helpers() {
+        let date = |day: u32| NaiveDate::from_ymd_opt(2025, 9, day).unwrap();
+
+        // Monday 15th compared with Wednesday 10th: starts on Monday 8th
+        assert_eq!(aligned_compare_start(date(15), date(10)), date(8));
+        assert_eq!(aligned_compare_start(date(17), date(8)), date(10));
+
+        let mut item = create_test_item_with_date("2025-09-15");
+        item.column_values.push(ColumnValue {
+            id: Some("numbers__1".to_string()),
+            value: Some(r#""7.5""#.to_string()),
+            ..Default::default()
+        });
+        let items = vec![&item, &item];
+        assert_eq!(
+            hours_per_day(&items, &[date(15), date(16)]),
+            vec![15.0, 0.0]
+        );
+
+        assert_eq!(
+            comparison_summary(40.0, 35.0),
+            "Total: 40h (primary) vs 35h (comparison), +5h"
+        );
+        assert_eq!(
+            comparison_summary(30.0, 32.5),
+            "Total: 30h (primary) vs 32.5h (comparison), -2.5h"
+        );
+    }
+
+    #[test]
+    fn test_query_sort_parse() {
+        assert_eq!(QuerySort::parse(None).unwrap(), QuerySort::Date);
+        assert_eq!(QuerySort::parse(Some("date")).unwrap(), QuerySort::Date);
+        assert_eq!(
+            QuerySort::parse(Some("Updated")).unwrap(),
+            QuerySort::Updated
+        );
+        assert!(QuerySort::parse(Some("customer")).is_err());
+    }
+
+    #[test]
+    fn test_sort_by_updated_desc() {
+        let item = |id: &str, updated_at: Option<&str>| Item {
+            id: Some(id.to_string()),
+            updated_at: updated_at.map(|u| u.to_string()),
+            ..Default::default()
+        };
+        let mut items = vec![
+            item("old", Some("2025-09-01T08:00:00Z")),
+            item("none", None),
+            item("new", Some("2025-09-15T08:00:00Z")),
+        ];
+
+        sort_by_updated_desc(&mut items);
+        let ids: Vec<_> = items.iter().map(|i| i.id.as_deref().unwrap()).collect();
+        assert_eq!(ids, vec!["new", "old", "none"]);
+    }
+
+    #[test]
+    fn test_most_recent_items() {
+        let items = vec![
+            create_test_item_with_date("2025-09-10"),
+            Item::default(),
+            create_test_item_with_date("2025-09-15"),
+            create_test_item_with_date("2025-09-12"),
+        ];
+
+        let latest = most_recent_items(items, 2);
+        let dates: Vec<_> = latest.iter().filter_map(extract_item_date).collect();
+        assert_eq!(dates, vec!["2025-09-15", "2025-09-12"]);
+    }
+
     #[test]
     fn test_is_item_matching_date() {
         let item = create_test_item_with_date("2025-09-15");
@@ -1162,6 +2008,7 @@ mod tests {
             &empty_items,
             &empty_date_range,
             "test_user",
+            &Config::default(),
             false,
             true,
             false,
@@ -0,0 +1,240 @@
+//! `claim query --interactive`: a scrollable view of the query results with
+//! a search-as-you-type filter
+
+use crate::interactive::{EventHandler, TerminalCleanup};
+use crate::truncate_string;
+use anyhow::{anyhow, Result};
+use crossterm::event::KeyCode;
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Terminal,
+};
+use std::io::{self, IsTerminal};
+
+/// One query result as shown by the pager
+#[derive(Debug, Clone, PartialEq)]
+pub struct PagerRow {
+    pub date: String,
+    pub status: String,
+    pub customer: String,
+    pub work_item: String,
+    pub hours: String,
+    pub comment: String,
+}
+
+/// Rows whose customer, work item or comment contains `search` (ignoring case)
+fn filter_rows<'a>(rows: &'a [PagerRow], search: &str) -> Vec<&'a PagerRow> {
+    let search = search.to_lowercase();
+    rows.iter()
+        .filter(|row| {
+            search.is_empty()
+                || [&row.customer, &row.work_item, &row.comment]
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&search))
+        })
+        .collect()
+}
+
+/// Shows `rows` until `q` is pressed, then prints the rows left by the filter
+pub fn run(rows: &[PagerRow], title: &str) -> Result<()> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err(anyhow!(
+            "--interactive requires a TTY. Run the query without it to print the results."
+        ));
+    }
+
+    let mut cleanup = TerminalCleanup::activate()?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    let events = EventHandler::new();
+
+    let mut search = String::new();
+    let mut searching = false;
+    let mut state = TableState::default();
+    state.select(if rows.is_empty() { None } else { Some(0) });
+
+    loop {
+        let visible = filter_rows(rows, &search);
+        match state.selected() {
+            Some(idx) if idx >= visible.len() => {
+                state.select(visible.len().checked_sub(1));
+            }
+            None if !visible.is_empty() => state.select(Some(0)),
+            _ => {}
+        }
+
+        terminal.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(5), Constraint::Length(3)])
+                .split(f.size());
+
+            let table_rows: Vec<Row> = visible
+                .iter()
+                .map(|row| {
+                    Row::new(vec![
+                        Cell::from(row.date.clone()),
+                        Cell::from(row.status.clone()),
+                        Cell::from(row.customer.clone()),
+                        Cell::from(row.work_item.clone()),
+                        Cell::from(row.hours.clone()),
+                        Cell::from(row.comment.clone()),
+                    ])
+                })
+                .collect();
+            let table = Table::new(
+                table_rows,
+                [
+                    Constraint::Length(11),
+                    Constraint::Length(12),
+                    Constraint::Length(20),
+                    Constraint::Length(15),
+                    Constraint::Length(6),
+                    Constraint::Min(10),
+                ],
+            )
+            .header(
+                Row::new(vec![
+                    "Date",
+                    "Status",
+                    "Customer",
+                    "Work Item",
+                    "Hours",
+                    "Comment",
+                ])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+            )
+            .block(
+                Block::default()
+                    .title(format!(" {} - {} of {} ", title, visible.len(), rows.len()))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+            f.render_stateful_widget(table, chunks[0], &mut state);
+
+            let footer = if searching {
+                Line::from(vec![
+                    Span::styled("/", Style::default().fg(Color::Yellow)),
+                    Span::raw(search.clone()),
+                    Span::styled("█", Style::default().fg(Color::Yellow)),
+                    Span::styled(
+                        "   [Enter] Keep filter  [Esc] Clear",
+                        Style::default().fg(Color::Gray),
+                    ),
+                ])
+            } else {
+                Line::from(Span::styled(
+                    format!(
+                        "[↑↓/PgUp/PgDn] Scroll  [/] Search customer, work item, comment{}  [q] Quit",
+                        if search.is_empty() {
+                            String::new()
+                        } else {
+                            format!(" (\"{}\")", search)
+                        }
+                    ),
+                    Style::default().fg(Color::Gray),
+                ))
+            };
+            f.render_widget(
+                Paragraph::new(footer).block(Block::default().borders(Borders::ALL)),
+                chunks[1],
+            );
+        })?;
+
+        let Some(event) = events.next()? else {
+            continue;
+        };
+        let last = visible.len().saturating_sub(1);
+        let selected = state.selected().unwrap_or(0);
+
+        if searching {
+            match event.code {
+                KeyCode::Esc => {
+                    search.clear();
+                    searching = false;
+                }
+                KeyCode::Enter => searching = false,
+                KeyCode::Backspace => {
+                    search.pop();
+                }
+                KeyCode::Char(c) => search.push(c),
+                _ => {}
+            }
+            continue;
+        }
+
+        match event.code {
+            KeyCode::Char('q') | KeyCode::Esc => break,
+            KeyCode::Char('/') => searching = true,
+            KeyCode::Up => state.select(Some(selected.saturating_sub(1))),
+            KeyCode::Down => state.select(Some((selected + 1).min(last))),
+            KeyCode::PageUp => state.select(Some(selected.saturating_sub(10))),
+            KeyCode::PageDown => state.select(Some((selected + 10).min(last))),
+            KeyCode::Home => state.select(Some(0)),
+            KeyCode::End => state.select(Some(last)),
+            _ => {}
+        }
+    }
+
+    terminal.show_cursor()?;
+    cleanup.restore()?;
+
+    let visible = filter_rows(rows, &search);
+    println!("\n=== {} ===", title);
+    if !search.is_empty() {
+        println!("Filter: \"{}\"", search);
+    }
+    println!(
+        "\n{:<12} {:<12} {:<20} {:<15} {:<6} {:<20}",
+        "Date", "Status", "Customer", "Work Item", "Hours", "Comment"
+    );
+    println!("{}", "-".repeat(90));
+    for row in &visible {
+        println!(
+            "{:<12} {:<12} {:<20} {:<15} {:<6} {:<20}",
+            row.date,
+            truncate_string(&row.status, 10),
+            truncate_string(&row.customer, 18),
+            truncate_string(&row.work_item, 13),
+            row.hours,
+            truncate_string(&row.comment, 18)
+        );
+    }
+    println!("\n{} of {} entries shown", visible.len(), rows.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(customer: &str, work_item: &str, comment: &str) -> PagerRow {
+        PagerRow {
+            date: "2025-09-15".to_string(),
+            status: "billable".to_string(),
+            customer: customer.to_string(),
+            work_item: work_item.to_string(),
+            hours: "4".to_string(),
+            comment: comment.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_filter_rows() {
+        let rows = vec![
+            row("ACME", "PROJ-123", ""),
+            row("Globex", "GLX-42", "sprint review"),
+            row("Initech", "TPS-7", ""),
+        ];
+
+        assert_eq!(filter_rows(&rows, "").len(), 3);
+        assert_eq!(filter_rows(&rows, "acme"), vec![&rows[0]]);
+        assert_eq!(filter_rows(&rows, "glx"), vec![&rows[1]]);
+        assert_eq!(filter_rows(&rows, "REVIEW"), vec![&rows[1]]);
+        assert!(filter_rows(&rows, "hooli").is_empty());
+    }
+}
@@ -0,0 +1,289 @@
+//! `claim recalculate`: converts the hours of existing entries when the
+//! length of a standard working day changes
+
+use crate::add::extract_date_from_item;
+use crate::config::Config;
+use crate::monday::{is_user_item, Item, MondayClient, MondayUser};
+use crate::query::extract_column_value;
+use crate::utils::{self, get_year_group_id};
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, NaiveDate, Weekday};
+use clap::ValueEnum;
+use std::io;
+
+/// What `claim recalculate` derives from what
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Direction {
+    /// Keep the number of days of each entry and update its hours
+    HoursFromDays,
+    /// Show how many days the logged hours amount to (nothing is updated)
+    DaysFromHours,
+}
+
+/// An entry whose hours change
+#[derive(Debug, Clone, PartialEq)]
+struct Recalculated {
+    item_id: String,
+    date: NaiveDate,
+    label: String,
+    old_hours: f64,
+    new_hours: f64,
+}
+
+/// Handles `claim recalculate (--date DATE | --week DATE) --hours-per-day N`
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_recalculate_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    date: Option<String>,
+    week: Option<String>,
+    hours_per_day: f64,
+    direction: Direction,
+    yes: bool,
+    verbose: bool,
+) -> Result<()> {
+    if !(hours_per_day > 0.0 && hours_per_day <= 24.0) {
+        return Err(anyhow!(
+            "--hours-per-day must be between 0 and 24, got {}",
+            hours_per_day
+        ));
+    }
+
+    let dates: Vec<NaiveDate> = match (date, week) {
+        (Some(date), _) => vec![utils::parse_date_or_week(&date, config.today())?],
+        (None, Some(week)) => {
+            let monday = utils::get_week_start(
+                utils::parse_date_or_week(&week, config.today())?,
+                Weekday::Mon,
+            );
+            (0..7)
+                .map(|offset| monday + chrono::Duration::days(offset))
+                .collect()
+        }
+        (None, None) => return Err(anyhow!("Pass --date or --week")),
+    };
+    let items = fetch_user_items(client, user, &config.board_id, &dates, verbose).await?;
+
+    if direction == Direction::DaysFromHours {
+        display_days(&items, hours_per_day);
+        return Ok(());
+    }
+
+    let old_hours_per_day = config.hours_per_day();
+    if old_hours_per_day == hours_per_day {
+        println!(
+            "✅ Working days are already {}h (hours_per_day); nothing to recalculate",
+            hours_per_day
+        );
+        return Ok(());
+    }
+
+    let (changes, locked): (Vec<_>, Vec<_>) =
+        recalculated_entries(&items, old_hours_per_day, hours_per_day)
+            .into_iter()
+            .partition(|change| !config.is_week_locked(change.date));
+    for change in &locked {
+        eprintln!(
+            "⚠️  Skipping {} on {}: the week is locked",
+            change.item_id, change.date
+        );
+    }
+    if changes.is_empty() {
+        println!("✅ No entries to recalculate");
+        return Ok(());
+    }
+
+    println!(
+        "\nRecalculating {} entr{} from {}h to {}h working days:",
+        changes.len(),
+        if changes.len() == 1 { "y" } else { "ies" },
+        old_hours_per_day,
+        hours_per_day
+    );
+    for change in &changes {
+        println!(
+            "  {} {:<12} {:<35} {:>6}h → {}h",
+            change.item_id, change.date, change.label, change.old_hours, change.new_hours
+        );
+    }
+
+    if !yes {
+        println!("\nDo you want to proceed? (y/N)");
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+        if confirmation.trim().to_lowercase() != "y" {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    }
+
+    let mut updated = 0;
+    for change in &changes {
+        let column_values = serde_json::json!({ "numbers__1": change.new_hours.to_string() });
+        match client
+            .update_item_verbose(&config.board_id, &change.item_id, &column_values, verbose)
+            .await
+        {
+            Ok(()) => updated += 1,
+            Err(e) => eprintln!("❌ Failed to update item {}: {}", change.item_id, e),
+        }
+    }
+
+    println!(
+        "\n✅ Updated {} of {} entr{}",
+        updated,
+        changes.len(),
+        if changes.len() == 1 { "y" } else { "ies" }
+    );
+    Ok(())
+}
+
+/// The user's entries dated on one of `dates`
+async fn fetch_user_items(
+    client: &MondayClient,
+    user: &MondayUser,
+    board_id: &str,
+    dates: &[NaiveDate],
+    verbose: bool,
+) -> Result<Vec<Item>> {
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let group_id = get_year_group_id(&board, &dates[0].year().to_string());
+    let date_strings: Vec<String> = dates
+        .iter()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect();
+
+    Ok(client
+        .query_items_with_filters(board_id, &group_id, user.id, &date_strings, 500, verbose)
+        .await?
+        .into_iter()
+        .filter(|item| is_user_item(item, user.id))
+        .filter(|item| extract_date_from_item(item).is_some_and(|d| dates.contains(&d)))
+        .collect())
+}
+
+fn item_hours(item: &Item) -> f64 {
+    extract_column_value(item, "numbers__1")
+        .trim_matches('"')
+        .parse::<f64>()
+        .unwrap_or(0.0)
+}
+
+fn item_label(item: &Item) -> String {
+    format!(
+        "{} / {}",
+        extract_column_value(item, "text__1"),
+        extract_column_value(item, "text8__1")
+    )
+}
+
+/// Hours of an entry once a working day changes from `old_hours_per_day`
+/// to `new_hours_per_day`, keeping the number of days (rounded to 0.01h)
+fn rescale_hours(hours: f64, old_hours_per_day: f64, new_hours_per_day: f64) -> f64 {
+    (hours / old_hours_per_day * new_hours_per_day * 100.0).round() / 100.0
+}
+
+/// Entries whose hours change, sorted by date
+fn recalculated_entries(
+    items: &[Item],
+    old_hours_per_day: f64,
+    new_hours_per_day: f64,
+) -> Vec<Recalculated> {
+    let mut changes: Vec<Recalculated> = items
+        .iter()
+        .filter_map(|item| {
+            let old_hours = item_hours(item);
+            let new_hours = rescale_hours(old_hours, old_hours_per_day, new_hours_per_day);
+            if new_hours == old_hours {
+                return None;
+            }
+            Some(Recalculated {
+                item_id: item.id.clone()?,
+                date: extract_date_from_item(item)?,
+                label: item_label(item),
+                old_hours,
+                new_hours,
+            })
+        })
+        .collect();
+    changes.sort_by_key(|change| change.date);
+    changes
+}
+
+/// Prints the days each entry amounts to; the board stores hours only, so
+/// nothing is written
+fn display_days(items: &[Item], hours_per_day: f64) {
+    println!("\n=== DAYS at {}h per working day ===", hours_per_day);
+    if items.is_empty() {
+        println!("No entries found");
+        return;
+    }
+
+    let mut total_days = 0.0;
+    for item in items {
+        let hours = item_hours(item);
+        let days = hours / hours_per_day;
+        total_days += days;
+        println!(
+            "{:<12} {:<35} {:>6}h = {:.2} day(s)",
+            extract_date_from_item(item)
+                .map(|d| d.to_string())
+                .unwrap_or_default(),
+            item_label(item),
+            hours,
+            days
+        );
+    }
+    println!("Total: {:.2} day(s)", total_days);
+    println!("The board stores hours only, so no entries were changed.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monday::ColumnValue;
+
+    fn entry(id: &str, date: &str, hours: &str) -> Item {
+        Item {
+            id: Some(id.to_string()),
+            column_values: vec![
+                ColumnValue {
+                    id: Some("date4".to_string()),
+                    value: Some(format!(r#"{{"date": "{}"}}"#, date)),
+                    text: Some(date.to_string()),
+                },
+                ColumnValue {
+                    id: Some("numbers__1".to_string()),
+                    value: Some(format!("\"{}\"", hours)),
+                    text: Some(hours.to_string()),
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rescale_hours() {
+        assert_eq!(rescale_hours(7.5, 7.5, 8.0), 8.0);
+        assert_eq!(rescale_hours(3.75, 7.5, 8.0), 4.0);
+        assert_eq!(rescale_hours(8.0, 8.0, 7.5), 7.5);
+        assert_eq!(rescale_hours(1.0, 7.5, 8.0), 1.07);
+    }
+
+    #[test]
+    fn test_recalculated_entries() {
+        let items = vec![
+            entry("2", "2025-09-16", "7.5"),
+            entry("1", "2025-09-15", "3.75"),
+            entry("3", "2025-09-17", "0"),
+        ];
+
+        let changes = recalculated_entries(&items, 7.5, 8.0);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].item_id, "1");
+        assert_eq!(changes[0].new_hours, 4.0);
+        assert_eq!(changes[1].old_hours, 7.5);
+        assert_eq!(changes[1].new_hours, 8.0);
+    }
+}
@@ -0,0 +1,402 @@
+//! `claim report`: billable hours by customer and work item and non-billable
+//! hours by activity type for every day of a month
+
+use crate::config::Config;
+use crate::export::query_user_items_in_range;
+use crate::formatters::{CsvFormatter, ExportFormat};
+use crate::interactive::app::{report_hours, ClaimEntry};
+use crate::monday::{MondayClient, MondayUser};
+use crate::target::parse_month;
+use crate::truncate_string;
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Serialize;
+
+/// Width of the label column of the table
+const LABEL_WIDTH: usize = 30;
+
+/// Hours of one row of the report, one value per day of the month
+#[derive(Debug, Clone, PartialEq)]
+struct ReportRow {
+    label: String,
+    hours: Vec<f64>,
+}
+
+/// The two sections of the monthly report
+#[derive(Debug, Clone, PartialEq)]
+struct MonthlyReport {
+    dates: Vec<NaiveDate>,
+    /// One row per customer and work item
+    billable: Vec<ReportRow>,
+    /// One row per activity type
+    non_billable: Vec<ReportRow>,
+}
+
+/// One line of `--output csv|json`: the hours of a day for an activity
+/// type, customer and work item
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct ReportRecord {
+    section: &'static str,
+    date: String,
+    activity_type: String,
+    customer: String,
+    work_item: String,
+    hours: f64,
+}
+
+/// Handles `claim report [--month YYYY-MM] [-o csv|json]`
+pub async fn handle_report_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    month: Option<String>,
+    output: Option<ExportFormat>,
+    verbose: bool,
+) -> Result<()> {
+    let dates = report_dates(config.today(), month.as_deref())?;
+    let (first, last) = (dates[0], dates[dates.len() - 1]);
+
+    if output.is_none() {
+        println!("Building the report for {}...", first.format("%Y-%m"));
+    }
+    let items = query_user_items_in_range(client, config, user, first, last, verbose).await?;
+    let entries: Vec<ClaimEntry> = items.iter().filter_map(ClaimEntry::from_item).collect();
+
+    let records = report_records(&entries, &dates, config);
+    match output {
+        Some(ExportFormat::Csv) => print!("{}", records_csv(&records)),
+        Some(ExportFormat::Json) => println!("{}", serde_json::to_string_pretty(&records)?),
+        None => print!(
+            "{}",
+            render_report(&build_report(&entries, &dates, config), first)
+        ),
+    }
+    Ok(())
+}
+
+/// Every day of `month` (YYYY-MM), the previous calendar month by default
+fn report_dates(today: NaiveDate, month: Option<&str>) -> Result<Vec<NaiveDate>> {
+    let first = match month {
+        Some(month) => parse_month(month)?,
+        None => {
+            let last_month = today.with_day(1).unwrap_or(today) - Duration::days(1);
+            last_month.with_day(1).unwrap_or(last_month)
+        }
+    };
+    let next = first
+        .checked_add_months(chrono::Months::new(1))
+        .ok_or_else(|| anyhow!("Invalid month: {}", first.format("%Y-%m")))?;
+    Ok(first.iter_days().take_while(|d| *d < next).collect())
+}
+
+/// Groups the hours like the interactive report view: billable rows by
+/// customer and work item, non-billable rows by activity type
+fn build_report(entries: &[ClaimEntry], dates: &[NaiveDate], config: &Config) -> MonthlyReport {
+    let mut billable: Vec<((String, String), Vec<f64>)> = Vec::new();
+    let mut non_billable: Vec<(i32, Vec<f64>)> = Vec::new();
+    for ((activity_value, customer, work_item), hours) in report_hours(entries, dates) {
+        if activity_value == 1 {
+            billable.push(((customer, work_item), hours));
+        } else {
+            match non_billable
+                .iter_mut()
+                .find(|(value, _)| *value == activity_value)
+            {
+                Some((_, total)) => total.iter_mut().zip(&hours).for_each(|(t, h)| *t += h),
+                None => non_billable.push((activity_value, hours)),
+            }
+        }
+    }
+    billable.sort_by(|a, b| a.0.cmp(&b.0));
+    non_billable.sort_by_key(|(value, _)| *value);
+
+    MonthlyReport {
+        dates: dates.to_vec(),
+        billable: billable
+            .into_iter()
+            .map(|((customer, work_item), hours)| ReportRow {
+                label: row_label(&customer, &work_item),
+                hours,
+            })
+            .collect(),
+        non_billable: non_billable
+            .into_iter()
+            .map(|(value, hours)| ReportRow {
+                label: config.activity_label(value as u8),
+                hours,
+            })
+            .collect(),
+    }
+}
+
+/// "WORK_ITEM - CUSTOMER", as in the report view, or whichever is set
+fn row_label(customer: &str, work_item: &str) -> String {
+    match (work_item.is_empty(), customer.is_empty()) {
+        (false, false) => format!("{} - {}", work_item, customer),
+        (false, true) => work_item.to_string(),
+        _ => customer.to_string(),
+    }
+}
+
+/// Sum of `rows` for each day
+fn day_totals(rows: &[ReportRow], days: usize) -> Vec<f64> {
+    let mut totals = vec![0.0; days];
+    for row in rows {
+        for (total, hours) in totals.iter_mut().zip(&row.hours) {
+            *total += hours;
+        }
+    }
+    totals
+}
+
+/// Hours without a decimal part when whole, blank when zero
+fn format_cell(hours: f64) -> String {
+    if hours == 0.0 {
+        String::new()
+    } else if hours % 1.0 == 0.0 {
+        format!("{:.0}", hours)
+    } else {
+        format!("{:.1}", hours)
+    }
+}
+
+fn render_line(label: &str, hours: &[f64]) -> String {
+    let mut line = format!("{:<LABEL_WIDTH$}", truncate_string(label, LABEL_WIDTH));
+    for day in hours {
+        line.push_str(&format!(" {:>4}", format_cell(*day)));
+    }
+    let total: f64 = hours.iter().sum();
+    line.push_str(&format!(" {:>7.1}\n", total));
+    line
+}
+
+/// The report as text: a header with the day of the month and weekday, the
+/// two sections with their totals and the overall total
+fn render_report(report: &MonthlyReport, first: NaiveDate) -> String {
+    let days = report.dates.len();
+    let width = LABEL_WIDTH + days * 5 + 8;
+    let rule = "-".repeat(width) + "\n";
+
+    let mut header = format!("{:<LABEL_WIDTH$}", "");
+    let mut weekdays = format!("{:<LABEL_WIDTH$}", "");
+    for date in &report.dates {
+        header.push_str(&format!(" {:>4}", date.format("%d")));
+        weekdays.push_str(&format!(" {:>4}", &date.weekday().to_string()[..2]));
+    }
+    header.push_str(&format!(" {:>7}\n", "Total"));
+    weekdays.push('\n');
+
+    let mut out = format!("\n=== REPORT for {} ===\n", first.format("%B %Y"));
+    let sections = [
+        (
+            "Billable Hours",
+            "Work Item / Customer",
+            "Billable total",
+            &report.billable,
+        ),
+        (
+            "Non-Billable Hours",
+            "Activity Type",
+            "Non-billable total",
+            &report.non_billable,
+        ),
+    ];
+    let mut overall = vec![0.0; days];
+    for (title, column, total_label, rows) in sections {
+        out.push_str(&format!("\n{}\n{}", title, header));
+        out.push_str(&format!(
+            "{:<LABEL_WIDTH$}{}",
+            column,
+            &weekdays[LABEL_WIDTH..]
+        ));
+        out.push_str(&rule);
+        if rows.is_empty() {
+            out.push_str("No entries\n");
+        }
+        for row in rows.iter() {
+            out.push_str(&render_line(&row.label, &row.hours));
+        }
+        let totals = day_totals(rows, days);
+        out.push_str(&rule);
+        out.push_str(&render_line(total_label, &totals));
+        for (sum, hours) in overall.iter_mut().zip(&totals) {
+            *sum += hours;
+        }
+    }
+    out.push('\n');
+    out.push_str(&rule);
+    out.push_str(&render_line("TOTAL", &overall));
+    out
+}
+
+/// One record per day with hours for each activity type, customer and work
+/// item, billable first, for pivot tables
+fn report_records(
+    entries: &[ClaimEntry],
+    dates: &[NaiveDate],
+    config: &Config,
+) -> Vec<ReportRecord> {
+    let mut groups: Vec<_> = report_hours(entries, dates).into_iter().collect();
+    groups.sort_by(
+        |((a_value, a_customer, a_wi), _), ((b_value, b_customer, b_wi), _)| {
+            (*a_value != 1)
+                .cmp(&(*b_value != 1))
+                .then(a_value.cmp(b_value))
+                .then_with(|| a_customer.cmp(b_customer))
+                .then_with(|| a_wi.cmp(b_wi))
+        },
+    );
+
+    let mut records = Vec::new();
+    for ((activity_value, customer, work_item), hours) in groups {
+        for (date, hours) in dates.iter().zip(hours) {
+            if hours == 0.0 {
+                continue;
+            }
+            records.push(ReportRecord {
+                section: if activity_value == 1 {
+                    "billable"
+                } else {
+                    "non-billable"
+                },
+                date: date.format("%Y-%m-%d").to_string(),
+                activity_type: config.activity_label(activity_value as u8),
+                customer: customer.clone(),
+                work_item: work_item.clone(),
+                hours,
+            });
+        }
+    }
+    records
+}
+
+fn records_csv(records: &[ReportRecord]) -> String {
+    let mut csv = CsvFormatter::new(&[
+        "section",
+        "date",
+        "activity_type",
+        "customer",
+        "work_item",
+        "hours",
+    ]);
+    for record in records {
+        csv.add_row(vec![
+            record.section.to_string(),
+            record.date.clone(),
+            record.activity_type.clone(),
+            record.customer.clone(),
+            record.work_item.clone(),
+            record.hours.to_string(),
+        ]);
+    }
+    csv.render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn entry(day: u32, activity_value: i32, customer: &str, hours: f64) -> ClaimEntry {
+        ClaimEntry {
+            id: String::new(),
+            date: date(9, day),
+            activity_type: utils::map_activity_value_to_name(activity_value as u8),
+            activity_value,
+            customer: customer.to_string(),
+            work_item: if activity_value == 1 { "WI-1" } else { "" }.to_string(),
+            hours,
+            comment: None,
+            updated_at: None,
+        }
+    }
+
+    fn entries() -> Vec<ClaimEntry> {
+        vec![
+            entry(1, 1, "Globex", 4.0),
+            entry(1, 1, "ACME", 4.0),
+            entry(2, 1, "ACME", 7.5),
+            entry(2, 2, "Internal", 0.5),
+            entry(3, 2, "", 8.0),
+            entry(4, 0, "", 8.0),
+        ]
+    }
+
+    #[test]
+    fn test_report_dates() {
+        let dates = report_dates(date(10, 16), None).unwrap();
+        assert_eq!((dates[0], dates.len()), (date(9, 1), 30));
+
+        let dates = report_dates(date(1, 5), None).unwrap();
+        assert_eq!(dates[0], NaiveDate::from_ymd_opt(2024, 12, 1).unwrap());
+        assert_eq!(dates.len(), 31);
+
+        let dates = report_dates(date(10, 16), Some("2024-02")).unwrap();
+        assert_eq!(dates.len(), 29);
+        assert!(report_dates(date(10, 16), Some("2024-13")).is_err());
+    }
+
+    #[test]
+    fn test_build_report() {
+        let config = Config::default();
+        let dates = report_dates(date(10, 16), None).unwrap();
+        let report = build_report(&entries(), &dates, &config);
+
+        let billable: Vec<(&str, f64)> = report
+            .billable
+            .iter()
+            .map(|row| (row.label.as_str(), row.hours.iter().sum::<f64>()))
+            .collect();
+        assert_eq!(billable, [("WI-1 - ACME", 11.5), ("WI-1 - Globex", 4.0)]);
+        assert_eq!(report.billable[0].hours[..3], [4.0, 7.5, 0.0]);
+
+        let non_billable: Vec<(String, f64)> = report
+            .non_billable
+            .iter()
+            .map(|row| (row.label.clone(), row.hours.iter().sum::<f64>()))
+            .collect();
+        assert_eq!(
+            non_billable,
+            [
+                (config.activity_label(0), 8.0),
+                (config.activity_label(2), 8.5)
+            ]
+        );
+
+        let text = render_report(&report, dates[0]);
+        assert!(text.contains("=== REPORT for September 2025 ==="));
+        assert!(text.contains("Non-billable total"));
+        assert!(text.lines().last().unwrap().trim_end().ends_with("32.0"));
+    }
+
+    #[test]
+    fn test_report_records() {
+        let config = Config::default();
+        let dates = report_dates(date(10, 16), None).unwrap();
+        let records = report_records(&entries(), &dates, &config);
+
+        assert_eq!(records.len(), 6);
+        assert_eq!(records[0].section, "billable");
+        assert_eq!(
+            (records[0].customer.as_str(), records[0].date.as_str()),
+            ("ACME", "2025-09-01")
+        );
+        assert_eq!(records[5].section, "non-billable");
+
+        let csv = records_csv(&records);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("section,date,activity_type,customer,work_item,hours")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("billable,2025-09-01,billable,ACME,WI-1,4")
+        );
+        assert_eq!(lines.count(), 5);
+    }
+}
@@ -0,0 +1,142 @@
+//! Session log of the interactive UI: one JSON line per session, appended
+//! on exit when `session_log` is set in the config file
+
+use crate::config::Config;
+use crate::utils;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+/// One interactive UI session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub start: DateTime<Local>,
+    pub end: DateTime<Local>,
+    pub entries_added: usize,
+    pub entries_edited: usize,
+    pub entries_deleted: usize,
+    /// ISO week the session started in (YYYY-Www)
+    pub week: String,
+}
+
+/// Appends `record` as one line to the session log at `path`
+pub fn append_session(path: &Path, record: &SessionRecord) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| anyhow!("Failed to open session log {}: {}", path.display(), e))?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+    Ok(())
+}
+
+/// Reads the session log, skipping lines that are not valid records
+fn read_sessions(path: &Path) -> Result<Vec<SessionRecord>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let data = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read session log {}: {}", path.display(), e))?;
+    Ok(data
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Sessions that started on or after `since`
+fn sessions_since(sessions: Vec<SessionRecord>, since: Option<NaiveDate>) -> Vec<SessionRecord> {
+    sessions
+        .into_iter()
+        .filter(|s| since.is_none_or(|since| s.start.date_naive() >= since))
+        .collect()
+}
+
+/// Handles `claim sessions list`
+pub fn handle_list(since: Option<String>) -> Result<()> {
+    let config = Config::load()
+        .map_err(|e| anyhow!("{}. Run `claim` once to set up your API key first.", e))?;
+    let today = config.today();
+    let path = config.session_log.ok_or_else(|| {
+        anyhow!("Session logging is off. Set `session_log` in the config file to a file path.")
+    })?;
+    let since = since
+        .map(|s| utils::parse_date_or_week(&s, today))
+        .transpose()?;
+
+    let sessions = sessions_since(read_sessions(&path)?, since);
+    if sessions.is_empty() {
+        println!("No sessions recorded in {}", path.display());
+        return Ok(());
+    }
+
+    println!(
+        "{:<17} {:>8} {:>6} {:>7} {:>8}  Week",
+        "Start", "Duration", "Added", "Edited", "Deleted"
+    );
+    for s in &sessions {
+        let minutes = (s.end - s.start).num_minutes();
+        println!(
+            "{:<17} {:>8} {:>6} {:>7} {:>8}  {}",
+            s.start.format("%Y-%m-%d %H:%M"),
+            format!("{}h{:02}m", minutes / 60, minutes % 60),
+            s.entries_added,
+            s.entries_edited,
+            s.entries_deleted,
+            s.week
+        );
+    }
+
+    let (added, edited, deleted) = sessions.iter().fold((0, 0, 0), |acc, s| {
+        (
+            acc.0 + s.entries_added,
+            acc.1 + s.entries_edited,
+            acc.2 + s.entries_deleted,
+        )
+    });
+    println!(
+        "\n{} session(s): {} added, {} edited, {} deleted",
+        sessions.len(),
+        added,
+        edited,
+        deleted
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn record(day: u32) -> SessionRecord {
+        SessionRecord {
+            start: Local.with_ymd_and_hms(2025, 9, day, 9, 0, 0).unwrap(),
+            end: Local.with_ymd_and_hms(2025, 9, day, 9, 30, 0).unwrap(),
+            entries_added: 2,
+            entries_edited: 1,
+            entries_deleted: 0,
+            week: "2025-W37".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_session_log_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("sessions.jsonl");
+
+        append_session(&path, &record(8)).unwrap();
+        append_session(&path, &record(10)).unwrap();
+
+        let sessions = read_sessions(&path).unwrap();
+        assert_eq!(sessions, vec![record(8), record(10)]);
+
+        let since = NaiveDate::from_ymd_opt(2025, 9, 9);
+        assert_eq!(sessions_since(sessions, since), vec![record(10)]);
+    }
+}
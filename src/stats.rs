@@ -0,0 +1,351 @@
+//! `claim stats`: statistics about logged time, such as the streak of
+//! consecutive working days with complete entries.
+
+use crate::config::Config;
+use crate::formatters::{OutputFormat, PsqlTableFormatter};
+use crate::monday::{Item, MondayClient, MondayUser};
+use crate::target::sum_hours_by_date;
+use crate::{calculate_working_dates, get_year_group_id, utils};
+use anyhow::Result;
+use chrono::prelude::*;
+use std::collections::HashMap;
+
+/// Number of weeks shown in the per-week streak chart
+const STREAK_CHART_WEEKS: i64 = 12;
+
+/// Upper bound on the items fetched for a year of entries
+const MAX_YEAR_ITEMS: usize = 10_000;
+
+/// Handles `claim stats`
+pub async fn handle_stats_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    streak: bool,
+    date_range: Option<(NaiveDate, NaiveDate)>,
+    format: OutputFormat,
+    verbose: bool,
+) -> Result<()> {
+    if !streak {
+        println!("Choose a statistic to show:");
+        println!("  --streak   Consecutive working days with complete entries");
+        return Ok(());
+    }
+
+    let board_id = config.board_id.as_str();
+    let today = config.today();
+    let daily_target = config.weekly_target_hours() / 5.0;
+
+    // Without a range, the longest streak is searched in the current year
+    let (range_start, range_end) =
+        date_range.unwrap_or_else(|| (NaiveDate::from_ymd_opt(today.year(), 1, 1).unwrap(), today));
+
+    let year = range_end.year().to_string();
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let group_id = get_year_group_id(&board, &year);
+
+    let items = client
+        .query_all_items_in_group(board_id, &group_id, MAX_YEAR_ITEMS, verbose)
+        .await?;
+    let user_items: Vec<Item> = items
+        .into_iter()
+        .filter(|item| is_user_item(item, user.id))
+        .collect();
+
+    if verbose {
+        println!("Fetched {} items for user {}", user_items.len(), user.id);
+    }
+
+    let hours_by_date = sum_hours_by_date(&user_items);
+
+    let (current, since) = current_streak(&hours_by_date, daily_target, today);
+    let longest = longest_streak(&hours_by_date, daily_target, range_start, range_end);
+    let weeks = weekly_streaks(&hours_by_date, daily_target, today);
+
+    let longest_label = match date_range {
+        Some((start, end)) => format!(
+            "from {} to {}",
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        ),
+        None => "this year".to_string(),
+    };
+    display_streak(
+        user,
+        daily_target,
+        current,
+        since,
+        longest,
+        &longest_label,
+        &weeks,
+        format,
+    );
+    Ok(())
+}
+
+/// Returns true when the item's person column contains `user_id`
+fn is_user_item(item: &Item, user_id: i64) -> bool {
+    item.column_values
+        .iter()
+        .filter(|col| col.id.as_deref() == Some("person"))
+        .filter_map(|col| col.value.as_deref())
+        .filter_map(|value| serde_json::from_str::<serde_json::Value>(value).ok())
+        .any(|value| {
+            value["personsAndTeams"]
+                .as_array()
+                .map(|people| people.iter().any(|p| p["id"].as_i64() == Some(user_id)))
+                .unwrap_or(false)
+        })
+}
+
+fn is_weekday(date: NaiveDate) -> bool {
+    date.weekday().num_days_from_monday() < 5
+}
+
+fn is_complete(
+    hours_by_date: &HashMap<NaiveDate, f64>,
+    date: NaiveDate,
+    daily_target: f64,
+) -> bool {
+    hours_by_date.get(&date).copied().unwrap_or(0.0) >= daily_target
+}
+
+/// Counts consecutive complete working days walking backward from `today`.
+/// Today is skipped while still incomplete, since the day is not over yet.
+/// Returns the streak length and the first day of the streak.
+fn current_streak(
+    hours_by_date: &HashMap<NaiveDate, f64>,
+    daily_target: f64,
+    today: NaiveDate,
+) -> (usize, Option<NaiveDate>) {
+    let mut day = today;
+    if !is_weekday(day) || !is_complete(hours_by_date, day, daily_target) {
+        day = previous_working_day(day);
+    }
+
+    let mut length = 0;
+    let mut since = None;
+    while is_complete(hours_by_date, day, daily_target) {
+        length += 1;
+        since = Some(day);
+        day = previous_working_day(day);
+    }
+
+    (length, since)
+}
+
+fn previous_working_day(date: NaiveDate) -> NaiveDate {
+    let mut day = date - chrono::Duration::days(1);
+    while !is_weekday(day) {
+        day -= chrono::Duration::days(1);
+    }
+    day
+}
+
+/// Longest run of consecutive complete working days between `from` and `to`
+fn longest_streak(
+    hours_by_date: &HashMap<NaiveDate, f64>,
+    daily_target: f64,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> usize {
+    longest_run(
+        from.iter_days()
+            .take_while(|d| *d <= to)
+            .filter(|d| is_weekday(*d))
+            .map(|d| is_complete(hours_by_date, d, daily_target)),
+    )
+}
+
+fn longest_run(days: impl Iterator<Item = bool>) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    for complete in days {
+        run = if complete { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    longest
+}
+
+/// Longest streak within each of the last `STREAK_CHART_WEEKS` weeks (oldest
+/// first), keyed by the week's Monday
+fn weekly_streaks(
+    hours_by_date: &HashMap<NaiveDate, f64>,
+    daily_target: f64,
+    today: NaiveDate,
+) -> Vec<(NaiveDate, usize)> {
+    let this_monday = utils::get_week_start(today, Weekday::Mon);
+
+    (0..STREAK_CHART_WEEKS)
+        .rev()
+        .map(|weeks_ago| {
+            let monday = this_monday - chrono::Duration::weeks(weeks_ago);
+            let days = calculate_working_dates(monday, 5, true);
+            let streak = longest_run(
+                days.into_iter()
+                    .take_while(|d| *d <= today)
+                    .map(|d| is_complete(hours_by_date, d, daily_target)),
+            );
+            (monday, streak)
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn display_streak(
+    user: &MondayUser,
+    daily_target: f64,
+    current: usize,
+    since: Option<NaiveDate>,
+    longest: usize,
+    longest_label: &str,
+    weeks: &[(NaiveDate, usize)],
+    format: OutputFormat,
+) {
+    let day_word = |n: usize| if n == 1 { "day" } else { "days" };
+
+    println!("\n=== STREAK for User {} ===", user.name);
+    println!(
+        "A day is complete with at least {:.1}h logged\n",
+        daily_target
+    );
+
+    match since {
+        Some(start) => println!(
+            "Current streak: {} {} (since {})",
+            current,
+            day_word(current),
+            start.format("%a %b %-d")
+        ),
+        None => println!("Current streak: 0 days"),
+    }
+    println!(
+        "Longest streak {}: {} {}",
+        longest_label,
+        longest,
+        day_word(longest)
+    );
+
+    println!("\nLongest streak per week (last {} weeks):", weeks.len());
+    if format == OutputFormat::Psql {
+        print!("{}", psql_weeks_table(weeks).render());
+        return;
+    }
+    for (monday, streak) in weeks {
+        println!(
+            "  W{:02} ({})  {:<20} {}",
+            monday.iso_week().week(),
+            monday.format("%b %d"),
+            "████".repeat(*streak),
+            streak
+        );
+    }
+}
+
+/// Builds the `--format psql` table of per-week streaks
+fn psql_weeks_table(weeks: &[(NaiveDate, usize)]) -> PsqlTableFormatter {
+    let mut table = PsqlTableFormatter::new(&["week", "monday", "streak"]).align_right(2);
+    for (monday, streak) in weeks {
+        table.add_row(vec![
+            format!("W{:02}", monday.iso_week().week()),
+            monday.format("%Y-%m-%d").to_string(),
+            streak.to_string(),
+        ]);
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monday::ColumnValue;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn hours(days: &[(u32, u32, f64)]) -> HashMap<NaiveDate, f64> {
+        days.iter().map(|(m, d, h)| (date(*m, *d), *h)).collect()
+    }
+
+    #[test]
+    fn test_current_streak_spans_weekend() {
+        // Thu Sep 4 .. Wed Sep 10 complete, Wed Sep 3 short
+        let logged = hours(&[
+            (9, 3, 4.0),
+            (9, 4, 8.0),
+            (9, 5, 8.0),
+            (9, 8, 8.0),
+            (9, 9, 8.0),
+            (9, 10, 8.0),
+        ]);
+
+        let (length, since) = current_streak(&logged, 8.0, date(9, 10));
+        assert_eq!(length, 5);
+        assert_eq!(since, Some(date(9, 4)));
+
+        // An unfinished today does not break the streak
+        let (length, _) = current_streak(&logged, 8.0, date(9, 11));
+        assert_eq!(length, 5);
+
+        // Counting from a weekend starts at Friday
+        let (length, _) = current_streak(&logged, 8.0, date(9, 7));
+        assert_eq!(length, 2);
+    }
+
+    #[test]
+    fn test_current_streak_empty() {
+        let (length, since) = current_streak(&HashMap::new(), 8.0, date(9, 10));
+        assert_eq!(length, 0);
+        assert_eq!(since, None);
+    }
+
+    #[test]
+    fn test_longest_and_weekly_streaks() {
+        let logged = hours(&[
+            (9, 1, 8.0),
+            (9, 2, 8.0),
+            (9, 3, 8.0),
+            (9, 4, 2.0),
+            (9, 5, 8.0),
+            (9, 8, 8.0),
+        ]);
+
+        assert_eq!(longest_streak(&logged, 8.0, date(1, 1), date(9, 12)), 3);
+
+        let weeks = weekly_streaks(&logged, 8.0, date(9, 12));
+        assert_eq!(weeks.len(), STREAK_CHART_WEEKS as usize);
+        assert_eq!(weeks[10], (date(9, 1), 3));
+        assert_eq!(weeks[11], (date(9, 8), 1));
+    }
+
+    #[test]
+    fn test_psql_weeks_table() {
+        let rendered = psql_weeks_table(&[(date(9, 1), 3), (date(9, 8), 1)]).render();
+
+        assert_eq!(
+            rendered,
+            " week | monday     | streak\n\
+             ------+------------+--------\n \
+             W36  | 2025-09-01 |      3\n \
+             W37  | 2025-09-08 |      1\n\
+             (2 rows)\n"
+        );
+    }
+
+    #[test]
+    fn test_is_user_item() {
+        let item = Item {
+            column_values: vec![ColumnValue {
+                id: Some("person".to_string()),
+                value: Some(r#"{"personsAndTeams":[{"id":12345,"kind":"person"}]}"#.to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(is_user_item(&item, 12345));
+        assert!(!is_user_item(&item, 1234));
+        assert!(!is_user_item(&Item::default(), 12345));
+    }
+}
@@ -0,0 +1,432 @@
+//! `claim summary`: hours of a week, month or year by activity type,
+//! customer and work item
+
+use crate::config::Config;
+use crate::export::query_user_items_in_range;
+use crate::interactive::app::{report_hours, ClaimEntry};
+use crate::monday::{is_user_item, MondayClient, MondayUser};
+use crate::target::parse_month;
+use crate::truncate_string;
+use crate::utils;
+use anyhow::{anyhow, Result};
+use chrono::{Duration, NaiveDate, Weekday};
+use serde_json::{json, Value};
+
+/// One line of the summary
+#[derive(Debug, Clone, PartialEq)]
+struct SummaryRow {
+    activity_value: i32,
+    customer: String,
+    work_item: String,
+    hours: f64,
+}
+
+/// Where `claim summary` posts the summary besides printing it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SlackTarget {
+    /// Webhook URL given with `--slack-webhook`; saved for later `--slack`
+    pub webhook: Option<String>,
+    /// `--slack`: post to the saved webhook
+    pub saved: bool,
+    /// `--rich`: add Block Kit blocks to the message
+    pub rich: bool,
+}
+
+/// Handles `claim summary [--week WEEK | --month YYYY-MM | --year YYYY]
+/// [--slack-webhook URL | --slack] [--rich]`
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_summary_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    week: Option<String>,
+    month: Option<String>,
+    year: Option<i32>,
+    billable_only: bool,
+    slack: SlackTarget,
+    verbose: bool,
+) -> Result<()> {
+    let webhook = slack_webhook(config, &slack)?;
+    let (title, dates) = period_dates(config.today(), week, month, year)?;
+
+    println!("Summarizing the {}...", title);
+    // A week can span New Year, so read the group of every year it touches
+    let (first, last) = (dates[0], dates[dates.len() - 1]);
+    let items = query_user_items_in_range(client, config, user, first, last, verbose).await?;
+    let entries: Vec<ClaimEntry> = items
+        .iter()
+        .filter(|item| is_user_item(item, user.id))
+        .filter_map(ClaimEntry::from_item)
+        .collect();
+
+    let rows = summary_rows(&entries, &dates, billable_only);
+    display_summary(&title, &rows, config);
+
+    if let Some(webhook) = webhook {
+        post_to_slack(&webhook, &slack_payload(&title, &rows, config, slack.rich)).await?;
+        println!("\n✅ Summary posted to Slack");
+        if slack.webhook.is_some() && config.slack_webhook.as_deref() != Some(webhook.as_str()) {
+            let mut updated = config.clone();
+            updated.slack_webhook = Some(webhook);
+            updated.save()?;
+        }
+    }
+    Ok(())
+}
+
+/// Fails unless `url` looks like an incoming webhook URL
+pub fn validate_slack_webhook(url: &str) -> Result<()> {
+    if !url.starts_with("https://") {
+        return Err(anyhow!(
+            "Invalid Slack webhook: {}. Expected an https:// incoming webhook URL",
+            url
+        ));
+    }
+    Ok(())
+}
+
+/// Webhook to post to: the one given, else the saved one with `--slack`
+fn slack_webhook(config: &Config, slack: &SlackTarget) -> Result<Option<String>> {
+    let webhook = match (&slack.webhook, slack.saved) {
+        (Some(url), _) => Some(url.clone()),
+        (None, true) => Some(config.slack_webhook.clone().ok_or_else(|| {
+            anyhow!(
+                "No Slack webhook saved. Pass --slack-webhook URL or run `claim config set-slack-webhook URL`"
+            )
+        })?),
+        (None, false) => None,
+    };
+    match &webhook {
+        Some(url) => validate_slack_webhook(url)?,
+        None if slack.rich => return Err(anyhow!("--rich requires --slack or --slack-webhook")),
+        None => {}
+    }
+    Ok(webhook)
+}
+
+/// The summary in Slack mrkdwn: one bullet per row and the total
+fn slack_text(title: &str, rows: &[SummaryRow], config: &Config) -> String {
+    let mut text = format!("*Summary for the {}*\n", title);
+    if rows.is_empty() {
+        text.push_str("No entries found");
+        return text;
+    }
+    let total: f64 = rows.iter().map(|row| row.hours).sum();
+    for row in rows {
+        text.push_str(&format!(
+            "• {} · {} / {}: *{:.1}h* ({:.1}%)\n",
+            config.activity_label(row.activity_value as u8),
+            row.customer,
+            row.work_item,
+            row.hours,
+            row.hours / total * 100.0
+        ));
+    }
+    text.push_str(&format!("*Total: {:.1}h*", total));
+    text
+}
+
+/// Incoming webhook payload; `rich` adds a header, a section per row and
+/// the total as Block Kit blocks (the `text` stays as the notification)
+fn slack_payload(title: &str, rows: &[SummaryRow], config: &Config, rich: bool) -> Value {
+    let text = slack_text(title, rows, config);
+    if !rich {
+        return json!({ "text": text });
+    }
+
+    let total: f64 = rows.iter().map(|row| row.hours).sum();
+    let mut blocks = vec![json!({
+        "type": "header",
+        "text": { "type": "plain_text", "text": format!("Summary for the {}", title) }
+    })];
+    blocks.extend(rows.iter().map(|row| {
+        json!({
+            "type": "section",
+            "fields": [
+                {
+                    "type": "mrkdwn",
+                    "text": format!(
+                        "*{}*\n{} / {}",
+                        config.activity_label(row.activity_value as u8),
+                        row.customer,
+                        row.work_item
+                    )
+                },
+                {
+                    "type": "mrkdwn",
+                    "text": format!("{:.1}h ({:.1}%)", row.hours, row.hours / total * 100.0)
+                }
+            ]
+        })
+    }));
+    blocks.push(json!({ "type": "divider" }));
+    blocks.push(json!({
+        "type": "section",
+        "text": { "type": "mrkdwn", "text": format!("*Total: {:.1}h*", total) }
+    }));
+    json!({ "text": text, "blocks": blocks })
+}
+
+/// Sends `payload` to the Slack incoming webhook
+async fn post_to_slack(webhook: &str, payload: &Value) -> Result<()> {
+    let response = reqwest::Client::new()
+        .post(webhook)
+        .json(payload)
+        .send()
+        .await
+        .map_err(|e| anyhow!("Failed to post to Slack: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(anyhow!("Slack rejected the summary ({}): {}", status, body));
+    }
+    Ok(())
+}
+
+/// Title and every day of the selected period (the current week by default)
+fn period_dates(
+    today: NaiveDate,
+    week: Option<String>,
+    month: Option<String>,
+    year: Option<i32>,
+) -> Result<(String, Vec<NaiveDate>)> {
+    let (title, first, last) = match (week, month, year) {
+        (_, Some(month), _) => {
+            let first = parse_month(&month)?;
+            let next = first
+                .checked_add_months(chrono::Months::new(1))
+                .ok_or_else(|| anyhow!("Invalid month: {}", month))?;
+            (
+                format!("month {}", first.format("%Y-%m")),
+                first,
+                next - Duration::days(1),
+            )
+        }
+        (_, None, Some(year)) => {
+            let first = NaiveDate::from_ymd_opt(year, 1, 1)
+                .ok_or_else(|| anyhow!("Invalid year: {}", year))?;
+            (
+                format!("year {}", year),
+                first,
+                NaiveDate::from_ymd_opt(year, 12, 31).unwrap(),
+            )
+        }
+        (week, None, None) => {
+            let date = match week.as_deref().map(str::trim) {
+                None | Some("current") => today,
+                Some("last") => today - Duration::days(7),
+                Some(week) => utils::parse_date_or_week(week, today)?,
+            };
+            let monday = utils::get_week_start(date, Weekday::Mon);
+            (
+                format!("week of {}", monday.format("%Y-%m-%d")),
+                monday,
+                monday + Duration::days(6),
+            )
+        }
+    };
+    Ok((
+        title,
+        first.iter_days().take_while(|d| *d <= last).collect(),
+    ))
+}
+
+/// Rows with the report view's grouping, billable work first and then by
+/// activity type, customer and work item
+fn summary_rows(
+    entries: &[ClaimEntry],
+    dates: &[NaiveDate],
+    billable_only: bool,
+) -> Vec<SummaryRow> {
+    let mut rows: Vec<SummaryRow> = report_hours(entries, dates)
+        .into_iter()
+        .filter(|((activity_value, _, _), _)| !billable_only || *activity_value == 1)
+        .map(
+            |((activity_value, customer, work_item), hours)| SummaryRow {
+                activity_value,
+                customer,
+                work_item,
+                hours: hours.iter().sum(),
+            },
+        )
+        .filter(|row| row.hours != 0.0)
+        .collect();
+    rows.sort_by(|a, b| {
+        (a.activity_value != 1)
+            .cmp(&(b.activity_value != 1))
+            .then(a.activity_value.cmp(&b.activity_value))
+            .then_with(|| a.customer.cmp(&b.customer))
+            .then_with(|| a.work_item.cmp(&b.work_item))
+    });
+    rows
+}
+
+fn display_summary(title: &str, rows: &[SummaryRow], config: &Config) {
+    println!("\n=== SUMMARY for the {} ===", title);
+    if rows.is_empty() {
+        println!("No entries found");
+        return;
+    }
+
+    let total: f64 = rows.iter().map(|row| row.hours).sum();
+    println!(
+        "\n{:<15} {:<25} {:<20} {:>11} {:>12}",
+        "Activity Type", "Customer", "Work Item", "Total Hours", "% of Period"
+    );
+    println!("{}", "-".repeat(87));
+    for row in rows {
+        println!(
+            "{:<15} {:<25} {:<20} {:>10.1}h {:>11.1}%",
+            truncate_string(&config.activity_label(row.activity_value as u8), 15),
+            truncate_string(&row.customer, 25),
+            truncate_string(&row.work_item, 20),
+            row.hours,
+            row.hours / total * 100.0
+        );
+    }
+    println!("{}", "-".repeat(87));
+    println!("{:<62} {:>10.1}h {:>11.1}%", "TOTAL", total, 100.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn entry(day: u32, activity_value: i32, customer: &str, hours: f64) -> ClaimEntry {
+        ClaimEntry {
+            id: String::new(),
+            date: date(9, day),
+            activity_type: utils::map_activity_value_to_name(activity_value as u8),
+            activity_value,
+            customer: customer.to_string(),
+            work_item: "WI-1".to_string(),
+            hours,
+            comment: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_period_dates() {
+        let today = date(9, 17);
+
+        let (title, dates) = period_dates(today, None, None, None).unwrap();
+        assert_eq!(title, "week of 2025-09-15");
+        assert_eq!((dates[0], dates.len()), (date(9, 15), 7));
+
+        let (_, dates) = period_dates(today, Some("last".into()), None, None).unwrap();
+        assert_eq!(dates[0], date(9, 8));
+
+        let (_, dates) = period_dates(today, Some("2025-W38".into()), None, None).unwrap();
+        assert_eq!(dates[0], date(9, 15));
+
+        // The first ISO week of 2025 starts in 2024
+        let (_, dates) = period_dates(today, Some("2025-W01".into()), None, None).unwrap();
+        assert_eq!(
+            (dates[0], dates[6]),
+            (
+                NaiveDate::from_ymd_opt(2024, 12, 30).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 1, 5).unwrap()
+            )
+        );
+
+        let (title, dates) = period_dates(today, None, Some("2025-02".into()), None).unwrap();
+        assert_eq!(title, "month 2025-02");
+        assert_eq!((dates[0], dates.len()), (date(2, 1), 28));
+
+        let (_, dates) = period_dates(today, None, None, Some(2024)).unwrap();
+        assert_eq!(dates.len(), 366);
+    }
+
+    #[test]
+    fn test_summary_rows() {
+        let entries = vec![
+            entry(15, 2, "Internal", 2.0),
+            entry(15, 1, "Globex", 4.0),
+            entry(16, 1, "ACME", 3.0),
+            entry(17, 1, "ACME", 5.0),
+            entry(30, 1, "ACME", 8.0),
+        ];
+        let dates: Vec<NaiveDate> = (15..=21).map(|day| date(9, day)).collect();
+
+        let rows = summary_rows(&entries, &dates, false);
+        let keys: Vec<(i32, &str, f64)> = rows
+            .iter()
+            .map(|row| (row.activity_value, row.customer.as_str(), row.hours))
+            .collect();
+        assert_eq!(
+            keys,
+            [(1, "ACME", 8.0), (1, "Globex", 4.0), (2, "Internal", 2.0)]
+        );
+
+        assert_eq!(summary_rows(&entries, &dates, true).len(), 2);
+    }
+
+    #[test]
+    fn test_slack_payload() {
+        let entries = vec![entry(15, 1, "ACME", 6.0), entry(16, 2, "Internal", 2.0)];
+        let dates: Vec<NaiveDate> = (15..=21).map(|day| date(9, day)).collect();
+        let rows = summary_rows(&entries, &dates, false);
+        let config = Config::default();
+
+        let plain = slack_payload("week of 2025-09-15", &rows, &config, false);
+        assert_eq!(
+            plain["text"],
+            "*Summary for the week of 2025-09-15*\n\
+             • billable · ACME / WI-1: *6.0h* (75.0%)\n\
+             • holding · Internal / WI-1: *2.0h* (25.0%)\n\
+             *Total: 8.0h*"
+        );
+        assert!(plain.get("blocks").is_none());
+
+        let rich = slack_payload("week of 2025-09-15", &rows, &config, true);
+        assert_eq!(rich["text"], plain["text"]);
+        let blocks = rich["blocks"].as_array().unwrap();
+        assert_eq!(blocks.len(), 5);
+        assert_eq!(blocks[0]["type"], "header");
+    }
+
+    #[test]
+    fn test_slack_webhook() {
+        let url = "https://hooks.slack.com/services/T0/B0/X";
+        let mut config = Config::default();
+        let given = SlackTarget {
+            webhook: Some(url.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            slack_webhook(&config, &given).unwrap().as_deref(),
+            Some(url)
+        );
+
+        let saved = SlackTarget {
+            saved: true,
+            ..Default::default()
+        };
+        assert!(slack_webhook(&config, &saved).is_err());
+        config.slack_webhook = Some(url.to_string());
+        assert_eq!(
+            slack_webhook(&config, &saved).unwrap().as_deref(),
+            Some(url)
+        );
+
+        assert_eq!(
+            slack_webhook(&config, &SlackTarget::default()).unwrap(),
+            None
+        );
+        let rich_only = SlackTarget {
+            rich: true,
+            ..Default::default()
+        };
+        assert!(slack_webhook(&config, &rich_only).is_err());
+        let http = SlackTarget {
+            webhook: Some("http://example.com".to_string()),
+            ..Default::default()
+        };
+        assert!(slack_webhook(&config, &http).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! `claim sync`: compares the local entry cache with Monday.com
+
+use crate::add::{extract_customer_from_item, extract_date_from_item, extract_work_item_from_item};
+use crate::cache::{CachedEntry, EntryCache};
+use crate::config::Config;
+use crate::monday::{is_user_item, MondayClient, MondayUser};
+use crate::utils::get_year_group_id;
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate};
+use std::collections::BTreeSet;
+
+/// Number of weeks of Monday.com entries compared with the cache
+const SYNC_WEEKS: i64 = 4;
+
+/// Upper bound on the items fetched from the year's group
+const MAX_SYNC_ITEMS: usize = 10_000;
+
+/// Customer/work item pairs that differ between Monday.com and the cache
+#[derive(Debug, Default, PartialEq)]
+struct SyncDiff {
+    /// Logged on Monday.com but missing from the cache
+    missing: Vec<(String, String)>,
+    /// Cached as used within the period, but no longer on Monday.com
+    stale: Vec<(String, String)>,
+}
+
+/// Handles `claim sync`
+pub async fn handle_sync_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    fix: bool,
+    verbose: bool,
+) -> Result<()> {
+    let board_id = config.board_id.as_str();
+    let today = config.today();
+    let since = today - chrono::Duration::weeks(SYNC_WEEKS);
+
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let group_id = get_year_group_id(&board, &today.year().to_string());
+    let items = client
+        .query_all_items_in_group(board_id, &group_id, MAX_SYNC_ITEMS, verbose)
+        .await?;
+
+    let remote: Vec<(String, String, NaiveDate)> = items
+        .iter()
+        .filter(|item| is_user_item(item, user.id))
+        .filter_map(|item| {
+            let date = extract_date_from_item(item)?;
+            Some((
+                extract_customer_from_item(item),
+                extract_work_item_from_item(item),
+                date,
+            ))
+        })
+        .filter(|(customer, work_item, date)| {
+            !customer.is_empty() && !work_item.is_empty() && *date >= since && *date <= today
+        })
+        .collect();
+
+    if verbose {
+        println!(
+            "Found {} entries with a customer and work item since {}",
+            remote.len(),
+            since
+        );
+    }
+
+    let mut cache = EntryCache::load().unwrap_or_else(|_| EntryCache::new());
+    let cached = cache.get_sorted_entries(user.id);
+    let diff = compare_cache(&cached, &remote, since);
+
+    println!("\n=== SYNC: cache vs Monday.com since {} ===", since);
+    println!(
+        "\nOn Monday.com but not in the cache ({}):",
+        diff.missing.len()
+    );
+    for (customer, work_item) in &diff.missing {
+        println!("  + {} | {}", customer, work_item);
+    }
+    println!(
+        "\nIn the cache but no longer on Monday.com ({}):",
+        diff.stale.len()
+    );
+    for (customer, work_item) in &diff.stale {
+        println!("  - {} | {}", customer, work_item);
+    }
+
+    if diff == SyncDiff::default() {
+        println!("\n✅ The cache is in sync");
+        return Ok(());
+    }
+
+    if !fix {
+        println!(
+            "\n{} to add, {} to remove. Run `claim sync --fix` to update the cache.",
+            diff.missing.len(),
+            diff.stale.len()
+        );
+        return Ok(());
+    }
+
+    for (customer, work_item) in &diff.stale {
+        cache.remove_entry(user.id, customer, work_item);
+    }
+    cache.update_from_items(user.id, &remote);
+    cache.save()?;
+
+    println!(
+        "\n✅ Cache updated: {} added, {} removed",
+        diff.missing.len(),
+        diff.stale.len()
+    );
+    Ok(())
+}
+
+/// Compares cached pairs with the pairs logged on Monday.com since `since`.
+/// Cached pairs last used before `since` are outside the compared period
+/// and never reported as stale.
+fn compare_cache(
+    cached: &[CachedEntry],
+    remote: &[(String, String, NaiveDate)],
+    since: NaiveDate,
+) -> SyncDiff {
+    let remote_pairs: BTreeSet<(String, String)> = remote
+        .iter()
+        .map(|(customer, work_item, _)| (customer.clone(), work_item.clone()))
+        .collect();
+    let cached_pairs: BTreeSet<(String, String)> = cached
+        .iter()
+        .map(|e| (e.customer.clone(), e.work_item.clone()))
+        .collect();
+
+    let missing = remote_pairs.difference(&cached_pairs).cloned().collect();
+    let stale = cached
+        .iter()
+        .filter(|e| e.last_used.date_naive() >= since)
+        .map(|e| (e.customer.clone(), e.work_item.clone()))
+        .filter(|pair| !remote_pairs.contains(pair))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    SyncDiff { missing, stale }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::day_start;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, day).unwrap()
+    }
+
+    fn cached(customer: &str, work_item: &str, last_used: &str) -> CachedEntry {
+        CachedEntry {
+            customer: customer.to_string(),
+            work_item: work_item.to_string(),
+            last_used: day_start(NaiveDate::parse_from_str(last_used, "%Y-%m-%d").unwrap()),
+            use_count: 1,
+        }
+    }
+
+    #[test]
+    fn test_compare_cache() {
+        let cache = vec![
+            cached("ACME", "PROJ-1", "2025-09-10"),
+            cached("Globex", "GLX-42", "2025-09-12"),
+            // Last used before the compared period: not stale
+            cached("Initech", "TPS-7", "2025-08-01"),
+        ];
+        let remote = vec![
+            ("ACME".to_string(), "PROJ-1".to_string(), date(10)),
+            ("Hooli".to_string(), "HL-9".to_string(), date(15)),
+        ];
+
+        let diff = compare_cache(&cache, &remote, date(1));
+
+        assert_eq!(
+            diff.missing,
+            vec![("Hooli".to_string(), "HL-9".to_string())]
+        );
+        assert_eq!(
+            diff.stale,
+            vec![("Globex".to_string(), "GLX-42".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_compare_cache_in_sync() {
+        let cache = vec![cached("ACME", "PROJ-1", "2025-09-10")];
+        let remote = vec![("ACME".to_string(), "PROJ-1".to_string(), date(10))];
+
+        assert_eq!(compare_cache(&cache, &remote, date(1)), SyncDiff::default());
+    }
+}
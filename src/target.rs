@@ -0,0 +1,305 @@
+//! Monthly target tracking: compares logged hours against the expected
+//! working hours for a month.
+
+use crate::monday::{Item, MondayClient, MondayUser};
+use crate::query::{extract_column_value, extract_item_date};
+use crate::{calculate_working_dates, get_year_group_id};
+use anyhow::{anyhow, Result};
+use chrono::prelude::*;
+
+/// Fraction of the expected hours below which the command exits with code 2
+pub const TARGET_ALERT_THRESHOLD: f64 = 0.8;
+
+/// Tolerance (in percent) within which a month is considered on track
+const ON_TRACK_TOLERANCE_PERCENT: f64 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TargetStatus {
+    Ahead,
+    OnTrack,
+    Behind,
+}
+
+impl TargetStatus {
+    pub fn from_variance_percent(variance_percent: f64) -> Self {
+        if variance_percent > ON_TRACK_TOLERANCE_PERCENT {
+            TargetStatus::Ahead
+        } else if variance_percent < -ON_TRACK_TOLERANCE_PERCENT {
+            TargetStatus::Behind
+        } else {
+            TargetStatus::OnTrack
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TargetStatus::Ahead => "🚀 ahead",
+            TargetStatus::OnTrack => "✅ on-track",
+            TargetStatus::Behind => "⚠️  behind",
+        }
+    }
+}
+
+/// Expected vs. actual hours for a period (a whole month or a single week)
+#[derive(Debug, Clone)]
+pub struct TargetRow {
+    pub label: String,
+    pub working_days: usize,
+    pub expected_hours: f64,
+    pub actual_hours: f64,
+}
+
+impl TargetRow {
+    pub fn variance_hours(&self) -> f64 {
+        self.actual_hours - self.expected_hours
+    }
+
+    pub fn variance_percent(&self) -> f64 {
+        if self.expected_hours > 0.0 {
+            self.variance_hours() / self.expected_hours * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    pub fn status(&self) -> TargetStatus {
+        TargetStatus::from_variance_percent(self.variance_percent())
+    }
+}
+
+/// Handles `claim target`. Returns `true` when actual hours are below
+/// `TARGET_ALERT_THRESHOLD` of the expected hours.
+pub async fn handle_target_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    board_id: &str,
+    month: Option<String>,
+    hours_per_day: f64,
+    today: NaiveDate,
+    verbose: bool,
+) -> Result<bool> {
+    let first_day = match month {
+        Some(ref m) => parse_month(m)?,
+        None => NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap(),
+    };
+
+    let working_dates = month_working_dates(first_day);
+
+    if verbose {
+        println!(
+            "Computing target for {} ({} working days, {:.1}h/day)",
+            first_day.format("%Y-%m"),
+            working_dates.len(),
+            hours_per_day
+        );
+    }
+
+    let year = first_day.year().to_string();
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let group_id = get_year_group_id(&board, &year);
+
+    let date_strings: Vec<String> = working_dates
+        .iter()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect();
+
+    let items = client
+        .query_items_with_filters(board_id, &group_id, user.id, &date_strings, 500, verbose)
+        .await?;
+
+    if verbose {
+        println!(
+            "Fetched {} items for {}",
+            items.len(),
+            first_day.format("%Y-%m")
+        );
+    }
+
+    let hours_by_date = sum_hours_by_date(&items);
+    let month_row = build_month_row(first_day, &working_dates, hours_per_day, &hours_by_date);
+    let week_rows = build_week_rows(&working_dates, hours_per_day, &hours_by_date);
+
+    display_target(user, &month_row, &week_rows);
+
+    Ok(month_row.actual_hours < month_row.expected_hours * TARGET_ALERT_THRESHOLD)
+}
+
+/// Parses a `YYYY-MM` string into the first day of that month
+pub fn parse_month(month: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(&format!("{}-01", month.trim()), "%Y-%m-%d")
+        .map_err(|_| anyhow!("Invalid month format: {}. Please use YYYY-MM.", month))
+}
+
+/// Returns all working days (Mon-Fri) of the month starting at `first_day`
+pub fn month_working_dates(first_day: NaiveDate) -> Vec<NaiveDate> {
+    // A month never has more than 23 working days, so 31 is a safe upper bound
+    calculate_working_dates(first_day, 31, true)
+        .into_iter()
+        .take_while(|d| d.month() == first_day.month())
+        .collect()
+}
+
+pub(crate) fn sum_hours_by_date(items: &[Item]) -> std::collections::HashMap<NaiveDate, f64> {
+    let mut hours_by_date = std::collections::HashMap::new();
+    for item in items {
+        let date =
+            extract_item_date(item).and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok());
+        if let Some(date) = date {
+            let hours = extract_column_value(item, "numbers__1")
+                .trim_matches('"')
+                .parse::<f64>()
+                .unwrap_or(0.0);
+            *hours_by_date.entry(date).or_insert(0.0) += hours;
+        }
+    }
+    hours_by_date
+}
+
+fn build_month_row(
+    first_day: NaiveDate,
+    working_dates: &[NaiveDate],
+    hours_per_day: f64,
+    hours_by_date: &std::collections::HashMap<NaiveDate, f64>,
+) -> TargetRow {
+    TargetRow {
+        label: first_day.format("%Y-%m").to_string(),
+        working_days: working_dates.len(),
+        expected_hours: working_dates.len() as f64 * hours_per_day,
+        actual_hours: working_dates
+            .iter()
+            .filter_map(|d| hours_by_date.get(d))
+            .sum(),
+    }
+}
+
+fn build_week_rows(
+    working_dates: &[NaiveDate],
+    hours_per_day: f64,
+    hours_by_date: &std::collections::HashMap<NaiveDate, f64>,
+) -> Vec<TargetRow> {
+    let mut rows: Vec<TargetRow> = Vec::new();
+    let mut current_week: Option<u32> = None;
+
+    for date in working_dates {
+        let week = date.iso_week().week();
+        if current_week != Some(week) {
+            current_week = Some(week);
+            rows.push(TargetRow {
+                label: format!("W{:02} ({})", week, date.format("%b %d")),
+                working_days: 0,
+                expected_hours: 0.0,
+                actual_hours: 0.0,
+            });
+        }
+        if let Some(row) = rows.last_mut() {
+            row.working_days += 1;
+            row.expected_hours += hours_per_day;
+            row.actual_hours += hours_by_date.get(date).copied().unwrap_or(0.0);
+        }
+    }
+
+    rows
+}
+
+fn print_row(row: &TargetRow) {
+    println!(
+        "{:<16} {:>5} {:>10.1} {:>10.1} {:>+9.1} {:>+8.1}%  {}",
+        row.label,
+        row.working_days,
+        row.expected_hours,
+        row.actual_hours,
+        row.variance_hours(),
+        row.variance_percent(),
+        row.status().label()
+    );
+}
+
+fn display_target(user: &MondayUser, month_row: &TargetRow, week_rows: &[TargetRow]) {
+    println!(
+        "\n=== MONTHLY TARGET for User {} ({}) ===",
+        user.name, month_row.label
+    );
+    println!(
+        "\n{:<16} {:>5} {:>10} {:>10} {:>9} {:>9}  Status",
+        "Period", "Days", "Expected", "Actual", "Var (h)", "Var (%)"
+    );
+    println!("{}", "-".repeat(80));
+    for row in week_rows {
+        print_row(row);
+    }
+    println!("{}", "-".repeat(80));
+    print_row(month_row);
+
+    if month_row.actual_hours < month_row.expected_hours * TARGET_ALERT_THRESHOLD {
+        println!(
+            "\n⚠️  Logged hours are below {:.0}% of the monthly target",
+            TARGET_ALERT_THRESHOLD * 100.0
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_month() {
+        let date = parse_month("2025-09").unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(2025, 9, 1).unwrap());
+        assert!(parse_month("2025-13").is_err());
+        assert!(parse_month("September").is_err());
+    }
+
+    #[test]
+    fn test_month_working_dates() {
+        // September 2025 has 22 working days
+        let dates = month_working_dates(NaiveDate::from_ymd_opt(2025, 9, 1).unwrap());
+        assert_eq!(dates.len(), 22);
+        assert!(dates.iter().all(|d| d.month() == 9));
+
+        // February 2025 starts on a Saturday and has 20 working days
+        let dates = month_working_dates(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap());
+        assert_eq!(dates.len(), 20);
+        assert_eq!(dates[0], NaiveDate::from_ymd_opt(2025, 2, 3).unwrap());
+    }
+
+    #[test]
+    fn test_target_status() {
+        assert_eq!(
+            TargetStatus::from_variance_percent(10.0),
+            TargetStatus::Ahead
+        );
+        assert_eq!(
+            TargetStatus::from_variance_percent(0.0),
+            TargetStatus::OnTrack
+        );
+        assert_eq!(
+            TargetStatus::from_variance_percent(-4.9),
+            TargetStatus::OnTrack
+        );
+        assert_eq!(
+            TargetStatus::from_variance_percent(-20.0),
+            TargetStatus::Behind
+        );
+    }
+
+    #[test]
+    fn test_week_rows_and_variance() {
+        let first_day = NaiveDate::from_ymd_opt(2025, 9, 1).unwrap();
+        let dates = month_working_dates(first_day);
+        let mut hours = std::collections::HashMap::new();
+        hours.insert(NaiveDate::from_ymd_opt(2025, 9, 1).unwrap(), 8.0);
+        hours.insert(NaiveDate::from_ymd_opt(2025, 9, 2).unwrap(), 4.0);
+
+        let weeks = build_week_rows(&dates, 8.0, &hours);
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0].working_days, 5);
+        assert_eq!(weeks[0].actual_hours, 12.0);
+        assert_eq!(weeks[4].working_days, 2);
+
+        let month = build_month_row(first_day, &dates, 8.0, &hours);
+        assert_eq!(month.expected_hours, 176.0);
+        assert_eq!(month.actual_hours, 12.0);
+        assert_eq!(month.status(), TargetStatus::Behind);
+    }
+}
@@ -25,15 +25,94 @@ pub fn truncate_string(s: &str, max_length: usize) -> String {
     }
 }
 
+/// Name the program was started as (file name of `argv[0]` without extension)
+pub fn binary_name() -> Option<String> {
+    std::env::args_os()
+        .next()
+        .as_deref()
+        .map(std::path::Path::new)
+        .and_then(|path| path.file_stem())
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .filter(|name| !name.is_empty())
+}
+
 // ===== DATE/TIME UTILITIES =====
 
-/// Gets the current year as i32
-pub fn get_current_year() -> i32 {
-    Local::now().year()
+/// Current time in the Monday.com workspace time zone
+pub fn now_in_workspace_tz(tz: chrono_tz::Tz) -> DateTime<chrono_tz::Tz> {
+    Utc::now().with_timezone(&tz)
+}
+
+/// Today's date in the workspace time zone, or in local time when the
+/// workspace time zone is unknown
+pub fn today_in_workspace_tz(tz: Option<chrono_tz::Tz>) -> NaiveDate {
+    match tz {
+        Some(tz) => now_in_workspace_tz(tz).date_naive(),
+        None => Local::now().date_naive(),
+    }
+}
+
+/// The fixed relative date expressions, offered by shell completion
+pub const RELATIVE_DATES: [&str; 12] = [
+    "today",
+    "yesterday",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "last-week-monday",
+    "last-week-tuesday",
+    "last-week-wednesday",
+    "last-week-thursday",
+    "last-week-friday",
+];
+
+/// Resolves a relative date expression against `today`: `today`,
+/// `yesterday`, `monday` to `friday` (the most recent one, today included),
+/// `last-week-monday` to `last-week-friday` and `N-days-ago`
+pub fn resolve_relative_date(expr: &str, today: NaiveDate) -> Option<NaiveDate> {
+    const WEEKDAYS: [(&str, Weekday); 5] = [
+        ("monday", Weekday::Mon),
+        ("tuesday", Weekday::Tue),
+        ("wednesday", Weekday::Wed),
+        ("thursday", Weekday::Thu),
+        ("friday", Weekday::Fri),
+    ];
+    let weekday = |name: &str| {
+        WEEKDAYS
+            .iter()
+            .find(|(weekday_name, _)| *weekday_name == name)
+            .map(|(_, weekday)| *weekday)
+    };
+
+    let expr = expr.trim().to_lowercase();
+    match expr.as_str() {
+        "today" => return Some(today),
+        "yesterday" => return today.pred_opt(),
+        _ => {}
+    }
+    if let Some(weekday) = weekday(&expr) {
+        let days_back =
+            (7 + today.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+        return Some(today - chrono::Duration::days(days_back as i64));
+    }
+    if let Some(weekday) = expr.strip_prefix("last-week-").and_then(weekday) {
+        let last_monday = get_week_start(today, Weekday::Mon) - chrono::Duration::days(7);
+        return Some(last_monday + chrono::Duration::days(weekday.num_days_from_monday() as i64));
+    }
+    let days = expr.strip_suffix("-days-ago")?.parse::<u16>().ok()?;
+    today.checked_sub_signed(chrono::Duration::days(days as i64))
 }
 
-/// Validates a date string in multiple formats (YYYY-MM-DD, YYYY.MM.DD, YYYY/MM/DD)
-pub fn validate_date(date_str: &str) -> Result<()> {
+/// Validates a date string in multiple formats (YYYY-MM-DD, YYYY.MM.DD,
+/// YYYY/MM/DD) or a relative expression resolved against `today` (see
+/// `resolve_relative_date`)
+pub fn validate_date(date_str: &str, today: NaiveDate) -> Result<()> {
+    if resolve_relative_date(date_str, today).is_some() {
+        return Ok(());
+    }
+
     let formats = ["%Y-%m-%d", "%Y.%m.%d", "%Y/%m/%d"];
 
     for format in &formats {
@@ -43,13 +122,18 @@ pub fn validate_date(date_str: &str) -> Result<()> {
     }
 
     Err(anyhow!(
-        "Invalid date format: {}. Please use YYYY-MM-DD, YYYY.MM.DD, or YYYY/MM/DD format.",
+        "Invalid date format: {}. Please use YYYY-MM-DD, YYYY.MM.DD, YYYY/MM/DD, or a relative date such as today, yesterday, monday, last-week-friday or 3-days-ago.",
         date_str
     ))
 }
 
-/// Normalizes a date string to YYYY-MM-DD format
-pub fn normalize_date(date_str: &str) -> String {
+/// Normalizes a date string, including relative expressions resolved
+/// against `today`, to YYYY-MM-DD format
+pub fn normalize_date(date_str: &str, today: NaiveDate) -> String {
+    if let Some(date) = resolve_relative_date(date_str, today) {
+        return date.format("%Y-%m-%d").to_string();
+    }
+
     let formats = ["%Y-%m-%d", "%Y.%m.%d", "%Y/%m/%d"];
 
     for format in &formats {
@@ -62,8 +146,108 @@ pub fn normalize_date(date_str: &str) -> String {
     date_str.to_string()
 }
 
-/// Calculates working dates (skips weekends) from a start date for a given number of days
-pub fn calculate_working_dates(start_date: NaiveDate, target_days: i64) -> Vec<NaiveDate> {
+/// Parses an ISO week (`YYYY-WN`, e.g. `2025-W38`) into the Monday of that week
+pub fn parse_iso_week(s: &str) -> Result<NaiveDate> {
+    let invalid = || {
+        anyhow!(
+            "Invalid week format: {}. Please use YYYY-WN (e.g. 2025-W38).",
+            s
+        )
+    };
+
+    let upper = s.trim().to_uppercase();
+    let (year, week) = upper.split_once("-W").ok_or_else(invalid)?;
+    let year: i32 = year.parse().map_err(|_| invalid())?;
+    let week: u32 = week.parse().map_err(|_| invalid())?;
+
+    NaiveDate::from_isoywd_opt(year, week, Weekday::Mon).ok_or_else(invalid)
+}
+
+/// Parses either a date (YYYY-MM-DD, YYYY.MM.DD, YYYY/MM/DD) or an ISO week
+/// (YYYY-WN, resolving to its Monday); relative dates are resolved against
+/// `today`
+pub fn parse_date_or_week(s: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let s = s.trim();
+    if let Some(date) = resolve_relative_date(s, today) {
+        return Ok(date);
+    }
+    if s.to_uppercase().contains('W') {
+        return parse_iso_week(s);
+    }
+    validate_date(s, today)?;
+    Ok(NaiveDate::parse_from_str(
+        &normalize_date(s, today),
+        "%Y-%m-%d",
+    )?)
+}
+
+/// Resolves `--date` / `--week-number` into a single start date string.
+/// A week number resolves to the Monday of that week.
+pub fn resolve_date_or_week(
+    date: Option<String>,
+    week_number: Option<String>,
+) -> Result<Option<String>> {
+    match week_number {
+        Some(week) => Ok(Some(parse_iso_week(&week)?.format("%Y-%m-%d").to_string())),
+        None => Ok(date),
+    }
+}
+
+/// Parses an ISO 8601 interval of two dates (`START/END`, e.g.
+/// `2025-09-01/2025-09-30`), both ends inclusive
+pub fn parse_date_range(s: &str, today: NaiveDate) -> Result<(NaiveDate, NaiveDate)> {
+    let (start, end) = s.split_once('/').ok_or_else(|| {
+        anyhow!(
+            "Invalid date range: {}. Please use START/END (e.g. 2025-09-01/2025-09-30).",
+            s
+        )
+    })?;
+
+    parse_date_pair(start, end, today)
+}
+
+/// Parses the two ends of a date range given as separate values (e.g.
+/// `--date-range FROM TO`); fails when `from` is after `to`
+pub fn parse_date_pair(from: &str, to: &str, today: NaiveDate) -> Result<(NaiveDate, NaiveDate)> {
+    let parse = |part: &str| -> Result<NaiveDate> {
+        let part = part.trim();
+        validate_date(part, today)?;
+        Ok(NaiveDate::parse_from_str(
+            &normalize_date(part, today),
+            "%Y-%m-%d",
+        )?)
+    };
+    let (start, end) = (parse(from)?, parse(to)?);
+
+    if start > end {
+        return Err(anyhow!(
+            "Invalid date range: {} is after {}",
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d")
+        ));
+    }
+    Ok((start, end))
+}
+
+/// Counts the days from `start` to `end` (inclusive) that
+/// `calculate_working_dates` would return, skipping weekends unless
+/// `skip_weekends` is false
+pub fn count_days_in_range(start: NaiveDate, end: NaiveDate, skip_weekends: bool) -> usize {
+    start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .filter(|d| !skip_weekends || d.weekday().number_from_monday() <= 5)
+        .count()
+}
+
+/// Calculates working dates from a start date for a given number of days.
+/// Weekends are skipped unless `skip_weekends` is false, in which case every
+/// calendar day is included.
+pub fn calculate_working_dates(
+    start_date: NaiveDate,
+    target_days: i64,
+    skip_weekends: bool,
+) -> Vec<NaiveDate> {
     let mut dates = Vec::new();
     let mut current_date = start_date;
     let mut days_added = 0;
@@ -71,7 +255,7 @@ pub fn calculate_working_dates(start_date: NaiveDate, target_days: i64) -> Vec<N
     while days_added < target_days {
         // Check if it's a weekday (Monday = 1, Friday = 5)
         let weekday = current_date.weekday().number_from_monday();
-        if weekday <= 5 {
+        if !skip_weekends || weekday <= 5 {
             dates.push(current_date);
             days_added += 1;
         }
@@ -83,6 +267,96 @@ pub fn calculate_working_dates(start_date: NaiveDate, target_days: i64) -> Vec<N
     dates
 }
 
+/// Rounds `value` to the nearest multiple of `nearest` (e.g. 7.4 -> 7.5 for 0.25).
+/// A non-positive `nearest` leaves the value unchanged.
+pub fn round_to_nearest(value: f64, nearest: f64) -> f64 {
+    if nearest <= 0.0 {
+        return value;
+    }
+    (value / nearest).round() * nearest
+}
+
+/// Describes a rounding increment for user-facing messages
+pub fn rounding_label(nearest: f64) -> String {
+    if nearest == 0.25 {
+        "quarter-hour".to_string()
+    } else if nearest == 0.5 {
+        "half-hour".to_string()
+    } else if nearest == 1.0 {
+        "hour".to_string()
+    } else {
+        format!("{}h", nearest)
+    }
+}
+
+/// Formats a past timestamp relative to now (e.g. "2 hours ago")
+pub fn format_relative_time(datetime: DateTime<Local>) -> String {
+    format_relative_duration(Local::now() - datetime)
+}
+
+/// Formats an elapsed duration as a human-readable relative time
+fn format_relative_duration(elapsed: chrono::Duration) -> String {
+    let plural = |n: i64, unit: &str| {
+        if n == 1 {
+            format!("1 {} ago", unit)
+        } else {
+            format!("{} {}s ago", n, unit)
+        }
+    };
+
+    let seconds = elapsed.num_seconds();
+    if seconds < 60 {
+        "just now".to_string()
+    } else if seconds < 3600 {
+        plural(elapsed.num_minutes(), "minute")
+    } else if seconds < 86_400 {
+        plural(elapsed.num_hours(), "hour")
+    } else if elapsed.num_days() < 30 {
+        plural(elapsed.num_days(), "day")
+    } else if elapsed.num_days() < 365 {
+        plural(elapsed.num_days() / 30, "month")
+    } else {
+        plural(elapsed.num_days() / 365, "year")
+    }
+}
+
+/// Parses an ISO 8601 / RFC 3339 timestamp (as returned by Monday.com) into local time
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Local>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Local))
+}
+
+/// Gets the first day of the week containing `date`, for a week starting on `first_day`
+pub fn get_week_start(date: NaiveDate, first_day: Weekday) -> NaiveDate {
+    date - chrono::Duration::days(days_since_week_start(date, first_day) as i64)
+}
+
+/// Position of `date` in its week (0-6) for a week starting on `first_day`
+pub fn days_since_week_start(date: NaiveDate, first_day: Weekday) -> u32 {
+    (7 + date.weekday().num_days_from_monday() - first_day.num_days_from_monday()) % 7
+}
+
+/// Gets the Monday of the business week (Mon-Fri) belonging to the week that
+/// contains `date`, for a week starting on `first_day`
+pub fn get_business_week_start(date: NaiveDate, first_day: Weekday) -> NaiveDate {
+    let week_start = get_week_start(date, first_day);
+    let to_monday = (7 - week_start.weekday().num_days_from_monday()) % 7;
+    week_start + chrono::Duration::days(to_monday as i64)
+}
+
+/// Parses the first day of a displayed week: "monday" or "sunday"
+pub fn parse_week_start(value: &str) -> Result<Weekday> {
+    match value.trim().to_lowercase().as_str() {
+        "monday" | "mon" => Ok(Weekday::Mon),
+        "sunday" | "sun" => Ok(Weekday::Sun),
+        _ => Err(anyhow!(
+            "Invalid week start: {}. Use monday or sunday",
+            value
+        )),
+    }
+}
+
 // ===== ACTIVITY TYPE UTILITIES =====
 
 /// Maps activity type string to numeric value
@@ -111,6 +385,9 @@ pub fn map_activity_type_to_value(activity_type: &str) -> u8 {
     }
 }
 
+/// Highest activity type index on the board's status column
+pub const MAX_ACTIVITY_VALUE: u8 = 12;
+
 /// Maps activity numeric value to string name
 pub fn map_activity_value_to_name(value: u8) -> String {
     match value {
@@ -149,6 +426,78 @@ pub fn get_year_group_id(board: &crate::monday::Board, year: &str) -> String {
     DEFAULT_GROUP_ID.to_string()
 }
 
+// ===== PROGRESS UTILITIES =====
+
+/// Renders a text progress bar such as `[██████░░░░] 3/5`
+pub fn create_progress_bar(done: usize, total: usize, width: usize) -> String {
+    let filled = (done.min(total) * width)
+        .checked_div(total)
+        .unwrap_or(width);
+    format!(
+        "[{}{}] {}/{}",
+        "█".repeat(filled),
+        "░".repeat(width - filled),
+        done,
+        total
+    )
+}
+
+/// Tracks successes and failures of a bulk operation and prints a progress
+/// line for each processed item
+#[derive(Debug)]
+pub struct ProgressTracker {
+    pub total: usize,
+    pub completed: usize,
+    pub failed: usize,
+    pub start_time: std::time::Instant,
+}
+
+impl ProgressTracker {
+    /// Width of the progress bar in characters
+    const BAR_WIDTH: usize = 20;
+
+    pub fn new(total: usize) -> Self {
+        ProgressTracker {
+            total,
+            completed: 0,
+            failed: 0,
+            start_time: std::time::Instant::now(),
+        }
+    }
+
+    /// Records a successful item and prints a progress line
+    pub fn tick_success(&mut self, message: &str) {
+        self.completed += 1;
+        println!("{} ✅ {}", self.progress_bar(), message);
+    }
+
+    /// Records a failed item and prints a progress line
+    pub fn tick_failure(&mut self, message: &str) {
+        self.failed += 1;
+        println!("{} ❌ {}", self.progress_bar(), message);
+    }
+
+    /// Returns true when every item succeeded
+    pub fn all_succeeded(&self) -> bool {
+        self.completed == self.total
+    }
+
+    /// Final totals, e.g. "3 of 5 succeeded, 2 failed in 1.2s"
+    pub fn summary(&self) -> String {
+        format!(
+            "{} of {} succeeded, {} failed in {:.1}s",
+            self.completed,
+            self.total,
+            self.failed,
+            self.start_time.elapsed().as_secs_f64()
+        )
+    }
+
+    fn progress_bar(&self) -> String {
+        create_progress_bar(self.completed + self.failed, self.total, Self::BAR_WIDTH)
+    }
+}
+
 // ===== ITEM PROCESSING UTILITIES =====
 // (Removed unused utility functions)
 
@@ -217,6 +566,114 @@ fn extract_item_date_placeholder(column_values: &[crate::monday::ColumnValue]) -
 mod tests {
     use super::*;
 
+    /// Reference date of the relative-date tests: Wednesday 2025-09-17
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, 17).unwrap()
+    }
+
+    #[test]
+    fn test_parse_date_range() {
+        let (start, end) = parse_date_range("2025-09-01/2025-09-30", today()).unwrap();
+        assert_eq!(start, NaiveDate::from_ymd_opt(2025, 9, 1).unwrap());
+        assert_eq!(end, NaiveDate::from_ymd_opt(2025, 9, 30).unwrap());
+
+        assert!(parse_date_range("2025.09.01/2025/09/01", today()).is_ok());
+        assert!(parse_date_range("2025-09-30/2025-09-01", today()).is_err());
+        assert!(parse_date_range("2025-09-01", today()).is_err());
+        assert!(parse_date_range("2025-09-01/2025-13-01", today()).is_err());
+
+        assert_eq!(
+            parse_date_pair("2025-09-15", "2025/09/19", today()).unwrap(),
+            (
+                NaiveDate::from_ymd_opt(2025, 9, 15).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 9, 19).unwrap()
+            )
+        );
+        assert!(parse_date_pair("2025-09-19", "2025-09-15", today()).is_err());
+    }
+
+    #[test]
+    fn test_count_days_in_range() {
+        let start = NaiveDate::from_ymd_opt(2025, 9, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 9, 30).unwrap();
+        assert_eq!(count_days_in_range(start, end, true), 22);
+        assert_eq!(count_days_in_range(start, end, false), 30);
+        assert_eq!(calculate_working_dates(start, 22, true).last(), Some(&end));
+    }
+
+    #[test]
+    fn test_create_progress_bar() {
+        assert_eq!(create_progress_bar(0, 4, 4), "[░░░░] 0/4");
+        assert_eq!(create_progress_bar(2, 4, 4), "[██░░] 2/4");
+        assert_eq!(create_progress_bar(4, 4, 4), "[████] 4/4");
+        assert_eq!(create_progress_bar(0, 0, 4), "[████] 0/0");
+    }
+
+    #[test]
+    fn test_progress_tracker() {
+        let mut tracker = ProgressTracker::new(3);
+        tracker.tick_success("first");
+        tracker.tick_failure("second");
+        tracker.tick_success("third");
+
+        assert_eq!(tracker.completed, 2);
+        assert_eq!(tracker.failed, 1);
+        assert!(!tracker.all_succeeded());
+        assert!(tracker
+            .summary()
+            .starts_with("2 of 3 succeeded, 1 failed in "));
+    }
+
+    #[test]
+    fn test_parse_iso_week() {
+        assert_eq!(
+            parse_iso_week("2025-W38").unwrap(),
+            NaiveDate::from_ymd_opt(2025, 9, 15).unwrap()
+        );
+        assert_eq!(
+            parse_iso_week("2025-w1").unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 30).unwrap()
+        );
+        assert!(parse_iso_week("2025-W54").is_err());
+        assert!(parse_iso_week("2025-09-15").is_err());
+    }
+
+    #[test]
+    fn test_parse_date_or_week() {
+        let monday = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+        assert_eq!(parse_date_or_week("2025-W38", today()).unwrap(), monday);
+        assert_eq!(parse_date_or_week("2025/09/15", today()).unwrap(), monday);
+        assert!(parse_date_or_week("next week", today()).is_err());
+        assert_eq!(parse_date_or_week("wednesday", today()).unwrap(), today());
+        assert_eq!(
+            parse_date_or_week("monday", today()).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 9, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resolve_date_or_week() {
+        assert_eq!(
+            resolve_date_or_week(None, Some("2025-W38".to_string())).unwrap(),
+            Some("2025-09-15".to_string())
+        );
+        assert_eq!(
+            resolve_date_or_week(Some("2025-09-17".to_string()), None).unwrap(),
+            Some("2025-09-17".to_string())
+        );
+        assert!(resolve_date_or_week(None, Some("W38".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_round_to_nearest() {
+        assert_eq!(round_to_nearest(7.4, 0.25), 7.5);
+        assert_eq!(round_to_nearest(7.1, 0.25), 7.0);
+        assert_eq!(round_to_nearest(7.3, 0.5), 7.5);
+        assert_eq!(round_to_nearest(7.4, 1.0), 7.0);
+        assert_eq!(round_to_nearest(7.4, 0.0), 7.4);
+        assert_eq!(rounding_label(0.25), "quarter-hour");
+    }
+
     #[test]
     fn test_mask_api_key() {
         assert_eq!(mask_api_key("12345678"), "1234****");
@@ -237,23 +694,105 @@ mod tests {
 
     #[test]
     fn test_validate_date() {
-        assert!(validate_date("2025-09-15").is_ok());
-        assert!(validate_date("2025.09.15").is_ok());
-        assert!(validate_date("2025/09/15").is_ok());
-        assert!(validate_date("invalid-date").is_err());
+        assert!(validate_date("2025-09-15", today()).is_ok());
+        assert!(validate_date("2025.09.15", today()).is_ok());
+        assert!(validate_date("2025/09/15", today()).is_ok());
+        assert!(validate_date("invalid-date", today()).is_err());
+        assert!(validate_date("yesterday", today()).is_ok());
+        assert!(validate_date("Last-Week-Friday", today()).is_ok());
+        assert!(validate_date("3-days-ago", today()).is_ok());
+        assert!(validate_date("saturday", today()).is_err());
+        assert!(validate_date("x-days-ago", today()).is_err());
+    }
+
+    #[test]
+    fn test_resolve_relative_date() {
+        let date = |day: u32| NaiveDate::from_ymd_opt(2025, 9, day).unwrap();
+        let wednesday = date(17);
+
+        assert_eq!(resolve_relative_date("today", wednesday), Some(wednesday));
+        assert_eq!(
+            resolve_relative_date(" YESTERDAY ", wednesday),
+            Some(date(16))
+        );
+        assert_eq!(resolve_relative_date("monday", wednesday), Some(date(15)));
+        assert_eq!(
+            resolve_relative_date("wednesday", wednesday),
+            Some(wednesday)
+        );
+        assert_eq!(resolve_relative_date("thursday", wednesday), Some(date(11)));
+        assert_eq!(
+            resolve_relative_date("last-week-monday", wednesday),
+            Some(date(8))
+        );
+        assert_eq!(
+            resolve_relative_date("last-week-friday", wednesday),
+            Some(date(12))
+        );
+        assert_eq!(
+            resolve_relative_date("10-days-ago", wednesday),
+            Some(date(7))
+        );
+        assert_eq!(
+            resolve_relative_date("0-days-ago", wednesday),
+            Some(wednesday)
+        );
+        assert_eq!(resolve_relative_date("tomorrow", wednesday), None);
+        assert_eq!(resolve_relative_date("2025-09-17", wednesday), None);
+
+        for expr in RELATIVE_DATES {
+            assert!(resolve_relative_date(expr, wednesday).is_some(), "{}", expr);
+        }
+    }
+
+    #[test]
+    fn test_resolve_relative_date_week_boundaries() {
+        let date = |day: u32| NaiveDate::from_ymd_opt(2025, 9, day).unwrap();
+        let sunday = date(21);
+        let monday = date(22);
+
+        // On Sunday the week's weekdays are still "this" week
+        assert_eq!(resolve_relative_date("monday", sunday), Some(date(15)));
+        assert_eq!(resolve_relative_date("friday", sunday), Some(date(19)));
+        assert_eq!(
+            resolve_relative_date("last-week-monday", sunday),
+            Some(date(8))
+        );
+
+        // On Monday, "friday" is three days back and last week starts 7 days back
+        assert_eq!(resolve_relative_date("monday", monday), Some(monday));
+        assert_eq!(resolve_relative_date("friday", monday), Some(date(19)));
+        assert_eq!(
+            resolve_relative_date("last-week-monday", monday),
+            Some(date(15))
+        );
+        assert_eq!(resolve_relative_date("yesterday", monday), Some(sunday));
+
+        // Across a month and a year boundary
+        let new_year = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(); // Thursday
+        assert_eq!(
+            resolve_relative_date("last-week-friday", new_year),
+            NaiveDate::from_ymd_opt(2025, 12, 26)
+        );
+        assert_eq!(
+            resolve_relative_date("monday", new_year),
+            NaiveDate::from_ymd_opt(2025, 12, 29)
+        );
     }
 
     #[test]
     fn test_normalize_date() {
-        assert_eq!(normalize_date("2025-09-15"), "2025-09-15");
-        assert_eq!(normalize_date("2025.09.15"), "2025-09-15");
-        assert_eq!(normalize_date("2025/09/15"), "2025-09-15");
+        assert_eq!(normalize_date("2025-09-15", today()), "2025-09-15");
+        assert_eq!(normalize_date("2025.09.15", today()), "2025-09-15");
+        assert_eq!(normalize_date("2025/09/15", today()), "2025-09-15");
+        assert_eq!(normalize_date("yesterday", today()), "2025-09-16");
+        assert_eq!(normalize_date("last-week-friday", today()), "2025-09-12");
     }
 
     #[test]
     fn test_calculate_working_dates() {
         let start_date = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap(); // Monday
-        let dates = calculate_working_dates(start_date, 5);
+        let dates = calculate_working_dates(start_date, 5, true);
 
         assert_eq!(dates.len(), 5);
         assert_eq!(dates[0].weekday(), Weekday::Mon);
@@ -261,9 +800,100 @@ mod tests {
 
         // Test that weekends are skipped
         let weekend_start = NaiveDate::from_ymd_opt(2025, 9, 13).unwrap(); // Saturday
-        let weekend_dates = calculate_working_dates(weekend_start, 2);
+        let weekend_dates = calculate_working_dates(weekend_start, 2, true);
         assert_eq!(weekend_dates.len(), 2);
         assert_eq!(weekend_dates[0].weekday(), Weekday::Mon); // Should skip to Monday
+
+        // Test that weekends are kept when requested
+        let all_days = calculate_working_dates(weekend_start, 3, false);
+        assert_eq!(all_days.len(), 3);
+        assert_eq!(all_days[0].weekday(), Weekday::Sat);
+        assert_eq!(all_days[1].weekday(), Weekday::Sun);
+        assert_eq!(all_days[2].weekday(), Weekday::Mon);
+    }
+
+    #[test]
+    fn test_format_relative_duration() {
+        use chrono::Duration;
+        assert_eq!(format_relative_duration(Duration::seconds(-5)), "just now");
+        assert_eq!(format_relative_duration(Duration::seconds(30)), "just now");
+        assert_eq!(
+            format_relative_duration(Duration::minutes(1)),
+            "1 minute ago"
+        );
+        assert_eq!(
+            format_relative_duration(Duration::minutes(45)),
+            "45 minutes ago"
+        );
+        assert_eq!(format_relative_duration(Duration::hours(2)), "2 hours ago");
+        assert_eq!(format_relative_duration(Duration::days(1)), "1 day ago");
+        assert_eq!(format_relative_duration(Duration::days(65)), "2 months ago");
+        assert_eq!(format_relative_duration(Duration::days(800)), "2 years ago");
+    }
+
+    #[test]
+    fn test_format_relative_time_and_parse_timestamp() {
+        let two_hours_ago = Local::now() - chrono::Duration::hours(2);
+        assert_eq!(format_relative_time(two_hours_ago), "2 hours ago");
+
+        assert!(parse_timestamp("2025-09-15T10:30:00Z").is_some());
+        assert!(parse_timestamp("2025-09-15").is_none());
+    }
+
+    #[test]
+    fn test_get_week_start() {
+        let wednesday = NaiveDate::from_ymd_opt(2025, 9, 17).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2025, 9, 21).unwrap();
+
+        assert_eq!(
+            get_week_start(wednesday, Weekday::Mon),
+            NaiveDate::from_ymd_opt(2025, 9, 15).unwrap()
+        );
+        assert_eq!(
+            get_week_start(wednesday, Weekday::Sun),
+            NaiveDate::from_ymd_opt(2025, 9, 14).unwrap()
+        );
+        assert_eq!(get_week_start(sunday, Weekday::Sun), sunday);
+        assert_eq!(
+            get_week_start(sunday, Weekday::Mon),
+            NaiveDate::from_ymd_opt(2025, 9, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_get_business_week_start() {
+        let sunday = NaiveDate::from_ymd_opt(2025, 9, 21).unwrap();
+        let saturday = NaiveDate::from_ymd_opt(2025, 9, 20).unwrap();
+
+        // Monday-first: Sunday belongs to the week that started on Sep 15
+        assert_eq!(
+            get_business_week_start(sunday, Weekday::Mon),
+            NaiveDate::from_ymd_opt(2025, 9, 15).unwrap()
+        );
+        // Sunday-first: Sunday starts a new week whose business days begin Sep 22
+        assert_eq!(
+            get_business_week_start(sunday, Weekday::Sun),
+            NaiveDate::from_ymd_opt(2025, 9, 22).unwrap()
+        );
+        // Saturday is the last day of a Sunday-first week
+        assert_eq!(
+            get_business_week_start(saturday, Weekday::Sun),
+            NaiveDate::from_ymd_opt(2025, 9, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_days_since_week_start() {
+        let sunday = NaiveDate::from_ymd_opt(2025, 9, 21).unwrap();
+        assert_eq!(days_since_week_start(sunday, Weekday::Sun), 0);
+        assert_eq!(days_since_week_start(sunday, Weekday::Mon), 6);
+    }
+
+    #[test]
+    fn test_parse_week_start() {
+        assert_eq!(parse_week_start("Sunday").unwrap(), Weekday::Sun);
+        assert_eq!(parse_week_start("mon").unwrap(), Weekday::Mon);
+        assert!(parse_week_start("friday").is_err());
     }
 
     #[test]
@@ -283,9 +913,17 @@ mod tests {
     }
 
     #[test]
-    fn test_get_current_year() {
-        let year = get_current_year();
-        let current_year = Local::now().year();
-        assert_eq!(year, current_year);
+    fn test_today_in_workspace_tz() {
+        assert_eq!(
+            today_in_workspace_tz(Some(chrono_tz::UTC)),
+            Utc::now().date_naive()
+        );
+        assert_eq!(today_in_workspace_tz(None), Local::now().date_naive());
+
+        // Zones 26 hours apart never share the same date
+        assert_ne!(
+            today_in_workspace_tz(Some(chrono_tz::Pacific::Kiritimati)),
+            today_in_workspace_tz(Some(chrono_tz::Etc::GMTPlus12))
+        );
     }
 }
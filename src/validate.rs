@@ -0,0 +1,271 @@
+//! `claim validate`: reports days with missing or inconsistent entries
+
+use crate::config::Config;
+use crate::export::query_user_items_in_range;
+use crate::interactive::app::ClaimEntry;
+use crate::monday::{MondayClient, MondayUser};
+use crate::utils;
+use anyhow::{anyhow, Result};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Most hours that fit in one day
+const HOURS_IN_DAY: f64 = 24.0;
+
+/// A problem found in the entries of one day
+#[derive(Debug, Clone, PartialEq)]
+enum Violation {
+    /// A working day without any hours
+    MissingHours(NaiveDate),
+    /// A working day whose total is outside `expected_daily_hours`
+    OutsideTarget(NaiveDate, f64),
+    /// A day whose entries add up to more than 24 hours
+    OverFullDay(NaiveDate, f64),
+    /// Several entries with the same date, customer and work item
+    Duplicate {
+        date: NaiveDate,
+        customer: String,
+        work_item: String,
+        count: usize,
+    },
+}
+
+impl Violation {
+    fn date(&self) -> NaiveDate {
+        match self {
+            Violation::MissingHours(date)
+            | Violation::OutsideTarget(date, _)
+            | Violation::OverFullDay(date, _)
+            | Violation::Duplicate { date, .. } => *date,
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::MissingHours(date) => {
+                write!(f, "{} {}: no hours logged", date, date.weekday())
+            }
+            Violation::OutsideTarget(date, hours) => {
+                write!(f, "{} {}: {}h logged", date, date.weekday(), hours)
+            }
+            Violation::OverFullDay(date, hours) => write!(
+                f,
+                "{} {}: {}h logged, more than {}h in one day",
+                date,
+                date.weekday(),
+                hours,
+                HOURS_IN_DAY
+            ),
+            Violation::Duplicate {
+                date,
+                customer,
+                work_item,
+                count,
+            } => write!(
+                f,
+                "{} {}: {} entries for {} / {}",
+                date,
+                date.weekday(),
+                count,
+                customer,
+                work_item
+            ),
+        }
+    }
+}
+
+/// Handles `claim validate [--from DATE] [--to DATE]`. Returns true when
+/// violations were found.
+pub async fn handle_validate_command(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    from: Option<String>,
+    to: Option<String>,
+    verbose: bool,
+) -> Result<bool> {
+    let (from, to) = validate_range(config.today(), from, to)?;
+    let dates: Vec<NaiveDate> = from.iter_days().take_while(|d| *d <= to).collect();
+
+    println!("Validating entries from {} to {}...", from, to);
+    let items = query_user_items_in_range(client, config, user, from, to, verbose).await?;
+    let entries: Vec<ClaimEntry> = items.iter().filter_map(ClaimEntry::from_item).collect();
+
+    let violations = find_violations(&entries, &dates, config.expected_daily_hours);
+    display_violations(&violations, config.expected_daily_hours);
+    Ok(!violations.is_empty())
+}
+
+/// First and last day to validate; the current week up to today by default
+fn validate_range(
+    today: NaiveDate,
+    from: Option<String>,
+    to: Option<String>,
+) -> Result<(NaiveDate, NaiveDate)> {
+    let parse = |date: &str| -> Result<NaiveDate> {
+        utils::validate_date(date, today)?;
+        Ok(NaiveDate::parse_from_str(
+            &utils::normalize_date(date, today),
+            "%Y-%m-%d",
+        )?)
+    };
+    let from = match from {
+        Some(from) => parse(&from)?,
+        None => utils::get_week_start(today, Weekday::Mon),
+    };
+    let to = match to {
+        Some(to) => parse(&to)?,
+        None => today.max(from).min(from + Duration::days(6)),
+    };
+    if to < from {
+        return Err(anyhow!("--to {} is before --from {}", to, from));
+    }
+    Ok((from, to))
+}
+
+/// Checks every day of `dates`: working days need hours within
+/// `expected_hours`, no day may exceed 24 hours, and no date, customer and
+/// work item may be logged twice
+fn find_violations(
+    entries: &[ClaimEntry],
+    dates: &[NaiveDate],
+    expected_hours: [f64; 2],
+) -> Vec<Violation> {
+    let mut totals: HashMap<NaiveDate, f64> = HashMap::new();
+    let mut counts: BTreeMap<(NaiveDate, &str, &str), usize> = BTreeMap::new();
+    for entry in entries {
+        *totals.entry(entry.date).or_default() += entry.hours;
+        *counts
+            .entry((entry.date, &entry.customer, &entry.work_item))
+            .or_default() += 1;
+    }
+
+    let mut violations = Vec::new();
+    for date in dates {
+        let total = totals.get(date).copied().unwrap_or(0.0);
+        let working_day = !matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+        if total > HOURS_IN_DAY {
+            violations.push(Violation::OverFullDay(*date, total));
+        } else if working_day && total == 0.0 {
+            violations.push(Violation::MissingHours(*date));
+        } else if working_day && (total < expected_hours[0] || total > expected_hours[1]) {
+            violations.push(Violation::OutsideTarget(*date, total));
+        }
+    }
+    violations.extend(counts.into_iter().filter(|(_, count)| *count > 1).map(
+        |((date, customer, work_item), count)| Violation::Duplicate {
+            date,
+            customer: customer.to_string(),
+            work_item: work_item.to_string(),
+            count,
+        },
+    ));
+    violations.sort_by_key(Violation::date);
+    violations
+}
+
+fn display_violations(violations: &[Violation], expected_hours: [f64; 2]) {
+    if violations.is_empty() {
+        println!("\n✅ No problems found");
+        return;
+    }
+
+    println!(
+        "\n=== VALIDATION: {} problem(s) found ===",
+        violations.len()
+    );
+    for violation in violations {
+        match violation {
+            Violation::OutsideTarget(..) => println!(
+                "  ⚠️  {} (expected {}-{}h)",
+                violation, expected_hours[0], expected_hours[1]
+            ),
+            _ => println!("  ❌ {}", violation),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, day).unwrap()
+    }
+
+    fn entry(day: u32, customer: &str, hours: f64) -> ClaimEntry {
+        ClaimEntry {
+            id: String::new(),
+            date: date(day),
+            activity_type: "billable".to_string(),
+            activity_value: 1,
+            customer: customer.to_string(),
+            work_item: "WI-1".to_string(),
+            hours,
+            comment: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_range() {
+        // Wednesday 2025-09-17
+        let today = date(17);
+        assert_eq!(
+            validate_range(today, None, None).unwrap(),
+            (date(15), date(17))
+        );
+        assert_eq!(
+            validate_range(today, Some("2025-09-01".into()), None).unwrap(),
+            (date(1), date(7))
+        );
+        assert_eq!(
+            validate_range(today, Some("2025.09.01".into()), Some("2025/09/30".into())).unwrap(),
+            (date(1), date(30))
+        );
+        assert!(
+            validate_range(today, Some("2025-09-10".into()), Some("2025-09-01".into())).is_err()
+        );
+        assert!(validate_range(today, Some("someday".into()), None).is_err());
+    }
+
+    #[test]
+    fn test_find_violations() {
+        let entries = vec![
+            entry(15, "ACME", 8.0),
+            entry(16, "ACME", 4.0),
+            entry(17, "ACME", 5.0),
+            entry(17, "ACME", 20.0),
+            entry(18, "ACME", 4.0),
+            entry(18, "Globex", 4.5),
+            entry(20, "ACME", 2.0),
+        ];
+        // Monday 15 to Sunday 21
+        let dates: Vec<NaiveDate> = (15..=21).map(date).collect();
+
+        assert_eq!(
+            find_violations(&entries, &dates, [7.0, 9.0]),
+            [
+                Violation::OutsideTarget(date(16), 4.0),
+                Violation::OverFullDay(date(17), 25.0),
+                Violation::Duplicate {
+                    date: date(17),
+                    customer: "ACME".to_string(),
+                    work_item: "WI-1".to_string(),
+                    count: 2,
+                },
+                Violation::MissingHours(date(19)),
+            ]
+        );
+
+        let entries = vec![entry(15, "ACME", 7.5)];
+        assert!(find_violations(&entries, &[date(15)], [7.0, 9.0]).is_empty());
+        assert_eq!(
+            find_violations(&entries, &[date(15)], [8.0, 8.0]),
+            [Violation::OutsideTarget(date(15), 7.5)]
+        );
+    }
+}
@@ -0,0 +1,472 @@
+//! Week templates: capture the entries of a week and re-create the same
+//! pattern in another week.
+
+use crate::add::create_items_on_monday;
+use crate::config::Config;
+use crate::monday::{Item, MondayClient, MondayUser};
+use crate::query::{
+    extract_column_value, extract_comment_value, extract_item_date, extract_status_value,
+};
+use crate::utils::get_week_start;
+use crate::{get_year_group_id, map_activity_type_to_value, normalize_date, validate_date};
+use anyhow::{anyhow, Result};
+use chrono::prelude::*;
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// A single entry of a week template, relative to the start of the week
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TemplateEntry {
+    pub activity_type: String,
+    pub customer: String,
+    pub work_item: String,
+    pub hours: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Days from Monday (0 = Monday, 4 = Friday)
+    #[serde(alias = "day_offset")]
+    pub day_of_week: u32,
+}
+
+/// A named week template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeekTemplate {
+    /// Monday of the week the template was captured from
+    pub source_week: String,
+    pub entries: Vec<TemplateEntry>,
+}
+
+/// All saved week templates, keyed by name
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WeekTemplateStore {
+    pub templates: BTreeMap<String, WeekTemplate>,
+}
+
+impl WeekTemplateStore {
+    /// Get the week templates file path
+    pub fn get_path() -> Option<PathBuf> {
+        ProjectDirs::from("com", "yourname", "claim")
+            .map(|proj_dirs| proj_dirs.config_dir().join("week_templates.toml"))
+    }
+
+    /// Load templates from disk (empty store when the file does not exist).
+    /// Templates saved as `week_templates.json` by older versions are read
+    /// until the store is saved again.
+    pub fn load() -> Result<Self> {
+        let path =
+            Self::get_path().ok_or_else(|| anyhow!("Could not determine config directory"))?;
+
+        if !path.exists() {
+            let legacy = path.with_extension("json");
+            if !legacy.exists() {
+                return Ok(Self::default());
+            }
+            let data = fs::read_to_string(&legacy)
+                .map_err(|e| anyhow!("Failed to read week templates file: {}", e))?;
+            return serde_json::from_str(&data)
+                .map_err(|e| anyhow!("Failed to parse week templates: {}", e));
+        }
+
+        let data = fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Failed to read week templates file: {}", e))?;
+
+        Self::from_toml(&data)
+    }
+
+    fn from_toml(data: &str) -> Result<Self> {
+        toml::from_str(data).map_err(|e| anyhow!("Failed to parse week templates: {}", e))
+    }
+
+    fn to_toml(&self) -> Result<String> {
+        toml::to_string_pretty(self)
+            .map_err(|e| anyhow!("Failed to serialize week templates: {}", e))
+    }
+
+    /// Save templates to disk
+    pub fn save(&self) -> Result<()> {
+        let path =
+            Self::get_path().ok_or_else(|| anyhow!("Could not determine config directory"))?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| anyhow!("Failed to create config directory: {}", e))?;
+        }
+
+        let data = self.to_toml()?;
+
+        fs::write(&path, data).map_err(|e| anyhow!("Failed to write week templates file: {}", e))
+    }
+}
+
+fn parse_date_arg(date: &str, today: NaiveDate) -> Result<NaiveDate> {
+    validate_date(date, today)?;
+    Ok(NaiveDate::parse_from_str(
+        &normalize_date(date, today),
+        "%Y-%m-%d",
+    )?)
+}
+
+/// Builds template entries from the items of the week starting at `monday`
+fn entries_from_items(items: &[Item], monday: NaiveDate) -> Vec<TemplateEntry> {
+    let mut entries: Vec<TemplateEntry> = items
+        .iter()
+        .filter_map(|item| {
+            let date = extract_item_date(item)
+                .and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok())?;
+            let day_of_week = (date - monday).num_days();
+            if !(0..5).contains(&day_of_week) {
+                return None;
+            }
+            let comment = extract_comment_value(item);
+
+            Some(TemplateEntry {
+                activity_type: extract_status_value(item),
+                customer: extract_column_value(item, "text__1"),
+                work_item: extract_column_value(item, "text8__1"),
+                hours: extract_column_value(item, "numbers__1")
+                    .trim_matches('"')
+                    .parse::<f64>()
+                    .unwrap_or(0.0),
+                comment: (!comment.is_empty()).then_some(comment),
+                day_of_week: day_of_week as u32,
+            })
+        })
+        .collect();
+
+    entries.sort_by(|a, b| {
+        a.day_of_week
+            .cmp(&b.day_of_week)
+            .then_with(|| a.customer.cmp(&b.customer))
+            .then_with(|| a.work_item.cmp(&b.work_item))
+    });
+    entries
+}
+
+/// Groups identical template entries so that each group can be created with a
+/// single call across several dates. Only days before `days` are kept.
+fn plan_template(
+    template: &WeekTemplate,
+    monday: NaiveDate,
+    days: u32,
+) -> Vec<(TemplateEntry, Vec<NaiveDate>)> {
+    let mut groups: Vec<(TemplateEntry, Vec<NaiveDate>)> = Vec::new();
+
+    for entry in template.entries.iter().filter(|e| e.day_of_week < days) {
+        let date = monday + chrono::Duration::days(entry.day_of_week as i64);
+        let same = |e: &TemplateEntry| {
+            e.activity_type == entry.activity_type
+                && e.customer == entry.customer
+                && e.work_item == entry.work_item
+                && e.hours == entry.hours
+                && e.comment == entry.comment
+        };
+
+        match groups.iter_mut().find(|(e, _)| same(e)) {
+            Some((_, dates)) => dates.push(date),
+            None => groups.push((entry.clone(), vec![date])),
+        }
+    }
+
+    groups
+}
+
+/// Captures the week containing `from` (the current week by default) as
+/// template `name`
+pub async fn handle_save(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    name: &str,
+    from: Option<&str>,
+    verbose: bool,
+) -> Result<()> {
+    let board_id = config.board_id.as_str();
+    let date = match from {
+        Some(from) => parse_date_arg(from, config.today())?,
+        None => config.today(),
+    };
+    let monday = get_week_start(date, Weekday::Mon);
+    let dates: Vec<String> = (0..5)
+        .map(|i| {
+            (monday + chrono::Duration::days(i))
+                .format("%Y-%m-%d")
+                .to_string()
+        })
+        .collect();
+
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let group_id = get_year_group_id(&board, &monday.year().to_string());
+    let items = client
+        .query_items_with_filters(board_id, &group_id, user.id, &dates, 500, verbose)
+        .await?;
+
+    let entries = entries_from_items(&items, monday);
+    if entries.is_empty() {
+        return Err(anyhow!(
+            "No entries found in the week of {}",
+            monday.format("%Y-%m-%d")
+        ));
+    }
+
+    let mut store = WeekTemplateStore::load()?;
+    let replaced = store
+        .templates
+        .insert(
+            name.to_string(),
+            WeekTemplate {
+                source_week: monday.format("%Y-%m-%d").to_string(),
+                entries: entries.clone(),
+            },
+        )
+        .is_some();
+    store.save()?;
+
+    println!(
+        "✅ {} template '{}' with {} entries from the week of {}",
+        if replaced { "Updated" } else { "Saved" },
+        name,
+        entries.len(),
+        monday.format("%Y-%m-%d")
+    );
+    print_entries(&entries);
+    Ok(())
+}
+
+/// Re-creates template `name` in the week containing `to`
+#[allow(clippy::too_many_arguments)]
+pub async fn handle_apply(
+    client: &MondayClient,
+    user: &MondayUser,
+    config: &Config,
+    name: &str,
+    to: &str,
+    days: u32,
+    yes: bool,
+    verbose: bool,
+) -> Result<()> {
+    let board_id = config.board_id.as_str();
+    let store = WeekTemplateStore::load()?;
+    let template = store.templates.get(name).ok_or_else(|| {
+        anyhow!(
+            "Week template '{}' not found. Use `claim week-template list` to see saved templates.",
+            name
+        )
+    })?;
+
+    let monday = get_week_start(parse_date_arg(to, config.today())?, Weekday::Mon);
+    config.ensure_week_unlocked(monday)?;
+    let plan = plan_template(template, monday, days.min(5));
+    let total: usize = plan.iter().map(|(_, dates)| dates.len()).sum();
+
+    if total == 0 {
+        println!(
+            "Nothing to apply: template '{}' has no entries for the selected days",
+            name
+        );
+        return Ok(());
+    }
+
+    println!(
+        "\n=== Apply week template '{}' to the week of {} ===",
+        name,
+        monday.format("%Y-%m-%d")
+    );
+    for (entry, dates) in &plan {
+        for date in dates {
+            println!(
+                "  {} {:<12} {:<20} {:<15} {}h",
+                date.format("%a %Y-%m-%d"),
+                entry.activity_type,
+                entry.customer,
+                entry.work_item,
+                entry.hours
+            );
+        }
+    }
+
+    if !yes {
+        println!("\n🚀 Ready to create {} item(s) on Monday.com", total);
+        println!("Do you want to proceed? (y/N)");
+
+        let mut confirmation = String::new();
+        io::stdin().read_line(&mut confirmation)?;
+
+        if confirmation.trim().to_lowercase() != "y" {
+            println!("Operation cancelled.");
+            return Ok(());
+        }
+    }
+
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let group_id = get_year_group_id(&board, &monday.year().to_string());
+
+    for (entry, dates) in &plan {
+        let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+        create_items_on_monday(
+            client,
+            dates,
+            map_activity_type_to_value(&entry.activity_type),
+            &non_empty(&entry.customer),
+            &non_empty(&entry.work_item),
+            &entry.comment,
+            Some(entry.hours),
+            user.id,
+            &user.name,
+            board_id,
+            &group_id,
+            verbose,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Lists all saved week templates
+pub fn handle_list() -> Result<()> {
+    let store = WeekTemplateStore::load()?;
+
+    if store.templates.is_empty() {
+        println!("No week templates saved. Create one with `claim template save NAME`.");
+        return Ok(());
+    }
+
+    for (name, template) in &store.templates {
+        let hours: f64 = template.entries.iter().map(|e| e.hours).sum();
+        println!(
+            "\n📋 {} (from week of {}, {} entries, {:.1}h)",
+            name,
+            template.source_week,
+            template.entries.len(),
+            hours
+        );
+        print_entries(&template.entries);
+    }
+    Ok(())
+}
+
+fn print_entries(entries: &[TemplateEntry]) {
+    const DAYS: [&str; 5] = ["Mon", "Tue", "Wed", "Thu", "Fri"];
+    for entry in entries {
+        println!(
+            "  {:<4} {:<12} {:<20} {:<15} {}h",
+            DAYS.get(entry.day_of_week as usize).unwrap_or(&"?"),
+            entry.activity_type,
+            entry.customer,
+            entry.work_item,
+            entry.hours
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monday::ColumnValue;
+
+    fn entry(day_of_week: u32, customer: &str, hours: f64) -> TemplateEntry {
+        TemplateEntry {
+            activity_type: "billable".to_string(),
+            customer: customer.to_string(),
+            work_item: "WI-1".to_string(),
+            hours,
+            comment: None,
+            day_of_week,
+        }
+    }
+
+    fn item(date: &str, customer: &str, hours: &str) -> Item {
+        let column = |id: &str, value: String| ColumnValue {
+            id: Some(id.to_string()),
+            value: Some(value),
+            ..Default::default()
+        };
+        Item {
+            column_values: vec![
+                column("date4", format!(r#"{{"date":"{}"}}"#, date)),
+                column("status", r#"{"index":1}"#.to_string()),
+                column("text__1", format!("\"{}\"", customer)),
+                column("text8__1", "\"WI-1\"".to_string()),
+                column("numbers__1", format!("\"{}\"", hours)),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_entries_from_items() {
+        let monday = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+        let items = vec![
+            item("2025-09-17", "ACME", "8"),
+            item("2025-09-15", "ACME", "4"),
+            item("2025-09-22", "OTHER", "8"), // next week, ignored
+        ];
+
+        let entries = entries_from_items(&items, monday);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], entry(0, "ACME", 4.0));
+        assert_eq!(entries[1], entry(2, "ACME", 8.0));
+    }
+
+    #[test]
+    fn test_plan_template_groups_identical_entries() {
+        let template = WeekTemplate {
+            source_week: "2025-09-15".to_string(),
+            entries: vec![
+                entry(0, "ACME", 8.0),
+                entry(1, "ACME", 8.0),
+                entry(2, "OTHER", 8.0),
+                entry(4, "ACME", 8.0),
+            ],
+        };
+        let monday = NaiveDate::from_ymd_opt(2025, 10, 6).unwrap();
+
+        let plan = plan_template(&template, monday, 5);
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].1.len(), 3);
+        assert_eq!(plan[0].1[2], NaiveDate::from_ymd_opt(2025, 10, 10).unwrap());
+        assert_eq!(plan[1].0.customer, "OTHER");
+
+        // Restricting to the first two days drops Wednesday and Friday
+        let plan = plan_template(&template, monday, 2);
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].1.len(), 2);
+    }
+
+    #[test]
+    fn test_store_toml_round_trip() {
+        let mut store = WeekTemplateStore::default();
+        let mut friday = entry(4, "Internal", 8.0);
+        friday.activity_type = "work_reduction".to_string();
+        friday.comment = Some("Overhead, team meeting".to_string());
+        store.templates.insert(
+            "standard".to_string(),
+            WeekTemplate {
+                source_week: "2025-09-15".to_string(),
+                entries: vec![entry(0, "ACME", 8.0), friday],
+            },
+        );
+
+        let data = store.to_toml().unwrap();
+        assert!(data.contains("day_of_week = 4"));
+        assert!(data.contains("comment = \"Overhead, team meeting\""));
+        let loaded = WeekTemplateStore::from_toml(&data).unwrap();
+        assert_eq!(
+            loaded.templates["standard"].entries,
+            store.templates["standard"].entries
+        );
+
+        // Files written by older versions used `day_offset` and no comment
+        let legacy: WeekTemplateStore = serde_json::from_str(
+            r#"{"templates":{"old":{"source_week":"2025-09-15","entries":[
+                {"day_offset":2,"activity_type":"billable","customer":"ACME",
+                 "work_item":"WI-1","hours":8.0}]}}}"#,
+        )
+        .unwrap();
+        assert_eq!(legacy.templates["old"].entries, [entry(2, "ACME", 8.0)]);
+    }
+}
@@ -0,0 +1,129 @@
+//! `claim whoami`: the authenticated user and the workspace the tool talks to
+
+use crate::cache::EntryCache;
+use crate::config::Config;
+use crate::monday::{Board, MondayClient};
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+/// Everything `claim whoami` reports
+#[derive(Debug, Serialize)]
+struct WhoAmI {
+    user_id: i64,
+    user_name: String,
+    user_email: String,
+    board_id: String,
+    board_name: Option<String>,
+    group_count: usize,
+    year: String,
+    year_group_id: Option<String>,
+    cache_entries: usize,
+    cache_last_refresh: Option<String>,
+    config_path: Option<String>,
+}
+
+/// Handles `claim whoami`
+pub async fn handle_whoami_command(json: bool, verbose: bool) -> Result<()> {
+    let config = Config::load()
+        .map_err(|e| anyhow!("{}. Run `claim` once to set up your API key first.", e))?;
+    let client = MondayClient::new(config.api_key.clone()).with_retry_policy(config.retry_policy());
+    let board_id = config.board_id.as_str();
+
+    let user = client.get_current_user_verbose(verbose).await?;
+    let board = client.get_board_with_groups(board_id, verbose).await?;
+    let year = config.today().format("%Y").to_string();
+
+    let cache = EntryCache::load().ok();
+    let info = WhoAmI {
+        user_id: user.id,
+        user_name: user.name,
+        user_email: user.email,
+        board_id: board_id.to_string(),
+        board_name: board.name.clone(),
+        group_count: board.groups.as_ref().map_or(0, Vec::len),
+        year_group_id: find_year_group(&board, &year),
+        year,
+        cache_entries: cache
+            .as_ref()
+            .and_then(|c| c.entries.get(&user.id))
+            .map_or(0, Vec::len),
+        cache_last_refresh: cache.map(|c| c.last_updated),
+        config_path: Config::get_config_path().map(|p| p.display().to_string()),
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+    } else {
+        display_whoami(&info);
+    }
+    Ok(())
+}
+
+/// Id of the group titled `year`, without falling back to a default group
+fn find_year_group(board: &Board, year: &str) -> Option<String> {
+    board
+        .groups
+        .as_ref()?
+        .iter()
+        .find(|group| group.title == year)
+        .map(|group| group.id.clone())
+}
+
+fn display_whoami(info: &WhoAmI) {
+    let unknown = || "<unknown>".to_string();
+
+    println!("\n=== WHOAMI ===");
+    println!("User ID:            {}", info.user_id);
+    println!("Name:               {}", info.user_name);
+    println!("Email:              {}", info.user_email);
+    println!(
+        "Board:              {} ({})",
+        info.board_name.clone().unwrap_or_else(unknown),
+        info.board_id
+    );
+    println!("Groups:             {}", info.group_count);
+    println!(
+        "Group for {}:     {}",
+        info.year,
+        info.year_group_id
+            .clone()
+            .unwrap_or_else(|| "not found".to_string())
+    );
+    println!("Cached entries:     {}", info.cache_entries);
+    println!(
+        "Cache refreshed:    {}",
+        info.cache_last_refresh
+            .clone()
+            .unwrap_or_else(|| "never".to_string())
+    );
+    println!(
+        "Config file:        {}",
+        info.config_path.clone().unwrap_or_else(unknown)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monday::Group;
+
+    #[test]
+    fn test_find_year_group() {
+        let board = Board {
+            id: Some("1".to_string()),
+            name: Some("Claims".to_string()),
+            columns: Vec::new(),
+            groups: Some(vec![Group {
+                id: "group_2025".to_string(),
+                title: "2025".to_string(),
+                items_page: None,
+            }]),
+        };
+
+        assert_eq!(
+            find_year_group(&board, "2025"),
+            Some("group_2025".to_string())
+        );
+        assert_eq!(find_year_group(&board, "2026"), None);
+    }
+}
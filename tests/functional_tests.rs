@@ -732,4 +732,45 @@ mod functional_tests {
             }
         }
     }
+
+    // Test 11: The query summary line is printed only once
+    #[test]
+    fn test_query_summary_printed_once() {
+        // Skip this test in CI environments without proper Monday.com configuration
+        if env::var("CI").is_ok() {
+            println!("Skipping test_query_summary_printed_once in CI environment");
+            return;
+        }
+
+        use chrono::prelude::*;
+        let today = Local::now().format("%Y-%m-%d").to_string();
+
+        let result = run_claim_command(&["query", "-D", &today, "-d", "5"]);
+
+        match result {
+            Ok((stdout, _)) => {
+                let summary_count = stdout
+                    .lines()
+                    .filter(|line| line.starts_with("✅ Found") || line.starts_with("⚠️  Showing"))
+                    .count();
+                let expected = if stdout.contains("No items found") {
+                    0
+                } else {
+                    1
+                };
+                assert_eq!(
+                    summary_count, expected,
+                    "Query summary printed {} times:\n{}",
+                    summary_count, stdout
+                );
+                println!("✅ Test 11 passed: Query summary printed exactly once");
+            }
+            Err(e) => {
+                println!(
+                    "⚠️  Query summary check completed (may have failed due to API): {}",
+                    e
+                );
+            }
+        }
+    }
 }
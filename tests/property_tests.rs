@@ -0,0 +1,62 @@
+// Property-based tests for the date and string utilities
+//
+// claim is a binary crate, so the utility module is compiled into this test
+// directly (together with the modules it refers to) instead of being imported.
+
+#[allow(dead_code)]
+#[path = "../src/error.rs"]
+mod error;
+#[allow(dead_code)]
+#[path = "../src/monday.rs"]
+mod monday;
+#[allow(dead_code)]
+#[path = "../src/utils.rs"]
+mod utils;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use proptest::prelude::*;
+use utils::{
+    calculate_working_dates, map_activity_type_to_value, map_activity_value_to_name, mask_api_key,
+    normalize_date, validate_date, MAX_ACTIVITY_VALUE,
+};
+
+/// Any date between 1900-01-01 and roughly 2173
+fn any_date() -> impl Strategy<Value = NaiveDate> {
+    (0i64..100_000)
+        .prop_map(|offset| NaiveDate::from_ymd_opt(1900, 1, 1).unwrap() + Duration::days(offset))
+}
+
+proptest! {
+    #[test]
+    fn working_dates_returns_exactly_n_dates(start in any_date(), n in 0i64..400) {
+        prop_assert_eq!(calculate_working_dates(start, n, true).len() as i64, n);
+        prop_assert_eq!(calculate_working_dates(start, n, false).len() as i64, n);
+    }
+
+    #[test]
+    fn working_dates_skip_weekends(start in any_date(), n in 0i64..400) {
+        for date in calculate_working_dates(start, n, true) {
+            prop_assert!(!matches!(date.weekday(), Weekday::Sat | Weekday::Sun), "{}", date);
+        }
+    }
+
+    #[test]
+    fn normalize_date_roundtrips_valid_dates(
+        date in any_date(),
+        format in prop::sample::select(vec!["%Y-%m-%d", "%Y.%m.%d", "%Y/%m/%d"]),
+    ) {
+        let input = date.format(format).to_string();
+        prop_assert!(validate_date(&input, date).is_ok());
+        prop_assert_eq!(normalize_date(&input, date), date.format("%Y-%m-%d").to_string());
+    }
+
+    #[test]
+    fn mask_api_key_keeps_the_length(key in "[!-~]{0,64}") {
+        prop_assert_eq!(mask_api_key(&key).len(), key.len());
+    }
+
+    #[test]
+    fn activity_value_name_roundtrips(value in 0..=MAX_ACTIVITY_VALUE) {
+        prop_assert_eq!(map_activity_type_to_value(&map_activity_value_to_name(value)), value);
+    }
+}